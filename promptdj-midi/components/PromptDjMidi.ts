@@ -662,6 +662,29 @@ export class PromptDjMidi extends LitElement {
     this.dispatchEvent(new CustomEvent('play', { bubbles: true }));
   }
 
+  /** Weighted prompts, strongest first, as one comma-separated string. */
+  public composePrompt(): string {
+    return [...this.prompts.values()]
+      .filter((p) => p.weight > 0 && p.text.trim() && !this.filteredPrompts.has(p.text))
+      .sort((a, b) => b.weight - a.weight)
+      .map((p) => p.text.trim())
+      .join(', ');
+  }
+
+  /** Hand the composed prompt to the native app, which writes it into the active preset. */
+  private usePromptInPreset() {
+    const prompt = this.composePrompt();
+    const t = LOCALES[this.lang as Lang];
+    if (!prompt) {
+      this.dispatchEvent(new CustomEvent('error', { detail: t.use_prompt_empty_toast }));
+      return;
+    }
+    if ((window as any).ipc) {
+      (window as any).ipc.postMessage('use_prompt:' + prompt);
+    }
+    this.dispatchEvent(new CustomEvent('notice', { detail: t.use_prompt_toast }));
+  }
+
   public addFilteredPrompt(prompt: string) {
     this.filteredPrompts = new Set([...this.filteredPrompts, prompt]);
   }
@@ -951,6 +974,11 @@ export class PromptDjMidi extends LitElement {
                <span class="material-symbols-rounded">${this.isRecording ? 'stop' : 'radio_button_checked'}</span>
              </button>
 
+             <!-- Use as preset prompt -->
+             <button class="mini-btn" @click=${() => this.usePromptInPreset()} title=${LOCALES[this.lang as Lang].use_prompt_tooltip}>
+               <span class="material-symbols-rounded">input</span>
+             </button>
+
              <!-- Reset -->
              <button class="mini-btn" @click=${() => this.resetAll()} title=${LOCALES[this.lang as Lang].reset_tooltip}>
                <span class="material-symbols-rounded">restart_alt</span>
@@ -60,6 +60,10 @@ function main() {
     toastMessage.show(error);
   }));
 
+  pdjMidi.addEventListener('notice', ((e: Event) => {
+    toastMessage.show((e as CustomEvent<string>).detail);
+  }));
+
   // Wire up UI buttons from PromptDjMidi
   pdjMidi.addEventListener('start-recording', () => startRecording());
   pdjMidi.addEventListener('stop-recording', () => stopRecording());
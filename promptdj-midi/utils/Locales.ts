@@ -14,6 +14,9 @@ export const LOCALES = {
         stop_tooltip: 'Stop Recording',
         midi_tooltip: 'MIDI Settings',
         reset_tooltip: 'Reset All Weights',
+        use_prompt_tooltip: 'Use as preset prompt',
+        use_prompt_toast: 'Prompt saved to the active preset.',
+        use_prompt_empty_toast: 'Raise at least one prompt weight first.',
         no_sound_toast: 'No sound detected in recording.',
         too_short_toast: 'Recording too short or silent.',
         api_key_toast: 'Please set your Gemini API key in the main app first.',
@@ -36,6 +39,9 @@ export const LOCALES = {
         stop_tooltip: 'Dừng ghi',
         midi_tooltip: 'Cài đặt MIDI',
         reset_tooltip: 'Đặt lại tất cả',
+        use_prompt_tooltip: 'Dùng làm lệnh cho cấu hình',
+        use_prompt_toast: 'Đã lưu lệnh vào cấu hình đang dùng.',
+        use_prompt_empty_toast: 'Hãy tăng trọng số của ít nhất một lệnh trước.',
         no_sound_toast: 'Không phát hiện âm thanh trong bản ghi.',
         too_short_toast: 'Bản ghi quá ngắn hoặc không có tiếng.',
         api_key_toast: 'Vui lòng thiết lập Gemini API key trong ứng dụng chính.',
@@ -58,6 +64,9 @@ export const LOCALES = {
         stop_tooltip: '녹음 중지',
         midi_tooltip: 'MIDI 설정',
         reset_tooltip: '모든 가중치 초기화',
+        use_prompt_tooltip: '프리셋 프롬프트로 사용',
+        use_prompt_toast: '활성 프리셋에 프롬프트를 저장했습니다.',
+        use_prompt_empty_toast: '먼저 하나 이상의 프롬프트 가중치를 올리세요.',
         no_sound_toast: '녹음에서 소리가 감지되지 않았습니다.',
         too_short_toast: '녹음이 너무 짧거나 소리가 없습니다.',
         api_key_toast: '메인 앱에서 Gemini API 키를 먼저 설정해주세요.',
@@ -1,6 +1,6 @@
 import { useState, useRef, useEffect, useCallback } from "react";
 import { invoke } from "@tauri-apps/api/core";
-import { Play, Pause, Video, Trash2, Search, Download, Loader2, FolderOpen, Upload, Wand2, Type, Keyboard, X, Minus, Square, Copy } from "lucide-react";
+import { Play, Pause, Video, Trash2, Search, Download, Loader2, FolderOpen, Upload, Wand2, Type, Keyboard, X, Minus, Square, Copy, ImageIcon, ClipboardCopy, Scissors } from "lucide-react";
 import "./App.css";
 import { Button } from "@/components/ui/button";
 import { videoRenderer } from '@/lib/videoRenderer';
@@ -37,6 +37,15 @@ interface MonitorInfo {
   x: number;
   y: number;
   is_primary: boolean;
+  scale: number;
+  is_default: boolean;
+}
+
+interface WindowInfo {
+  id: string;
+  title: string;
+  width: number;
+  height: number;
 }
 
 interface Hotkey {
@@ -51,7 +60,7 @@ const sortMonitorsByPosition = (monitors: MonitorInfo[]) => {
     .sort((a, b) => a.x - b.x)
     .map((monitor, index) => ({
       ...monitor,
-      name: `Display ${index + 1}${monitor.is_primary ? ' (Primary)' : ''}`
+      name: `${monitor.name || `Display ${index + 1}`} (${monitor.width}×${monitor.height}${monitor.is_primary ? ', primary' : ''})`
     }));
 };
 
@@ -83,6 +92,9 @@ function App() {
   const [currentVideo, setCurrentVideo] = useState<string | null>(null);
   const [exportProgress, setExportProgress] = useState(0);
   const [isLoadingVideo, setIsLoadingVideo] = useState(false);
+  const [posterUrl, setPosterUrl] = useState<string | null>(null);
+  // The editor shows the MP4 just recorded (not a saved project), so it can be copied as a file
+  const [hasRecordingFile, setHasRecordingFile] = useState(false);
   const [loadingProgress, setLoadingProgress] = useState(0);
   const [currentAudio, setCurrentAudio] = useState<string | null>(null);
   const [isCropping, setIsCropping] = useState(false);
@@ -285,6 +297,22 @@ function App() {
   // Add these state variables inside App component
   const [monitors, setMonitors] = useState<MonitorInfo[]>([]);
   const [showMonitorSelect, setShowMonitorSelect] = useState(false);
+  const [captureWindows, setCaptureWindows] = useState<WindowInfo[]>([]);
+  // Auto-stop limit for new recordings (0 = unlimited)
+  const [maxDurationSecs, setMaxDurationSecs] = useState<number>(() => Number(localStorage.getItem('sr-max-duration-secs')) || 0);
+  const [recordingTimeLeft, setRecordingTimeLeft] = useState<number | null>(null);
+  const [droppedFrames, setDroppedFrames] = useState(0);
+  // Record the OS cursor into the video (kept in the app config; null until loaded)
+  const [captureCursor, setCaptureCursor] = useState<boolean | null>(null);
+  // Yellow capture border around the recorded region (null = system default)
+  const [drawBorder, setDrawBorder] = useState<boolean | null>(() => {
+    const saved = localStorage.getItem('sr-draw-border');
+    return saved === null ? null : saved === 'true';
+  });
+  // Move finished recordings into a folder (kept in the app config; null until loaded)
+  const [autoSave, setAutoSave] = useState<{ enabled: boolean; dir: string } | null>(null);
+  // file:// URL of the last auto-saved recording
+  const [savedFileUrl, setSavedFileUrl] = useState<string | null>(null);
 
 
   // Add this function to fetch monitors
@@ -302,6 +330,17 @@ function App() {
     }
   };
 
+  const getCaptureWindows = async () => {
+    try {
+      const windows = await invoke<WindowInfo[]>("get_windows");
+      setCaptureWindows(windows);
+      return windows;
+    } catch (err) {
+      console.error("Failed to get windows:", err);
+      return [];
+    }
+  };
+
   const [hotkeys, setHotkeys] = useState<Hotkey[]>([]);
   const [showHotkeyDialog, setShowHotkeyDialog] = useState(false);
   const [listeningForKey, setListeningForKey] = useState(false);
@@ -389,8 +428,11 @@ function App() {
 
     try {
       const monitors = await getMonitors();
+      const windows = await getCaptureWindows();
+      setCaptureCursor(await invoke<boolean>("get_capture_cursor"));
+      setAutoSave(await invoke<{ enabled: boolean; dir: string }>("get_auto_save"));
 
-      if (monitors.length > 1) {
+      if (monitors.length > 1 || windows.length > 0) {
         setShowMonitorSelect(true);
         return;
       }
@@ -404,7 +446,7 @@ function App() {
   }
 
   // Update startNewRecording to handle string IDs
-  async function startNewRecording(monitorId: string) {
+  async function startNewRecording(monitorId: string, windowId?: string) {
     try {
       // Clear all states first
       setMousePositions([]);
@@ -416,6 +458,9 @@ function App() {
       setZoomFactor(1.5);
       setEditingKeyframeId(null);
       setThumbnails([]);
+      setPosterUrl(null);
+      setHasRecordingFile(false);
+      setSavedFileUrl(null);
 
       // Clear previous video
       if (currentVideo) {
@@ -454,7 +499,14 @@ function App() {
       }
 
       // Now start the new recording
-      await invoke("start_recording", { monitorId });
+      setRecordingTimeLeft(null);
+      setDroppedFrames(0);
+      // Leaving captureCursor out uses the saved choice
+      const cursorArg = captureCursor ?? undefined;
+      const borderArg = drawBorder ?? undefined;
+      await invoke("start_recording", windowId
+        ? { monitorId, windowId, maxDurationSecs, captureCursor: cursorArg, drawBorder: borderArg }
+        : { monitorId, maxDurationSecs, captureCursor: cursorArg, drawBorder: borderArg });
       setIsRecording(true);
       setError(null);
     } catch (err) {
@@ -463,18 +515,85 @@ function App() {
     }
   }
 
+  // Bake the trim handles into the recording file. Shift-click re-encodes for an
+  // exact in point; a plain click copies from the keyframe before it (fast).
+  async function handleCutRecording(precise: boolean) {
+    if (!segment) return;
+    try {
+      setIsLoadingVideo(true);
+      const result = await invoke<{ videoUrl: string; audioUrl: string; startMs: number; durationMs: number }>("trim_recording", {
+        startMs: Math.round(segment.trimStart * 1000),
+        endMs: Math.round(segment.trimEnd * 1000),
+        precise
+      });
+      const offset = result.startMs / 1000;
+      const newDuration = result.durationMs / 1000;
+      const inRange = (time: number) => time >= offset && time <= offset + newDuration;
+
+      const objectUrl = await videoControllerRef.current?.loadVideo({ videoUrl: result.videoUrl });
+      if (!objectUrl) return;
+      if (currentVideo) URL.revokeObjectURL(currentVideo);
+      setCurrentVideo(objectUrl);
+
+      const audioObjectUrl = await videoControllerRef.current?.loadAudio({ audioUrl: result.audioUrl });
+      if (audioObjectUrl) {
+        if (currentAudio) URL.revokeObjectURL(currentAudio);
+        setCurrentAudio(audioObjectUrl);
+      }
+
+      // Everything timed against the old file moves back by the cut offset
+      setMousePositions(prev => prev
+        .filter(p => inRange(p.timestamp))
+        .map(p => ({ ...p, timestamp: p.timestamp - offset })));
+      setSegment({
+        ...segment,
+        trimStart: 0,
+        trimEnd: newDuration,
+        zoomKeyframes: segment.zoomKeyframes
+          .filter(k => inRange(k.time))
+          .map(k => ({ ...k, time: k.time - offset })),
+        smoothMotionPath: segment.smoothMotionPath
+          ?.filter(p => inRange(p.time))
+          .map(p => ({ ...p, time: p.time - offset })),
+        zoomInfluencePoints: segment.zoomInfluencePoints
+          ?.filter(p => inRange(p.time))
+          .map(p => ({ ...p, time: p.time - offset })),
+        textSegments: segment.textSegments
+          .filter(t => t.endTime > offset && t.startTime < offset + newDuration)
+          .map(t => ({
+            ...t,
+            startTime: Math.max(0, t.startTime - offset),
+            endTime: Math.min(newDuration, t.endTime - offset)
+          }))
+      });
+      generateThumbnails();
+    } catch (err) {
+      console.error("Failed to trim recording:", err);
+      setError(err as string);
+    } finally {
+      setIsLoadingVideo(false);
+    }
+  }
+
   // Update handleStopRecording
   async function handleStopRecording() {
     if (!isRecording) return;
 
     try {
       setIsRecording(false);
+      setRecordingTimeLeft(null);
       setIsLoadingVideo(true);
       setIsVideoReady(false);
       setLoadingProgress(0);
       setThumbnails([]);
 
-      const [videoUrl, audioUrl, rawMouseData] = await invoke<[string, string, any[]]>("stop_recording");
+      const [videoUrl, audioUrl, rawMouseData, poster, cursorBaked, savedFile] = await invoke<[string, string, any[], string, boolean, string | null]>("stop_recording");
+      setPosterUrl(poster || null);
+      setSavedFileUrl(savedFile ?? null);
+      // A cursor recorded into the video replaces the overlay one for this recording
+      const recordingConfig: BackgroundConfig = { ...backgroundConfig, cursorBaked: !!cursorBaked };
+      setBackgroundConfig(recordingConfig);
+      setHasRecordingFile(true);
 
       // Explicitly map fields to handle potential camelCase vs snake_case mismatches
       const mouseData: MousePosition[] = rawMouseData.map(p => ({
@@ -482,7 +601,8 @@ function App() {
         y: p.y,
         timestamp: p.timestamp,
         isClicked: p.isClicked !== undefined ? p.isClicked : p.is_clicked, // Handle both casing
-        cursor_type: p.cursor_type || 'default'
+        cursor_type: p.cursor_type || 'default',
+        event: p.event
       }));
 
       setMousePositions(mouseData);
@@ -531,7 +651,7 @@ function App() {
             canvas: canvasRef.current,
             tempCanvas: tempCanvasRef.current,
             segment: initialSegment,
-            backgroundConfig,
+            backgroundConfig: recordingConfig,
             mousePositions: mouseData,
             currentTime: 0
           });
@@ -558,7 +678,7 @@ function App() {
           videoBlob,
           audioBlob,
           segment: initialSegment,
-          backgroundConfig,
+          backgroundConfig: recordingConfig,
           mousePositions: mouseData,
           thumbnail
         });
@@ -888,6 +1008,33 @@ function App() {
     };
   }, [isRecording]);
 
+  // Countdown from the backend while a duration limit is set
+  useEffect(() => {
+    const handleTimeLeft = (e: Event) => {
+      setRecordingTimeLeft((e as CustomEvent<number>).detail);
+    };
+    window.addEventListener('recording-time-left', handleTimeLeft);
+    return () => window.removeEventListener('recording-time-left', handleTimeLeft);
+  }, []);
+
+  // Live progress pushed by the backend: its clock and dropped-frame count
+  useEffect(() => {
+    const handleStatus = (e: Event) => {
+      const status = (e as CustomEvent<{ elapsedMs: number; droppedFrames: number }>).detail;
+      setRecordingDuration(Math.floor(status.elapsedMs / 1000));
+      setDroppedFrames(status.droppedFrames);
+    };
+    window.addEventListener('recording-status', handleStatus);
+    return () => window.removeEventListener('recording-status', handleStatus);
+  }, []);
+
+  // Limit reached: the backend already stopped capturing, collect the result as usual
+  useEffect(() => {
+    if (isRecording && recordingTimeLeft === 0) {
+      handleStopRecording();
+    }
+  }, [isRecording, recordingTimeLeft]);
+
   // Add this effect after the other useEffect hooks
   useEffect(() => {
     if (!segment || !isVideoReady) return;
@@ -926,6 +1073,10 @@ function App() {
   const renderPlaceholder = () => {
     return (
       <div className="absolute inset-0 bg-[#1a1a1b] flex flex-col items-center justify-center">
+        {/* Poster still of the finished recording while the video loads */}
+        {isLoadingVideo && posterUrl && (
+          <img src={posterUrl} className="absolute inset-0 w-full h-full object-contain opacity-30" alt="" />
+        )}
         {/* Grid pattern background */}
         <div className="absolute inset-0 opacity-5">
           <div className="w-full h-full" style={{
@@ -951,6 +1102,12 @@ function App() {
             <p className="text-[#d7dadc] font-medium">Recording in progress...</p>
             <p className="text-[#818384] text-sm mt-1">Screen is being captured</p>
             <span className="text-[#d7dadc] text-xl font-mono mt-4">{formatTime(recordingDuration)}</span>
+            {recordingTimeLeft !== null && (
+              <span className="text-[#818384] text-sm mt-1">Auto-stop in {formatTime(recordingTimeLeft)}</span>
+            )}
+            {droppedFrames > 0 && (
+              <span className="text-yellow-500 text-sm mt-1">{droppedFrames} dropped frames</span>
+            )}
           </div>
         ) : (
           // No video state
@@ -1057,6 +1214,8 @@ function App() {
   const handleLoadProject = async (projectId: string) => {
     const project = await projectManager.loadProject(projectId);
     if (!project) return;
+    setHasRecordingFile(false);
+    setSavedFileUrl(null);
 
     // Clear previous video and audio URLs
     if (currentVideo) URL.revokeObjectURL(currentVideo);
@@ -1270,6 +1429,12 @@ function App() {
                   <span className="text-[#818384] text-[9px] leading-tight">Screen is being captured</span>
                 </div>
                 <span className="text-[#d7dadc] text-xs font-mono ml-1">{formatTime(recordingDuration)}</span>
+                {recordingTimeLeft !== null && (
+                  <span className="text-[#818384] text-xs font-mono">-{formatTime(recordingTimeLeft)}</span>
+                )}
+                {droppedFrames > 0 && (
+                  <span className="text-yellow-500 text-xs font-mono" title="Dropped frames">{droppedFrames}⚠</span>
+                )}
               </div>
             )}
           </div>
@@ -1370,6 +1535,11 @@ function App() {
 
       <main className="max-w-6xl mx-auto px-4 py-6">
         {error && <p className="text-red-500 mb-4">{error}</p>}
+        {savedFileUrl && (
+          <p className="text-sm text-[#818384] mb-4 truncate" title={decodeURI(savedFileUrl.replace('file:///', ''))}>
+            Saved to {decodeURI(savedFileUrl.replace('file:///', ''))}
+          </p>
+        )}
 
         <div className="space-y-6">
           <div className="grid grid-cols-4 gap-6 items-start">
@@ -1706,6 +1876,49 @@ function App() {
                     <div className="text-white/90 text-sm font-medium">
                       {formatTime(currentTime)} / {formatTime(duration)}
                     </div>
+                    {posterUrl && (
+                      <>
+                        <div className="w-px h-4 bg-white/20 mx-1" />
+                        <Button
+                          onClick={() => invoke("copy_poster").catch(err => setError(err as string))}
+                          variant="ghost"
+                          size="icon"
+                          className="w-8 h-8 rounded-full transition-colors text-white/80 hover:text-white hover:bg-white/10"
+                          title="Copy Still"
+                        >
+                          <ImageIcon className="w-4 h-4" />
+                        </Button>
+                      </>
+                    )}
+                    {hasRecordingFile && (
+                      <>
+                        <div className="w-px h-4 bg-white/20 mx-1" />
+                        <Button
+                          onClick={() => invoke("copy_recording_to_clipboard").catch(err => setError(err as string))}
+                          variant="ghost"
+                          size="icon"
+                          className="w-8 h-8 rounded-full transition-colors text-white/80 hover:text-white hover:bg-white/10"
+                          title="Copy Video File"
+                        >
+                          <ClipboardCopy className="w-4 h-4" />
+                        </Button>
+                      </>
+                    )}
+                    {hasRecordingFile && segment && (segment.trimStart > 0 || segment.trimEnd < duration) && (
+                      <>
+                        <div className="w-px h-4 bg-white/20 mx-1" />
+                        <Button
+                          onClick={(e) => handleCutRecording(e.shiftKey)}
+                          disabled={isProcessing || isLoadingVideo}
+                          variant="ghost"
+                          size="icon"
+                          className="w-8 h-8 rounded-full transition-colors text-white/80 hover:text-white hover:bg-white/10"
+                          title="Cut to Trim (Shift: exact cut, slower)"
+                        >
+                          <Scissors className="w-4 h-4" />
+                        </Button>
+                      </>
+                    )}
                   </div>
                 )}
               </div>
@@ -2128,26 +2341,126 @@ function App() {
         showMonitorSelect && (
           <div className="fixed inset-0 bg-black/80 flex items-center justify-center z-50">
             <div className="bg-[#1a1a1b] p-6 rounded-lg border border-[#343536] max-w-md w-full mx-4">
-              <h3 className="text-lg font-semibold text-[#d7dadc] mb-4">Select Monitor</h3>
+              <h3 className="text-lg font-semibold text-[#d7dadc] mb-4">Select Source</h3>
               <div className="space-y-3 mb-6">
                 {monitors.map((monitor) => (
                   <button
                     key={monitor.id}
+                    autoFocus={monitor.is_default}
                     onClick={() => {
                       setShowMonitorSelect(false);
                       startNewRecording(monitor.id);
                     }}
-                    className="w-full p-4 rounded-lg border border-[#343536] hover:bg-[#272729] transition-colors text-left"
+                    className={`w-full p-4 rounded-lg border ${monitor.is_default ? 'border-[#0079d3] bg-[#272729]' : 'border-[#343536]'} hover:bg-[#272729] transition-colors text-left`}
                   >
                     <div className="font-medium text-[#d7dadc]">
                       {monitor.name}
                     </div>
                     <div className="text-sm text-[#818384] mt-1">
-                      {monitor.width}x{monitor.height} at ({monitor.x}, {monitor.y})
+                      {Math.round(monitor.scale * 100)}% scale at ({monitor.x}, {monitor.y})
                     </div>
                   </button>
                 ))}
+                {monitors.length > 1 && (() => {
+                  const left = Math.min(...monitors.map(m => m.x));
+                  const top = Math.min(...monitors.map(m => m.y));
+                  const right = Math.max(...monitors.map(m => m.x + m.width));
+                  const bottom = Math.max(...monitors.map(m => m.y + m.height));
+                  return (
+                    <button
+                      onClick={() => {
+                        setShowMonitorSelect(false);
+                        startNewRecording('all');
+                      }}
+                      className="w-full p-4 rounded-lg border border-[#343536] hover:bg-[#272729] transition-colors text-left"
+                    >
+                      <div className="font-medium text-[#d7dadc]">
+                        All Displays
+                      </div>
+                      <div className="text-sm text-[#818384] mt-1">
+                        {right - left}x{bottom - top} at ({left}, {top})
+                      </div>
+                    </button>
+                  );
+                })()}
+              </div>
+              <div className="flex items-center justify-between mb-4">
+                <span className="text-sm text-[#818384]">Auto-stop after</span>
+                <select
+                  value={maxDurationSecs}
+                  onChange={(e) => {
+                    const secs = Number(e.target.value);
+                    setMaxDurationSecs(secs);
+                    localStorage.setItem('sr-max-duration-secs', String(secs));
+                  }}
+                  className="bg-[#272729] border border-[#343536] rounded px-2 py-1 text-sm text-[#d7dadc]"
+                >
+                  <option value={0}>Never</option>
+                  <option value={60}>1 minute</option>
+                  <option value={300}>5 minutes</option>
+                  <option value={600}>10 minutes</option>
+                  <option value={1800}>30 minutes</option>
+                  <option value={3600}>1 hour</option>
+                </select>
               </div>
+              <label className="flex items-center justify-between mb-4 cursor-pointer">
+                <span className="text-sm text-[#818384]">Record system cursor</span>
+                <input
+                  type="checkbox"
+                  checked={captureCursor ?? false}
+                  onChange={(e) => setCaptureCursor(e.target.checked)}
+                  className="accent-[#0079d3]"
+                />
+              </label>
+              <label className="flex items-center justify-between mb-4 cursor-pointer" title={autoSave?.dir}>
+                <span className="text-sm text-[#818384]">Auto-save to folder</span>
+                <input
+                  type="checkbox"
+                  checked={autoSave?.enabled ?? false}
+                  onChange={(e) => {
+                    const enabled = e.target.checked;
+                    setAutoSave(prev => ({ enabled, dir: prev?.dir ?? '' }));
+                    invoke("set_auto_save", { enabled }).catch(err => setError(err as string));
+                  }}
+                  className="accent-[#0079d3]"
+                />
+              </label>
+              <label className="flex items-center justify-between mb-4 cursor-pointer">
+                <span className="text-sm text-[#818384]">Show capture border</span>
+                <input
+                  type="checkbox"
+                  checked={drawBorder ?? true}
+                  onChange={(e) => {
+                    setDrawBorder(e.target.checked);
+                    localStorage.setItem('sr-draw-border', String(e.target.checked));
+                  }}
+                  className="accent-[#0079d3]"
+                />
+              </label>
+              {captureWindows.length > 0 && (
+                <>
+                  <h4 className="text-sm font-medium text-[#818384] mb-2">Windows</h4>
+                  <div className="space-y-2 mb-6 max-h-64 overflow-y-auto">
+                    {captureWindows.map((win) => (
+                      <button
+                        key={win.id}
+                        onClick={() => {
+                          setShowMonitorSelect(false);
+                          startNewRecording('0', win.id);
+                        }}
+                        className="w-full p-3 rounded-lg border border-[#343536] hover:bg-[#272729] transition-colors text-left"
+                      >
+                        <div className="font-medium text-[#d7dadc] truncate">
+                          {win.title}
+                        </div>
+                        <div className="text-sm text-[#818384] mt-1">
+                          {win.width}x{win.height}
+                        </div>
+                      </button>
+                    ))}
+                  </div>
+                </>
+              )}
               <div className="flex justify-end">
                 <Button
                   onClick={() => setShowMonitorSelect(false)}
@@ -51,6 +51,11 @@ export class VideoRenderer {
   private readonly CLICK_FUSE_THRESHOLD = 0.15;
   private readonly SQUISH_SPEED = 0.015;
   private readonly RELEASE_SPEED = 0.01;
+  private readonly RIPPLE_DURATION = 0.5;
+
+  // Press events of the current mousePositions array, sorted by timestamp (for ripples)
+  private clickSource: MousePosition[] | null = null;
+  private clicks: MousePosition[] = [];
 
   constructor() {
     // Preload the pointer SVG image.
@@ -283,6 +288,26 @@ export class VideoRenderer {
       // Composite temp canvas onto main canvas
       ctx.drawImage(tempCanvas, 0, 0);
 
+      // Map a point in original video pixels to canvas space (null if it's cropped out)
+      const mapToCanvas = (mX: number, mY: number): { x: number; y: number } | null => {
+        if (mX < srcX || mX > (srcX + srcW) || mY < srcY || mY > (srcY + srcH * (1 - legacyCrop))) {
+          return null;
+        }
+        // Relative to Source Crop top-left
+        const relX = (mX - srcX) / srcW; // 0-1 in cropped frame
+        const relY = (mY - srcY) / (srcH * (1 - legacyCrop)); // 0-1 in cropped frame
+
+        let canvasX = x + (relX * scaledWidth);
+        let canvasY = y + (relY * scaledHeight);
+
+        // If there's zoom, apply the same zoom transformation
+        if (zoomState && zoomState.zoomFactor !== 1) {
+          canvasX = canvasX * zoomState.zoomFactor + (canvas.width - canvas.width * zoomState.zoomFactor) * zoomState.positionX;
+          canvasY = canvasY * zoomState.zoomFactor + (canvas.height - canvas.height * zoomState.zoomFactor) * zoomState.positionY;
+        }
+        return { x: canvasX, y: canvasY };
+      };
+
       // Mouse cursor
       const interpolatedPosition = this.interpolateCursorPosition(
         video.currentTime,
@@ -294,31 +319,25 @@ export class VideoRenderer {
         // Reset the transform before drawing cursor
         ctx.setTransform(1, 0, 0, 1, 0, 0);
 
-        // Map mouse position to the cropped video space
-        // interpolatedPosition.x/y are pixels in original video
-        const mX = interpolatedPosition.x;
-        const mY = interpolatedPosition.y;
-
-        // Check if inside Source Crop
-        if (mX >= srcX && mX <= (srcX + srcW) && mY >= srcY && mY <= (srcY + srcH * (1 - legacyCrop))) {
-          // Map to Canvas space
-          // Relative to Source Crop top-left
-          const relX = (mX - srcX) / srcW; // 0-1 in cropped frame
-          const relY = (mY - srcY) / (srcH * (1 - legacyCrop)); // 0-1 in cropped frame
-
-          let cursorX = x + (relX * scaledWidth);
-          let cursorY = y + (relY * scaledHeight);
-
-          // If there's zoom, adjust cursor position
-          if (zoomState && zoomState.zoomFactor !== 1) {
-            // Apply the same zoom transformation to cursor position
-            cursorX = cursorX * zoomState.zoomFactor + (canvas.width - canvas.width * zoomState.zoomFactor) * zoomState.positionX;
-            cursorY = cursorY * zoomState.zoomFactor + (canvas.height - canvas.height * zoomState.zoomFactor) * zoomState.positionY;
+        // Scale cursor size based on canvas to source ratio and zoom
+        const sizeRatio = Math.min(canvas.width / srcW, canvas.height / srcH);
+        const cursorSizeScale = (backgroundConfig.cursorScale || 2) * sizeRatio * (zoomState?.zoomFactor || 1);
+
+        // Click ripples (drawn under the cursor)
+        for (const click of this.getRipplingClicks(video.currentTime, mousePositions)) {
+          const age = video.currentTime - click.timestamp;
+          const pos = mapToCanvas(click.x, click.y);
+          if (pos) {
+            this.drawClickRipple(ctx, pos.x, pos.y, age / this.RIPPLE_DURATION, cursorSizeScale, click.event);
           }
+        }
 
-          // Scale cursor size based on canvas to source ratio and zoom
-          const sizeRatio = Math.min(canvas.width / srcW, canvas.height / srcH);
-          const cursorSizeScale = (backgroundConfig.cursorScale || 2) * sizeRatio * (zoomState?.zoomFactor || 1);
+        // interpolatedPosition.x/y are pixels in original video
+        const cursorPos = backgroundConfig.cursorBaked
+          ? null
+          : mapToCanvas(interpolatedPosition.x, interpolatedPosition.y);
+        if (cursorPos) {
+          const { x: cursorX, y: cursorY } = cursorPos;
 
           // Update smooth squish animation state
           const isActuallyClicked = interpolatedPosition.isClicked;
@@ -838,6 +857,58 @@ export class VideoRenderer {
     };
   }
 
+  // Clicks whose ripple is still playing at currentTime
+  private getRipplingClicks(currentTime: number, mousePositions: MousePosition[]): MousePosition[] {
+    if (this.clickSource !== mousePositions) {
+      this.clickSource = mousePositions;
+      this.clicks = mousePositions
+        .filter(p => p.event)
+        .sort((a, b) => a.timestamp - b.timestamp);
+    }
+
+    // Binary search for the oldest click that is still within RIPPLE_DURATION
+    const from = currentTime - this.RIPPLE_DURATION;
+    let lo = 0;
+    let hi = this.clicks.length;
+    while (lo < hi) {
+      const mid = (lo + hi) >> 1;
+      if (this.clicks[mid].timestamp < from) {
+        lo = mid + 1;
+      } else {
+        hi = mid;
+      }
+    }
+
+    const rippling: MousePosition[] = [];
+    for (let i = lo; i < this.clicks.length && this.clicks[i].timestamp <= currentTime; i++) {
+      rippling.push(this.clicks[i]);
+    }
+    return rippling;
+  }
+
+  // Expanding, fading ring at a click; progress goes 0 -> 1 over RIPPLE_DURATION
+  private drawClickRipple(
+    ctx: CanvasRenderingContext2D,
+    x: number,
+    y: number,
+    progress: number,
+    scale: number,
+    event: MousePosition['event']
+  ) {
+    const eased = 1 - Math.pow(1 - progress, 3);
+    const radius = (4 + eased * 14) * scale;
+    const color = event === 'right_down' ? '255, 149, 0' : '0, 121, 211';
+    ctx.save();
+    ctx.beginPath();
+    ctx.arc(x, y, radius, 0, Math.PI * 2);
+    ctx.strokeStyle = `rgba(${color}, ${0.8 * (1 - progress)})`;
+    ctx.lineWidth = 2 * scale;
+    ctx.stroke();
+    ctx.fillStyle = `rgba(${color}, ${0.2 * (1 - progress)})`;
+    ctx.fill();
+    ctx.restore();
+  }
+
   private drawMouseCursor(
     ctx: CanvasRenderingContext2D,
     x: number,
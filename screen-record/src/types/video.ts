@@ -50,6 +50,7 @@ export interface BackgroundConfig {
   customBackground?: string;
   cropBottom?: number; // 0-100 percentage
   volume?: number; // 0-1
+  cursorBaked?: boolean; // OS cursor recorded into the video: don't draw another
 }
 
 export interface MousePosition {
@@ -58,6 +59,8 @@ export interface MousePosition {
   timestamp: number;
   isClicked?: boolean;
   cursor_type?: string;
+  /** Set on samples recorded at a button press */
+  event?: 'left_down' | 'right_down';
 }
 
 export interface VideoMetadata {
@@ -0,0 +1,201 @@
+//! Anthropic Claude API Integration
+//! Messages API with SSE streaming for text and vision
+
+use super::client::{read_json_with_usage, send_json_with_retry, stream_reader, UREQ_AGENT};
+use crate::gui::locale::LocaleText;
+use anyhow::Result;
+use serde::Deserialize;
+use std::io::BufRead;
+
+const ANTHROPIC_MESSAGES_URL: &str = "https://api.anthropic.com/v1/messages";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+const ANTHROPIC_MAX_TOKENS: u32 = 8192;
+
+/// Streaming event (`content_block_delta`, `message_stop`, ...)
+#[derive(Deserialize, Debug)]
+struct AnthropicStreamEvent {
+    #[serde(rename = "type", default)]
+    event_type: String,
+    #[serde(default)]
+    delta: Option<AnthropicDelta>,
+    #[serde(default)]
+    error: Option<AnthropicError>,
+}
+
+#[derive(Deserialize, Debug)]
+struct AnthropicDelta {
+    #[serde(rename = "type", default)]
+    delta_type: String,
+    #[serde(default)]
+    text: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct AnthropicError {
+    #[serde(default)]
+    message: String,
+}
+
+/// Non-streaming response
+#[derive(Deserialize, Debug)]
+struct AnthropicMessageResponse {
+    #[serde(default)]
+    content: Vec<AnthropicContentBlock>,
+}
+
+#[derive(Deserialize, Debug)]
+struct AnthropicContentBlock {
+    #[serde(rename = "type", default)]
+    block_type: String,
+    #[serde(default)]
+    text: Option<String>,
+}
+
+/// Read the Anthropic API key from config
+pub fn get_anthropic_api_key() -> String {
+    crate::APP
+        .lock()
+        .ok()
+        .map(|app| app.config.anthropic_api_key.clone())
+        .unwrap_or_default()
+}
+
+/// Generate with Claude.
+/// `image` is an optional (base64 data, mime type) pair for vision requests.
+pub fn anthropic_generate<F>(
+    api_key: &str,
+    model: &str,
+    prompt: &str,
+    image: Option<(String, String)>,
+    streaming_enabled: bool,
+    ui_language: &str,
+    mut on_chunk: F,
+) -> Result<String>
+where
+    F: FnMut(&str),
+{
+    if api_key.trim().is_empty() {
+        return Err(anyhow::anyhow!("NO_API_KEY:anthropic"));
+    }
+
+    let content = match image {
+        Some((b64_image, mime_type)) => serde_json::json!([
+            {
+                "type": "image",
+                "source": {
+                    "type": "base64",
+                    "media_type": mime_type,
+                    "data": b64_image
+                }
+            },
+            { "type": "text", "text": prompt }
+        ]),
+        None => serde_json::json!([{ "type": "text", "text": prompt }]),
+    };
+
+    let payload = serde_json::json!({
+        "model": model,
+        "max_tokens": ANTHROPIC_MAX_TOKENS,
+        "messages": [
+            { "role": "user", "content": content }
+        ],
+        "stream": streaming_enabled
+    });
+
+    let resp = send_json_with_retry(
+        || {
+            UREQ_AGENT
+                .post(ANTHROPIC_MESSAGES_URL)
+                .header("x-api-key", api_key)
+                .header("anthropic-version", ANTHROPIC_VERSION)
+                .header("Content-Type", "application/json")
+        },
+        &payload,
+        streaming_enabled,
+    )
+    .map_err(|e| {
+        let err_str = e.to_string();
+        if err_str.contains("401") || err_str.contains("403") {
+            anyhow::anyhow!("INVALID_API_KEY:anthropic")
+        } else {
+            anyhow::anyhow!("Anthropic API Error: {}", err_str)
+        }
+    })?;
+
+    let mut full_content = String::new();
+
+    if streaming_enabled {
+        let reader = stream_reader(resp);
+        let mut thinking_shown = false;
+        let mut content_started = false;
+        let locale = LocaleText::get(ui_language);
+
+        for line in reader.lines() {
+            let line = line?;
+            // Only `data:` lines carry payloads; `event:` lines duplicate the type field
+            if !line.starts_with("data: ") {
+                continue;
+            }
+            let data = &line[6..];
+
+            let event = match serde_json::from_str::<AnthropicStreamEvent>(data) {
+                Ok(event) => event,
+                Err(_) => continue,
+            };
+
+            match event.event_type.as_str() {
+                "content_block_delta" => {
+                    let Some(delta) = event.delta else {
+                        continue;
+                    };
+                    match delta.delta_type.as_str() {
+                        "thinking_delta" => {
+                            // Model is thinking - show thinking indicator (only once)
+                            if !thinking_shown && !content_started {
+                                on_chunk(locale.model_thinking);
+                                thinking_shown = true;
+                            }
+                        }
+                        "text_delta" => {
+                            let Some(text) = delta.text.filter(|t| !t.is_empty()) else {
+                                continue;
+                            };
+                            if !content_started && thinking_shown {
+                                // Wipe thinking message on first content
+                                content_started = true;
+                                full_content.push_str(&text);
+                                let wipe_content =
+                                    format!("{}{}", crate::api::WIPE_SIGNAL, full_content);
+                                on_chunk(&wipe_content);
+                            } else {
+                                content_started = true;
+                                full_content.push_str(&text);
+                                on_chunk(&text);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                "message_stop" => break,
+                "error" => {
+                    let message = event.error.map(|e| e.message).unwrap_or_default();
+                    return Err(anyhow::anyhow!("Anthropic API Error: {}", message));
+                }
+                _ => {}
+            }
+        }
+    } else {
+        let message: AnthropicMessageResponse = read_json_with_usage(resp)
+            .map_err(|e| anyhow::anyhow!("Failed to parse Anthropic response: {}", e))?;
+
+        full_content = message
+            .content
+            .into_iter()
+            .filter(|block| block.block_type == "text")
+            .filter_map(|block| block.text)
+            .collect::<String>();
+        on_chunk(&full_content);
+    }
+
+    Ok(full_content)
+}
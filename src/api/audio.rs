@@ -17,6 +17,23 @@ use std::sync::{
 use windows::Win32::Foundation::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
 
+/// Show an audio failure as a localized error notification instead of only logging it
+fn notify_audio_error(preset: &Preset, error: &str) {
+    let ui_language = APP
+        .lock()
+        .map(|app| app.config.ui_language.clone())
+        .unwrap_or_default();
+    let model_name = preset
+        .blocks
+        .iter()
+        .find(|b| b.block_type == "audio")
+        .and_then(|b| get_model_by_id(&b.model))
+        .map(|m| m.full_name);
+    let message =
+        crate::overlay::utils::get_error_message(error, &ui_language, model_name.as_deref());
+    crate::overlay::auto_copy_badge::show_error_notification(&message);
+}
+
 fn encode_wav(samples: &[i16], sample_rate: u32, channels: u16) -> Vec<u8> {
     let spec = hound::WavSpec {
         channels,
@@ -657,6 +674,7 @@ pub fn record_and_stream_gemini_live(
                 &render_mode,
                 "Listening...".to_string(),
             );
+            crate::overlay::result::set_window_preset(hwnd, &preset_for_thread.id);
 
             unsafe {
                 let _ = ShowWindow(hwnd, SW_SHOW);
@@ -697,6 +715,7 @@ pub fn record_and_stream_gemini_live(
 
     if gemini_api_key.trim().is_empty() {
         eprintln!("[GeminiLiveStream] No API key");
+        notify_audio_error(&preset, "NO_API_KEY:google");
         unsafe {
             let _ = PostMessageW(Some(overlay_hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
         }
@@ -714,6 +733,7 @@ pub fn record_and_stream_gemini_live(
         }
         Err(e) => {
             println!("[GeminiLiveStream] Connection failed: {}", e);
+            notify_audio_error(&preset, &e.to_string());
             AUDIO_INITIALIZING.store(false, Ordering::SeqCst);
             unsafe {
                 let _ = PostMessageW(Some(overlay_hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
@@ -1384,6 +1404,7 @@ pub fn record_and_stream_parakeet(
                 &render_mode,
                 "Listening...".to_string(),
             );
+            crate::overlay::result::set_window_preset(hwnd, &preset_for_thread.id);
 
             unsafe {
                 let _ = ShowWindow(hwnd, SW_SHOW);
@@ -1454,6 +1475,7 @@ pub fn record_and_stream_parakeet(
 
     if let Err(e) = res {
         eprintln!("[ParakeetStream] Error: {:?}", e);
+        notify_audio_error(&preset, &e.to_string());
     }
 
     // Check for abort
@@ -1683,17 +1705,23 @@ fn execute_audio_processing_logic(preset: &Preset, wav_data: Vec<u8>) -> anyhow:
         audio_block.prompt.clone()
     };
 
+    let (target, fallback) = crate::overlay::utils::keyboard_language_source();
+    let resolve =
+        |value: &str| crate::overlay::utils::resolve_language_var(value, target, &fallback);
     for (key, value) in &audio_block.language_vars {
         let pattern = format!("{{{}}}", key);
-        final_prompt = final_prompt.replace(&pattern, value);
+        final_prompt = final_prompt.replace(&pattern, &resolve(value));
     }
 
     if final_prompt.contains("{language1}") && !audio_block.language_vars.contains_key("language1")
     {
-        final_prompt = final_prompt.replace("{language1}", &audio_block.selected_language);
+        final_prompt =
+            final_prompt.replace("{language1}", &resolve(&audio_block.selected_language));
     }
 
-    final_prompt = final_prompt.replace("{language}", &audio_block.selected_language);
+    if final_prompt.contains("{language}") {
+        final_prompt = final_prompt.replace("{language}", &resolve(&audio_block.selected_language));
+    }
 
     if provider == "groq" {
         if groq_api_key.trim().is_empty() {
@@ -2005,7 +2033,7 @@ pub fn record_audio_and_transcribe(
         // Show preset wheel - filter by audio source
         let audio_mode = Some(preset.audio_source.as_str());
         let selected =
-            crate::overlay::preset_wheel::show_preset_wheel("audio", audio_mode, cursor_pos);
+            crate::overlay::preset_wheel::choose_preset(&preset, "audio", audio_mode, cursor_pos);
 
         if let Some(idx) = selected {
             // Get the selected preset from config AND update active_preset_idx
@@ -2114,6 +2142,7 @@ pub fn record_audio_and_transcribe(
         }
         Err(e) => {
             eprintln!("Transcription error: {}", e);
+            notify_audio_error(&working_preset, &e.to_string());
             // Close overlay on error
             unsafe {
                 if IsWindow(Some(overlay_hwnd)).as_bool() {
@@ -2196,6 +2225,7 @@ pub fn process_audio_file_request(preset: Preset, wav_data: Vec<u8>) {
         }
         Err(e) => {
             eprintln!("Audio file processing error: {}", e);
+            notify_audio_error(&preset, &e.to_string());
         }
     }
 }
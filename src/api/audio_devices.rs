@@ -0,0 +1,64 @@
+//! WASAPI audio endpoint enumeration, for picking a specific speaker or microphone
+
+use windows::core::{Interface, HSTRING};
+use windows::Win32::Devices::FunctionDiscovery::PKEY_Device_FriendlyName;
+use windows::Win32::Media::Audio::*;
+use windows::Win32::System::Com::StructuredStorage::{PropVariantClear, PropVariantToStringAlloc};
+use windows::Win32::System::Com::*;
+
+/// Active render (`capture == false`) or capture endpoints as (ID, friendly name)
+pub fn list_audio_endpoints(capture: bool) -> Vec<(String, String)> {
+    let flow = if capture { eCapture } else { eRender };
+    let mut devices = Vec::new();
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+        let Ok(enumerator) =
+            CoCreateInstance::<_, IMMDeviceEnumerator>(&MMDeviceEnumerator, None, CLSCTX_ALL)
+        else {
+            return devices;
+        };
+        let Ok(collection) = enumerator.EnumAudioEndpoints(flow, DEVICE_STATE_ACTIVE) else {
+            return devices;
+        };
+        let count = collection.GetCount().unwrap_or(0);
+        for i in 0..count {
+            let Ok(device) = collection.Item(i) else {
+                continue;
+            };
+            let Ok(id) = device.GetId() else {
+                continue;
+            };
+            let id_str = id.to_string().unwrap_or_default();
+            CoTaskMemFree(Some(id.0 as *const _));
+            let name = friendly_name(&device).unwrap_or_else(|| id_str.clone());
+            devices.push((id_str, name));
+        }
+    }
+    devices
+}
+
+/// Open an endpoint by ID, if it still exists and flows the expected way
+pub unsafe fn open_endpoint(id: &str, capture: bool) -> Option<IMMDevice> {
+    let flow = if capture { eCapture } else { eRender };
+    let enumerator: IMMDeviceEnumerator =
+        CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL).ok()?;
+    let device = enumerator.GetDevice(&HSTRING::from(id)).ok()?;
+    let endpoint_flow = device.cast::<IMMEndpoint>().ok()?.GetDataFlow().ok()?;
+    (endpoint_flow == flow).then_some(device)
+}
+
+/// e.g. "Speakers (Realtek High Definition Audio)"
+unsafe fn friendly_name(device: &IMMDevice) -> Option<String> {
+    let props = device.OpenPropertyStore(STGM_READ).ok()?;
+    let mut value = props.GetValue(&PKEY_Device_FriendlyName).ok()?;
+    let name = PropVariantToStringAlloc(&value)
+        .ok()
+        .map(|s| {
+            let name = s.to_string().unwrap_or_default();
+            CoTaskMemFree(Some(s.0 as *const _));
+            name
+        })
+        .filter(|n| !n.is_empty());
+    let _ = PropVariantClear(&mut value);
+    name
+}
@@ -1,5 +1,15 @@
 use lazy_static::lazy_static;
-use std::time::Duration;
+use serde::Serialize;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+use ureq::http::Response;
+use ureq::typestate::WithBody;
+use ureq::{Body, RequestBuilder};
 
 lazy_static! {
     pub static ref UREQ_AGENT: ureq::Agent = {
@@ -8,4 +18,767 @@ lazy_static! {
             .build();
         config.into()
     };
+    /// Requests currently running per provider, for `ProviderSlot`
+    static ref IN_FLIGHT: Mutex<HashMap<String, u32>> = Mutex::new(HashMap::new());
+    /// When each rate-limited "provider/model" accepts requests again
+    static ref COOLDOWNS: Mutex<HashMap<String, SystemTime>> = Mutex::new(HashMap::new());
+}
+
+/// Signalled whenever a `ProviderSlot` is released
+static SLOT_FREED: Condvar = Condvar::new();
+
+/// Error text used when a request is aborted through its cancel token
+pub const CANCELLED: &str = "CANCELLED";
+
+thread_local! {
+    /// Cancel token of the streaming call currently running on this thread
+    static CANCEL_TOKEN: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+    /// Token counter for the streaming call currently running on this thread
+    static USAGE_SINK: RefCell<Option<Arc<Mutex<TokenUsage>>>> = const { RefCell::new(None) };
+    /// Providers this thread already holds a `ProviderSlot` for
+    static HELD_SLOTS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    /// "provider/model" a rate limit on this thread's requests is charged to
+    static RATE_LIMIT_KEY: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Makes `token` the cancel token for every API request issued on this thread
+/// until the scope is dropped (the previous token, if any, is restored).
+pub struct CancelScope {
+    previous: Option<Arc<AtomicBool>>,
+}
+
+impl CancelScope {
+    pub fn enter(token: &Arc<AtomicBool>) -> Self {
+        let previous = CANCEL_TOKEN.with(|t| t.borrow_mut().replace(token.clone()));
+        Self { previous }
+    }
+}
+
+impl Drop for CancelScope {
+    fn drop(&mut self) {
+        CANCEL_TOKEN.with(|t| *t.borrow_mut() = self.previous.take());
+    }
+}
+
+/// True if the current thread's request was cancelled (e.g. its result window closed)
+pub fn is_cancelled() -> bool {
+    CANCEL_TOKEN.with(|t| {
+        t.borrow()
+            .as_ref()
+            .is_some_and(|token| token.load(Ordering::Relaxed))
+    })
+}
+
+/// Charges rate limits hit by this thread's requests to `provider`/`model` until
+/// the scope is dropped, so the model picker can show when it frees up again
+pub struct RateLimitScope {
+    previous: Option<String>,
+}
+
+impl RateLimitScope {
+    pub fn enter(provider: &str, model: &str) -> Self {
+        let key = format!("{}/{}", provider, model);
+        let previous = RATE_LIMIT_KEY.with(|k| k.borrow_mut().replace(key));
+        Self { previous }
+    }
+}
+
+impl Drop for RateLimitScope {
+    fn drop(&mut self) {
+        RATE_LIMIT_KEY.with(|k| *k.borrow_mut() = self.previous.take());
+    }
+}
+
+/// Time left until a model that hit its rate limit accepts requests again
+pub fn model_cooldown(provider: &str, model: &str) -> Option<Duration> {
+    let key = format!("{}/{}", provider, model);
+    let until = *COOLDOWNS.lock().unwrap().get(&key)?;
+    until
+        .duration_since(SystemTime::now())
+        .ok()
+        .filter(|left| !left.is_zero())
+}
+
+/// "resets in 3m" / "resets in 40s" for the model picker
+pub fn format_cooldown(left: Duration, ui_language: &str) -> String {
+    let secs = left.as_secs().max(1);
+    let amount = if secs >= 3600 {
+        format!("{}h{}m", secs / 3600, secs % 3600 / 60)
+    } else if secs >= 60 {
+        format!("{}m", secs.div_ceil(60))
+    } else {
+        format!("{}s", secs)
+    };
+    match ui_language {
+        "vi" => format!("đặt lại sau {}", amount),
+        "ko" => format!("{} 후 재설정", amount),
+        "ja" => format!("{}後にリセット", amount),
+        _ => format!("resets in {}", amount),
+    }
+}
+
+fn set_cooldown(wait: Option<Duration>) {
+    let Some(key) = RATE_LIMIT_KEY.with(|k| k.borrow().clone()) else {
+        return;
+    };
+    let mut cooldowns = COOLDOWNS.lock().unwrap();
+    match wait {
+        Some(wait) => {
+            cooldowns.insert(key, SystemTime::now() + wait);
+        }
+        None => {
+            cooldowns.remove(&key);
+        }
+    }
+}
+
+/// One of a provider's limited concurrent request slots, released on drop.
+/// Keeps bursts of presets or fanned-out chains from tripping provider 429s.
+pub struct ProviderSlot {
+    /// None when no slot was taken (no limit, or already held by this thread)
+    provider: Option<String>,
+}
+
+impl ProviderSlot {
+    /// Wait for a free slot for `provider`. Fails with `CANCELLED` if the
+    /// current thread's request is cancelled while queued.
+    pub fn acquire(provider: &str) -> anyhow::Result<Self> {
+        let limit = max_in_flight(provider);
+        // A request that calls another one for the same provider must not wait on itself
+        let nested = HELD_SLOTS.with(|held| held.borrow().iter().any(|p| p == provider));
+        if limit == 0 || nested {
+            return Ok(Self { provider: None });
+        }
+
+        let mut in_flight = IN_FLIGHT.lock().unwrap();
+        let mut logged = false;
+        loop {
+            let count = in_flight.entry(provider.to_string()).or_insert(0);
+            if *count < limit {
+                *count += 1;
+                break;
+            }
+            if is_cancelled() {
+                return Err(anyhow::anyhow!(CANCELLED));
+            }
+            if !logged {
+                println!(
+                    "[API] {} has {} requests in flight - queued",
+                    provider, limit
+                );
+                logged = true;
+            }
+            in_flight = SLOT_FREED
+                .wait_timeout(in_flight, STREAM_POLL_INTERVAL)
+                .unwrap()
+                .0;
+        }
+        HELD_SLOTS.with(|held| held.borrow_mut().push(provider.to_string()));
+        Ok(Self {
+            provider: Some(provider.to_string()),
+        })
+    }
+}
+
+impl Drop for ProviderSlot {
+    fn drop(&mut self) {
+        let Some(provider) = self.provider.take() else {
+            return;
+        };
+        HELD_SLOTS.with(|held| held.borrow_mut().retain(|p| *p != provider));
+        if let Some(count) = IN_FLIGHT.lock().unwrap().get_mut(&provider) {
+            *count = count.saturating_sub(1);
+        }
+        SLOT_FREED.notify_all();
+    }
+}
+
+/// Configured concurrent request limit for a provider (0 = no limit)
+fn max_in_flight(provider: &str) -> u32 {
+    crate::APP
+        .lock()
+        .ok()
+        .map(|app| {
+            if provider == "ollama" {
+                app.config.ollama_max_concurrent
+            } else {
+                app.config.api_max_concurrent
+            }
+        })
+        .unwrap_or(0)
+}
+
+/// Prompt / completion token counts of one request
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokenUsage {
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    /// Counts were estimated from characters because the provider reported nothing
+    pub estimated: bool,
+}
+
+impl TokenUsage {
+    /// Keep the largest value seen per field (providers report running totals)
+    fn merge(&mut self, prompt_tokens: u64, completion_tokens: u64) {
+        self.prompt_tokens = self.prompt_tokens.max(prompt_tokens);
+        self.completion_tokens = self.completion_tokens.max(completion_tokens);
+    }
+}
+
+/// Rough token count for providers that don't report usage (~4 chars per token)
+pub fn estimate_tokens(text: &str) -> u64 {
+    (text.chars().count() as u64).div_ceil(4)
+}
+
+/// Collects token usage reported by every stream read on this thread until dropped
+pub struct UsageScope {
+    sink: Arc<Mutex<TokenUsage>>,
+    previous: Option<Arc<Mutex<TokenUsage>>>,
+}
+
+impl UsageScope {
+    pub fn enter() -> Self {
+        let sink = Arc::new(Mutex::new(TokenUsage::default()));
+        let previous = USAGE_SINK.with(|s| s.borrow_mut().replace(sink.clone()));
+        Self { sink, previous }
+    }
+
+    /// Usage reported by the provider, or an estimate from the prompt/output text
+    pub fn finish(self, prompt: &str, output: &str) -> TokenUsage {
+        let reported = *self.sink.lock().unwrap();
+        if reported.prompt_tokens > 0 || reported.completion_tokens > 0 {
+            reported
+        } else {
+            TokenUsage {
+                prompt_tokens: estimate_tokens(prompt),
+                completion_tokens: estimate_tokens(output),
+                estimated: true,
+            }
+        }
+    }
+}
+
+impl Drop for UsageScope {
+    fn drop(&mut self) {
+        USAGE_SINK.with(|s| *s.borrow_mut() = self.previous.take());
+    }
+}
+
+/// Pull (prompt, completion) token counts out of a stream event, whatever the provider's format
+fn extract_usage(event: &serde_json::Value) -> Option<(u64, u64)> {
+    // OpenAI-style (Groq / Cerebras / OpenRouter), Groq's x_groq, Anthropic message_start / message_delta
+    let usage = event
+        .get("usage")
+        .or_else(|| event.get("x_groq").and_then(|x| x.get("usage")))
+        .or_else(|| event.get("message").and_then(|m| m.get("usage")))
+        .filter(|u| u.is_object());
+    if let Some(u) = usage {
+        let prompt = u["prompt_tokens"]
+            .as_u64()
+            .or_else(|| u["input_tokens"].as_u64())
+            .unwrap_or(0);
+        let completion = u["completion_tokens"]
+            .as_u64()
+            .or_else(|| u["output_tokens"].as_u64())
+            .unwrap_or(0);
+        return Some((prompt, completion));
+    }
+
+    // Gemini
+    if let Some(u) = event.get("usageMetadata") {
+        let prompt = u["promptTokenCount"].as_u64().unwrap_or(0);
+        let completion = u["candidatesTokenCount"].as_u64().unwrap_or(0)
+            + u["thoughtsTokenCount"].as_u64().unwrap_or(0);
+        return Some((prompt, completion));
+    }
+
+    // Ollama (final chunk)
+    if event.get("eval_count").is_some() || event.get("prompt_eval_count").is_some() {
+        return Some((
+            event["prompt_eval_count"].as_u64().unwrap_or(0),
+            event["eval_count"].as_u64().unwrap_or(0),
+        ));
+    }
+
+    None
+}
+
+/// Read a non-streaming JSON response, recording the usage it reports into the
+/// current [`UsageScope`] (streams are covered by [`stream_reader`] instead)
+pub fn read_json_with_usage<T: serde::de::DeserializeOwned>(
+    resp: Response<Body>,
+) -> Result<T, ureq::Error> {
+    let body: serde_json::Value = resp.into_body().read_json()?;
+    if let Some((prompt, completion)) = extract_usage(&body) {
+        USAGE_SINK.with(|s| {
+            if let Some(mut usage) = s.borrow().as_ref().and_then(|sink| sink.lock().ok()) {
+                usage.merge(prompt, completion);
+            }
+        });
+    }
+    serde_json::from_value(body).map_err(ureq::Error::Json)
+}
+
+/// Inspect one SSE / NDJSON line for a usage object
+fn sniff_usage(line: &[u8], sink: &Mutex<TokenUsage>) {
+    let line = line.strip_prefix(b"data: ").unwrap_or(line);
+    let mentions = |needle: &[u8]| line.windows(needle.len()).any(|w| w == needle);
+    // Cheap pre-check so ordinary content chunks aren't parsed twice
+    if !mentions(b"usage") && !mentions(b"eval_count") {
+        return;
+    }
+    if let Ok(event) = serde_json::from_slice::<serde_json::Value>(line) {
+        if let Some((prompt, completion)) = extract_usage(&event) {
+            if let Ok(mut usage) = sink.lock() {
+                usage.merge(prompt, completion);
+            }
+        }
+    }
+}
+
+/// Error text used when a connection or stream exceeds its configured timeout
+pub const REQUEST_TIMEOUT: &str = "REQUEST_TIMEOUT";
+
+/// How often a waiting stream read wakes up to check the cancel token
+const STREAM_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// How often a retry backoff checks for cancellation
+const BACKOFF_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Connect / stall limits for one class of provider
+#[derive(Clone, Copy, Debug)]
+pub struct ApiTimeouts {
+    /// Max time to establish the connection
+    pub connect: Duration,
+    /// Max time to wait for the response headers and between two stream chunks
+    pub stall: Duration,
+}
+
+/// Timeouts for hosted providers (Groq, Gemini, OpenRouter, ...)
+pub fn cloud_timeouts() -> ApiTimeouts {
+    crate::APP
+        .lock()
+        .ok()
+        .map(|app| ApiTimeouts {
+            connect: Duration::from_secs(app.config.api_connect_timeout_secs.max(1)),
+            stall: Duration::from_secs(app.config.api_stall_timeout_secs.max(1)),
+        })
+        .unwrap_or(ApiTimeouts {
+            connect: Duration::from_secs(15),
+            stall: Duration::from_secs(60),
+        })
+}
+
+/// Timeouts for Ollama: local models may need minutes to load before the first token
+pub fn ollama_timeouts() -> ApiTimeouts {
+    crate::APP
+        .lock()
+        .ok()
+        .map(|app| ApiTimeouts {
+            connect: Duration::from_secs(app.config.api_connect_timeout_secs.max(1)),
+            stall: Duration::from_secs(app.config.ollama_stall_timeout_secs.max(1)),
+        })
+        .unwrap_or(ApiTimeouts {
+            connect: Duration::from_secs(15),
+            stall: Duration::from_secs(300),
+        })
+}
+
+/// Overall cap on a non-streaming request, body included (the agent's old 120s at least)
+const MIN_NON_STREAMING_TOTAL: Duration = Duration::from_secs(120);
+
+impl ApiTimeouts {
+    /// Longest a whole non-streaming request may take
+    pub fn total(&self) -> Duration {
+        (self.stall * 2).max(MIN_NON_STREAMING_TOTAL)
+    }
+}
+
+/// Apply connect / response timeouts to a request. Non-streaming requests keep an
+/// overall limit so reading the body can't hang; for streams the global limit is
+/// lifted and `StreamReader` enforces the stall timeout between chunks instead.
+pub fn with_timeouts<B>(
+    request: RequestBuilder<B>,
+    timeouts: ApiTimeouts,
+    streaming: bool,
+) -> RequestBuilder<B> {
+    let total = (!streaming).then(|| timeouts.total());
+    request
+        .config()
+        .timeout_global(total)
+        .timeout_connect(Some(timeouts.connect))
+        .timeout_recv_response(Some(timeouts.stall))
+        .build()
+}
+
+/// Streaming response body. The socket is read on a pump thread so that a read here
+/// can give up when the cancel token is raised or no data arrives within the stall timeout.
+pub struct StreamReader {
+    rx: Receiver<std::io::Result<Vec<u8>>>,
+    pending: Vec<u8>,
+    pos: usize,
+    token: Option<Arc<AtomicBool>>,
+    stall_timeout: Duration,
+}
+
+impl Read for StreamReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut waited = Duration::ZERO;
+        while self.pos >= self.pending.len() {
+            if self
+                .token
+                .as_ref()
+                .is_some_and(|token| token.load(Ordering::Relaxed))
+            {
+                return Err(std::io::Error::other(CANCELLED));
+            }
+            match self.rx.recv_timeout(STREAM_POLL_INTERVAL) {
+                Ok(Ok(bytes)) => {
+                    self.pending = bytes;
+                    self.pos = 0;
+                }
+                Ok(Err(e)) => return Err(e),
+                Err(RecvTimeoutError::Timeout) => {
+                    waited += STREAM_POLL_INTERVAL;
+                    if waited >= self.stall_timeout {
+                        return Err(std::io::Error::new(
+                            std::io::ErrorKind::TimedOut,
+                            REQUEST_TIMEOUT,
+                        ));
+                    }
+                }
+                // Pump thread finished: end of stream
+                Err(RecvTimeoutError::Disconnected) => return Ok(0),
+            }
+        }
+
+        let n = buf.len().min(self.pending.len() - self.pos);
+        buf[..n].copy_from_slice(&self.pending[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+/// Buffered line reader over a streaming (SSE / NDJSON) response from a hosted provider,
+/// bound to the current thread's cancel token
+pub fn stream_reader(resp: Response<Body>) -> BufReader<StreamReader> {
+    stream_reader_with_stall(resp, cloud_timeouts().stall)
+}
+
+/// Same as [`stream_reader`] with an explicit stall timeout (time allowed between chunks)
+pub fn stream_reader_with_stall(
+    resp: Response<Body>,
+    stall_timeout: Duration,
+) -> BufReader<StreamReader> {
+    let token = CANCEL_TOKEN.with(|t| t.borrow().clone());
+    let usage_sink = USAGE_SINK.with(|s| s.borrow().clone());
+    let (tx, rx) = mpsc::sync_channel(16);
+    let mut body = resp.into_body().into_reader();
+
+    std::thread::spawn(move || {
+        let mut buf = vec![0u8; 8192];
+        let mut line_buf: Vec<u8> = Vec::new();
+        loop {
+            match body.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => {
+                    // Watch for the provider's usage report while passing bytes through
+                    if let Some(sink) = &usage_sink {
+                        line_buf.extend_from_slice(&buf[..n]);
+                        while let Some(pos) = line_buf.iter().position(|&b| b == b'\n') {
+                            sniff_usage(line_buf[..pos].trim_ascii(), sink);
+                            line_buf.drain(..=pos);
+                        }
+                    }
+
+                    // Reader dropped (cancelled / timed out): stop and close the connection
+                    if tx.send(Ok(buf[..n].to_vec())).is_err() {
+                        break;
+                    }
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    let _ = tx.send(Err(e));
+                    break;
+                }
+            }
+        }
+    });
+
+    BufReader::new(StreamReader {
+        rx,
+        pending: Vec::new(),
+        pos: 0,
+        token,
+        stall_timeout,
+    })
+}
+
+/// Wrap a chunk callback so nothing reaches the UI once `token` is raised
+pub fn cancellable_on_chunk<F>(token: &Arc<AtomicBool>, mut on_chunk: F) -> impl FnMut(&str)
+where
+    F: FnMut(&str),
+{
+    let token = token.clone();
+    move |chunk: &str| {
+        if !token.load(Ordering::Relaxed) {
+            on_chunk(chunk);
+        }
+    }
+}
+
+/// True for connect / response / stall timeouts raised while talking to a provider
+pub fn is_timeout_error(error: &str) -> bool {
+    let lower = error.to_lowercase();
+    error.contains(REQUEST_TIMEOUT) || lower.contains("timeout") || lower.contains("timed out")
+}
+
+/// Error text used when a provider can't be reached at all (no network / DNS)
+pub const OFFLINE: &str = "OFFLINE";
+
+/// True when the request never reached the provider: DNS lookup or connection
+/// failures, as opposed to an HTTP error or a slow model
+pub fn is_offline_error(error: &str) -> bool {
+    if error.contains(OFFLINE) {
+        return true;
+    }
+    let lower = error.to_lowercase();
+    [
+        "host not found",
+        "failed to lookup address",
+        "no such host",
+        "connection failed",
+        "connection refused",
+        "network is unreachable",
+        "os error 10051", // WSAENETUNREACH
+        "os error 10065", // WSAEHOSTUNREACH
+        "os error 11001", // WSAHOST_NOT_FOUND
+    ]
+    .iter()
+    .any(|needle| lower.contains(needle))
+}
+
+/// Quick check that an Ollama server answers, so an offline fallback doesn't
+/// wait on the regular connect timeout
+pub fn is_ollama_reachable(base_url: &str) -> bool {
+    let url = format!("{}/api/tags", base_url.trim_end_matches('/'));
+    let probe = ApiTimeouts {
+        connect: Duration::from_millis(800),
+        stall: Duration::from_millis(1500),
+    };
+    with_timeouts(UREQ_AGENT.get(&url), probe, false)
+        .call()
+        .is_ok()
+}
+
+/// On a timeout, replace whatever was streamed so far with the localized error
+/// so the result window stops showing a half-finished answer
+pub fn report_timeout<F>(result: &anyhow::Result<String>, ui_language: &str, on_chunk: &mut F)
+where
+    F: FnMut(&str),
+{
+    if let Err(e) = result {
+        let error = e.to_string();
+        if is_timeout_error(&error) {
+            let message = crate::overlay::utils::get_error_message(&error, ui_language, None);
+            on_chunk(&format!("{}{}", crate::api::WIPE_SIGNAL, message));
+        }
+    }
+}
+
+/// First backoff step; doubles with every retry (1s, 2s, 4s, ...)
+const RETRY_BASE_DELAY_MS: u64 = 1000;
+/// Upper bound for a single wait, including server-provided `Retry-After`
+const RETRY_MAX_DELAY_SECS: u64 = 30;
+
+/// Rate-limited (429) or server-side (5xx) failures are worth retrying
+fn is_retryable_status(status: u16) -> bool {
+    status == 429 || (500..600).contains(&status)
+}
+
+/// Read the configured maximum number of attempts (1 = no retry)
+fn get_max_attempts() -> u32 {
+    crate::APP
+        .lock()
+        .ok()
+        .map(|app| app.config.api_max_attempts)
+        .unwrap_or(3)
+        .max(1)
+}
+
+/// Delay before the next attempt: `Retry-After` (seconds) if the server sent one,
+/// exponential backoff otherwise
+fn retry_delay(resp: &Response<Body>, attempt: u32) -> Duration {
+    let retry_after = resp
+        .headers()
+        .get("retry-after")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok());
+
+    let delay = match retry_after {
+        Some(secs) => Duration::from_secs(secs),
+        None => Duration::from_millis(RETRY_BASE_DELAY_MS << (attempt - 1).min(5)),
+    };
+    delay.min(Duration::from_secs(RETRY_MAX_DELAY_SECS))
+}
+
+/// Assumed cooldown when a 429 says nothing about when the limit resets
+const DEFAULT_RATE_LIMIT_COOLDOWN_SECS: u64 = 60;
+
+/// "1h2m3.5s", "59.56s", "120ms", "37s" (OpenAI/Groq reset headers, Gemini RetryInfo)
+fn parse_reset_duration(value: &str) -> Option<Duration> {
+    let mut total = 0.0;
+    let mut number = String::new();
+    let mut chars = value.trim().chars().peekable();
+    let mut any = false;
+    while let Some(c) = chars.next() {
+        if c.is_ascii_digit() || c == '.' {
+            number.push(c);
+            continue;
+        }
+        let amount: f64 = number.parse().ok()?;
+        number.clear();
+        total += match c {
+            'h' => amount * 3600.0,
+            'm' if chars.peek() == Some(&'s') => {
+                chars.next();
+                amount / 1000.0
+            }
+            'm' => amount * 60.0,
+            's' => amount,
+            _ => return None,
+        };
+        any = true;
+    }
+    // A bare number is seconds
+    if !number.is_empty() {
+        total += number.parse::<f64>().ok()?;
+        any = true;
+    }
+    any.then(|| Duration::from_secs_f64(total))
+}
+
+/// How long until the provider lifts a rate limit, from the response headers:
+/// `Retry-After`, OpenAI-style `x-ratelimit-reset-*` or Anthropic's reset timestamps
+fn rate_limit_reset(resp: &Response<Body>) -> Option<Duration> {
+    let header = |name: &str| {
+        resp.headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    let mut waits = Vec::new();
+    if let Some(secs) = header("retry-after").and_then(|v| v.trim().parse::<u64>().ok()) {
+        waits.push(Duration::from_secs(secs));
+    }
+    for name in ["x-ratelimit-reset-requests", "x-ratelimit-reset-tokens"] {
+        waits.extend(header(name).as_deref().and_then(parse_reset_duration));
+    }
+    for name in [
+        "anthropic-ratelimit-requests-reset",
+        "anthropic-ratelimit-tokens-reset",
+    ] {
+        let reset = header(name).and_then(|v| chrono::DateTime::parse_from_rfc3339(&v).ok());
+        if let Some(reset) = reset {
+            waits.extend(
+                (reset.with_timezone(&chrono::Utc) - chrono::Utc::now())
+                    .to_std()
+                    .ok(),
+            );
+        }
+    }
+    waits.into_iter().max()
+}
+
+/// Gemini puts the wait in the 429 body: `error.details[].retryDelay` ("37s")
+fn retry_delay_from_body(body: &str) -> Option<Duration> {
+    let json: serde_json::Value = serde_json::from_str(body).ok()?;
+    json["error"]["details"]
+        .as_array()?
+        .iter()
+        .find_map(|d| d["retryDelay"].as_str())
+        .and_then(parse_reset_duration)
+}
+
+/// POST a JSON payload, retrying on HTTP 429/5xx with exponential backoff.
+///
+/// `build` is called once per attempt to produce a fresh request (URL + headers).
+/// `streaming` says whether the body will be read through `stream_reader`, which
+/// decides between an overall and a per-chunk time limit (see `with_timeouts`).
+/// Only the initial connection is retried: the response is returned as soon as the
+/// status is accepted, before any of the body (stream) has been read, so partial
+/// output can never be emitted twice. Failed statuses are reported as
+/// `ureq::Error::StatusCode` just like a plain `send_json` call.
+pub fn send_json_with_retry<F, T>(
+    build: F,
+    payload: &T,
+    streaming: bool,
+) -> Result<Response<Body>, ureq::Error>
+where
+    F: Fn() -> RequestBuilder<WithBody>,
+    T: Serialize,
+{
+    let max_attempts = get_max_attempts();
+    let timeouts = cloud_timeouts();
+    let mut attempt = 1;
+
+    loop {
+        if is_cancelled() {
+            return Err(ureq::Error::Io(std::io::Error::other(CANCELLED)));
+        }
+
+        let mut resp = with_timeouts(build(), timeouts, streaming)
+            .config()
+            .http_status_as_error(false)
+            .build()
+            .send_json(payload)?;
+
+        let status = resp.status().as_u16();
+        if status < 400 {
+            set_cooldown(None);
+            return Ok(resp);
+        }
+        if !is_retryable_status(status) || attempt >= max_attempts || is_cancelled() {
+            if status == 429 {
+                // Out of retries: remember when the model is usable again
+                let wait = rate_limit_reset(&resp).or_else(|| {
+                    let body = resp.body_mut().read_to_string().ok()?;
+                    retry_delay_from_body(&body)
+                });
+                set_cooldown(Some(
+                    wait.unwrap_or(Duration::from_secs(DEFAULT_RATE_LIMIT_COOLDOWN_SECS)),
+                ));
+            }
+            return Err(ureq::Error::StatusCode(status));
+        }
+
+        let delay = retry_delay(&resp, attempt);
+        println!(
+            "[API] HTTP {} - retrying in {:?} (attempt {}/{})",
+            status,
+            delay,
+            attempt + 1,
+            max_attempts
+        );
+        if !sleep_unless_cancelled(delay) {
+            return Err(ureq::Error::Io(std::io::Error::other(CANCELLED)));
+        }
+        attempt += 1;
+    }
+}
+
+/// Sleep in short slices so a cancel (Esc, closing the window) ends a long backoff
+/// right away; false if cancelled
+fn sleep_unless_cancelled(delay: Duration) -> bool {
+    let deadline = Instant::now() + delay;
+    loop {
+        if is_cancelled() {
+            return false;
+        }
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return true;
+        }
+        std::thread::sleep(left.min(BACKOFF_POLL_INTERVAL));
+    }
 }
@@ -108,6 +108,12 @@ where
 
     // Process events from the worker
     loop {
+        // Result window was closed - stop forwarding the response
+        if crate::api::client::is_cancelled() {
+            println!("[GeminiLive] Request {} cancelled", id);
+            return Err(anyhow::anyhow!(crate::api::client::CANCELLED));
+        }
+
         match rx.recv() {
             Ok(LiveEvent::Thinking) => {
                 event_count += 1;
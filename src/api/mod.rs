@@ -1,7 +1,10 @@
+pub mod anthropic;
 pub mod audio;
+pub mod audio_devices;
 pub mod client;
 pub mod gemini_live;
 pub mod ollama;
+pub mod openai_compat;
 pub mod realtime_audio;
 pub mod text;
 pub mod tts;
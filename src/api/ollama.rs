@@ -4,9 +4,11 @@
 use anyhow::Result;
 use image::{ImageBuffer, Rgba};
 use base64::{Engine as _, engine::general_purpose};
-use std::io::{Cursor, BufRead, BufReader};
+use std::io::{Cursor, BufRead};
 use serde::Deserialize;
-use super::client::UREQ_AGENT;
+use super::client::{
+    ollama_timeouts, read_json_with_usage, stream_reader_with_stall, with_timeouts, UREQ_AGENT,
+};
 use crate::gui::locale::LocaleText;
 
 /// Ollama streaming chunk response
@@ -154,15 +156,14 @@ where
         "stream": streaming_enabled
     });
     
-    let resp = UREQ_AGENT.post(&url)
-        
+    let resp = with_timeouts(UREQ_AGENT.post(&url), ollama_timeouts(), streaming_enabled)
                 .send_json(&payload)
         .map_err(|e| anyhow::anyhow!("Ollama API Error: {}", e))?;
     
     let mut full_content = String::new();
     
     if streaming_enabled {
-        let reader = BufReader::new(resp.into_body().into_reader());
+        let reader = stream_reader_with_stall(resp, ollama_timeouts().stall);
         let mut thinking_shown = false;
         let mut content_started = false;
         let locale = LocaleText::get(ui_language);
@@ -204,7 +205,7 @@ where
             }
         }
     } else {
-        let ollama_resp: OllamaGenerateResponse = resp.into_body().read_json()
+        let ollama_resp: OllamaGenerateResponse = read_json_with_usage(resp)
             .map_err(|e| anyhow::anyhow!("Failed to parse Ollama response: {}", e))?;
         
         full_content = ollama_resp.response;
@@ -241,15 +242,14 @@ where
         "stream": streaming_enabled
     });
     
-    let resp = UREQ_AGENT.post(&url)
-        
+    let resp = with_timeouts(UREQ_AGENT.post(&url), ollama_timeouts(), streaming_enabled)
                 .send_json(&payload)
         .map_err(|e| anyhow::anyhow!("Ollama Vision API Error: {}", e))?;
     
     let mut full_content = String::new();
     
     if streaming_enabled {
-        let reader = BufReader::new(resp.into_body().into_reader());
+        let reader = stream_reader_with_stall(resp, ollama_timeouts().stall);
         let mut thinking_shown = false;
         let mut content_started = false;
         let locale = LocaleText::get(ui_language);
@@ -290,7 +290,7 @@ where
             }
         }
     } else {
-        let ollama_resp: OllamaGenerateResponse = resp.into_body().read_json()
+        let ollama_resp: OllamaGenerateResponse = read_json_with_usage(resp)
             .map_err(|e| anyhow::anyhow!("Failed to parse Ollama response: {}", e))?;
         
         full_content = ollama_resp.response;
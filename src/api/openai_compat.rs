@@ -0,0 +1,156 @@
+//! Generic OpenAI-compatible endpoint (vLLM, LM Studio, llama.cpp server, ...)
+//! Speaks the chat-completions protocol against a user-configured base URL
+
+use super::client::{read_json_with_usage, send_json_with_retry, stream_reader, UREQ_AGENT};
+use super::types::{ChatCompletionResponse, StreamChunk};
+use crate::gui::locale::LocaleText;
+use anyhow::Result;
+use std::io::BufRead;
+
+/// Read the custom endpoint base URL and API key from config
+pub fn get_custom_openai_settings() -> (String, String) {
+    crate::APP
+        .lock()
+        .ok()
+        .map(|app| {
+            (
+                app.config.custom_openai_base_url.clone(),
+                app.config.custom_openai_api_key.clone(),
+            )
+        })
+        .unwrap_or_default()
+}
+
+/// Generate with an OpenAI-compatible server.
+/// `image_b64_png` is an optional base64-encoded PNG for vision requests.
+pub fn openai_compat_generate<F>(
+    base_url: &str,
+    api_key: &str,
+    model: &str,
+    prompt: &str,
+    image_b64_png: Option<String>,
+    streaming_enabled: bool,
+    ui_language: &str,
+    mut on_chunk: F,
+) -> Result<String>
+where
+    F: FnMut(&str),
+{
+    if base_url.trim().is_empty() {
+        return Err(anyhow::anyhow!(
+            "OpenAI-compatible endpoint base URL is not configured"
+        ));
+    }
+
+    let url = format!("{}/chat/completions", base_url.trim().trim_end_matches('/'));
+
+    let content = match image_b64_png {
+        Some(b64_image) => serde_json::json!([
+            { "type": "text", "text": prompt },
+            { "type": "image_url", "image_url": { "url": format!("data:image/png;base64,{}", b64_image) } }
+        ]),
+        None => serde_json::json!(prompt),
+    };
+
+    let mut payload = serde_json::json!({
+        "model": model,
+        "messages": [
+            { "role": "user", "content": content }
+        ],
+        "stream": streaming_enabled
+    });
+    if streaming_enabled {
+        // Ask for the final usage chunk (servers that don't know the option ignore it)
+        payload["stream_options"] = serde_json::json!({ "include_usage": true });
+    }
+
+    let build_request = || {
+        let request = UREQ_AGENT
+            .post(&url)
+            .header("Content-Type", "application/json");
+        // Self-hosted servers often run without auth
+        if api_key.trim().is_empty() {
+            request
+        } else {
+            request.header("Authorization", &format!("Bearer {}", api_key.trim()))
+        }
+    };
+
+    let resp = send_json_with_retry(build_request, &payload, streaming_enabled).map_err(|e| {
+        let err_str = e.to_string();
+        if err_str.contains("401") || err_str.contains("403") {
+            anyhow::anyhow!("INVALID_API_KEY:custom-openai")
+        } else {
+            anyhow::anyhow!("OpenAI-compatible API Error: {}", err_str)
+        }
+    })?;
+
+    let mut full_content = String::new();
+
+    if streaming_enabled {
+        let reader = stream_reader(resp);
+        let mut thinking_shown = false;
+        let mut content_started = false;
+        let locale = LocaleText::get(ui_language);
+
+        for line in reader.lines() {
+            let line = line?;
+            if line.starts_with("data: ") {
+                let data = &line[6..];
+                if data == "[DONE]" {
+                    break;
+                }
+
+                match serde_json::from_str::<StreamChunk>(data) {
+                    Ok(chunk) => {
+                        // Check for reasoning tokens (thinking phase)
+                        if chunk
+                            .choices
+                            .get(0)
+                            .and_then(|c| c.delta.reasoning.as_ref())
+                            .filter(|s| !s.is_empty())
+                            .is_some()
+                            && !thinking_shown
+                            && !content_started
+                        {
+                            on_chunk(locale.model_thinking);
+                            thinking_shown = true;
+                        }
+
+                        // Check for content tokens (final result)
+                        if let Some(content) = chunk
+                            .choices
+                            .get(0)
+                            .and_then(|c| c.delta.content.as_ref())
+                            .filter(|s| !s.is_empty())
+                        {
+                            if !content_started && thinking_shown {
+                                content_started = true;
+                                // Use WIPE_SIGNAL to tell callback to clear accumulator
+                                full_content.push_str(content);
+                                let wipe_content =
+                                    format!("{}{}", crate::api::WIPE_SIGNAL, full_content);
+                                on_chunk(&wipe_content);
+                            } else {
+                                content_started = true;
+                                full_content.push_str(content);
+                                on_chunk(content);
+                            }
+                        }
+                    }
+                    Err(_) => continue,
+                }
+            }
+        }
+    } else {
+        let chat_resp: ChatCompletionResponse = read_json_with_usage(resp)
+            .map_err(|e| anyhow::anyhow!("Failed to parse non-streaming response: {}", e))?;
+
+        if let Some(choice) = chat_resp.choices.first() {
+            full_content = choice.message.content.clone();
+            on_chunk(&full_content);
+        }
+    }
+
+    Ok(full_content)
+}
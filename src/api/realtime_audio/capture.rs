@@ -10,6 +10,63 @@ use std::time::Duration;
 
 use super::REALTIME_RMS;
 
+/// Token of the capture stream that currently owns `REALTIME_RMS`.
+/// Checked and written under one lock so a stream that is still winding down
+/// after a source switch can never overwrite the meter of its replacement.
+static METER_OWNER: Mutex<u32> = Mutex::new(0);
+
+/// Meter ceiling in RMS units (the canvas maps 0..~0.3 onto its full height)
+const METER_CEILING: f32 = 0.3;
+/// Loopback level at which the compressed meter reaches ~63% of the ceiling
+const LOOPBACK_KNEE: f32 = 0.2;
+
+/// What kind of signal a capture stream produces, for meter normalization
+#[derive(Clone, Copy)]
+enum LevelSource {
+    Mic,
+    Loopback,
+}
+
+impl LevelSource {
+    /// Map a raw RMS onto the shared meter range. Mic speech sits well inside
+    /// the range as-is; loopback audio is mastered near full scale and would
+    /// peg the meter, so it is compressed with a soft knee instead.
+    fn normalize(self, rms: f32) -> f32 {
+        match self {
+            LevelSource::Mic => rms.min(METER_CEILING),
+            LevelSource::Loopback => METER_CEILING * (1.0 - (-rms / LOOPBACK_KNEE).exp()),
+        }
+    }
+}
+
+/// Invalidate whichever stream owns the volume meter and zero it.
+/// Called when the audio source is about to be swapped.
+pub fn reset_volume_meter() {
+    claim_volume_meter();
+}
+
+/// Take ownership of the volume meter for a new stream, zeroing it
+fn claim_volume_meter() -> u32 {
+    let mut owner = METER_OWNER.lock().unwrap_or_else(|e| e.into_inner());
+    *owner = owner.wrapping_add(1);
+    REALTIME_RMS.store(0, Ordering::SeqCst);
+    *owner
+}
+
+/// Publish the RMS of `samples` if `token` still owns the meter
+fn publish_level(token: u32, source: LevelSource, samples: &[i16]) {
+    if samples.is_empty() {
+        return;
+    }
+    let sum_sq: f64 = samples.iter().map(|&s| (s as f64 / 32768.0).powi(2)).sum();
+    let rms = (sum_sq / samples.len() as f64).sqrt() as f32;
+
+    let owner = METER_OWNER.lock().unwrap_or_else(|e| e.into_inner());
+    if *owner == token {
+        REALTIME_RMS.store(source.normalize(rms).to_bits(), Ordering::Relaxed);
+    }
+}
+
 /// Start per-app audio capture using WASAPI process loopback (Windows 10 1903+)
 ///
 /// This function spawns a thread that captures audio from a specific process
@@ -24,6 +81,8 @@ pub fn start_per_app_capture(
     use std::collections::VecDeque;
     use wasapi::{AudioClient, Direction, SampleType, StreamMode, WaveFormat};
 
+    let meter_token = claim_volume_meter();
+
     std::thread::spawn(move || {
         // Initialize COM for this thread (required for WASAPI)
         if wasapi::initialize_mta().is_err() {
@@ -143,13 +202,8 @@ pub fn start_per_app_capture(
                                 buf.extend(&samples);
                             }
 
-                            // Calculate RMS for volume visualization
-                            if !samples.is_empty() {
-                                let sum_sq: f64 =
-                                    samples.iter().map(|&s| (s as f64 / 32768.0).powi(2)).sum();
-                                let rms = (sum_sq / samples.len() as f64).sqrt() as f32;
-                                REALTIME_RMS.store(rms.to_bits(), Ordering::Relaxed);
-                            }
+                            // Volume visualization
+                            publish_level(meter_token, LevelSource::Loopback, &samples);
                         }
                     }
                 }
@@ -170,13 +224,183 @@ pub fn start_per_app_capture(
     Ok(())
 }
 
+/// A running capture started by `start_mic_capture` / `start_device_loopback_capture`.
+/// Dropping it stops the capture, so a source or model switch doesn't leave the
+/// old one running until the session ends.
+#[allow(dead_code)] // The streams are only held for their Drop
+pub enum CaptureStream {
+    Cpal(cpal::Stream),
+    #[cfg(target_os = "windows")]
+    Endpoint(EndpointCapture),
+}
+
+/// The thread reading a specific WASAPI endpoint, stopped and joined on drop
+#[cfg(target_os = "windows")]
+pub struct EndpointCapture {
+    stop: Arc<AtomicBool>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+#[cfg(target_os = "windows")]
+impl Drop for EndpointCapture {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// The endpoint picked for realtime audio, if any
+#[cfg(target_os = "windows")]
+fn selected_endpoint() -> Option<String> {
+    let app = crate::APP.lock().ok()?;
+    let id = app.config.realtime_audio_device.clone();
+    (!id.is_empty()).then_some(id)
+}
+
+/// Capture a specific WASAPI endpoint on its own thread: speakers as loopback,
+/// microphones directly. Windows converts to 16kHz mono 16-bit for us.
+/// Runs until `stop_signal` is set or the returned guard is dropped.
+/// Fails if the endpoint can't be opened (unplugged, or the wrong kind).
+#[cfg(target_os = "windows")]
+fn start_endpoint_capture(
+    device_id: String,
+    loopback: bool,
+    audio_buffer: Arc<Mutex<Vec<i16>>>,
+    stop_signal: Arc<AtomicBool>,
+    pause_signal: Arc<AtomicBool>,
+) -> Result<EndpointCapture> {
+    use windows::Win32::Media::Audio::AUDCLNT_BUFFERFLAGS_SILENT;
+    use windows::Win32::System::Com::{CoInitializeEx, CoUninitialize, COINIT_MULTITHREADED};
+
+    let source = if loopback {
+        LevelSource::Loopback
+    } else {
+        LevelSource::Mic
+    };
+    let (ready_tx, ready_rx) = std::sync::mpsc::channel::<Result<(), String>>();
+    let own_stop = Arc::new(AtomicBool::new(false));
+    let thread_stop = own_stop.clone();
+
+    let thread = std::thread::spawn(move || unsafe {
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+        let (client, capture) = match open_capture_client(&device_id, loopback) {
+            Ok(opened) => {
+                let _ = ready_tx.send(Ok(()));
+                opened
+            }
+            Err(e) => {
+                let _ = ready_tx.send(Err(e.to_string()));
+                CoUninitialize();
+                return;
+            }
+        };
+        let meter_token = claim_volume_meter();
+
+        while !stop_signal.load(Ordering::Relaxed) && !thread_stop.load(Ordering::Relaxed) {
+            std::thread::sleep(Duration::from_millis(10));
+            while capture.GetNextPacketSize().unwrap_or(0) > 0 {
+                let mut data = std::ptr::null_mut();
+                let mut frames = 0u32;
+                let mut flags = 0u32;
+                if capture
+                    .GetBuffer(&mut data, &mut frames, &mut flags, None, None)
+                    .is_err()
+                {
+                    break;
+                }
+                let samples: Vec<i16> =
+                    if data.is_null() || flags & AUDCLNT_BUFFERFLAGS_SILENT.0 as u32 != 0 {
+                        vec![0; frames as usize]
+                    } else {
+                        std::slice::from_raw_parts(data as *const i16, frames as usize).to_vec()
+                    };
+                let _ = capture.ReleaseBuffer(frames);
+
+                if pause_signal.load(Ordering::Relaxed) {
+                    continue;
+                }
+                if let Ok(mut buf) = audio_buffer.lock() {
+                    buf.extend(&samples);
+                }
+                publish_level(meter_token, source, &samples);
+            }
+        }
+
+        let _ = client.Stop();
+        drop(capture);
+        drop(client);
+        CoUninitialize();
+    });
+
+    ready_rx
+        .recv()
+        .map_err(|_| anyhow::anyhow!("Capture thread exited"))?
+        .map_err(|e| anyhow::anyhow!(e))?;
+    Ok(EndpointCapture {
+        stop: own_stop,
+        thread: Some(thread),
+    })
+}
+
+#[cfg(target_os = "windows")]
+unsafe fn open_capture_client(
+    device_id: &str,
+    loopback: bool,
+) -> Result<(
+    windows::Win32::Media::Audio::IAudioClient,
+    windows::Win32::Media::Audio::IAudioCaptureClient,
+)> {
+    use windows::Win32::Media::Audio::*;
+    use windows::Win32::System::Com::CLSCTX_ALL;
+
+    let device = crate::api::audio_devices::open_endpoint(device_id, !loopback)
+        .ok_or_else(|| anyhow::anyhow!("Audio device not found"))?;
+    let client: IAudioClient = device.Activate(CLSCTX_ALL, None)?;
+
+    // 16kHz mono 16-bit (what Gemini and Parakeet expect)
+    let format = WAVEFORMATEX {
+        wFormatTag: WAVE_FORMAT_PCM as u16,
+        nChannels: 1,
+        nSamplesPerSec: 16000,
+        nAvgBytesPerSec: 32000,
+        nBlockAlign: 2,
+        wBitsPerSample: 16,
+        cbSize: 0,
+    };
+    let mut flags = AUDCLNT_STREAMFLAGS_AUTOCONVERTPCM | AUDCLNT_STREAMFLAGS_SRC_DEFAULT_QUALITY;
+    if loopback {
+        flags |= AUDCLNT_STREAMFLAGS_LOOPBACK;
+    }
+    // 100ms buffer in 100-nanosecond units
+    client.Initialize(AUDCLNT_SHAREMODE_SHARED, flags, 1_000_000, 0, &format, None)?;
+    let capture: IAudioCaptureClient = client.GetService()?;
+    client.Start()?;
+    Ok((client, capture))
+}
+
 /// Start device loopback capture (captures all system audio)
-/// Returns the cpal Stream that must be kept alive
+/// Returns the stream that must be kept alive; dropping it stops the capture
 pub fn start_device_loopback_capture(
     audio_buffer: Arc<Mutex<Vec<i16>>>,
     stop_signal: Arc<AtomicBool>,
     pause_signal: Arc<AtomicBool>,
-) -> Result<cpal::Stream> {
+) -> Result<CaptureStream> {
+    #[cfg(target_os = "windows")]
+    if let Some(device_id) = selected_endpoint() {
+        match start_endpoint_capture(
+            device_id,
+            true,
+            audio_buffer.clone(),
+            stop_signal.clone(),
+            pause_signal.clone(),
+        ) {
+            Ok(capture) => return Ok(CaptureStream::Endpoint(capture)),
+            Err(e) => eprintln!("Loopback capture: {} - using the default device", e),
+        }
+    }
+
     #[cfg(target_os = "windows")]
     let host = cpal::host_from_id(cpal::HostId::Wasapi).unwrap_or(cpal::default_host());
     #[cfg(not(target_os = "windows"))]
@@ -200,6 +424,7 @@ pub fn start_device_loopback_capture(
     let stop_signal_audio = stop_signal.clone();
     let pause_signal_audio = pause_signal.clone();
     let err_fn = |err| eprintln!("Audio stream error: {}", err);
+    let meter_token = claim_volume_meter();
 
     let stream = match config.sample_format() {
         cpal::SampleFormat::F32 => device.build_input_stream(
@@ -243,15 +468,8 @@ pub fn start_device_loopback_capture(
                     buf.extend(resampled.iter().cloned());
                 }
 
-                // Calculate RMS for volume visualization
-                if !resampled.is_empty() {
-                    let sum_sq: f64 = resampled
-                        .iter()
-                        .map(|&s| (s as f64 / 32768.0).powi(2))
-                        .sum();
-                    let rms = (sum_sq / resampled.len() as f64).sqrt() as f32;
-                    REALTIME_RMS.store(rms.to_bits(), Ordering::Relaxed);
-                }
+                // Volume visualization
+                publish_level(meter_token, LevelSource::Loopback, &resampled);
             },
             err_fn,
             None,
@@ -296,15 +514,8 @@ pub fn start_device_loopback_capture(
                     buf.extend(resampled.iter().cloned());
                 }
 
-                // Calculate RMS for volume visualization
-                if !resampled.is_empty() {
-                    let sum_sq: f64 = resampled
-                        .iter()
-                        .map(|&s| (s as f64 / 32768.0).powi(2))
-                        .sum();
-                    let rms = (sum_sq / resampled.len() as f64).sqrt() as f32;
-                    REALTIME_RMS.store(rms.to_bits(), Ordering::Relaxed);
-                }
+                // Volume visualization
+                publish_level(meter_token, LevelSource::Loopback, &resampled);
             },
             err_fn,
             None,
@@ -313,16 +524,30 @@ pub fn start_device_loopback_capture(
     };
 
     stream.play()?;
-    Ok(stream)
+    Ok(CaptureStream::Cpal(stream))
 }
 
 /// Start microphone capture
-/// Returns the cpal Stream that must be kept alive
+/// Returns the stream that must be kept alive; dropping it stops the capture
 pub fn start_mic_capture(
     audio_buffer: Arc<Mutex<Vec<i16>>>,
     stop_signal: Arc<AtomicBool>,
     pause_signal: Arc<AtomicBool>,
-) -> Result<cpal::Stream> {
+) -> Result<CaptureStream> {
+    #[cfg(target_os = "windows")]
+    if let Some(device_id) = selected_endpoint() {
+        match start_endpoint_capture(
+            device_id,
+            false,
+            audio_buffer.clone(),
+            stop_signal.clone(),
+            pause_signal.clone(),
+        ) {
+            Ok(capture) => return Ok(CaptureStream::Endpoint(capture)),
+            Err(e) => eprintln!("Mic capture: {} - using the default microphone", e),
+        }
+    }
+
     let host = cpal::default_host();
     let device = host
         .default_input_device()
@@ -337,6 +562,7 @@ pub fn start_mic_capture(
     let stop_signal_audio = stop_signal.clone();
     let pause_signal_audio = pause_signal.clone();
     let err_fn = |err| eprintln!("Audio stream error: {}", err);
+    let meter_token = claim_volume_meter();
 
     let stream = match config.sample_format() {
         cpal::SampleFormat::F32 => device.build_input_stream(
@@ -378,14 +604,7 @@ pub fn start_mic_capture(
                     buf.extend(resampled.iter().cloned());
                 }
 
-                if !resampled.is_empty() {
-                    let sum_sq: f64 = resampled
-                        .iter()
-                        .map(|&s| (s as f64 / 32768.0).powi(2))
-                        .sum();
-                    let rms = (sum_sq / resampled.len() as f64).sqrt() as f32;
-                    REALTIME_RMS.store(rms.to_bits(), Ordering::Relaxed);
-                }
+                publish_level(meter_token, LevelSource::Mic, &resampled);
             },
             err_fn,
             None,
@@ -394,5 +613,5 @@ pub fn start_mic_capture(
     };
 
     stream.play()?;
-    Ok(stream)
+    Ok(CaptureStream::Cpal(stream))
 }
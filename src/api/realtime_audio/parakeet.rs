@@ -9,7 +9,7 @@ use windows::Win32::Foundation::LPARAM;
 use windows::Win32::Foundation::WPARAM;
 use windows::Win32::UI::WindowsAndMessaging::PostMessageW;
 
-use super::{REALTIME_RMS, WM_REALTIME_UPDATE, WM_VOLUME_UPDATE};
+use super::{WM_REALTIME_UPDATE, WM_VOLUME_UPDATE};
 use crate::overlay::realtime_webview::AUDIO_SOURCE_CHANGE;
 
 /// 160ms chunk at 16kHz = 2560 samples (recommended by parakeet-rs)
@@ -182,11 +182,9 @@ where
         };
 
         if !new_samples.is_empty() {
-            // Volume Visualization
+            // Recording overlay viz (the realtime meter is fed by the capture stream)
             let sum_sq: f64 = new_samples.iter().map(|&s| (s as f64).powi(2)).sum();
             let rms = (sum_sq / new_samples.len() as f64).sqrt() as f32;
-            REALTIME_RMS.store(rms.to_bits(), Ordering::Relaxed);
-            // Also update recording overlay viz
             crate::overlay::recording::update_audio_viz(rms);
             if rms > 0.001 {
                 crate::overlay::recording::AUDIO_WARMUP_COMPLETE.store(true, Ordering::SeqCst);
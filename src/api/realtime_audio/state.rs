@@ -35,6 +35,15 @@ impl Default for TranscriptionMethod {
     }
 }
 
+/// A committed source sentence and its translation, kept for the saved session
+#[derive(Clone, Debug)]
+pub struct SessionSegment {
+    /// Time since the session started
+    pub offset: Duration,
+    pub source: String,
+    pub translation: String,
+}
+
 pub struct RealtimeState {
     pub full_transcript: String,
     pub display_transcript: String,
@@ -60,6 +69,12 @@ pub struct RealtimeState {
 
     pub transcription_method: TranscriptionMethod,
     pub parakeet_segment_start_time: Instant,
+
+    /// Wall-clock start of the session, for the saved session file
+    pub started_at: chrono::DateTime<chrono::Local>,
+    pub session_start: Instant,
+    /// Every committed translation of the session (translation_history only keeps a few)
+    pub segments: Vec<SessionSegment>,
 }
 
 impl RealtimeState {
@@ -81,6 +96,9 @@ impl RealtimeState {
             download_progress: 0.0,
             transcription_method: TranscriptionMethod::GeminiLive,
             parakeet_segment_start_time: Instant::now(),
+            started_at: chrono::Local::now(),
+            session_start: Instant::now(),
+            segments: Vec::new(),
         }
     }
 
@@ -262,7 +280,12 @@ impl RealtimeState {
                 "[continued]".to_string()
             };
 
-            self.add_to_history(source_segment, trans_segment.clone());
+            self.add_to_history(source_segment.clone(), trans_segment.clone());
+            self.segments.push(SessionSegment {
+                offset: self.session_start.elapsed(),
+                source: source_segment,
+                translation: trans_segment.clone(),
+            });
 
             if self.committed_translation.is_empty() {
                 self.committed_translation = trans_segment;
@@ -328,6 +351,7 @@ impl RealtimeState {
     /// Safely advance the commit pointer by a specific amount.
     /// Used to commit exactly what was translated, avoiding race conditions.
     pub fn advance_committed_pos(&mut self, amount: usize) {
+        let old_pos = self.last_committed_pos;
         let new_pos = self.last_committed_pos + amount;
 
         // Safety bounds check
@@ -339,6 +363,13 @@ impl RealtimeState {
                 self.last_committed_pos = self.full_transcript.len();
             }
         }
+
+        // A translation committed just before this belongs to the source just passed
+        if let Some(segment) = self.segments.last_mut().filter(|s| s.source.is_empty()) {
+            if let Some(source) = self.full_transcript.get(old_pos..self.last_committed_pos) {
+                segment.source = source.trim().to_string();
+            }
+        }
     }
 
     pub fn start_new_translation(&mut self) {
@@ -349,6 +380,12 @@ impl RealtimeState {
     pub fn commit_current_translation(&mut self) {
         let trans_segment = self.uncommitted_translation.trim().to_string();
         if !trans_segment.is_empty() {
+            // Its source is filled in by the advance_committed_pos call that follows
+            self.segments.push(SessionSegment {
+                offset: self.session_start.elapsed(),
+                source: String::new(),
+                translation: trans_segment.clone(),
+            });
             if self.committed_translation.is_empty() {
                 self.committed_translation = trans_segment;
             } else {
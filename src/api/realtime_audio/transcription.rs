@@ -13,7 +13,9 @@ use crate::config::Preset;
 use crate::overlay::realtime_webview::SELECTED_APP_PID;
 use crate::APP;
 
-use super::capture::{start_device_loopback_capture, start_mic_capture, start_per_app_capture};
+use super::capture::{
+    start_device_loopback_capture, start_mic_capture, start_per_app_capture, CaptureStream,
+};
 use super::state::SharedRealtimeState;
 use super::translation::run_translation_loop;
 use super::utils::update_overlay_text;
@@ -21,7 +23,7 @@ use super::websocket::{
     connect_websocket, parse_input_transcription, send_audio_chunk, send_setup_message,
     set_socket_nonblocking, set_socket_short_timeout,
 };
-use super::{REALTIME_RMS, WM_VOLUME_UPDATE};
+use super::WM_VOLUME_UPDATE;
 
 /// Audio mode state machine for silence injection
 #[derive(Clone, Copy, PartialEq)]
@@ -71,7 +73,8 @@ fn transcription_thread_entry(
     let hwnd_translation = translation_send.map(|h| h.0);
 
     use crate::overlay::realtime_webview::{
-        AUDIO_SOURCE_CHANGE, NEW_AUDIO_SOURCE, NEW_TRANSCRIPTION_MODEL, TRANSCRIPTION_MODEL_CHANGE,
+        AUDIO_SOURCE_CHANGE, NEW_AUDIO_DEVICE, NEW_AUDIO_SOURCE, NEW_TRANSCRIPTION_MODEL,
+        TRANSCRIPTION_MODEL_CHANGE,
     };
 
     let mut current_preset = preset;
@@ -80,8 +83,10 @@ fn transcription_thread_entry(
         AUDIO_SOURCE_CHANGE.store(false, Ordering::SeqCst);
         TRANSCRIPTION_MODEL_CHANGE.store(false, Ordering::SeqCst);
 
-        // Reset volume indicator to ensure fresh state when switching methods
-        REALTIME_RMS.store(0, Ordering::SeqCst);
+        // Cut off the previous stream's meter updates and start from zero
+        // when switching methods or sources
+        super::capture::reset_volume_meter();
+        show_volume_source(hwnd_overlay, &current_preset.audio_source);
 
         let trans_model = {
             let app = APP.lock().unwrap();
@@ -157,6 +162,9 @@ fn transcription_thread_entry(
                     // println!("Changing audio source to: {}", new_source);
                     let mut app = APP.lock().unwrap();
                     app.config.realtime_audio_source = new_source.clone();
+                    if let Ok(new_device) = NEW_AUDIO_DEVICE.lock() {
+                        app.config.realtime_audio_device = new_device.clone();
+                    }
                     current_preset.audio_source = new_source.clone();
                     // Save config? Optional, but UI should sync.
                 }
@@ -189,6 +197,42 @@ fn transcription_thread_entry(
             break;
         }
     }
+
+    // The session is over: keep its captions for "reopen last session"
+    let target_language = APP
+        .lock()
+        .map(|app| app.config.realtime_target_language.clone())
+        .unwrap_or_default();
+    if let Ok(mut s) = state.lock() {
+        s.force_commit_all();
+        crate::overlay::realtime_webview::session::save_last_session(&s, &target_language);
+    }
+}
+
+/// Tint the overlay's volume meter for the source being captured
+fn show_volume_source(hwnd_overlay: HWND, source: &str) {
+    // The minimal (egui) overlay has no window of its own here
+    if hwnd_overlay.is_invalid() {
+        return;
+    }
+    let script = Box::new(format!(
+        "if(window.setVolumeSource) window.setVolumeSource('{}');",
+        if source == "device" { "device" } else { "mic" }
+    ));
+    let ptr = Box::into_raw(script);
+    unsafe {
+        if PostMessageW(
+            Some(hwnd_overlay),
+            super::WM_EXEC_SCRIPT,
+            WPARAM(0),
+            LPARAM(ptr as isize),
+        )
+        .is_err()
+        {
+            // Not delivered: the window owns nothing, free it here
+            drop(Box::from_raw(ptr));
+        }
+    }
 }
 
 fn run_realtime_transcription(
@@ -291,7 +335,7 @@ fn run_realtime_transcription(
     let using_per_app_capture = preset.audio_source == "device" && tts_enabled && selected_pid > 0;
     let using_device_loopback = preset.audio_source == "device" && !tts_enabled;
 
-    let _stream: Option<cpal::Stream>;
+    let _stream: Option<CaptureStream>;
 
     let dummy_pause = Arc::new(AtomicBool::new(false));
 
@@ -48,7 +48,7 @@ pub fn run_translation_loop(
             });
 
         from_ui.unwrap_or_else(|| {
-            if !translation_block.selected_language.is_empty() {
+            let language = if !translation_block.selected_language.is_empty() {
                 translation_block.selected_language.clone()
             } else {
                 translation_block
@@ -57,7 +57,9 @@ pub fn run_translation_loop(
                     .cloned()
                     .or_else(|| translation_block.language_vars.get("language1").cloned())
                     .unwrap_or_else(|| "English".to_string())
-            }
+            };
+            let (target, fallback) = crate::overlay::utils::keyboard_language_source();
+            crate::overlay::utils::resolve_language_var(&language, target, &fallback)
         })
     };
 
@@ -1,4 +1,8 @@
-use super::client::UREQ_AGENT;
+use super::client::{
+    cancellable_on_chunk, is_offline_error, is_ollama_reachable, read_json_with_usage,
+    report_timeout, send_json_with_retry, stream_reader, CancelScope, ProviderSlot, RateLimitScope,
+    OFFLINE, UREQ_AGENT,
+};
 use super::types::{ChatCompletionResponse, StreamChunk};
 use super::vision::translate_image_streaming as vision_translate_image_streaming;
 use crate::gui::locale::LocaleText;
@@ -6,9 +10,100 @@ use crate::overlay::result::RefineContext;
 use crate::overlay::utils::get_context_quote;
 use crate::APP;
 use anyhow::Result;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 
 pub fn translate_text_streaming<F>(
+    groq_api_key: &str,
+    gemini_api_key: &str,
+    text: String,
+    instruction: String,
+    model: String,
+    provider: String,
+    streaming_enabled: bool,
+    use_json_format: bool,
+    search_label: Option<String>,
+    ui_language: &str,
+    cancel_token: &Arc<AtomicBool>,
+    on_chunk: F,
+) -> Result<String>
+where
+    F: FnMut(&str),
+{
+    // Stop reading the response and drop chunks once the caller cancels
+    let _cancel_scope = CancelScope::enter(cancel_token);
+    let mut on_chunk = cancellable_on_chunk(cancel_token, on_chunk);
+    // Queue behind other requests to the same provider if it is at its limit
+    let _slot = ProviderSlot::acquire(&provider)?;
+    // A 429 this call gives up on puts the model on cooldown
+    let _rate_limit_scope = RateLimitScope::enter(&provider, &model);
+
+    let is_local = provider == "ollama";
+    let result = translate_text_streaming_inner(
+        groq_api_key,
+        gemini_api_key,
+        text.clone(),
+        instruction.clone(),
+        model,
+        provider,
+        streaming_enabled,
+        use_json_format,
+        search_label,
+        ui_language,
+        &mut on_chunk,
+    );
+    let result = match result {
+        Err(e) if !is_local && is_offline_error(&e.to_string()) => offline_fallback(
+            &text,
+            &instruction,
+            streaming_enabled,
+            ui_language,
+            &mut on_chunk,
+        ),
+        other => other,
+    };
+    report_timeout(&result, ui_language, &mut on_chunk);
+    result
+}
+
+/// No network: run the request on the configured local Ollama text model if
+/// its server answers, otherwise fail with `OFFLINE` for a localized message
+fn offline_fallback<F>(
+    text: &str,
+    instruction: &str,
+    streaming_enabled: bool,
+    ui_language: &str,
+    on_chunk: F,
+) -> Result<String>
+where
+    F: FnMut(&str),
+{
+    let ollama = APP.lock().ok().and_then(|app| {
+        let config = &app.config;
+        (config.use_ollama && !config.ollama_text_model.is_empty()).then(|| {
+            (
+                config.ollama_base_url.clone(),
+                config.ollama_text_model.clone(),
+            )
+        })
+    });
+    let Some((base_url, model)) = ollama.filter(|(url, _)| is_ollama_reachable(url)) else {
+        return Err(anyhow::anyhow!(OFFLINE));
+    };
+
+    crate::log_info!("[Text] Offline, falling back to Ollama model {}", model);
+    super::ollama::ollama_generate_text(
+        &base_url,
+        &model,
+        &format!("{}\n\n{}", instruction, text),
+        streaming_enabled,
+        ui_language,
+        on_chunk,
+    )
+}
+
+fn translate_text_streaming_inner<F>(
     groq_api_key: &str,
     gemini_api_key: &str,
     text: String,
@@ -81,6 +176,30 @@ where
             ui_language,
             on_chunk,
         );
+    } else if provider == "anthropic" {
+        // --- ANTHROPIC CLAUDE API ---
+        return super::anthropic::anthropic_generate(
+            &super::anthropic::get_anthropic_api_key(),
+            &model,
+            &prompt,
+            None, // No image for text-only
+            streaming_enabled,
+            ui_language,
+            on_chunk,
+        );
+    } else if provider == "custom-openai" {
+        // --- OPENAI-COMPATIBLE ENDPOINT ---
+        let (base_url, api_key) = super::openai_compat::get_custom_openai_settings();
+        return super::openai_compat::openai_compat_generate(
+            &base_url,
+            &api_key,
+            &model,
+            &prompt,
+            None, // No image for text-only
+            streaming_enabled,
+            ui_language,
+            on_chunk,
+        );
     } else if provider == "gemini-live" {
         // --- GEMINI LIVE API (WebSocket-based low-latency streaming) ---
         return super::gemini_live::gemini_live_generate(
@@ -177,21 +296,26 @@ where
             ]);
         }
 
-        let resp = UREQ_AGENT
-            .post(&url)
-            .header("x-goog-api-key", gemini_api_key)
-            .send_json(payload)
-            .map_err(|e| {
-                let err_str = e.to_string();
-                if err_str.contains("401") || err_str.contains("403") {
-                    anyhow::anyhow!("INVALID_API_KEY")
-                } else {
-                    anyhow::anyhow!("Gemini Text API Error: {}", err_str)
-                }
-            })?;
+        let resp = send_json_with_retry(
+            || {
+                UREQ_AGENT
+                    .post(&url)
+                    .header("x-goog-api-key", gemini_api_key)
+            },
+            &payload,
+            streaming_enabled,
+        )
+        .map_err(|e| {
+            let err_str = e.to_string();
+            if err_str.contains("401") || err_str.contains("403") {
+                anyhow::anyhow!("INVALID_API_KEY")
+            } else {
+                anyhow::anyhow!("Gemini Text API Error: {}", err_str)
+            }
+        })?;
 
         if streaming_enabled {
-            let reader = BufReader::new(resp.into_body().into_reader());
+            let reader = stream_reader(resp);
             let mut thinking_shown = false;
             let mut content_started = false;
             let locale = LocaleText::get(ui_language);
@@ -257,9 +381,7 @@ where
                 }
             }
         } else {
-            let chat_resp: serde_json::Value = resp
-                .into_body()
-                .read_json()
+            let chat_resp: serde_json::Value = read_json_with_usage(resp)
                 .map_err(|e| anyhow::anyhow!("Failed to parse non-streaming response: {}", e))?;
 
             if let Some(candidates) = chat_resp.get("candidates").and_then(|c| c.as_array()) {
@@ -296,19 +418,24 @@ where
             "stream": streaming_enabled
         });
 
-        let resp = UREQ_AGENT
-            .post("https://api.cerebras.ai/v1/chat/completions")
-            .header("Authorization", &format!("Bearer {}", cerebras_api_key))
-            .header("Content-Type", "application/json")
-            .send_json(payload)
-            .map_err(|e| {
-                let err_str = e.to_string();
-                if err_str.contains("401") || err_str.contains("403") {
-                    anyhow::anyhow!("INVALID_API_KEY")
-                } else {
-                    anyhow::anyhow!("Cerebras API Error: {}", err_str)
-                }
-            })?;
+        let resp = send_json_with_retry(
+            || {
+                UREQ_AGENT
+                    .post("https://api.cerebras.ai/v1/chat/completions")
+                    .header("Authorization", &format!("Bearer {}", cerebras_api_key))
+                    .header("Content-Type", "application/json")
+            },
+            &payload,
+            streaming_enabled,
+        )
+        .map_err(|e| {
+            let err_str = e.to_string();
+            if err_str.contains("401") || err_str.contains("403") {
+                anyhow::anyhow!("INVALID_API_KEY")
+            } else {
+                anyhow::anyhow!("Cerebras API Error: {}", err_str)
+            }
+        })?;
 
         // Extract rate limit info
         // Extract rate limit info
@@ -343,7 +470,7 @@ where
         }
 
         if streaming_enabled {
-            let reader = BufReader::new(resp.into_body().into_reader());
+            let reader = stream_reader(resp);
             let mut thinking_shown = false;
             let mut content_started = false;
             let locale = LocaleText::get(ui_language);
@@ -407,9 +534,7 @@ where
                 }
             }
         } else {
-            let chat_resp: ChatCompletionResponse = resp
-                .into_body()
-                .read_json()
+            let chat_resp: ChatCompletionResponse = read_json_with_usage(resp)
                 .map_err(|e| anyhow::anyhow!("Failed to parse non-streaming response: {}", e))?;
 
             if let Some(choice) = chat_resp.choices.first() {
@@ -431,22 +556,27 @@ where
             "stream": streaming_enabled
         });
 
-        let resp = UREQ_AGENT
-            .post("https://openrouter.ai/api/v1/chat/completions")
-            .header("Authorization", &format!("Bearer {}", openrouter_api_key))
-            .header("Content-Type", "application/json")
-            .send_json(payload)
-            .map_err(|e| {
-                let err_str = e.to_string();
-                if err_str.contains("401") || err_str.contains("403") {
-                    anyhow::anyhow!("INVALID_API_KEY")
-                } else {
-                    anyhow::anyhow!("OpenRouter API Error: {}", err_str)
-                }
-            })?;
+        let resp = send_json_with_retry(
+            || {
+                UREQ_AGENT
+                    .post("https://openrouter.ai/api/v1/chat/completions")
+                    .header("Authorization", &format!("Bearer {}", openrouter_api_key))
+                    .header("Content-Type", "application/json")
+            },
+            &payload,
+            streaming_enabled,
+        )
+        .map_err(|e| {
+            let err_str = e.to_string();
+            if err_str.contains("401") || err_str.contains("403") {
+                anyhow::anyhow!("INVALID_API_KEY")
+            } else {
+                anyhow::anyhow!("OpenRouter API Error: {}", err_str)
+            }
+        })?;
 
         if streaming_enabled {
-            let reader = BufReader::new(resp.into_body().into_reader());
+            let reader = stream_reader(resp);
             let mut thinking_shown = false;
             let mut content_started = false;
             let locale = LocaleText::get(ui_language);
@@ -503,9 +633,7 @@ where
                 }
             }
         } else {
-            let chat_resp: ChatCompletionResponse = resp
-                .into_body()
-                .read_json()
+            let chat_resp: ChatCompletionResponse = read_json_with_usage(resp)
                 .map_err(|e| anyhow::anyhow!("Failed to parse non-streaming response: {}", e))?;
 
             if let Some(choice) = chat_resp.choices.first() {
@@ -556,18 +684,23 @@ where
             };
             on_chunk(&search_msg);
 
-            let resp = UREQ_AGENT
-                .post("https://api.groq.com/openai/v1/chat/completions")
-                .header("Authorization", &format!("Bearer {}", groq_api_key))
-                .send_json(payload)
-                .map_err(|e| {
-                    let err_str = e.to_string();
-                    if err_str.contains("401") {
-                        anyhow::anyhow!("INVALID_API_KEY")
-                    } else {
-                        anyhow::anyhow!("{}", err_str)
-                    }
-                })?;
+            let resp = send_json_with_retry(
+                || {
+                    UREQ_AGENT
+                        .post("https://api.groq.com/openai/v1/chat/completions")
+                        .header("Authorization", &format!("Bearer {}", groq_api_key))
+                },
+                &payload,
+                false,
+            )
+            .map_err(|e| {
+                let err_str = e.to_string();
+                if err_str.contains("401") {
+                    anyhow::anyhow!("INVALID_API_KEY")
+                } else {
+                    anyhow::anyhow!("{}", err_str)
+                }
+            })?;
 
             if let Some(remaining) = resp
                 .headers()
@@ -586,9 +719,7 @@ where
                 }
             }
 
-            let json: serde_json::Value = resp
-                .into_body()
-                .read_json()
+            let json: serde_json::Value = read_json_with_usage(resp)
                 .map_err(|e| anyhow::anyhow!("Failed to parse compound response: {}", e))?;
 
             if let Some(choices) = json.get("choices").and_then(|c| c.as_array()) {
@@ -782,18 +913,23 @@ where
                 payload_obj
             };
 
-            let resp = UREQ_AGENT
-                .post("https://api.groq.com/openai/v1/chat/completions")
-                .header("Authorization", &format!("Bearer {}", groq_api_key))
-                .send_json(payload)
-                .map_err(|e| {
-                    let err_str = e.to_string();
-                    if err_str.contains("401") {
-                        anyhow::anyhow!("INVALID_API_KEY")
-                    } else {
-                        anyhow::anyhow!("{}", err_str)
-                    }
-                })?;
+            let resp = send_json_with_retry(
+                || {
+                    UREQ_AGENT
+                        .post("https://api.groq.com/openai/v1/chat/completions")
+                        .header("Authorization", &format!("Bearer {}", groq_api_key))
+                },
+                &payload,
+                streaming_enabled,
+            )
+            .map_err(|e| {
+                let err_str = e.to_string();
+                if err_str.contains("401") {
+                    anyhow::anyhow!("INVALID_API_KEY")
+                } else {
+                    anyhow::anyhow!("{}", err_str)
+                }
+            })?;
 
             if let Some(remaining) = resp
                 .headers()
@@ -813,7 +949,7 @@ where
             }
 
             if streaming_enabled {
-                let reader = BufReader::new(resp.into_body().into_reader());
+                let reader = stream_reader(resp);
 
                 for line in reader.lines() {
                     let line = line?;
@@ -838,7 +974,7 @@ where
                 }
             } else {
                 let chat_resp: ChatCompletionResponse =
-                    resp.into_body().read_json().map_err(|e| {
+                    read_json_with_usage(resp).map_err(|e| {
                         anyhow::anyhow!("Failed to parse non-streaming response: {}", e)
                     })?;
 
@@ -872,6 +1008,42 @@ where
 }
 
 pub fn refine_text_streaming<F>(
+    groq_api_key: &str,
+    gemini_api_key: &str,
+    context: RefineContext,
+    previous_text: String,
+    user_prompt: String,
+    original_model_id: &str,
+    original_provider: &str,
+    streaming_enabled: bool,
+    ui_language: &str,
+    cancel_token: &Arc<AtomicBool>,
+    on_chunk: F,
+) -> Result<String>
+where
+    F: FnMut(&str),
+{
+    // Stop reading the response and drop chunks once the caller cancels
+    let _cancel_scope = CancelScope::enter(cancel_token);
+    let mut on_chunk = cancellable_on_chunk(cancel_token, on_chunk);
+
+    let result = refine_text_streaming_inner(
+        groq_api_key,
+        gemini_api_key,
+        context,
+        previous_text,
+        user_prompt,
+        original_model_id,
+        original_provider,
+        streaming_enabled,
+        ui_language,
+        &mut on_chunk,
+    );
+    report_timeout(&result, ui_language, &mut on_chunk);
+    result
+}
+
+fn refine_text_streaming_inner<F>(
     groq_api_key: &str,
     gemini_api_key: &str,
     context: RefineContext,
@@ -912,10 +1084,18 @@ where
         })
         .unwrap_or_default();
 
-    let final_prompt = format!(
-        "Content:\n{}\n\nInstruction:\n{}\n\nOutput ONLY the result.",
-        previous_text, user_prompt
-    );
+    let final_prompt = if matches!(context, RefineContext::Image(_)) {
+        // The screenshot is re-sent, so the instruction may be a brand-new question about it
+        format!(
+            "The attached image is the original screenshot. A previous answer about it was:\n{}\n\nNew request (answer from the image itself, not only from the previous answer):\n{}\n\nOutput ONLY the result.",
+            previous_text, user_prompt
+        )
+    } else {
+        format!(
+            "Content:\n{}\n\nInstruction:\n{}\n\nOutput ONLY the result.",
+            previous_text, user_prompt
+        )
+    };
 
     let (mut target_id_or_name, mut target_provider) = match context {
         RefineContext::Image(_) => (original_model_id.to_string(), original_provider.to_string()),
@@ -944,6 +1124,10 @@ where
         target_provider = conf.provider;
     }
 
+    // Same limits as a first run, for the model the refinement actually goes to
+    let _slot = ProviderSlot::acquire(&target_provider)?;
+    let _rate_limit_scope = RateLimitScope::enter(&target_provider, &target_id_or_name);
+
     let mut exec_text_only = |p_model: String, p_provider: String| -> Result<String> {
         let mut full_content = String::new();
 
@@ -993,14 +1177,19 @@ where
                 ]);
             }
 
-            let resp = UREQ_AGENT
-                .post(&url)
-                .header("x-goog-api-key", gemini_api_key)
-                .send_json(payload)
-                .map_err(|e| anyhow::anyhow!("Gemini Refine Error: {}", e))?;
+            let resp = send_json_with_retry(
+                || {
+                    UREQ_AGENT
+                        .post(&url)
+                        .header("x-goog-api-key", gemini_api_key)
+                },
+                &payload,
+                streaming_enabled,
+            )
+            .map_err(|e| anyhow::anyhow!("Gemini Refine Error: {}", e))?;
 
             if streaming_enabled {
-                let reader = BufReader::new(resp.into_body().into_reader());
+                let reader = stream_reader(resp);
                 let mut thinking_shown = false;
                 let mut content_started = false;
                 let locale = LocaleText::get(ui_language);
@@ -1062,7 +1251,7 @@ where
                     }
                 }
             } else {
-                let json: serde_json::Value = resp.into_body().read_json()?;
+                let json: serde_json::Value = read_json_with_usage(resp)?;
                 if let Some(candidates) = json.get("candidates").and_then(|c| c.as_array()) {
                     if let Some(first) = candidates.first() {
                         if let Some(parts) = first
@@ -1083,6 +1272,30 @@ where
                     }
                 }
             }
+        } else if p_provider == "anthropic" {
+            // --- ANTHROPIC CLAUDE REFINE ---
+            return super::anthropic::anthropic_generate(
+                &super::anthropic::get_anthropic_api_key(),
+                &p_model,
+                &final_prompt,
+                None,
+                streaming_enabled,
+                ui_language,
+                &mut on_chunk,
+            );
+        } else if p_provider == "custom-openai" {
+            // --- OPENAI-COMPATIBLE REFINE ---
+            let (base_url, api_key) = super::openai_compat::get_custom_openai_settings();
+            return super::openai_compat::openai_compat_generate(
+                &base_url,
+                &api_key,
+                &p_model,
+                &final_prompt,
+                None,
+                streaming_enabled,
+                ui_language,
+                &mut on_chunk,
+            );
         } else if p_provider == "gemini-live" {
             // --- GEMINI LIVE REFINE ---
             return super::gemini_live::gemini_live_generate(
@@ -1107,12 +1320,17 @@ where
                 "stream": streaming_enabled
             });
 
-            let resp = UREQ_AGENT
-                .post("https://api.cerebras.ai/v1/chat/completions")
-                .header("Authorization", &format!("Bearer {}", cerebras_api_key))
-                .header("Content-Type", "application/json")
-                .send_json(payload)
-                .map_err(|e| anyhow::anyhow!("Cerebras Refine Error: {}", e))?;
+            let resp = send_json_with_retry(
+                || {
+                    UREQ_AGENT
+                        .post("https://api.cerebras.ai/v1/chat/completions")
+                        .header("Authorization", &format!("Bearer {}", cerebras_api_key))
+                        .header("Content-Type", "application/json")
+                },
+                &payload,
+                streaming_enabled,
+            )
+            .map_err(|e| anyhow::anyhow!("Cerebras Refine Error: {}", e))?;
 
             // Extract rate limit info
             // Extract rate limit info
@@ -1147,7 +1365,7 @@ where
             }
 
             if streaming_enabled {
-                let reader = BufReader::new(resp.into_body().into_reader());
+                let reader = stream_reader(resp);
                 let mut thinking_shown = false;
                 let mut content_started = false;
                 let locale = LocaleText::get(ui_language);
@@ -1207,7 +1425,7 @@ where
                     }
                 }
             } else {
-                let json: ChatCompletionResponse = resp.into_body().read_json()?;
+                let json: ChatCompletionResponse = read_json_with_usage(resp)?;
                 if let Some(choice) = json.choices.first() {
                     full_content = choice.message.content.clone();
                     on_chunk(&full_content);
@@ -1226,15 +1444,20 @@ where
                 "stream": streaming_enabled
             });
 
-            let resp = UREQ_AGENT
-                .post("https://openrouter.ai/api/v1/chat/completions")
-                .header("Authorization", &format!("Bearer {}", openrouter_api_key))
-                .header("Content-Type", "application/json")
-                .send_json(payload)
-                .map_err(|e| anyhow::anyhow!("OpenRouter Refine Error: {}", e))?;
+            let resp = send_json_with_retry(
+                || {
+                    UREQ_AGENT
+                        .post("https://openrouter.ai/api/v1/chat/completions")
+                        .header("Authorization", &format!("Bearer {}", openrouter_api_key))
+                        .header("Content-Type", "application/json")
+                },
+                &payload,
+                streaming_enabled,
+            )
+            .map_err(|e| anyhow::anyhow!("OpenRouter Refine Error: {}", e))?;
 
             if streaming_enabled {
-                let reader = BufReader::new(resp.into_body().into_reader());
+                let reader = stream_reader(resp);
                 let mut thinking_shown = false;
                 let mut content_started = false;
                 let locale = LocaleText::get(ui_language);
@@ -1288,7 +1511,7 @@ where
                     }
                 }
             } else {
-                let json: ChatCompletionResponse = resp.into_body().read_json()?;
+                let json: ChatCompletionResponse = read_json_with_usage(resp)?;
                 if let Some(choice) = json.choices.first() {
                     full_content = choice.message.content.clone();
                     on_chunk(&full_content);
@@ -1328,11 +1551,16 @@ where
                     context_quote, locale.search_doing, locale.search_searching
                 ));
 
-                let resp = UREQ_AGENT
-                    .post("https://api.groq.com/openai/v1/chat/completions")
-                    .header("Authorization", &format!("Bearer {}", groq_api_key))
-                    .send_json(payload)
-                    .map_err(|e| anyhow::anyhow!("Groq Compound Refine Error: {}", e))?;
+                let resp = send_json_with_retry(
+                    || {
+                        UREQ_AGENT
+                            .post("https://api.groq.com/openai/v1/chat/completions")
+                            .header("Authorization", &format!("Bearer {}", groq_api_key))
+                    },
+                    &payload,
+                    false,
+                )
+                .map_err(|e| anyhow::anyhow!("Groq Compound Refine Error: {}", e))?;
 
                 if let Some(remaining) = resp
                     .headers()
@@ -1350,7 +1578,7 @@ where
                     }
                 }
 
-                let json: serde_json::Value = resp.into_body().read_json()?;
+                let json: serde_json::Value = read_json_with_usage(resp)?;
 
                 if let Some(choices) = json.get("choices").and_then(|c| c.as_array()) {
                     if let Some(first_choice) = choices.first() {
@@ -1472,11 +1700,16 @@ where
                     "stream": streaming_enabled
                 });
 
-                let resp = UREQ_AGENT
-                    .post("https://api.groq.com/openai/v1/chat/completions")
-                    .header("Authorization", &format!("Bearer {}", groq_api_key))
-                    .send_json(payload)
-                    .map_err(|e| anyhow::anyhow!("Groq Refine Error: {}", e))?;
+                let resp = send_json_with_retry(
+                    || {
+                        UREQ_AGENT
+                            .post("https://api.groq.com/openai/v1/chat/completions")
+                            .header("Authorization", &format!("Bearer {}", groq_api_key))
+                    },
+                    &payload,
+                    streaming_enabled,
+                )
+                .map_err(|e| anyhow::anyhow!("Groq Refine Error: {}", e))?;
 
                 if let Some(remaining) = resp
                     .headers()
@@ -1495,7 +1728,7 @@ where
                 }
 
                 if streaming_enabled {
-                    let reader = BufReader::new(resp.into_body().into_reader());
+                    let reader = stream_reader(resp);
                     for line in reader.lines() {
                         let line = line?;
                         if line.starts_with("data: ") {
@@ -1514,7 +1747,7 @@ where
                         }
                     }
                 } else {
-                    let json: ChatCompletionResponse = resp.into_body().read_json()?;
+                    let json: ChatCompletionResponse = read_json_with_usage(resp)?;
                     if let Some(choice) = json.choices.first() {
                         full_content = choice.message.content.clone();
                         on_chunk(&full_content);
@@ -1543,6 +1776,22 @@ where
                     Some(img_bytes.clone()),
                     streaming_enabled,
                     false,
+                    cancel_token,
+                    on_chunk,
+                )
+            } else if target_provider == "anthropic" || target_provider == "custom-openai" {
+                let img = image::load_from_memory(&img_bytes)?.to_rgba8();
+                vision_translate_image_streaming(
+                    groq_api_key,
+                    gemini_api_key,
+                    final_prompt,
+                    target_id_or_name,
+                    target_provider,
+                    img,
+                    Some(img_bytes.clone()),
+                    streaming_enabled,
+                    false,
+                    cancel_token,
                     on_chunk,
                 )
             } else if target_provider == "gemini-live" {
@@ -1572,6 +1821,7 @@ where
                     Some(img_bytes.clone()),
                     streaming_enabled,
                     false,
+                    cancel_token,
                     on_chunk,
                 )
             }
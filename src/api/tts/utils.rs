@@ -1,7 +1,5 @@
 use windows::Win32::Foundation::HWND;
 use windows::Win32::Graphics::Gdi::InvalidateRect;
-use windows::Win32::Media::Audio::*;
-use windows::Win32::System::Com::*;
 
 use crate::overlay::result::state::WINDOW_STATES;
 
@@ -63,38 +61,5 @@ pub fn get_language_instruction_for_text(
 
 /// List available audio output devices (ID, Name)
 pub fn get_output_devices() -> Vec<(String, String)> {
-    let mut devices = Vec::new();
-    unsafe {
-        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
-        if let Ok(enumerator) =
-            CoCreateInstance::<_, IMMDeviceEnumerator>(&MMDeviceEnumerator, None, CLSCTX_ALL)
-        {
-            if let Ok(collection) = enumerator.EnumAudioEndpoints(eRender, DEVICE_STATE_ACTIVE) {
-                if let Ok(count) = collection.GetCount() {
-                    for i in 0..count {
-                        if let Ok(device) = collection.Item(i) {
-                            if let Ok(id) = device.GetId() {
-                                let id_str = id.to_string().unwrap_or_default();
-                                // Try to get friendly name
-                                let name = if let Ok(_props) = device.OpenPropertyStore(STGM_READ) {
-                                    // PKEY_Device_FriendlyName would be ideal but requires property key definition
-                                    // For now, let's just use the ID or partial ID if needed,
-                                    // but usually we rely on the ID.
-                                    // If we really need the name, we would need to implement property getters.
-                                    // Given the original code had this logic, we keep it simple or reuse if possible.
-                                    // The original code comment said: "In windows 0.62, PropVariant access is verbose."
-                                    // and "Let's rely on the ID matching...".
-                                    id_str.clone()
-                                } else {
-                                    id_str.clone()
-                                };
-                                devices.push((id_str, name));
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
-    devices
+    crate::api::audio_devices::list_audio_endpoints(false)
 }
@@ -1,13 +1,63 @@
-use super::client::UREQ_AGENT;
+use super::client::{
+    cancellable_on_chunk, read_json_with_usage, report_timeout, send_json_with_retry,
+    stream_reader, CancelScope, ProviderSlot, RateLimitScope, UREQ_AGENT,
+};
 use super::types::{ChatCompletionResponse, StreamChunk};
 use crate::gui::locale::LocaleText;
 use crate::APP;
 use anyhow::Result;
 use base64::{engine::general_purpose, Engine as _};
 use image::{ImageBuffer, Rgba};
-use std::io::{BufRead, BufReader, Cursor};
+use std::io::{BufRead, Cursor};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 
 pub fn translate_image_streaming<F>(
+    groq_api_key: &str,
+    gemini_api_key: &str,
+    prompt: String,
+    model: String,
+    provider: String,
+    image: ImageBuffer<Rgba<u8>, Vec<u8>>,
+    original_bytes: Option<Vec<u8>>, // Zero-Copy support
+    streaming_enabled: bool,
+    use_json_format: bool,
+    cancel_token: &Arc<AtomicBool>,
+    on_chunk: F,
+) -> Result<String>
+where
+    F: FnMut(&str),
+{
+    // Stop reading the response and drop chunks once the caller cancels
+    let _cancel_scope = CancelScope::enter(cancel_token);
+    let mut on_chunk = cancellable_on_chunk(cancel_token, on_chunk);
+    // Queue behind other requests to the same provider if it is at its limit
+    let _slot = ProviderSlot::acquire(&provider)?;
+    // A 429 this call gives up on puts the model on cooldown
+    let _rate_limit_scope = RateLimitScope::enter(&provider, &model);
+    let ui_language = crate::APP
+        .lock()
+        .ok()
+        .map(|app| app.config.ui_language.clone())
+        .unwrap_or_else(|| "en".to_string());
+
+    let result = translate_image_streaming_inner(
+        groq_api_key,
+        gemini_api_key,
+        prompt,
+        model,
+        provider,
+        image,
+        original_bytes,
+        streaming_enabled,
+        use_json_format,
+        &mut on_chunk,
+    );
+    report_timeout(&result, &ui_language, &mut on_chunk);
+    result
+}
+
+fn translate_image_streaming_inner<F>(
     groq_api_key: &str,
     gemini_api_key: &str,
     prompt: String,
@@ -161,6 +211,43 @@ where
             &ui_language,
             on_chunk,
         );
+    } else if provider == "anthropic" {
+        // --- ANTHROPIC CLAUDE API ---
+        let ui_language = crate::APP
+            .lock()
+            .ok()
+            .map(|app| app.config.ui_language.clone())
+            .unwrap_or_else(|| "en".to_string());
+
+        return super::anthropic::anthropic_generate(
+            &super::anthropic::get_anthropic_api_key(),
+            &model,
+            &prompt,
+            Some((b64_image, mime_type)),
+            streaming_enabled,
+            &ui_language,
+            on_chunk,
+        );
+    } else if provider == "custom-openai" {
+        // --- OPENAI-COMPATIBLE ENDPOINT ---
+        let ui_language = crate::APP
+            .lock()
+            .ok()
+            .map(|app| app.config.ui_language.clone())
+            .unwrap_or_else(|| "en".to_string());
+
+        // Standard path always produced a PNG for non-Google providers
+        let (base_url, api_key) = super::openai_compat::get_custom_openai_settings();
+        return super::openai_compat::openai_compat_generate(
+            &base_url,
+            &api_key,
+            &model,
+            &prompt,
+            Some(b64_image),
+            streaming_enabled,
+            &ui_language,
+            on_chunk,
+        );
     } else if provider == "qrserver" {
         // --- QR SERVER API ---
         // Non-LLM QR Code scanner - no API key required
@@ -289,21 +376,26 @@ where
             ]);
         }
 
-        let resp = UREQ_AGENT
-            .post(&url)
-            .header("x-goog-api-key", gemini_api_key)
-            .send_json(payload)
-            .map_err(|e| {
-                let err_str = e.to_string();
-                if err_str.contains("401") || err_str.contains("403") {
-                    anyhow::anyhow!("INVALID_API_KEY")
-                } else {
-                    anyhow::anyhow!("{}", err_str)
-                }
-            })?;
+        let resp = send_json_with_retry(
+            || {
+                UREQ_AGENT
+                    .post(&url)
+                    .header("x-goog-api-key", gemini_api_key)
+            },
+            &payload,
+            streaming_enabled,
+        )
+        .map_err(|e| {
+            let err_str = e.to_string();
+            if err_str.contains("401") || err_str.contains("403") {
+                anyhow::anyhow!("INVALID_API_KEY")
+            } else {
+                anyhow::anyhow!("{}", err_str)
+            }
+        })?;
 
         if streaming_enabled {
-            let reader = BufReader::new(resp.into_body().into_reader());
+            let reader = stream_reader(resp);
             let mut thinking_shown = false;
             let mut content_started = false;
 
@@ -374,9 +466,7 @@ where
                 }
             }
         } else {
-            let chat_resp: serde_json::Value = resp
-                .into_body()
-                .read_json()
+            let chat_resp: serde_json::Value = read_json_with_usage(resp)
                 .map_err(|e| anyhow::anyhow!("Failed to parse non-streaming response: {}", e))?;
 
             if let Some(candidates) = chat_resp.get("candidates").and_then(|c| c.as_array()) {
@@ -420,22 +510,27 @@ where
             "stream": streaming_enabled
         });
 
-        let resp = UREQ_AGENT
-            .post("https://openrouter.ai/api/v1/chat/completions")
-            .header("Authorization", &format!("Bearer {}", openrouter_api_key))
-            .header("Content-Type", "application/json")
-            .send_json(payload)
-            .map_err(|e| {
-                let err_str = e.to_string();
-                if err_str.contains("401") || err_str.contains("403") {
-                    anyhow::anyhow!("INVALID_API_KEY")
-                } else {
-                    anyhow::anyhow!("OpenRouter API Error: {}", err_str)
-                }
-            })?;
+        let resp = send_json_with_retry(
+            || {
+                UREQ_AGENT
+                    .post("https://openrouter.ai/api/v1/chat/completions")
+                    .header("Authorization", &format!("Bearer {}", openrouter_api_key))
+                    .header("Content-Type", "application/json")
+            },
+            &payload,
+            streaming_enabled,
+        )
+        .map_err(|e| {
+            let err_str = e.to_string();
+            if err_str.contains("401") || err_str.contains("403") {
+                anyhow::anyhow!("INVALID_API_KEY")
+            } else {
+                anyhow::anyhow!("OpenRouter API Error: {}", err_str)
+            }
+        })?;
 
         if streaming_enabled {
-            let reader = BufReader::new(resp.into_body().into_reader());
+            let reader = stream_reader(resp);
             let mut thinking_shown = false;
             let mut content_started = false;
 
@@ -496,9 +591,7 @@ where
                 }
             }
         } else {
-            let chat_resp: ChatCompletionResponse = resp
-                .into_body()
-                .read_json()
+            let chat_resp: ChatCompletionResponse = read_json_with_usage(resp)
                 .map_err(|e| anyhow::anyhow!("Failed to parse non-streaming response: {}", e))?;
 
             if let Some(choice) = chat_resp.choices.first() {
@@ -548,19 +641,30 @@ where
             payload_obj
         };
 
-        let resp = UREQ_AGENT.post("https://api.groq.com/openai/v1/chat/completions")
-            .header("Authorization", &format!("Bearer {}", groq_api_key))
-            .send_json(payload)
-            .map_err(|e| {
-                let err_str = e.to_string();
-                if err_str.contains("401") {
-                    anyhow::anyhow!("INVALID_API_KEY")
-                } else if err_str.contains("400") {
-                    anyhow::anyhow!("Groq API 400: Bad request. Check model availability or API request format.")
-                } else {
-                    anyhow::anyhow!("Error: https://api.groq.com/openai/v1/chat/completions: {}", err_str)
-                }
-            })?;
+        let resp = send_json_with_retry(
+            || {
+                UREQ_AGENT
+                    .post("https://api.groq.com/openai/v1/chat/completions")
+                    .header("Authorization", &format!("Bearer {}", groq_api_key))
+            },
+            &payload,
+            streaming_enabled,
+        )
+        .map_err(|e| {
+            let err_str = e.to_string();
+            if err_str.contains("401") {
+                anyhow::anyhow!("INVALID_API_KEY")
+            } else if err_str.contains("400") {
+                anyhow::anyhow!(
+                    "Groq API 400: Bad request. Check model availability or API request format."
+                )
+            } else {
+                anyhow::anyhow!(
+                    "Error: https://api.groq.com/openai/v1/chat/completions: {}",
+                    err_str
+                )
+            }
+        })?;
 
         if let Some(remaining) = resp
             .headers()
@@ -580,7 +684,7 @@ where
         }
 
         if streaming_enabled {
-            let reader = BufReader::new(resp.into_body().into_reader());
+            let reader = stream_reader(resp);
             for line in reader.lines() {
                 let line = line?;
 
@@ -605,9 +709,7 @@ where
                 }
             }
         } else {
-            let chat_resp: ChatCompletionResponse = resp
-                .into_body()
-                .read_json()
+            let chat_resp: ChatCompletionResponse = read_json_with_usage(resp)
                 .map_err(|e| anyhow::anyhow!("Failed to parse non-streaming response: {}", e))?;
 
             if let Some(choice) = chat_resp.choices.first() {
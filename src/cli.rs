@@ -0,0 +1,208 @@
+//! Headless command-line mode for scripting:
+//! `sgt --translate-image shot.png [--preset preset_translate] [--lang Vietnamese]`
+//! runs the preset's image block on the file, prints the result to stdout and
+//! exits without creating any window.
+//! `sgt --translate-folder dir [--preset ...] [--lang ...]` does the same for every
+//! image in `dir`, writing each result to a `.txt` file next to the image.
+
+use anyhow::{anyhow, Context, Result};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
+use windows::Win32::System::Console::{AttachConsole, ATTACH_PARENT_PROCESS};
+
+use crate::api::{translate_image_streaming, WIPE_SIGNAL};
+use crate::config::{Config, ProcessingBlock};
+use crate::overlay::process::chain::resolve_block_prompt;
+use crate::APP;
+
+/// Images translated at the same time in folder mode. Requests already retry
+/// on 429/5xx with backoff; this keeps a large folder from causing them.
+const MAX_IN_FLIGHT: usize = 3;
+
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "bmp", "gif", "webp", "tiff"];
+
+/// Run the headless command if its flags are present. Returns the process exit
+/// code, or `None` when the app should start normally.
+pub fn run_from_args() -> Option<i32> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let image_path = flag_value(&args, "--translate-image");
+    let folder_path = flag_value(&args, "--translate-folder");
+    if image_path.is_none() && folder_path.is_none() {
+        return None;
+    }
+
+    // Release builds use the windows subsystem: borrow the calling terminal's
+    // console so stdout/stderr show up there
+    unsafe {
+        let _ = AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+
+    let preset_id = flag_value(&args, "--preset");
+    let language = flag_value(&args, "--lang");
+    let result = resolve_image_job(preset_id.as_deref(), language.as_deref())
+        .and_then(|job| run_job(&job, image_path.as_deref(), folder_path.as_deref()));
+    match result {
+        Ok(true) => Some(0),
+        Ok(false) => Some(1),
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            Some(1)
+        }
+    }
+}
+
+/// Returns whether every image was translated
+fn run_job(job: &ImageJob, image: Option<&str>, folder: Option<&str>) -> Result<bool> {
+    match (image, folder) {
+        (Some(image), _) => {
+            println!("{}", translate_image_file(job, Path::new(image))?);
+            Ok(true)
+        }
+        (None, Some(folder)) => translate_folder(job, Path::new(folder)),
+        (None, None) => Ok(true),
+    }
+}
+
+/// Value following `flag`, e.g. `--lang Vietnamese`
+fn flag_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .filter(|v| !v.starts_with("--"))
+        .cloned()
+}
+
+/// Everything needed to run a preset's image block outside the overlay
+struct ImageJob {
+    config: Config,
+    block: ProcessingBlock,
+    /// Single-block presets ask for JSON output, like the overlay pipeline
+    use_json: bool,
+}
+
+fn resolve_image_job(preset_id: Option<&str>, language: Option<&str>) -> Result<ImageJob> {
+    let config = APP
+        .lock()
+        .map_err(|_| anyhow!("Config lock poisoned"))?
+        .config
+        .clone();
+    // No --preset: use whichever preset is active in the app
+    let preset = match preset_id {
+        Some(id) => config.presets.iter().find(|p| p.id == id),
+        None => config.presets.get(config.active_preset_idx),
+    }
+    .ok_or_else(|| anyhow!("Preset not found: {}", preset_id.unwrap_or("(active)")))?;
+
+    let mut block = preset
+        .blocks
+        .iter()
+        .find(|b| b.block_type == "image")
+        .cloned()
+        .ok_or_else(|| anyhow!("Preset {} has no image block", preset.id))?;
+    if let Some(language) = language {
+        block.selected_language = language.to_string();
+        block
+            .language_vars
+            .insert("language1".to_string(), language.to_string());
+    }
+    let use_json = preset.blocks.len() == 1;
+
+    Ok(ImageJob {
+        config,
+        block,
+        use_json,
+    })
+}
+
+fn translate_image_file(job: &ImageJob, path: &Path) -> Result<String> {
+    let bytes = std::fs::read(path).with_context(|| format!("Cannot read {}", path.display()))?;
+    let image = image::load_from_memory(&bytes)
+        .with_context(|| format!("{} is not a supported image", path.display()))?
+        .to_rgba8();
+
+    let model = crate::model_config::get_model_by_id(&job.block.model)
+        .ok_or_else(|| anyhow!("Unknown model: {}", job.block.model))?;
+    let cancel_token = Arc::new(AtomicBool::new(false));
+    let mut output = String::new();
+
+    let result = translate_image_streaming(
+        &job.config.api_key,
+        &job.config.gemini_api_key,
+        resolve_block_prompt(&job.block),
+        model.full_name,
+        model.provider,
+        image,
+        Some(bytes),
+        job.block.streaming_enabled,
+        job.use_json,
+        &cancel_token,
+        |chunk| {
+            if let Some(rest) = chunk.strip_prefix(WIPE_SIGNAL) {
+                output.clear();
+                output.push_str(rest);
+            } else {
+                output.push_str(chunk);
+            }
+        },
+    )?;
+
+    // Non-streaming providers may only return the final text
+    Ok(if output.trim().is_empty() {
+        result
+    } else {
+        output
+    })
+}
+
+/// Translate every image in `folder` into a sidecar `.txt`. Failed files are
+/// reported and skipped; returns whether all of them succeeded.
+fn translate_folder(job: &ImageJob, folder: &Path) -> Result<bool> {
+    let mut images: Vec<PathBuf> = std::fs::read_dir(folder)
+        .with_context(|| format!("Cannot read folder {}", folder.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+        })
+        .collect();
+    images.sort();
+    if images.is_empty() {
+        return Err(anyhow!("No images found in {}", folder.display()));
+    }
+
+    let total = images.len();
+    // Reversed so workers pop files in name order
+    images.reverse();
+    let queue = Mutex::new(images);
+    let failures = Mutex::new(0usize);
+
+    std::thread::scope(|scope| {
+        for _ in 0..MAX_IN_FLIGHT.min(total) {
+            scope.spawn(|| loop {
+                let Some(path) = queue.lock().unwrap().pop() else {
+                    break;
+                };
+                let result = translate_image_file(job, &path).and_then(|text| {
+                    let out = path.with_extension("txt");
+                    std::fs::write(&out, text)
+                        .with_context(|| format!("Cannot write {}", out.display()))
+                });
+                match result {
+                    Ok(()) => println!("OK    {}", path.display()),
+                    Err(e) => {
+                        *failures.lock().unwrap() += 1;
+                        eprintln!("FAIL  {}: {:#}", path.display(), e);
+                    }
+                }
+            });
+        }
+    });
+
+    let failures = failures.into_inner().unwrap();
+    println!("{} of {} images translated", total - failures, total);
+    Ok(failures == 0)
+}
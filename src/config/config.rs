@@ -1,11 +1,13 @@
 //! Main Config struct definition.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use crate::config::preset::{get_default_presets, Preset};
 use crate::config::types::{
-    default_tts_language_conditions, get_system_ui_language, EdgeTtsSettings, Hotkey, ThemeMode,
-    TtsLanguageCondition, TtsMethod, DEFAULT_HISTORY_LIMIT, DEFAULT_PROJECTS_LIMIT,
+    default_tts_language_conditions, get_system_ui_language, EdgeTtsSettings, Hotkey,
+    NodeGraphView, PromptSnippet, ThemeMode, TtsLanguageCondition, TtsMethod,
+    DEFAULT_HISTORY_LIMIT, DEFAULT_PROJECTS_LIMIT,
 };
 
 // ============================================================================
@@ -52,6 +54,34 @@ fn default_realtime_font_size() -> u32 {
     16
 }
 
+fn default_overlay_opacity() -> u8 {
+    217
+}
+
+fn default_paste_method() -> String {
+    "ctrl_v".to_string()
+}
+
+fn default_paste_key_delay_ms() -> u64 {
+    50
+}
+
+fn default_overlay_animation_speed() -> f32 {
+    1.0
+}
+
+fn default_result_right_click() -> String {
+    "close".to_string()
+}
+
+fn default_retranslation_layout() -> String {
+    "auto".to_string()
+}
+
+fn default_keyboard_language_fallback() -> String {
+    "English".to_string()
+}
+
 fn default_realtime_window_size() -> (i32, i32) {
     (500, 180)
 }
@@ -68,6 +98,30 @@ fn default_ollama_base_url() -> String {
     "http://localhost:11434".to_string()
 }
 
+fn default_api_max_attempts() -> u32 {
+    3
+}
+
+fn default_api_connect_timeout_secs() -> u64 {
+    15
+}
+
+fn default_api_stall_timeout_secs() -> u64 {
+    60
+}
+
+fn default_ollama_stall_timeout_secs() -> u64 {
+    300
+}
+
+fn default_api_max_concurrent() -> u32 {
+    4
+}
+
+/// Schema version written by this build. Bump together with a new step in
+/// `io::upgrade_config_schema` whenever the on-disk layout changes.
+pub const CURRENT_CONFIG_VERSION: u32 = 1;
+
 // ============================================================================
 // CONFIG STRUCT
 // ============================================================================
@@ -91,6 +145,14 @@ pub struct Config {
     #[serde(default)]
     pub cerebras_api_key: String,
 
+    /// Anthropic Claude API key
+    #[serde(default)]
+    pub anthropic_api_key: String,
+
+    /// API key for the OpenAI-compatible endpoint (optional for self-hosted servers)
+    #[serde(default)]
+    pub custom_openai_api_key: String,
+
     // -------------------------------------------------------------------------
     // Presets
     // -------------------------------------------------------------------------
@@ -100,6 +162,14 @@ pub struct Config {
     /// Index of the currently active preset
     pub active_preset_idx: usize,
 
+    /// Saved prompts that can be inserted into any block's prompt
+    #[serde(default)]
+    pub prompt_snippets: Vec<PromptSnippet>,
+
+    /// Node graph zoom/pan per preset ID, restored when the preset is reopened
+    #[serde(default)]
+    pub node_graph_views: HashMap<String, NodeGraphView>,
+
     // -------------------------------------------------------------------------
     // UI Settings
     // -------------------------------------------------------------------------
@@ -164,6 +234,21 @@ pub struct Config {
     #[serde(default)]
     pub use_ollama: bool,
 
+    /// Enable Anthropic Claude models
+    #[serde(default)]
+    pub use_anthropic: bool,
+
+    /// Enable the generic OpenAI-compatible endpoint
+    #[serde(default)]
+    pub use_custom_openai: bool,
+
+    // -------------------------------------------------------------------------
+    // OpenAI-Compatible Endpoint
+    // -------------------------------------------------------------------------
+    /// Base URL including the version path, e.g. "http://myserver:8000/v1"
+    #[serde(default)]
+    pub custom_openai_base_url: String,
+
     // -------------------------------------------------------------------------
     // Ollama Configuration
     // -------------------------------------------------------------------------
@@ -179,6 +264,37 @@ pub struct Config {
     #[serde(default)]
     pub ollama_text_model: String,
 
+    // -------------------------------------------------------------------------
+    // Network
+    // -------------------------------------------------------------------------
+    /// Max attempts per API request when the provider answers 429/5xx (1 = no retry)
+    #[serde(default = "default_api_max_attempts")]
+    pub api_max_attempts: u32,
+
+    /// Max seconds to establish a connection to any provider
+    #[serde(default = "default_api_connect_timeout_secs")]
+    pub api_connect_timeout_secs: u64,
+
+    /// Max seconds without data (before the first chunk or between chunks) from a cloud provider
+    #[serde(default = "default_api_stall_timeout_secs")]
+    pub api_stall_timeout_secs: u64,
+
+    /// Same as `api_stall_timeout_secs` for Ollama, where loading a model can take minutes
+    #[serde(default = "default_ollama_stall_timeout_secs")]
+    pub ollama_stall_timeout_secs: u64,
+
+    /// Max requests in flight per cloud provider; more wait their turn (0 = no limit)
+    #[serde(default = "default_api_max_concurrent")]
+    pub api_max_concurrent: u32,
+
+    /// Same as `api_max_concurrent` for the local Ollama server
+    #[serde(default)]
+    pub ollama_max_concurrent: u32,
+
+    /// Start on a fallback model while the chosen one is cooling down from a rate limit
+    #[serde(default = "default_true")]
+    pub skip_rate_limited_models: bool,
+
     // -------------------------------------------------------------------------
     // Realtime Audio Settings
     // -------------------------------------------------------------------------
@@ -206,6 +322,11 @@ pub struct Config {
     #[serde(default)]
     pub realtime_audio_source: String,
 
+    /// Endpoint ID for the realtime audio source: a speaker captured as loopback
+    /// for "device", a microphone for "mic". Empty = system default
+    #[serde(default)]
+    pub realtime_audio_device: String,
+
     /// Target language for realtime translation
     #[serde(default = "default_realtime_target_language")]
     pub realtime_target_language: String,
@@ -256,9 +377,21 @@ pub struct Config {
     #[serde(default = "default_bubble_size")]
     pub favorite_bubble_size: u32,
 
+    /// Snap the bubble to the nearest screen edge after it is thrown
+    #[serde(default)]
+    pub favorite_bubble_snap_to_edge: bool,
+
+    /// Hide the bubble while the foreground app covers its whole monitor (games, video)
+    #[serde(default)]
+    pub favorite_bubble_hide_in_fullscreen: bool,
+
     // -------------------------------------------------------------------------
     // Maintenance Flags
     // -------------------------------------------------------------------------
+    /// Schema version of this config (0 = written before versioning existed)
+    #[serde(default)]
+    pub config_version: u32,
+
     /// Clear WebView data on next startup (for MIDI permission reset)
     #[serde(default)]
     pub clear_webview_on_startup: bool,
@@ -266,6 +399,79 @@ pub struct Config {
     /// Global hotkeys for screen recording start/stop
     #[serde(default = "default_screen_record_hotkeys")]
     pub screen_record_hotkeys: Vec<Hotkey>,
+
+    /// GDI device name (e.g. "\\.\DISPLAY2") of the monitor last recorded from
+    #[serde(default)]
+    pub screen_record_monitor: String,
+
+    /// Record the real OS cursor into the video instead of overlaying one during playback
+    #[serde(default)]
+    pub screen_record_capture_cursor: bool,
+
+    /// How often recordings sample the mouse position (0 = every captured frame)
+    #[serde(default = "default_screen_record_mouse_sample_hz")]
+    pub screen_record_mouse_sample_hz: u32,
+
+    /// Move each finished recording into `recording_output_dir` under a timestamped name
+    #[serde(default)]
+    pub auto_save_recordings: bool,
+
+    /// Where auto-saved recordings go (empty = the user's Videos folder)
+    #[serde(default)]
+    pub recording_output_dir: String,
+
+    /// Global hotkeys that capture the focused window and process it without region selection
+    #[serde(default)]
+    pub active_window_capture_hotkeys: Vec<Hotkey>,
+
+    /// Image preset used by the active-window capture hotkeys
+    #[serde(default = "default_active_window_capture_preset_id")]
+    pub active_window_capture_preset_id: String,
+
+    /// Global hotkeys that stop realtime mode and save the transcript to a text file
+    #[serde(default)]
+    pub realtime_stop_hotkeys: Vec<Hotkey>,
+
+    /// Global hotkeys that cycle through recent text-selection results
+    #[serde(default)]
+    pub selection_history_hotkeys: Vec<Hotkey>,
+
+    /// Global hotkeys that run the last image/text translation again with the same input
+    #[serde(default)]
+    pub repeat_last_hotkeys: Vec<Hotkey>,
+
+    /// Initial opacity (0-255) of result windows; also sets webview overlay background alpha
+    #[serde(default = "default_overlay_opacity")]
+    pub overlay_opacity: u8,
+
+    /// How auto-paste delivers the result: "ctrl_v", "shift_insert" or "type"
+    /// (synthesized key presses, for apps that block clipboard paste)
+    #[serde(default = "default_paste_method")]
+    pub paste_method: String,
+
+    /// Delay between the key events of the auto-paste shortcut
+    #[serde(default = "default_paste_key_delay_ms")]
+    pub paste_key_delay_ms: u64,
+
+    /// Speed multiplier for the realtime overlay's streaming text wipe-in;
+    /// 0 turns the animation off
+    #[serde(default = "default_overlay_animation_speed")]
+    pub overlay_animation_speed: f32,
+
+    /// What right-clicking a result window does: "close" (it and its linked
+    /// windows), "menu" (copy / speak / close) or "copy"
+    #[serde(default = "default_result_right_click")]
+    pub result_right_click: String,
+
+    /// Where a chain's next result window goes: "auto" (roomiest side),
+    /// "horizontal" (beside the previous one) or "vertical" (below / above it)
+    #[serde(default = "default_retranslation_layout")]
+    pub retranslation_layout: String,
+
+    /// Used for the "keyboard language" value when the active layout's locale
+    /// doesn't map to a known language
+    #[serde(default = "default_keyboard_language_fallback")]
+    pub keyboard_language_fallback: String,
 }
 
 fn default_screen_record_hotkeys() -> Vec<Hotkey> {
@@ -276,6 +482,14 @@ fn default_screen_record_hotkeys() -> Vec<Hotkey> {
     }]
 }
 
+fn default_screen_record_mouse_sample_hz() -> u32 {
+    30
+}
+
+fn default_active_window_capture_preset_id() -> String {
+    "preset_translate".to_string()
+}
+
 // ============================================================================
 // CONFIG STRUCT METHODS
 // ============================================================================
@@ -299,6 +513,46 @@ impl Config {
             }
         }
 
+        // Check active window capture hotkeys
+        for h in &self.active_window_capture_hotkeys {
+            if h.code == vk && h.modifiers == mods {
+                return Some(format!(
+                    "Conflict with global hotkey '{}' (Active Window Capture)",
+                    h.name
+                ));
+            }
+        }
+
+        // Check realtime stop hotkeys
+        for h in &self.realtime_stop_hotkeys {
+            if h.code == vk && h.modifiers == mods {
+                return Some(format!(
+                    "Conflict with global hotkey '{}' (Stop Realtime)",
+                    h.name
+                ));
+            }
+        }
+
+        // Check selection history hotkeys
+        for h in &self.selection_history_hotkeys {
+            if h.code == vk && h.modifiers == mods {
+                return Some(format!(
+                    "Conflict with global hotkey '{}' (Recent Selections)",
+                    h.name
+                ));
+            }
+        }
+
+        // Check repeat last hotkeys
+        for h in &self.repeat_last_hotkeys {
+            if h.code == vk && h.modifiers == mods {
+                return Some(format!(
+                    "Conflict with global hotkey '{}' (Repeat Last)",
+                    h.name
+                ));
+            }
+        }
+
         // Check all presets
         for (idx, preset) in self.presets.iter().enumerate() {
             if Some(idx) == exclude_preset_idx {
@@ -315,6 +569,48 @@ impl Config {
         }
         None
     }
+
+    /// Validation pass over every hotkey binding (presets and global hotkeys).
+    /// Returns one message per `(code, modifiers)` pair bound more than once,
+    /// naming all clashing bindings.
+    pub fn find_hotkey_conflicts(&self) -> Vec<String> {
+        let mut bindings: Vec<((u32, u32), String, Vec<String>)> = Vec::new();
+        let mut add = |h: &Hotkey, owner: String| {
+            let key = (h.code, h.modifiers);
+            if let Some((_, _, owners)) = bindings.iter_mut().find(|(k, _, _)| *k == key) {
+                owners.push(owner);
+            } else {
+                bindings.push((key, h.name.clone(), vec![owner]));
+            }
+        };
+
+        for h in &self.screen_record_hotkeys {
+            add(h, "Screen Record".to_string());
+        }
+        for h in &self.active_window_capture_hotkeys {
+            add(h, "Active Window Capture".to_string());
+        }
+        for h in &self.realtime_stop_hotkeys {
+            add(h, "Stop Realtime".to_string());
+        }
+        for h in &self.selection_history_hotkeys {
+            add(h, "Recent Selections".to_string());
+        }
+        for h in &self.repeat_last_hotkeys {
+            add(h, "Repeat Last".to_string());
+        }
+        for preset in &self.presets {
+            for h in &preset.hotkeys {
+                add(h, format!("preset '{}'", preset.name));
+            }
+        }
+
+        bindings
+            .into_iter()
+            .filter(|(_, _, owners)| owners.len() > 1)
+            .map(|(_, name, owners)| format!("'{}' is used by {}", name, owners.join(", ")))
+            .collect()
+    }
 }
 
 // ============================================================================
@@ -329,10 +625,14 @@ impl Default for Config {
             gemini_api_key: String::new(),
             openrouter_api_key: String::new(),
             cerebras_api_key: String::new(),
+            anthropic_api_key: String::new(),
+            custom_openai_api_key: String::new(),
 
             // Presets - use the centralized ordered list
             presets: get_default_presets(),
             active_preset_idx: 0,
+            prompt_snippets: Vec::new(),
+            node_graph_views: HashMap::new(),
 
             // UI Settings
             theme_mode: ThemeMode::System,
@@ -353,12 +653,26 @@ impl Default for Config {
             use_openrouter: false,
             use_cerebras: true,
             use_ollama: false,
+            use_anthropic: false,
+            use_custom_openai: false,
+
+            // OpenAI-Compatible Endpoint
+            custom_openai_base_url: String::new(),
 
             // Ollama
             ollama_base_url: "http://localhost:11434".to_string(),
             ollama_vision_model: String::new(),
             ollama_text_model: String::new(),
 
+            // Network
+            api_max_attempts: default_api_max_attempts(),
+            api_connect_timeout_secs: default_api_connect_timeout_secs(),
+            api_stall_timeout_secs: default_api_stall_timeout_secs(),
+            ollama_stall_timeout_secs: default_ollama_stall_timeout_secs(),
+            api_max_concurrent: default_api_max_concurrent(),
+            ollama_max_concurrent: 0,
+            skip_rate_limited_models: true,
+
             // Realtime Audio
             realtime_translation_model: "cerebras-oss".to_string(),
             realtime_transcription_model: "gemini".to_string(),
@@ -366,6 +680,7 @@ impl Default for Config {
             realtime_transcription_size: (500, 180),
             realtime_translation_size: (500, 180),
             realtime_audio_source: "device".to_string(),
+            realtime_audio_device: String::new(),
             realtime_target_language: "Vietnamese".to_string(),
 
             // TTS
@@ -381,12 +696,32 @@ impl Default for Config {
             favorite_bubble_position: None,
             favorites_keep_open: false,
             favorite_bubble_size: 40,
+            favorite_bubble_snap_to_edge: false,
+            favorite_bubble_hide_in_fullscreen: false,
 
             // Maintenance
+            config_version: CURRENT_CONFIG_VERSION,
             clear_webview_on_startup: false,
 
             // Screen Record
             screen_record_hotkeys: default_screen_record_hotkeys(),
+            screen_record_monitor: String::new(),
+            screen_record_capture_cursor: false,
+            screen_record_mouse_sample_hz: default_screen_record_mouse_sample_hz(),
+            auto_save_recordings: false,
+            recording_output_dir: String::new(),
+            active_window_capture_hotkeys: Vec::new(),
+            active_window_capture_preset_id: default_active_window_capture_preset_id(),
+            realtime_stop_hotkeys: Vec::new(),
+            selection_history_hotkeys: Vec::new(),
+            repeat_last_hotkeys: Vec::new(),
+            overlay_opacity: default_overlay_opacity(),
+            paste_method: default_paste_method(),
+            paste_key_delay_ms: default_paste_key_delay_ms(),
+            overlay_animation_speed: default_overlay_animation_speed(),
+            result_right_click: default_result_right_click(),
+            retranslation_layout: default_retranslation_layout(),
+            keyboard_language_fallback: default_keyboard_language_fallback(),
         }
     }
 }
@@ -1,8 +1,8 @@
-//! Config I/O operations: load, save, and language utilities.
+//! Config I/O operations: load, save, import/export, and language utilities.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use crate::config::config::Config;
+use crate::config::config::{Config, CURRENT_CONFIG_VERSION};
 use crate::config::preset::{get_default_presets, Preset, ProcessingBlock};
 
 // ============================================================================
@@ -37,15 +37,71 @@ pub fn load_config() -> Config {
 
     let mut config: Config = match serde_json::from_str(&data) {
         Ok(c) => c,
-        Err(_) => return Config::default(),
+        Err(e) => {
+            // Keep the unreadable file around instead of overwriting it on next save
+            let backup = path.with_extension("json.bak");
+            let _ = std::fs::copy(&path, &backup);
+            crate::log_info!(
+                "[Config] Failed to parse {:?} ({}), using defaults. Backup saved to {:?}",
+                path,
+                e,
+                backup
+            );
+            return Config::default();
+        }
     };
 
-    // Apply migrations and merge new defaults
+    // Upgrade old on-disk layouts, then apply migrations and merge new defaults
+    let upgraded = upgrade_config_schema(&mut config);
     migrate_config(&mut config);
 
+    if upgraded {
+        save_config(&config);
+    }
+
     config
 }
 
+/// Upgrade configs written by older versions to the current schema.
+/// Returns true if anything was upgraded (the caller writes the file back once).
+fn upgrade_config_schema(config: &mut Config) -> bool {
+    if config.config_version >= CURRENT_CONFIG_VERSION {
+        return false;
+    }
+    let from_version = config.config_version;
+
+    // -------------------------------------------------------------------------
+    // v0 -> v1: language_vars
+    // -------------------------------------------------------------------------
+    if config.config_version < 1 {
+        let mut language_vars_filled = 0;
+
+        for block in config.presets.iter_mut().flat_map(|p| p.blocks.iter_mut()) {
+            // Old blocks only stored `selected_language`; prompts now use {language1}
+            if !block.selected_language.is_empty() && !block.language_vars.contains_key("language1")
+            {
+                block
+                    .language_vars
+                    .insert("language1".to_string(), block.selected_language.clone());
+                language_vars_filled += 1;
+            }
+        }
+
+        crate::log_info!(
+            "[Config] Migration v0 -> v1: filled language_vars on {} block(s)",
+            language_vars_filled
+        );
+        config.config_version = 1;
+    }
+
+    crate::log_info!(
+        "[Config] Upgraded config schema from v{} to v{}",
+        from_version,
+        config.config_version
+    );
+    true
+}
+
 /// Apply config migrations and merge new default presets
 fn migrate_config(config: &mut Config) {
     let default_presets = get_default_presets();
@@ -123,6 +179,65 @@ pub fn save_config(config: &Config) {
     }
 }
 
+// ============================================================================
+// CONFIG IMPORT / EXPORT
+// ============================================================================
+
+/// Write the whole config to `path` as pretty JSON.
+/// API keys are blanked out unless `include_api_keys` is set.
+pub fn export_config(config: &Config, path: &Path, include_api_keys: bool) -> anyhow::Result<()> {
+    let mut exported = config.clone();
+    if !include_api_keys {
+        for key in api_keys_mut(&mut exported) {
+            key.clear();
+        }
+    }
+    // Machine-specific, meaningless on another computer
+    exported.authorized_startup_path.clear();
+
+    let data = serde_json::to_string_pretty(&exported)?;
+    std::fs::write(path, data)?;
+    Ok(())
+}
+
+/// Read a config previously written by `export_config`.
+/// Missing fields fall back to their serde defaults, so older exports still load.
+/// API keys left empty in the file and startup settings are kept from `current`.
+pub fn import_config(path: &Path, current: &Config) -> anyhow::Result<Config> {
+    let data = std::fs::read_to_string(path)?;
+    let mut imported: Config = serde_json::from_str(&data)
+        .map_err(|e| anyhow::anyhow!("Not a valid configuration file: {}", e))?;
+
+    upgrade_config_schema(&mut imported);
+    migrate_config(&mut imported);
+
+    let mut current = current.clone();
+    for (key, current_key) in api_keys_mut(&mut imported)
+        .into_iter()
+        .zip(api_keys_mut(&mut current))
+    {
+        if key.is_empty() {
+            *key = std::mem::take(current_key);
+        }
+    }
+    imported.run_at_startup = current.run_at_startup;
+    imported.run_as_admin_on_startup = current.run_as_admin_on_startup;
+    imported.authorized_startup_path = current.authorized_startup_path;
+
+    Ok(imported)
+}
+
+fn api_keys_mut(config: &mut Config) -> [&mut String; 6] {
+    [
+        &mut config.api_key,
+        &mut config.gemini_api_key,
+        &mut config.openrouter_api_key,
+        &mut config.cerebras_api_key,
+        &mut config.anthropic_api_key,
+        &mut config.custom_openai_api_key,
+    ]
+}
+
 // ============================================================================
 // LANGUAGE UTILITIES
 // ============================================================================
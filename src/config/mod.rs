@@ -6,7 +6,7 @@
 //! - `config`: Main Config struct
 //! - `preset`: Preset and ProcessingBlock with builder patterns
 //! - `types`: Core types (enums, TTS settings, hotkeys)
-//! - `io`: Load/save operations
+//! - `io`: Load/save and import/export operations
 //!
 //! ## Usage
 //! ```rust
@@ -41,10 +41,10 @@ pub mod types;
 pub use config::Config;
 
 // Preset and ProcessingBlock
-pub use preset::{Preset, ProcessingBlock};
+pub use preset::{CaptureRegion, Preset, ProcessingBlock, SYSTEM_LANGUAGE};
 
 // I/O functions
-pub use io::{get_all_languages, load_config, save_config};
+pub use io::{export_config, get_all_languages, import_config, load_config, save_config};
 
 // ============================================================================
 // RE-EXPORTS - Types (only what's actually used externally)
@@ -56,5 +56,11 @@ pub use types::ThemeMode;
 // Hotkey
 pub use types::Hotkey;
 
+// Prompt snippets
+pub use types::PromptSnippet;
+
+// Node graph viewport
+pub use types::NodeGraphView;
+
 // TTS types
 pub use types::{EdgeTtsSettings, EdgeTtsVoiceConfig, TtsLanguageCondition, TtsMethod};
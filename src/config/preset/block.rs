@@ -8,6 +8,10 @@ use std::collections::HashMap;
 
 use crate::config::types::BlockType;
 
+/// Language value resolved when the block runs to the language of the keyboard
+/// layout active in the app the user was in
+pub const SYSTEM_LANGUAGE: &str = "__system__";
+
 // ============================================================================
 // PROCESSING BLOCK
 // ============================================================================
@@ -34,7 +38,8 @@ pub struct ProcessingBlock {
     #[serde(default)]
     pub selected_language: String,
 
-    /// Language variable mappings for prompt template
+    /// Language variable mappings for prompt template. A value of
+    /// `SYSTEM_LANGUAGE` follows the keyboard layout of the app in use.
     #[serde(default)]
     pub language_vars: HashMap<String, String>,
 
@@ -57,6 +62,12 @@ pub struct ProcessingBlock {
     /// Auto-speak result using TTS
     #[serde(default)]
     pub auto_speak: bool,
+
+    /// What the chain does when this block's API call fails: "stop" ends the chain,
+    /// "pass_input" shows the error but forwards this block's input downstream,
+    /// "skip" closes this block's window and forwards its input
+    #[serde(default = "default_on_error")]
+    pub on_error: String,
 }
 
 fn generate_block_id() -> String {
@@ -77,6 +88,10 @@ fn default_render_mode() -> String {
     "markdown_stream".to_string()
 }
 
+fn default_on_error() -> String {
+    "stop".to_string()
+}
+
 impl Default for ProcessingBlock {
     fn default() -> Self {
         Self {
@@ -91,6 +106,7 @@ impl Default for ProcessingBlock {
             show_overlay: true,
             auto_copy: false,
             auto_speak: false,
+            on_error: default_on_error(),
         }
     }
 }
@@ -11,8 +11,8 @@ mod block;
 pub mod defaults;
 mod preset;
 
-pub use block::{BlockBuilder, ProcessingBlock};
-pub use preset::{Preset, PresetBuilder};
+pub use block::{BlockBuilder, ProcessingBlock, SYSTEM_LANGUAGE};
+pub use preset::{CaptureRegion, Preset, PresetBuilder};
 
 // Re-export default preset functions for convenience
 pub use defaults::get_default_presets;
@@ -11,6 +11,39 @@ use serde::{Deserialize, Serialize};
 use super::block::ProcessingBlock;
 use crate::config::types::Hotkey;
 
+// ============================================================================
+// CAPTURE REGION
+// ============================================================================
+
+/// A screen rectangle in virtual-screen coordinates (a serializable `RECT`)
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct CaptureRegion {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+impl CaptureRegion {
+    pub fn from_rect(rect: windows::Win32::Foundation::RECT) -> Self {
+        Self {
+            left: rect.left,
+            top: rect.top,
+            right: rect.right,
+            bottom: rect.bottom,
+        }
+    }
+
+    pub fn to_rect(self) -> windows::Win32::Foundation::RECT {
+        windows::Win32::Foundation::RECT {
+            left: self.left,
+            top: self.top,
+            right: self.right,
+            bottom: self.bottom,
+        }
+    }
+}
+
 // ============================================================================
 // PRESET STRUCT
 // ============================================================================
@@ -43,7 +76,8 @@ pub struct Preset {
     #[serde(default = "default_preset_type")]
     pub preset_type: String,
 
-    /// Text input mode: "select" (highlight text) or "type" (keyboard input)
+    /// Text input mode: "select" (highlight text), "type" (keyboard input) or
+    /// "clipboard" (the clipboard's current text)
     #[serde(default = "default_text_input_mode")]
     pub text_input_mode: String,
 
@@ -63,6 +97,17 @@ pub struct Preset {
     #[serde(default)]
     pub video_capture_method: String,
 
+    // -------------------------------------------------------------------------
+    // Image Capture Options
+    // -------------------------------------------------------------------------
+    /// Capture this screen region right away instead of dragging one
+    #[serde(default)]
+    pub fixed_region: Option<CaptureRegion>,
+
+    /// Keep the dragged region at this width / height ratio
+    #[serde(default)]
+    pub lock_aspect: Option<f32>,
+
     // -------------------------------------------------------------------------
     // Output Behavior
     // -------------------------------------------------------------------------
@@ -74,6 +119,24 @@ pub struct Preset {
     #[serde(default = "default_true")]
     pub auto_paste_newline: bool,
 
+    /// Auto-copied text "replace"s the clipboard or is "append"ed to what is
+    /// already there, for collecting several results in a row
+    #[serde(default = "default_auto_copy_mode")]
+    pub auto_copy_mode: String,
+
+    /// User zoom applied on top of the auto-fit font size in result windows (Ctrl+scroll)
+    #[serde(default = "default_result_font_scale")]
+    pub result_font_scale: f32,
+
+    /// Accent color ("#rrggbb") for this preset's overlays; empty keeps the built-in colors
+    #[serde(default)]
+    pub glow_color: String,
+
+    /// Close result windows this many seconds after streaming completes, unless
+    /// hovered in the meantime; 0 keeps them open until dismissed
+    #[serde(default)]
+    pub auto_close_secs: u32,
+
     // -------------------------------------------------------------------------
     // Audio Recording Options
     // -------------------------------------------------------------------------
@@ -92,6 +155,10 @@ pub struct Preset {
     #[serde(default)]
     pub continuous_input: bool,
 
+    /// Select mode: show the selected text in the input window for editing before it's sent
+    #[serde(default)]
+    pub confirm_selection: bool,
+
     // -------------------------------------------------------------------------
     // Hotkeys
     // -------------------------------------------------------------------------
@@ -110,6 +177,11 @@ pub struct Preset {
     #[serde(default)]
     pub is_master: bool,
 
+    /// How a MASTER preset picks its preset: "wheel" (radial wheel) or
+    /// "cycle" (repeated hotkey presses step through presets in a HUD)
+    #[serde(default = "default_master_mode")]
+    pub master_mode: String,
+
     /// Controller UI mode: hides advanced UI elements
     #[serde(default)]
     pub show_controller_ui: bool,
@@ -117,6 +189,10 @@ pub struct Preset {
     /// Favorite preset for quick access via floating bubble
     #[serde(default)]
     pub is_favorite: bool,
+
+    /// Section the favorite is grouped under in the bubble panel (empty = "General")
+    #[serde(default)]
+    pub category: String,
 }
 
 // ============================================================================
@@ -147,10 +223,22 @@ fn default_realtime_window_mode() -> String {
     "standard".to_string()
 }
 
+fn default_master_mode() -> String {
+    "wheel".to_string()
+}
+
 fn default_true() -> bool {
     true
 }
 
+fn default_result_font_scale() -> f32 {
+    1.0
+}
+
+fn default_auto_copy_mode() -> String {
+    "replace".to_string()
+}
+
 // ============================================================================
 // PRESET DEFAULT IMPL
 // ============================================================================
@@ -171,14 +259,23 @@ impl Default for Preset {
             video_capture_method: "region".to_string(),
             auto_paste: false,
             auto_paste_newline: false,
+            auto_copy_mode: default_auto_copy_mode(),
+            result_font_scale: 1.0,
+            glow_color: String::new(),
+            auto_close_secs: 0,
             hide_recording_ui: false,
             auto_stop_recording: false,
             continuous_input: false,
+            confirm_selection: false,
+            fixed_region: None,
+            lock_aspect: None,
             hotkeys: vec![],
             is_upcoming: false,
             is_master: false,
+            master_mode: default_master_mode(),
             show_controller_ui: false,
             is_favorite: false,
+            category: String::new(),
         }
     }
 }
@@ -365,6 +462,15 @@ impl Preset {
         self.is_master
     }
 
+    /// Custom overlay accent color as 0x00RRGGBB, if one is set and valid
+    pub fn glow_color_rgb(&self) -> Option<u32> {
+        let hex = self.glow_color.trim().trim_start_matches('#');
+        if hex.len() != 6 {
+            return None;
+        }
+        u32::from_str_radix(hex, 16).ok()
+    }
+
     /// Get the first block (input block)
     pub fn input_block(&self) -> Option<&ProcessingBlock> {
         self.blocks.first()
@@ -0,0 +1,11 @@
+//! Saved zoom/pan of a preset's node graph.
+
+use serde::{Deserialize, Serialize};
+
+/// Node graph viewport: graph coordinates are scaled by `scale`, then shifted by the pan
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct NodeGraphView {
+    pub scale: f32,
+    pub pan_x: f32,
+    pub pan_y: f32,
+}
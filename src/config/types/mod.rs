@@ -4,9 +4,13 @@
 //! - `enums`: Core enums (ThemeMode, BlockType)
 //! - `hotkey`: Hotkey binding type
 //! - `tts`: TTS-related types (TtsMethod, EdgeTtsSettings, etc.)
+//! - `snippet`: Saved prompt templates
+//! - `graph_view`: Saved node graph zoom/pan
 
 mod enums;
+mod graph_view;
 mod hotkey;
+mod snippet;
 mod tts;
 
 // Re-export all types for easy access
@@ -14,8 +18,12 @@ pub use enums::{
     get_system_ui_language, BlockType, ThemeMode, DEFAULT_HISTORY_LIMIT, DEFAULT_PROJECTS_LIMIT,
 };
 
+pub use graph_view::NodeGraphView;
+
 pub use hotkey::Hotkey;
 
+pub use snippet::PromptSnippet;
+
 pub use tts::{
     default_tts_language_conditions, EdgeTtsSettings, EdgeTtsVoiceConfig, TtsLanguageCondition,
     TtsMethod,
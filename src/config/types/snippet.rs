@@ -0,0 +1,15 @@
+//! Saved prompt templates for the preset editor.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A named prompt that can be dropped into any processing block's prompt
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PromptSnippet {
+    pub name: String,
+    /// Prompt text; `{language1}`, `{language2}`… are renumbered on insert
+    pub prompt: String,
+    /// Languages picked for the snippet's `{languageN}` tags when it was saved
+    #[serde(default)]
+    pub language_vars: HashMap<String, String>,
+}
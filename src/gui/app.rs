@@ -6,7 +6,7 @@ mod types;
 mod utils;
 
 pub use types::SettingsApp;
-pub use utils::{restart_app, signal_restore_window};
+pub use utils::{restart_app, signal_presets_changed, signal_restore_window};
 
 use eframe::egui;
 
@@ -51,6 +51,9 @@ impl eframe::App for SettingsApp {
         // Restore Signal
         self.check_restore_signal(ctx);
 
+        // Presets edited by overlays
+        self.check_presets_changed();
+
         // Hotkey Recording
         self.update_hotkey_recording(ctx);
 
@@ -105,6 +108,7 @@ impl eframe::App for SettingsApp {
     }
 
     fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        self.persist_node_graph_views();
         self.tray_icon = None;
     }
 }
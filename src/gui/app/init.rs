@@ -135,6 +135,9 @@ impl SettingsApp {
 
         let run_at_startup = run_at_startup_ui;
 
+        // History thumbnails are loaded from file:// URIs
+        egui_extras::install_image_loaders(&ctx);
+
         let (tx, rx) = channel();
 
         // Tray thread
@@ -307,12 +310,18 @@ impl SettingsApp {
             show_gemini_api_key: false,
             show_openrouter_api_key: false,
             show_cerebras_api_key: false,
+            show_anthropic_api_key: false,
+            show_custom_openai_api_key: false,
             icon_dark: None,
             icon_light: None,
             view_mode,
             recording_hotkey_for_preset: None,
             hotkey_conflict_msg: None,
             recording_sr_hotkey: false,
+            recording_active_window_hotkey: false,
+            recording_realtime_stop_hotkey: false,
+            recording_selection_history_hotkey: false,
+            recording_repeat_last_hotkey: false,
             splash: None, // DELAYED CREATION to stage 35 for perfect $t=0$ timing
             fade_in_start: None,
             startup_stage: 0,
@@ -187,7 +187,7 @@ fn process_image_content(img: ImageBuffer<Rgba<u8>, Vec<u8>>) {
 
         // Spawn processing in background thread
         std::thread::spawn(move || {
-            start_processing_pipeline(img, rect, config, preset);
+            start_processing_pipeline(img, rect, config, preset, None);
         });
     }
 }
@@ -219,7 +219,15 @@ fn process_text_content(text: String) {
 
         // Spawn processing in background thread
         std::thread::spawn(move || {
-            start_text_processing(text, rect, config, preset, localized_name, cancel_hotkey);
+            start_text_processing(
+                text,
+                rect,
+                config,
+                preset,
+                localized_name,
+                cancel_hotkey,
+                None,
+            );
         });
     }
 }
@@ -268,7 +276,15 @@ fn process_text_parallel(rx: mpsc::Receiver<Option<String>>) {
 
         std::thread::spawn(move || {
             if let Ok(Some(text)) = rx.recv() {
-                start_text_processing(text, rect, config, preset, localized_name, cancel_hotkey);
+                start_text_processing(
+                    text,
+                    rect,
+                    config,
+                    preset,
+                    localized_name,
+                    cancel_hotkey,
+                    None,
+                );
             }
         });
     }
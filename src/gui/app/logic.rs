@@ -1,5 +1,6 @@
 use super::types::{
-    SettingsApp, UserEvent, MOD_ALT, MOD_CONTROL, MOD_SHIFT, MOD_WIN, RESTORE_SIGNAL,
+    SettingsApp, UserEvent, MOD_ALT, MOD_CONTROL, MOD_SHIFT, MOD_WIN, PRESETS_CHANGED_SIGNAL,
+    RESTORE_SIGNAL,
 };
 use crate::config::{Hotkey, ThemeMode};
 use crate::gui::app::utils::simple_rand;
@@ -268,6 +269,16 @@ impl SettingsApp {
         }
     }
 
+    pub(crate) fn check_presets_changed(&mut self) {
+        if PRESETS_CHANGED_SIGNAL.swap(false, Ordering::SeqCst) {
+            if let Ok(app) = crate::APP.lock() {
+                self.config.presets = app.config.presets.clone();
+            }
+            // Rebuild the node graph from the updated blocks
+            self.last_edited_preset_idx = None;
+        }
+    }
+
     pub(crate) fn update_tips_logic(&mut self, ctx: &egui::Context) {
         let text = LocaleText::get(&self.config.ui_language);
         let now = ctx.input(|i| i.time);
@@ -437,7 +448,14 @@ impl SettingsApp {
     }
 
     pub(crate) fn update_sr_hotkey_recording(&mut self, ctx: &egui::Context) {
-        if self.recording_sr_hotkey {
+        // Shared recorder for global (non-preset) hotkeys: screen record, active window
+        // capture, realtime stop, selection history and repeat last
+        if self.recording_sr_hotkey
+            || self.recording_active_window_hotkey
+            || self.recording_realtime_stop_hotkey
+            || self.recording_selection_history_hotkey
+            || self.recording_repeat_last_hotkey
+        {
             let mut key_recorded: Option<(u32, u32, String)> = None;
             let mut cancel = false;
 
@@ -504,6 +522,11 @@ impl SettingsApp {
 
             if cancel {
                 self.recording_sr_hotkey = false;
+                self.recording_active_window_hotkey = false;
+                self.recording_realtime_stop_hotkey = false;
+                self.recording_selection_history_hotkey = false;
+                self.recording_repeat_last_hotkey = false;
+                self.hotkey_conflict_msg = None;
             } else if let Some((vk, mods, key_name)) = key_recorded {
                 let mut name_parts = Vec::new();
                 if (mods & MOD_CONTROL) != 0 {
@@ -528,11 +551,28 @@ impl SettingsApp {
 
                 if let Some(msg) = self.config.check_hotkey_conflict(vk, mods, None) {
                     crate::log_info!("Hotkey conflict: {}", msg);
+                    // Keep recording so the user can pick another combination
+                    self.hotkey_conflict_msg = Some(msg);
+                    return;
+                }
+                if self.recording_active_window_hotkey {
+                    self.config.active_window_capture_hotkeys.push(new_hotkey);
+                } else if self.recording_realtime_stop_hotkey {
+                    self.config.realtime_stop_hotkeys.push(new_hotkey);
+                } else if self.recording_selection_history_hotkey {
+                    self.config.selection_history_hotkeys.push(new_hotkey);
+                } else if self.recording_repeat_last_hotkey {
+                    self.config.repeat_last_hotkeys.push(new_hotkey);
                 } else {
                     self.config.screen_record_hotkeys.push(new_hotkey);
-                    self.save_and_sync();
                 }
+                self.save_and_sync();
                 self.recording_sr_hotkey = false;
+                self.recording_active_window_hotkey = false;
+                self.recording_realtime_stop_hotkey = false;
+                self.recording_selection_history_hotkey = false;
+                self.recording_repeat_last_hotkey = false;
+                self.hotkey_conflict_msg = None;
             }
         }
     }
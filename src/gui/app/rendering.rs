@@ -1,6 +1,8 @@
 use super::types::SettingsApp;
 use crate::gui::locale::LocaleText;
-use crate::gui::settings_ui::node_graph::{blocks_to_snarl, snarl_to_graph};
+use crate::gui::settings_ui::node_graph::{
+    blocks_to_snarl, request_node_graph_view_restore, snarl_to_graph,
+};
 use crate::gui::settings_ui::{
     render_footer, render_global_settings, render_history_panel, render_preset_editor,
     render_sidebar, ViewMode,
@@ -233,6 +235,7 @@ impl SettingsApp {
                     let lang_flag = match self.config.ui_language.as_str() {
                         "vi" => "🇻🇳",
                         "ko" => "🇰🇷",
+                        "ja" => "🇯🇵",
                         _ => "🇺🇸",
                     };
                     egui::ComboBox::from_id_salt("title_lang_switch")
@@ -254,6 +257,11 @@ impl SettingsApp {
                                 "ko".to_string(),
                                 "🇰🇷 한국어",
                             );
+                            ui.selectable_value(
+                                &mut self.config.ui_language,
+                                "ja".to_string(),
+                                "🇯🇵 日本語",
+                            );
                         });
                     if original_lang != self.config.ui_language {
                         self.save_and_sync();
@@ -624,6 +632,8 @@ impl SettingsApp {
                                         &mut self.show_gemini_api_key,
                                         &mut self.show_openrouter_api_key,
                                         &mut self.show_cerebras_api_key,
+                                        &mut self.show_anthropic_api_key,
+                                        &mut self.show_custom_openai_api_key,
                                         &usage_stats,
                                         &self.updater,
                                         &self.update_status,
@@ -637,6 +647,11 @@ impl SettingsApp {
                                         &mut self.download_manager,
                                         &self.cached_audio_devices,
                                         &mut self.recording_sr_hotkey,
+                                        &mut self.recording_active_window_hotkey,
+                                        &mut self.recording_realtime_stop_hotkey,
+                                        &mut self.recording_selection_history_hotkey,
+                                        &mut self.recording_repeat_last_hotkey,
+                                        &self.hotkey_conflict_msg,
                                     ) {
                                         self.save_and_sync();
                                     }
@@ -660,6 +675,9 @@ impl SettingsApp {
                                     // Sync snarl state if switching presets or first load
                                     if self.last_edited_preset_idx != Some(idx) {
                                         if idx < self.config.presets.len() {
+                                            // Keep the framing of the graph we're leaving
+                                            self.persist_node_graph_views();
+                                            request_node_graph_view_restore(ui.ctx());
                                             self.snarl = Some(blocks_to_snarl(
                                                 &self.config.presets[idx].blocks,
                                                 &self.config.presets[idx].block_connections,
@@ -20,6 +20,8 @@ pub const MOD_WIN: u32 = 0x0008;
 
 lazy_static::lazy_static! {
     pub static ref RESTORE_SIGNAL: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+    /// Set when an overlay edits presets in `APP` directly (e.g. Prompt DJ)
+    pub static ref PRESETS_CHANGED_SIGNAL: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
 }
 
 pub enum UserEvent {
@@ -47,6 +49,8 @@ pub struct SettingsApp {
     pub(crate) show_gemini_api_key: bool,
     pub(crate) show_openrouter_api_key: bool,
     pub(crate) show_cerebras_api_key: bool,
+    pub(crate) show_anthropic_api_key: bool,
+    pub(crate) show_custom_openai_api_key: bool,
     pub(crate) icon_dark: Option<egui::TextureHandle>,
     pub(crate) icon_light: Option<egui::TextureHandle>,
 
@@ -54,6 +58,10 @@ pub struct SettingsApp {
     pub(crate) recording_hotkey_for_preset: Option<usize>,
     pub(crate) hotkey_conflict_msg: Option<String>,
     pub(crate) recording_sr_hotkey: bool,
+    pub(crate) recording_active_window_hotkey: bool,
+    pub(crate) recording_realtime_stop_hotkey: bool,
+    pub(crate) recording_selection_history_hotkey: bool,
+    pub(crate) recording_repeat_last_hotkey: bool,
     pub(crate) splash: Option<crate::gui::splash::SplashScreen>,
     pub(crate) fade_in_start: Option<f64>,
 
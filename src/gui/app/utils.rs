@@ -1,4 +1,4 @@
-use super::types::{SettingsApp, RESTORE_SIGNAL};
+use super::types::{SettingsApp, PRESETS_CHANGED_SIGNAL, RESTORE_SIGNAL};
 use crate::config::save_config;
 use eframe::egui;
 use std::sync::atomic::Ordering;
@@ -26,7 +26,30 @@ pub fn signal_restore_window() {
     }
 }
 
+/// Tell the settings window to reload presets that were changed outside of it,
+/// so its next save doesn't overwrite them.
+pub fn signal_presets_changed() {
+    PRESETS_CHANGED_SIGNAL.store(true, Ordering::SeqCst);
+    if let Ok(guard) = crate::gui::GUI_CONTEXT.lock() {
+        if let Some(ctx) = guard.as_ref() {
+            ctx.request_repaint();
+        }
+    }
+}
+
 impl SettingsApp {
+    /// Save node graph zoom/pan changes (tracked in memory while panning) if there are any
+    pub(crate) fn persist_node_graph_views(&mut self) {
+        let changed = self
+            .app_state_ref
+            .lock()
+            .map(|app| app.config.node_graph_views != self.config.node_graph_views)
+            .unwrap_or(false);
+        if changed {
+            self.save_and_sync();
+        }
+    }
+
     pub(crate) fn save_and_sync(&mut self) {
         if let crate::gui::settings_ui::ViewMode::Preset(idx) = self.view_mode {
             self.config.active_preset_idx = idx;
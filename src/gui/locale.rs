@@ -4,9 +4,12 @@ pub struct LocaleText {
     pub history_title: &'static str,
     pub max_items_label: &'static str,
     pub history_empty: &'static str,
+    pub history_incomplete: &'static str,
+    pub history_incomplete_tooltip: &'static str,
     pub clear_all_history_btn: &'static str,
     pub view_image_btn: &'static str,
     pub listen_audio_btn: &'static str,
+    pub reopen_result_btn: &'static str,
     pub view_text_btn: &'static str, // NEW
 
     pub prompt_mode_fixed: &'static str,
@@ -23,6 +26,53 @@ pub struct LocaleText {
     pub cerebras_api_key_label: &'static str,
     pub cerebras_get_key_link: &'static str,
     pub use_cerebras_checkbox: &'static str,
+    pub anthropic_api_key_label: &'static str,
+    pub anthropic_get_key_link: &'static str,
+    pub use_anthropic_checkbox: &'static str,
+    pub use_custom_openai_checkbox: &'static str,
+    pub custom_openai_base_url_label: &'static str,
+    pub custom_openai_api_key_label: &'static str,
+    pub api_max_attempts_label: &'static str,
+    pub api_max_concurrent_label: &'static str,
+    pub skip_rate_limited_models_label: &'static str,
+    pub api_timeouts_label: &'static str,
+    pub api_connect_timeout_label: &'static str,
+    pub api_stall_timeout_label: &'static str,
+    pub active_window_capture_header: &'static str,
+    pub active_window_capture_desc: &'static str,
+    pub active_window_capture_preset_label: &'static str,
+    pub realtime_stop_hotkey_header: &'static str,
+    pub realtime_stop_hotkey_desc: &'static str,
+    pub realtime_reopen_session_btn: &'static str,
+    pub selection_history_hotkey_header: &'static str,
+    pub selection_history_hotkey_desc: &'static str,
+    pub repeat_last_hotkey_header: &'static str,
+    pub repeat_last_hotkey_desc: &'static str,
+    pub overlay_opacity_header: &'static str,
+    pub overlay_opacity_desc: &'static str,
+    pub text_animation_header: &'static str,
+    pub text_animation_desc: &'static str,
+    pub text_animation_enabled: &'static str,
+    pub paste_method_header: &'static str,
+    pub paste_method_desc: &'static str,
+    pub paste_method_type: &'static str,
+    pub paste_key_delay_label: &'static str,
+    pub result_right_click_header: &'static str,
+    pub result_right_click_desc: &'static str,
+    pub result_right_click_close: &'static str,
+    pub result_right_click_menu: &'static str,
+    pub result_right_click_copy: &'static str,
+    pub retranslation_layout_label: &'static str,
+    pub retranslation_layout_auto: &'static str,
+    pub retranslation_layout_horizontal: &'static str,
+    pub retranslation_layout_vertical: &'static str,
+    pub config_backup_header: &'static str,
+    pub export_config_button: &'static str,
+    pub import_config_button: &'static str,
+    pub export_include_api_keys: &'static str,
+    pub config_exported_msg: &'static str,
+    pub config_imported_msg: &'static str,
+    pub config_backup_failed_msg: &'static str,
 
     pub global_settings: &'static str,
     pub preset_name_label: &'static str,
@@ -64,6 +114,10 @@ pub struct LocaleText {
     pub usage_model_column: &'static str,
     pub usage_remaining_column: &'static str,
     pub usage_check_link: &'static str,
+    pub usage_tokens_by_preset_title: &'static str,
+    pub usage_tokens_empty: &'static str,
+    pub usage_preset_column: &'static str,
+    pub usage_tokens_column: &'static str,
 
     pub footer_admin_text: &'static str,
     pub footer_version: &'static str,
@@ -86,6 +140,8 @@ pub struct LocaleText {
     pub text_input_mode_label: &'static str,
     pub text_mode_select: &'static str,
     pub text_mode_type: &'static str,
+    pub text_mode_clipboard: &'static str,
+    pub clipboard_empty_error: &'static str,
     pub continuous_input_label: &'static str, // Checkbox for continuous input mode
     pub command_mode_label: &'static str, // For prompt mode in text/image presets (different from text_input_mode_label)
     pub text_input_title_default: &'static str,
@@ -143,6 +199,17 @@ pub struct LocaleText {
     pub realtime_waiting: &'static str,
     pub realtime_translation: &'static str,
     pub realtime_mic: &'static str,
+    pub realtime_audio_device_tooltip: &'static str,
+    pub realtime_target_language_tooltip: &'static str,
+    pub realtime_gemini_live_tooltip: &'static str,
+    pub realtime_parakeet_tooltip: &'static str,
+    pub realtime_gemma_tooltip: &'static str,
+    pub realtime_cerebras_tooltip: &'static str,
+    pub realtime_gtx_tooltip: &'static str,
+    pub realtime_header_toggle_tooltip: &'static str,
+    pub realtime_cancel_download_tooltip: &'static str,
+    pub realtime_auto_speed_tooltip: &'static str,
+    pub realtime_app_list_loading: &'static str,
     pub ollama_url_guide: &'static str,
     pub tts_settings_button: &'static str,
     pub tts_settings_title: &'static str,
@@ -188,7 +255,10 @@ pub struct LocaleText {
     pub tray_favorite_bubble_disabled: &'static str,
     // --- FAVORITE BUBBLE ---
     pub favorites_empty: &'static str,
+    pub favorites_empty_hint: &'static str,
     pub favorites_keep_open: &'static str,
+    pub favorites_snap_to_edge: &'static str,
+    pub favorites_hide_in_fullscreen: &'static str,
     pub recording_subtext: &'static str,
     pub recording_paused: &'static str,
     // --- AUTO COPY BADGE ---
@@ -197,6 +267,7 @@ pub struct LocaleText {
     pub live_translate_loading: &'static str,
     pub text_input_loading: &'static str,
     pub recording_loading: &'static str,
+    pub screen_record_loading: &'static str,
     pub markdown_view_loading: &'static str,
     pub preset_wheel_loading: &'static str,
     pub prompt_dj_loading: &'static str,
@@ -238,6 +309,7 @@ pub struct LocaleText {
     pub parakeet_supports_english_only: &'static str,
     // --- OVERLAY BUTTONS TOOLTIPS ---
     pub overlay_copy_tooltip: &'static str,
+    pub overlay_retry_tooltip: &'static str,
     pub overlay_undo_tooltip: &'static str,
     pub overlay_redo_tooltip: &'static str,
     pub overlay_edit_tooltip: &'static str,
@@ -310,6 +382,9 @@ pub struct LocaleText {
     pub tool_update_retry: &'static str,
     pub tool_update_check_btn: &'static str,
     pub tool_update_available: &'static str,
+    // --- WEBVIEW2 ---
+    pub webview2_missing: &'static str,
+    pub webview2_too_old: &'static str,
     // --- CONTINUOUS MODE ---
     pub continuous_mode_activated: &'static str, // "✨ Cấu hình \"{preset}\" sẽ hoạt động liên tục, bấm ESC hay {hotkey} để thoát"
 }
@@ -322,9 +397,12 @@ impl LocaleText {
                  history_title: "Thư viện kết quả",
                  max_items_label: "Giới hạn lưu:",
                  history_empty: "Chưa có lịch sử nào.",
+                 history_incomplete: "Chưa xong",
+                 history_incomplete_tooltip: "Kết quả chưa nhận xong: bị ngắt giữa chừng hoặc vẫn đang đến. Phần đã nhận được vẫn được giữ lại.",
                  clear_all_history_btn: "Dọn tất cả", 
                  view_image_btn: "Xem ảnh",
                  listen_audio_btn: "Nghe audio",
+                 reopen_result_btn: "Mở lại",
                  view_text_btn: "Xem text",
 
                  prompt_mode_fixed: "Làm theo lệnh sẵn",
@@ -341,6 +419,53 @@ impl LocaleText {
                  cerebras_api_key_label: "Mã API Cerebras:",
                  cerebras_get_key_link: "Lấy mã tại cloud.cerebras.ai",
                  use_cerebras_checkbox: "Cerebras",
+                 anthropic_api_key_label: "Mã API Anthropic:",
+                 anthropic_get_key_link: "Lấy mã tại console.anthropic.com",
+                 use_anthropic_checkbox: "Claude",
+                 use_custom_openai_checkbox: "Tương thích OpenAI",
+                 custom_openai_base_url_label: "Địa chỉ máy chủ tương thích OpenAI (Base URL):",
+                 custom_openai_api_key_label: "API Key (để trống nếu máy chủ không yêu cầu):",
+                 api_max_attempts_label: "Số lần thử lại khi bị giới hạn tốc độ (429/5xx):",
+                 api_max_concurrent_label: "Số yêu cầu đồng thời mỗi nhà cung cấp (0 = không giới hạn):",
+                 skip_rate_limited_models_label: "Dùng model dự phòng khi model đã chọn đang bị giới hạn tốc độ",
+                 api_timeouts_label: "Thời gian chờ:",
+                 api_connect_timeout_label: "Kết nối",
+                 api_stall_timeout_label: "Giữa các phần",
+                 active_window_capture_header: "Chụp cửa sổ đang mở",
+                 active_window_capture_desc: "Phím tắt chụp ngay vùng nội dung của cửa sổ đang được chọn và xử lý, không cần kéo chọn vùng.",
+                 active_window_capture_preset_label: "Cấu hình xử lý:",
+                 realtime_stop_hotkey_header: "Dừng dịch trực tiếp & lưu bản ghi",
+                 realtime_stop_hotkey_desc: "Phím tắt dừng chế độ dịch trực tiếp và lưu toàn bộ bản ghi (kèm bản dịch) ra tệp văn bản có dấu thời gian.",
+                 realtime_reopen_session_btn: "Mở lại phiên gần nhất",
+                 selection_history_hotkey_header: "Bản dịch văn bản gần đây",
+                 selection_history_hotkey_desc: "Phím tắt mở lại lần lượt các đoạn văn bản đã chọn và dịch gần đây (tối đa 20), không cần chọn lại.",
+                 repeat_last_hotkey_header: "Lặp lại lần dịch trước",
+                 repeat_last_hotkey_desc: "Phím tắt chạy lại lần dịch ảnh/văn bản gần nhất với cùng đầu vào và preset, hiển thị kết quả tại vị trí cũ.",
+                 overlay_opacity_header: "Độ trong suốt của cửa sổ kết quả",
+                 overlay_opacity_desc: "Độ đục ban đầu của cửa sổ kết quả và nền các lớp phủ. Cao hơn dễ đọc hơn trên nền rối, thấp hơn giữ được ngữ cảnh phía sau.",
+                 text_animation_header: "Hiệu ứng chữ xuất hiện",
+                 text_animation_desc: "Hiệu ứng quét khi chữ mới hiện ra trong lớp phủ phiên dịch trực tiếp. Tắt đi hoặc tăng tốc nếu thấy rối mắt.",
+                 text_animation_enabled: "Bật hiệu ứng",
+                 paste_method_header: "Cách tự động dán",
+                 paste_method_desc: "Một số terminal và game không nhận Ctrl+V. Hãy thử Shift+Insert hoặc gõ từng ký tự.",
+                 paste_method_type: "Gõ từng ký tự",
+                 paste_key_delay_label: "Độ trễ giữa các phím:",
+                 result_right_click_header: "Nhấp chuột phải vào cửa sổ kết quả",
+                 result_right_click_desc: "Nút sao chép ở góc luôn sao chép kết quả.",
+                 result_right_click_close: "Đóng",
+                 result_right_click_menu: "Hiện menu",
+                 result_right_click_copy: "Sao chép",
+                 retranslation_layout_label: "Vị trí cửa sổ kết quả tiếp theo:",
+                 retranslation_layout_auto: "Tự động",
+                 retranslation_layout_horizontal: "Bên cạnh",
+                 retranslation_layout_vertical: "Bên dưới",
+                 config_backup_header: "Sao lưu cài đặt",
+                 export_config_button: "📤 Xuất cài đặt",
+                 import_config_button: "📥 Nhập cài đặt",
+                 export_include_api_keys: "Kèm API key",
+                 config_exported_msg: "Đã xuất cài đặt ra",
+                 config_imported_msg: "Đã nhập cài đặt thành công.",
+                 config_backup_failed_msg: "Thất bại:",
 
                 global_settings: "Cài Đặt Chung",
                 preset_name_label: "Tên Cấu Hình:",
@@ -387,6 +512,10 @@ impl LocaleText {
                 usage_model_column: "Mô hình",
                 usage_remaining_column: "Còn lại / Tổng",
                 usage_check_link: "Xem lượng dùng ↗",
+                usage_tokens_by_preset_title: "Token theo preset (tháng này)",
+                usage_tokens_empty: "Chưa có dữ liệu trong tháng này.",
+                usage_preset_column: "Preset",
+                usage_tokens_column: "Token",
 
                 footer_admin_text: "chạy bằng admin để dịch game",
                 footer_version: "phiên bản",
@@ -409,6 +538,8 @@ impl LocaleText {
                 text_input_mode_label: "Phương thức:",
                 text_mode_select: "Hotkey rồi bôi text",
                 text_mode_type: "Hotkey rồi gõ",
+                text_mode_clipboard: "Hotkey dùng ngay clipboard",
+                clipboard_empty_error: "Clipboard không có văn bản",
                 continuous_input_label: "Nhập liên tục",
                 command_mode_label: "Lệnh:",
                 text_input_title_default: "Nhập văn bản cần xử lý:",
@@ -498,6 +629,17 @@ impl LocaleText {
                 realtime_waiting: "Đang chờ nói...",
                 realtime_translation: "Bản dịch",
                 realtime_mic: "Micro",
+                realtime_audio_device_tooltip: "Thiết bị âm thanh",
+                realtime_target_language_tooltip: "Ngôn ngữ đích",
+                realtime_gemini_live_tooltip: "Gemini Live (Đám mây)",
+                realtime_parakeet_tooltip: "Parakeet (Cục bộ)",
+                realtime_gemma_tooltip: "Dịch bằng AI (Gemma)",
+                realtime_cerebras_tooltip: "AI tức thì (Cerebras)",
+                realtime_gtx_tooltip: "Dịch không giới hạn (Google)",
+                realtime_header_toggle_tooltip: "Ẩn/hiện thanh tiêu đề",
+                realtime_cancel_download_tooltip: "Hủy tải và quay lại Gemini Live",
+                realtime_auto_speed_tooltip: "Tự tăng tốc độ để theo kịp",
+                realtime_app_list_loading: "Đang tải...",
                 ollama_url_guide: "Xem hướng dẫn tại ollama.com",
                 tts_settings_button: "Cài đặt giọng đọc",
                 tts_settings_title: "Thiết lập Giọng Đọc",
@@ -554,7 +696,10 @@ impl LocaleText {
                 tray_favorite_bubble_disabled: "Hiện bong bóng yêu thích (Chưa có mục yêu thích)",
                 // --- FAVORITE BUBBLE VI ---
                  favorites_empty: "Vui lòng đưa ít nhất một cấu hình vào ưa thích",
+                 favorites_empty_hint: "Mở cài đặt và nhấn ngôi sao ☆ cạnh một cấu hình để ghim vào đây. Nhấn vào đây để mở cài đặt.",
                  favorites_keep_open: "Giữ mở",
+                 favorites_snap_to_edge: "Bám vào cạnh màn hình",
+                 favorites_hide_in_fullscreen: "Ẩn khi có ứng dụng toàn màn hình",
                  recording_subtext: "Nhấn ESC/Hotkey để dừng",
                  recording_paused: "Đã tạm dừng",
                  // --- AUTO COPY BADGE VI ---
@@ -563,6 +708,7 @@ impl LocaleText {
                  live_translate_loading: "⏳ Đang khởi động Live Translate...",
                  text_input_loading: "⏳ Đang khởi động nhập văn bản...",
                  recording_loading: "⏳ Đang khởi động ghi âm...",
+                 screen_record_loading: "⏳ Đang khởi động trình ghi màn hình...",
                  markdown_view_loading: "⏳ Đang khởi động xem kết quả...",
                  preset_wheel_loading: "⏳ Đang khởi động bảng chọn...",
                  prompt_dj_loading: "⏳ Đang khởi động Chill Corner...",
@@ -601,6 +747,7 @@ impl LocaleText {
                   parakeet_downloading_file: "Đang tải {}...",
                   parakeet_supports_english_only: "(Chỉ hỗ trợ tiếng Anh)",
                   overlay_copy_tooltip: "Sao chép",
+                  overlay_retry_tooltip: "Thử lại",
                   overlay_undo_tooltip: "Hoàn tác",
                   overlay_redo_tooltip: "Làm lại",
                   overlay_edit_tooltip: "Chỉnh sửa / Viết lại",
@@ -673,6 +820,8 @@ impl LocaleText {
                    tool_update_retry: "Thử lại",
                    tool_update_check_btn: "Kiểm tra cập nhật",
                    tool_update_available: "Cập nhật ({})",
+                   webview2_missing: "Chưa cài Microsoft Edge WebView2 Runtime.\n\nCửa sổ kết quả sẽ chỉ hiện văn bản thuần; quay màn hình, Prompt DJ, phụ đề trực tiếp và các cửa sổ HTML khác sẽ bị tắt.\n\nMở trang tải về?",
+                   webview2_too_old: "Microsoft Edge WebView2 Runtime đã quá cũ.\n\nCửa sổ kết quả sẽ chỉ hiện văn bản thuần; quay màn hình, Prompt DJ, phụ đề trực tiếp và các cửa sổ HTML khác sẽ bị tắt.\n\nMở trang tải về để cập nhật?",
                    continuous_mode_activated: "✨ Cấu hình \"{preset}\" sẽ hoạt động liên tục, bấm ESC hay {hotkey} để thoát",
                    },
             "ko" => Self {
@@ -680,9 +829,12 @@ impl LocaleText {
                  history_title: "결과 라이브러리",
                  max_items_label: "저장 한도:",
                  history_empty: "기록이 없습니다.",
+                 history_incomplete: "미완료",
+                 history_incomplete_tooltip: "결과를 끝까지 받지 못했습니다. 중간에 끊겼거나 아직 받는 중입니다. 받은 부분까지는 보존됩니다.",
                  clear_all_history_btn: "모두 삭제",
                  view_image_btn: "이미지 보기",
                  listen_audio_btn: "오디오 듣기",
+                 reopen_result_btn: "다시 열기",
                  view_text_btn: "텍스트 보기",
 
                  prompt_mode_fixed: "사전 정의된 프롬프트",
@@ -699,6 +851,53 @@ impl LocaleText {
                 cerebras_api_key_label: "Cerebras API 키:",
                 cerebras_get_key_link: "cloud.cerebras.ai에서 API 키 받기",
                 use_cerebras_checkbox: "Cerebras",
+                anthropic_api_key_label: "Anthropic API 키:",
+                anthropic_get_key_link: "console.anthropic.com에서 API 키 받기",
+                use_anthropic_checkbox: "Claude",
+                use_custom_openai_checkbox: "OpenAI 호환",
+                custom_openai_base_url_label: "OpenAI 호환 서버 주소 (Base URL):",
+                custom_openai_api_key_label: "API 키 (서버에서 필요하지 않으면 비워 두세요):",
+                api_max_attempts_label: "요청 한도 초과 시 최대 시도 횟수 (429/5xx):",
+                api_max_concurrent_label: "제공자별 동시 요청 수 (0 = 무제한):",
+                skip_rate_limited_models_label: "선택한 모델이 속도 제한 중이면 대체 모델 사용",
+                api_timeouts_label: "시간 제한:",
+                api_connect_timeout_label: "연결",
+                api_stall_timeout_label: "응답 간격",
+                active_window_capture_header: "활성 창 캡처",
+                active_window_capture_desc: "단축키를 누르면 영역 선택 없이 현재 포커스된 창의 내용을 바로 캡처하여 처리합니다.",
+                active_window_capture_preset_label: "처리 프리셋:",
+                realtime_stop_hotkey_header: "실시간 번역 중지 및 기록 저장",
+                realtime_stop_hotkey_desc: "실시간 모드를 중지하고 누적된 기록(번역 포함)을 타임스탬프가 붙은 텍스트 파일로 저장하는 단축키입니다.",
+                realtime_reopen_session_btn: "마지막 세션 다시 열기",
+                selection_history_hotkey_header: "최근 선택 번역",
+                selection_history_hotkey_desc: "누를 때마다 최근에 선택하여 번역한 텍스트(최대 20개)를 차례로 다시 보여줍니다. 다시 선택할 필요가 없습니다.",
+                repeat_last_hotkey_header: "마지막 번역 다시 실행",
+                repeat_last_hotkey_desc: "마지막 이미지/텍스트 번역을 같은 입력과 프리셋으로 다시 실행하고, 결과를 이전과 같은 위치에 표시합니다.",
+                overlay_opacity_header: "오버레이 불투명도",
+                overlay_opacity_desc: "결과 창과 오버레이 배경의 초기 불투명도입니다. 높으면 복잡한 화면에서도 읽기 쉽고, 낮으면 뒤의 내용이 보입니다.",
+                text_animation_header: "텍스트 등장 애니메이션",
+                text_animation_desc: "실시간 오버레이에서 새 텍스트가 나타날 때의 와이프 효과입니다. 산만하다면 끄거나 빠르게 하세요.",
+                text_animation_enabled: "애니메이션 사용",
+                paste_method_header: "자동 붙여넣기 방식",
+                paste_method_desc: "일부 터미널과 게임은 Ctrl+V를 받지 않습니다. Shift+Insert 또는 문자 입력을 사용해 보세요.",
+                paste_method_type: "문자 직접 입력",
+                paste_key_delay_label: "키 입력 간격:",
+                result_right_click_header: "결과 창 오른쪽 클릭",
+                result_right_click_desc: "모서리의 복사 버튼은 항상 결과를 복사합니다.",
+                result_right_click_close: "닫기",
+                result_right_click_menu: "메뉴 표시",
+                result_right_click_copy: "복사",
+                retranslation_layout_label: "다음 결과 창 위치:",
+                retranslation_layout_auto: "자동",
+                retranslation_layout_horizontal: "옆에",
+                retranslation_layout_vertical: "아래에",
+                config_backup_header: "설정 백업",
+                export_config_button: "📤 설정 내보내기",
+                import_config_button: "📥 설정 가져오기",
+                export_include_api_keys: "API 키 포함",
+                config_exported_msg: "설정을 내보냈습니다:",
+                config_imported_msg: "설정을 가져왔습니다.",
+                config_backup_failed_msg: "실패:",
 
                 global_settings: "전역 설정",
                 preset_name_label: "프리셋 이름:",
@@ -745,6 +944,10 @@ impl LocaleText {
                 usage_model_column: "모델",
                 usage_remaining_column: "남은 / 전체",
                 usage_check_link: "사용량 확인 ↗",
+                usage_tokens_by_preset_title: "프리셋별 토큰 (이번 달)",
+                usage_tokens_empty: "이번 달 데이터가 아직 없습니다.",
+                usage_preset_column: "프리셋",
+                usage_tokens_column: "토큰",
 
                 footer_admin_text: "게임을 번역하려면 관리자로 실행하세요",
                 footer_version: "버전",
@@ -767,6 +970,8 @@ impl LocaleText {
                 text_input_mode_label: "작동 방식:",
                 text_mode_select: "단축키 후 텍스트 선택",
                 text_mode_type: "단축키 후 입력",
+                text_mode_clipboard: "단축키로 클립보드 바로 처리",
+                clipboard_empty_error: "클립보드에 텍스트가 없습니다",
                 continuous_input_label: "연속 입력",
                 command_mode_label: "명령:",
                 text_input_title_default: "처리할 텍스트 입력:",
@@ -856,6 +1061,17 @@ impl LocaleText {
                 realtime_waiting: "말하기 대기 중...",
                 realtime_translation: "번역",
                 realtime_mic: "마이크",
+                realtime_audio_device_tooltip: "오디오 장치",
+                realtime_target_language_tooltip: "대상 언어",
+                realtime_gemini_live_tooltip: "Gemini Live (클라우드)",
+                realtime_parakeet_tooltip: "Parakeet (로컬)",
+                realtime_gemma_tooltip: "AI 번역 (Gemma)",
+                realtime_cerebras_tooltip: "즉시 AI (Cerebras)",
+                realtime_gtx_tooltip: "무제한 번역 (Google)",
+                realtime_header_toggle_tooltip: "헤더 표시/숨기기",
+                realtime_cancel_download_tooltip: "다운로드를 취소하고 Gemini Live로 돌아가기",
+                realtime_auto_speed_tooltip: "따라잡도록 속도 자동 조절",
+                realtime_app_list_loading: "불러오는 중...",
                 ollama_url_guide: "올라마 설명서 보기",
                 tts_settings_button: "TTS 설정",
                 tts_settings_title: "TTS 설정",
@@ -912,7 +1128,10 @@ impl LocaleText {
                 tray_favorite_bubble_disabled: "즐겨찾기 버블 표시 (즐겨찾기 없음)",
                 // --- FAVORITE BUBBLE KO ---
                  favorites_empty: "즐겨찾기에 최소한 하나의 프리셋을 추가해주세요",
+                 favorites_empty_hint: "설정에서 프리셋 옆의 ☆ 별을 누르면 여기에 추가됩니다. 여기를 클릭하면 설정이 열립니다.",
                  favorites_keep_open: "열린 상태 유지",
+                 favorites_snap_to_edge: "화면 가장자리에 붙이기",
+                 favorites_hide_in_fullscreen: "전체 화면 앱 실행 중에는 숨기기",
                  recording_subtext: "ESC/Hotkey를 눌러 중지",
                  recording_paused: "일시 중지됨",
                  // --- AUTO COPY BADGE KO ---
@@ -921,6 +1140,7 @@ impl LocaleText {
                  live_translate_loading: "⏳ 실시간 번역 로딩 중...",
                  text_input_loading: "⏳ 텍스트 입력 로딩 중...",
                  recording_loading: "⏳ 녹음 로딩 중...",
+                 screen_record_loading: "⏳ 화면 녹화 로딩 중...",
                  markdown_view_loading: "⏳ 결과 보기 로딩 중...",
                  preset_wheel_loading: "⏳ 선택 휠 로딩 중...",
                  prompt_dj_loading: "⏳ Chill Corner 로딩 중...",
@@ -996,6 +1216,8 @@ impl LocaleText {
                    tool_update_retry: "재시도",
                    tool_update_check_btn: "업데이트 확인",
                    tool_update_available: "업데이트 ({})",
+                   webview2_missing: "Microsoft Edge WebView2 런타임이 설치되어 있지 않습니다.\n\n결과 창은 일반 텍스트로만 표시되며 화면 녹화, Prompt DJ, 실시간 자막 등 HTML 창은 비활성화됩니다.\n\n다운로드 페이지를 여시겠습니까?",
+                   webview2_too_old: "Microsoft Edge WebView2 런타임이 너무 오래되었습니다.\n\n결과 창은 일반 텍스트로만 표시되며 화면 녹화, Prompt DJ, 실시간 자막 등 HTML 창은 비활성화됩니다.\n\n업데이트하려면 다운로드 페이지를 여시겠습니까?",
 // --- HELP ASSISTANT KO ---
                  help_assistant_btn: "SGT 사용법 물어보기...",
                  help_assistant_title: "SGT에 대해 물어보기",
@@ -1015,6 +1237,7 @@ impl LocaleText {
                   parakeet_downloading_file: "{} 다운로드 중...",
                    parakeet_supports_english_only: "(영어만 지원됨)",
                    overlay_copy_tooltip: "복사",
+                   overlay_retry_tooltip: "다시 시도",
                    overlay_undo_tooltip: "실행 취소",
                    overlay_redo_tooltip: "다시 실행",
                    overlay_edit_tooltip: "편집 / 다듬기",
@@ -1032,14 +1255,448 @@ impl LocaleText {
                    download_subs_none_found: "자막을 찾을 수 없습니다",
                    continuous_mode_activated: "✨ 프리셋 \"{preset}\"이(가) 연속 모드로 실행됩니다. ESC 또는 {hotkey}를 눌러 종료",
                   },
+                "ja" => Self {
+                 history_btn: "履歴",
+                 history_title: "結果ライブラリ",
+                 max_items_label: "最大保存数:",
+                 history_empty: "履歴はまだありません。",
+                 history_incomplete: "未完了",
+                 history_incomplete_tooltip: "結果を最後まで受信できていません。途中で中断されたか、まだ受信中です。受信済みの部分は保持されています。",
+                 clear_all_history_btn: "すべて削除",
+                 view_image_btn: "画像を表示",
+                 listen_audio_btn: "音声を聴く",
+                 reopen_result_btn: "再度開く",
+                 view_text_btn: "テキストを表示",
+
+                 prompt_mode_fixed: "定義済みプロンプト",
+                 prompt_mode_dynamic: "その場で入力",
+
+                get_key_link: "console.groq.com で API キーを取得",
+                gemini_api_key_label: "Gemini API キー:",
+                gemini_get_key_link: "aistudio.google.com で API キーを取得",
+                openrouter_api_key_label: "OpenRouter API キー:",
+                openrouter_get_key_link: "openrouter.ai で API キーを取得",
+                use_groq_checkbox: "Groq",
+                use_gemini_checkbox: "Gemini",
+                use_openrouter_checkbox: "OpenRouter",
+                cerebras_api_key_label: "Cerebras API キー:",
+                cerebras_get_key_link: "cloud.cerebras.ai で API キーを取得",
+                 use_cerebras_checkbox: "Cerebras",
+                 anthropic_api_key_label: "Anthropic API キー:",
+                 anthropic_get_key_link: "console.anthropic.com で API キーを取得",
+                 use_anthropic_checkbox: "Claude",
+                 use_custom_openai_checkbox: "OpenAI 互換",
+                 custom_openai_base_url_label: "OpenAI 互換ベース URL:",
+                 custom_openai_api_key_label: "API キー（サーバーに認証がない場合は空欄）:",
+                 api_max_attempts_label: "レート制限時の最大試行回数 (429/5xx):",
+                 api_max_concurrent_label: "プロバイダーごとの同時リクエスト数 (0 = 無制限):",
+                 skip_rate_limited_models_label: "選択したモデルがレート制限中は代替モデルを使う",
+                 api_timeouts_label: "タイムアウト:",
+                 api_connect_timeout_label: "接続",
+                 api_stall_timeout_label: "チャンク間",
+                 active_window_capture_header: "アクティブウィンドウのキャプチャ",
+                 active_window_capture_desc: "範囲選択を省略し、フォーカス中のウィンドウの内容を即座にキャプチャして処理するホットキーです。",
+                 active_window_capture_preset_label: "プリセット:",
+                 realtime_stop_hotkey_header: "リアルタイム停止と文字起こし保存",
+                 realtime_stop_hotkey_desc: "リアルタイムモードを停止し、蓄積された文字起こし（翻訳を含む）をタイムスタンプ付きのテキストファイルに保存するホットキーです。",
+                 realtime_reopen_session_btn: "前回のセッションを再表示",
+                 selection_history_hotkey_header: "最近の選択テキスト翻訳",
+                 selection_history_hotkey_desc: "押すたびに最近選択して翻訳したテキスト（最大20件）を順に再表示します。再選択は不要です。",
+                 repeat_last_hotkey_header: "前回の翻訳を再実行",
+                 repeat_last_hotkey_desc: "直前の画像/テキスト翻訳を同じ入力とプリセットで再実行し、結果を前回と同じ位置に表示します。",
+                 overlay_opacity_header: "オーバーレイの不透明度",
+                 overlay_opacity_desc: "結果ウィンドウとオーバーレイ背景の初期不透明度です。高いと複雑な画面でも読みやすく、低いと背後の内容が見えます。",
+                 text_animation_header: "テキスト表示アニメーション",
+                 text_animation_desc: "リアルタイムオーバーレイで新しいテキストが現れる時のワイプ効果です。気になる場合はオフにするか速くしてください。",
+                 text_animation_enabled: "アニメーションを有効にする",
+                 paste_method_header: "自動貼り付けの方法",
+                 paste_method_desc: "一部のターミナルやゲームは Ctrl+V を受け付けません。Shift+Insert か文字入力を試してください。",
+                 paste_method_type: "文字を入力",
+                 paste_key_delay_label: "キー入力の間隔:",
+                 result_right_click_header: "結果ウィンドウの右クリック",
+                 result_right_click_desc: "角のコピーボタンでいつでも結果をコピーできます。",
+                 result_right_click_close: "閉じる",
+                 result_right_click_menu: "メニューを表示",
+                 result_right_click_copy: "コピー",
+                 retranslation_layout_label: "次の結果ウィンドウの位置:",
+                 retranslation_layout_auto: "自動",
+                 retranslation_layout_horizontal: "横に並べる",
+                 retranslation_layout_vertical: "縦に並べる",
+                 config_backup_header: "設定のバックアップ",
+                 export_config_button: "📤 設定をエクスポート",
+                 import_config_button: "📥 設定をインポート",
+                 export_include_api_keys: "API キーを含める",
+                 config_exported_msg: "設定のエクスポート先:",
+                 config_imported_msg: "設定をインポートしました。",
+                 config_backup_failed_msg: "失敗:",
+                global_settings: "全体設定",
+                 preset_name_label: "プリセット名:",
+
+
+
+                search_placeholder: "検索...",
+
+                auto_paste_label: "自動貼り付け",
+                auto_paste_newline_label: "コピー後に自動で改行を追加",
+                startup_label: "Windows 起動時に実行",
+                add_hotkey_button: "+ キーを追加",
+                press_keys: "キーの組み合わせを押してください...",
+                cancel_label: "キャンセル",
+                reset_defaults_btn: "初期設定に戻す",
+                force_quit: "強制終了",
+
+
+
+                preset_type_label: "種類:",
+                preset_type_image: "画像理解",
+                preset_type_audio: "音声理解",
+                preset_type_video: "動画理解（近日公開）",
+                preset_type_text: "テキスト理解", // NEW
+
+
+
+                audio_source_label: "音声ソース:",
+                audio_src_mic: "マイク",
+                audio_src_device: "デバイス音声",
+                hide_recording_ui_label: "録音 UI を隠す",
+                auto_stop_recording_label: "自動停止",
+                hotkeys_section: "ホットキー",
+                start_in_tray_label: "トレイで起動",
+                footer_admin_running: "管理者として実行中",
+                admin_startup_on: "起動時に管理者として実行",
+                admin_startup_success: "有効: 起動時に管理者として実行します（タスク スケジューラ）。",
+                admin_startup_fail: "エラー: この設定にはアプリを管理者として実行する必要があります。",
+                graphics_mode_label: "グラフィックス:",
+                graphics_mode_standard: "標準（グラデーション発光エフェクト）",
+                graphics_mode_minimal: "低スペック PC 向け最小（レーザースキャン）",
+                usage_statistics_title: "使用状況",
+                usage_statistics_tooltip: "正確なデータを得るには、モデルを一度以上使用してください",
+                usage_model_column: "モデル",
+                usage_remaining_column: "残り / 合計",
+                usage_check_link: "使用量を確認 ↗",
+                usage_tokens_by_preset_title: "プリセット別トークン（今月）",
+                usage_tokens_empty: "今月の使用記録はまだありません。",
+                usage_preset_column: "プリセット",
+                usage_tokens_column: "トークン",
+
+                footer_admin_text: "ゲームを翻訳するには管理者として実行",
+                footer_version: "バージョン",
+                check_for_updates_btn: "更新を確認",
+                current_version_label: "現在のバージョン:",
+                checking_github: "GitHub を確認中...",
+                up_to_date: "最新の状態です",
+                check_again_btn: "再確認",
+                new_version_available: "新しいバージョンがあります:",
+                release_notes_label: "リリースノート",
+                download_update_btn: "今すぐダウンロードして更新",
+                downloading_update: "ダウンロード中... アプリはその場で更新されます。",
+                update_failed: "更新に失敗しました:",
+                app_folder_writable_hint: "アプリのフォルダーが書き込み可能か確認してください。",
+                retry_btn: "再試行",
+                update_success: "更新に成功しました！",
+                restart_to_use_new_version: "再起動すると新しいバージョンが使えます。",
+                restart_app_btn: "アプリを再起動",
+                // --- NEW TEXT INPUT FIELDS JA ---
+                text_input_mode_label: "モード:",
+                text_mode_select: "ホットキー後にテキストを選択",
+                text_mode_type: "ホットキー後に入力",
+                text_mode_clipboard: "ホットキーでクリップボードを処理",
+                clipboard_empty_error: "クリップボードにテキストがありません",
+                continuous_input_label: "連続入力",
+                command_mode_label: "コマンド:",
+                text_input_title_default: "処理するテキストを入力:",
+                text_input_placeholder: "処理する内容...",
+                text_input_footer_submit: "Enter で送信",
+                text_input_footer_newline: "Shift+Enter で改行",
+                text_input_footer_cancel: "でキャンセル",
+                add_text_preset_btn: "+ テキスト",
+                add_image_preset_btn: "+ 画像",
+                add_audio_preset_btn: "+ 音声",
+                // --- PROCESSING CHAIN UI JA ---
+                node_input_prefix: "入力:",
+                node_input_audio: "音声",
+                node_input_image: "画像",
+                node_input_text: "テキスト",
+                node_process_title: "テキスト -> テキスト",
+                node_special_default: "特殊ノード",
+                node_special_image_to_text: "画像 -> テキスト",
+                node_special_audio_to_text: "音声 -> テキスト",
+                node_menu_add_normal: "➕ テキスト -> テキスト ノードを追加",
+                node_menu_add_special_generic: "⭐ 特殊ノードを追加",
+                node_menu_add_special_image: "⭐ 画像 -> テキスト ノードを追加",
+                node_menu_add_special_audio: "⭐ 音声 -> テキスト ノードを追加",
+                input_auto_copy_tooltip: "自動コピー（原文）",
+                input_auto_speak_tooltip: "原文を読み上げ",
+
+
+                tips_title: "使い方のヒント",
+                tips_click_hint: "クリックしてヒント一覧を表示",
+                tips_list: vec![
+                    "オーバーレイを**中クリック**すると、すべてのオーバーレイを一度に閉じます！",
+                    "オーバーレイを**右クリック**すると、テキストをすばやくコピーできます！",
+                    "範囲を描いている間（画面が暗い状態）、**ESC** かホットキーをもう一度押すとキャンセルできます。",
+                    "**SGT** を気に入ったら、GitHub でスターを付けてシェアしてください！",
+                    "**ホットキー**には中ボタン、ボタン 4、ボタン 5 も割り当てられます！",
+                    "オーバーレイが**落ちる/重い**場合は、グラフィックスモードを「最小」に切り替えてみてください！",
+                    "既定のプリセットは名前を変更できませんが、**カスタムプリセット**は自由に変更できます！",
+                    "**履歴ライブラリ**は上限に達すると古い項目を自動で削除します。",
+                    "処理チェーンで自動コピーを有効にできるのは**1 ステップ**だけ（またはなし）です。",
+                    "**自動貼り付け**が有効でも、貼り付け先にテキストカーソル（点滅）がないと動作しません。",
+                    "**自動改行**の設定は、自動コピーが（少なくとも 1 ステップで）有効なときだけ表示されます。",
+                    "プリセット名の背景が**緑/黄緑**なら、ホットキーが割り当て済みですぐに使えます。",
+                    "左ドラッグは**現在のオーバーレイ**だけを動かし、右ドラッグは同じグループのオーバーレイをまとめて動かします。",
+                    "**キャンバス**では、スクロール: ズーム、ドラッグ: 移動、ダブルクリック: 表示リセット、右クリック: ノード追加。ノード上では、ドラッグ: 移動、右クリック: 削除。",
+                    "テキスト選択モードの特別機能: **テキストがすでに選択されている**場合、ホットキーを押すとすぐに処理します！",
+                    "**音声録音**にはスマート停止モードもあります。ぜひ試してください！",
+                    "オーバーレイで直接**ウェブ**を閲覧するときはマウスのみ使えます。キーボード入力は使えません、ごめんなさい！",
+                    "**「Image MASTER」**や**「Selection MASTER」**にホットキーを割り当てるとホイールが開き、1 つのキーで多くのツールにすばやくアクセスできます！",
+                    "**編集ボタン**はテキストの修正だけでなく、AI への指示（例: 「日本語に翻訳」）を入力して内容を書き直すこともできます。",
+                    "画像ファイルやテキストファイルを設定ウィンドウに**ドラッグ＆ドロップ**または貼り付けると、すぐに処理できます！",
+                    "システムトレイのメニューで**お気に入りバブル**を有効にすると、ホットキーを覚えなくても設定にすばやくアクセスできます。",
+                    "音声設定で**標準音声**を試し、地域のアクセントを調整して自分に合うものを見つけてください！",
+                    "**リアルタイム翻訳**モードは、話者のペースに合わせて読み上げ速度（TTS）を自動調整できます。",
+                    "**表示モードボタン**（目のアイコンの隣）をクリックすると、プレーンテキストと整形された Markdown/HTML 表示を切り替えられます。",
+                    "**虫眼鏡アイコン**付きのモデルはインターネットにアクセスして、引用や最新情報を調べられます。",
+                    "**自動コピー**が有効になると、画面下部に小さな緑の通知が表示され、クリップボードへのコピーを確認できます。",
+                    "SGT の使い方がわからない？**「SGT の使い方を質問...」**を開いて AI アシスタントに聞いてみましょう！",
+                    "疲れたら**「Chill Corner」**で、作業中にリラックスできる BGM を作ってみましょう。",
+                    "動画/音声をダウンロードしたい？内蔵の**ユニバーサルダウンローダー**は YouTube や Facebook などに対応し、4K や字幕もサポートします！",
+                    "**ホットキー**やバブルのプリセットを**長押し**すると**連続モード**になり、複数の範囲やテキストを続けて処理できます。",
+                    "MIDI キーボードをお持ちですか？**Chill Corner** を開くときに接続すると、実際のノブで音楽を調整できます！",
+                     ],
+                   restore_preset_btn: "復元",
+                   restore_preset_tooltip: "プリセットを初期設定に戻す",
+                   // --- COMPOUND SEARCH UI JA ---
+                   search_doing: "実行中",
+                   search_searching: "検索中",
+                   search_query_label: "📝 検索クエリ:",
+                   search_found_sources: "📚 {} 件のソースが見つかりました",
+                   search_sources_label: "🌐 参照ソース（関連度順）:",
+                   search_no_title: "（タイトルなし）",
+                   search_synthesizing: "⚡ 情報をまとめています...",
+                   search_analyzed_sources: "📊 {} 件のソースを分析しました",
+                   search_processing: "🧠 結果を処理・要約しています...",
+                   // --- MASTER PRESET UI JA ---
+                   controller_checkbox_label: "コントローラー",
+
+                   // --- GLOBAL SETTINGS UI HEADERS JA ---
+                   api_keys_header: "🔑 API キー",
+                   groq_label: "Groq API キー:",
+                   software_update_header: "⬆ ソフトウェア更新",
+                   startup_display_header: "⚙ 起動と表示",
+                   model_thinking: "💭 考え中...",
+                // --- REALTIME OVERLAY JA ---
+                realtime_listening: "聞き取り中...",
+                realtime_device: "デバイス",
+                realtime_waiting: "音声を待っています...",
+                realtime_translation: "翻訳",
+                realtime_mic: "マイク",
+                realtime_audio_device_tooltip: "オーディオデバイス",
+                realtime_target_language_tooltip: "翻訳先の言語",
+                realtime_gemini_live_tooltip: "Gemini Live (クラウド)",
+                realtime_parakeet_tooltip: "Parakeet (ローカル)",
+                realtime_gemma_tooltip: "AI 翻訳 (Gemma)",
+                realtime_cerebras_tooltip: "高速 AI (Cerebras)",
+                realtime_gtx_tooltip: "無制限翻訳 (Google)",
+                realtime_header_toggle_tooltip: "ヘッダーの表示/非表示",
+                realtime_cancel_download_tooltip: "ダウンロードを中止して Gemini Live に戻る",
+                realtime_auto_speed_tooltip: "追いつくように速度を自動調整",
+                realtime_app_list_loading: "読み込み中...",
+                ollama_url_guide: "ollama.com でガイドを見る",
+                tts_settings_button: "音声設定",
+                tts_settings_title: "TTS 設定",
+                tts_method_label: "TTS 方式:",
+                tts_method_standard: "標準 (Gemini Live)",
+                tts_method_fast: "高速 (Google 翻訳)",
+                tts_method_edge: "Edge TTS",
+                tts_google_translate_title: "Google 翻訳 TTS",
+                tts_google_translate_desc: "この方式は高速で、API キーは不要です。",
+                tts_edge_title: "Microsoft Edge TTS",
+                tts_edge_desc: "高品質なニューラル音声。無料で API キーは不要です。",
+                tts_pitch_label: "ピッチ:",
+                tts_rate_label: "速度:",
+                tts_voice_per_language_label: "言語ごとの音声:",
+                tts_loading_voices: "音声リストを読み込み中...",
+                tts_failed_load_voices: "音声の読み込みに失敗しました: {}",
+                tts_retry_label: "再試行",
+                tts_initializing_voices: "音声リストを初期化中...",
+                tts_add_language_label: "+ 音声設定を追加",
+                tts_reset_to_defaults_label: "初期設定に戻す",
+                tts_speed_label: "読み上げ速度:",
+                tts_speed_normal: "標準",
+                tts_speed_slow: "遅い",
+                tts_speed_fast: "速い",
+                _tts_voice_label: "音声:",
+                tts_preview_texts: vec![
+                    "こんにちは、{} です。このテキストを読み上げる準備ができました。",
+                    "素早い茶色の狐がのろまな犬を飛び越える。",
+                    "今日はいい天気ですね。{} は、あなたが何か新しいことを学べるよう願っています。",
+                    "テクノロジーは私たちの暮らす世界を急速に変えています。",
+                    "素敵な一日をお過ごしください。{} より。",
+                    "これは {} の合成音声のデモンストレーションです。",
+                    "ときどき休憩して、目を休めるのを忘れずに。",
+                    "成功とは、日々繰り返される小さな努力の積み重ねです。",
+                    "好奇心を忘れずに、{} と一緒に探求し続けましょう。",
+                    "Screen Goated Toolbox をご利用いただきありがとうございます。",
+                ],
+                tts_male: "男性",
+                tts_female: "女性",
+                tts_instructions_label: "言語ごとのアクセント:",
+                tts_instructions_hint: "例: 関西弁で話す",
+                tts_add_condition: "+ 条件を追加...",
+                // Realtime TTS modal
+                realtime_tts_title: "翻訳を読み上げ",
+                realtime_tts_speed: "速度",
+                realtime_tts_auto: "自動",
+                // App selection modal
+                app_select_title: "キャプチャするアプリを選択",
+                app_select_hint: "文字起こしする音声のアプリを選んでください（TTS は分離されます）",
+                // --- TRAY MENU JA ---
+                tray_settings: "⚙️ 設定",
+                tray_quit: "終了",
+                tray_favorite_bubble: "お気に入りバブルを表示",
+                tray_favorite_bubble_disabled: "お気に入りバブルを表示（お気に入りが未設定）",
+                // --- FAVORITE BUBBLE JA ---
+                 favorites_empty: "お気に入りに設定を 1 つ以上追加してください",
+                 favorites_empty_hint: "設定でプリセット横の ☆ をクリックするとここに追加されます。ここをクリックすると設定を開きます。",
+                 favorites_keep_open: "開いたままにする",
+                 favorites_snap_to_edge: "画面の端に吸着",
+                 favorites_hide_in_fullscreen: "全画面アプリの使用中は隠す",
+                 recording_subtext: "ESC/ホットキーで停止",
+                 recording_paused: "一時停止中",
+                 // --- AUTO COPY BADGE JA ---
+                 auto_copied_badge: "自動コピー済み",
+                 auto_copied_image_badge: "🖼️ 画像をコピーしました",
+                 live_translate_loading: "⏳ ライブ翻訳を読み込み中...",
+                 text_input_loading: "⏳ テキスト入力を読み込み中...",
+                 recording_loading: "⏳ 録音を読み込み中...",
+                 screen_record_loading: "⏳ 画面録画を読み込み中...",
+                 markdown_view_loading: "⏳ 結果ビューアーを読み込み中...",
+                 preset_wheel_loading: "⏳ プリセット選択を読み込み中...",
+                 prompt_dj_loading: "⏳ Chill Corner を読み込み中...",
+                 tray_popup_loading: "⏳ メニューを読み込み中...",
+                 update_available_notification: "🎉 新しい更新があります！",
+                 cannot_type_no_caret: "自動貼り付け/入力が有効ですが、テキスト入力欄が選択されていません！",
+                 // --- DROP OVERLAY JA ---
+                 drop_overlay_text: "ここにドロップして処理",
+                 // --- REALTIME EGUI SPECIFIC JA ---
+                 device_mode_warning: "⚠ デバイス音声が選択されていますが、アプリが未選択です",
+                 select_app_btn: "アプリを選択",
+                 toggle_translation_tooltip: "翻訳の切り替え",
+                 toggle_transcription_tooltip: "文字起こしの切り替え",
+                 font_minus_tooltip: "文字 -",
+                 font_plus_tooltip: "文字 +",
+                 google_gtx_label: "Google 翻訳",
+                 opacity_label: "不透明度",
+                 downloaded_successfully: "ダウンロードしました！",
+                 download_recording_tooltip: "録音をダウンロード",
+                 // --- HELP ASSISTANT JA ---
+                 help_assistant_btn: "SGT の使い方を質問...",
+                 help_assistant_title: "SGT について質問",
+                 help_assistant_question_label: "質問:",
+                 help_assistant_placeholder: "例: 画面の一部を翻訳するには？",
+                 help_assistant_ask_btn: "質問",
+                 help_assistant_loading: "回答を探しています...",
+                 help_assistant_answer_label: "回答:",
+                 help_assistant_hint: "SGT の使い方について質問を入力し、Enter を押すか「質問」をクリックしてください",
+                  prompt_dj_btn: "Chill Corner",
+                  prompt_dj_title: "PromptDJ - Chill Corner",
+                  screen_record_btn: "画面録画",
+                  screen_record_title: "画面録画",
+                  // --- PARAKEET DOWNLOAD MODAL JA ---
+                  parakeet_downloading_title: "Parakeet をダウンロード中 (0.6 GB)",
+                  parakeet_downloading_message: "お待ちください...",
+                  parakeet_downloading_file: "{} をダウンロード中...",
+                   parakeet_supports_english_only: "（英語のみ対応）",
+                   overlay_copy_tooltip: "コピー",
+                   overlay_retry_tooltip: "再試行",
+                   overlay_undo_tooltip: "元に戻す",
+                   overlay_redo_tooltip: "やり直し",
+                   overlay_edit_tooltip: "編集 / 修正",
+                   overlay_refine_placeholder: "結果を修正...",
+                   overlay_markdown_tooltip: "Markdown 切り替え",
+                   overlay_download_tooltip: "HTML を保存",
+                   overlay_speaker_tooltip: "読み上げ (TTS)",
+                   overlay_broom_tooltip: "ほうき: 左 - 閉じる | 右 - グループを閉じる | 中 - すべて閉じる | ドラッグ - 移動 | 右ドラッグ - グループを移動 | 中ドラッグ - すべて移動",
+                   overlay_back_tooltip: "戻る",
+                   overlay_forward_tooltip: "進む",
+                   overlay_opacity_tooltip: "不透明度",
+                   
+                   download_feature_btn: "どこからでも動画/音声をダウンロード",
+                   download_feature_title: "どこからでも動画/音声をダウンロード",
+                   download_delete_deps_btn: "yt-dlp ({}) と ffmpeg ({}) を削除",
+                   download_url_label: "URL:",
+                   download_format_label: "形式:",
+                   download_start_btn: "ダウンロード開始",
+                   download_open_file_btn: "ファイルを開く",
+                   download_open_folder_btn: "フォルダーを開く",
+                   download_status_starting: "開始中...",
+                   download_status_finished: "ダウンロード完了！",
+                   download_status_error: "エラー:",
+                   download_deps_missing: "外部ツールが必要です（後で削除できます）:",
+                   download_deps_ytdlp: "yt-dlp:",
+                   download_deps_ffmpeg: "ffmpeg:",
+                   download_deps_download_btn: "ダウンロード",
+                   download_status_ready: "✅ 準備完了",
+                   download_status_extracting: "展開中...",
+                   download_cancel_btn: "キャンセル",
+                   download_file_label: "ファイル:",
+                   download_size_label: "サイズ:",
+                   download_change_folder_btn: "フォルダーを変更...",
+
+                   download_progress_info_fmt: "{}% / {}、速度 {}、残り {}",
+                   download_advanced_header: "高度な機能",
+                   download_opt_metadata: "完全なメタデータ（サムネイル、チャプター、情報）",
+                   download_opt_sponsorblock: "SponsorBlock（スポンサー/イントロを除去）",
+                   download_opt_subtitles: "字幕をダウンロード（埋め込み/SRT）",
+                   download_opt_playlist: "プレイリストを処理",
+                   download_opt_cookies: "ブラウザーの Cookie を使用（プレミアム/年齢制限用）:",
+                   download_scan_ignore_btn: "今すぐダウンロード（画質スキャンを省略）",
+                   download_quality_label_text: "画質:",
+                   download_quality_best: "最高",
+                   download_scanning_label: "（スキャン中...）",
+                   download_no_cookie_option: "Cookie を使用しない",
+                   download_show_log_btn: "エラーログを表示",
+                   download_hide_log_btn: "エラーログを隠す",
+                    download_subtitle_label: "字幕:",
+                    download_subtitle_auto: "自動",
+                    download_subs_found_header: "見つかった言語",
+                    download_subs_none_found: "字幕が見つかりません",
+
+                   downloaded_tools_button: "ダウンロード済みツール",
+                   downloaded_tools_title: "ダウンロード済みツールの管理",
+                   tool_parakeet: "Parakeet リアルタイムモデル",
+                   tool_ytdlp: "yt-dlp ツール",
+                   tool_ffmpeg: "ffmpeg ツール",
+                   tool_status_installed: "インストール済み ({})",
+                   tool_status_missing: "未インストール",
+                   tool_action_download: "ダウンロード",
+                   tool_action_delete: "削除",
+                   tool_desc_parakeet: "キャビン翻訳と、音声->テキスト ノードの「オフライン ストリーム」モデルで使用",
+                   tool_desc_ytdlp: "メディアダウンロード機能で使用",
+                   tool_desc_ffmpeg: "メディアダウンロード機能で使用",
+                   tool_update_checking: "確認中...",
+                   tool_update_latest: "最新",
+                   tool_update_check_again: "再確認",
+                   tool_update_error: "エラー",
+                   tool_update_retry: "再試行",
+                   tool_update_check_btn: "更新を確認",
+                   tool_update_available: "更新 ({})",
+                   webview2_missing: "Microsoft Edge WebView2 ランタイムがインストールされていません。\n\n結果ウィンドウはプレーンテキストのみになり、画面録画、Prompt DJ、リアルタイム字幕などの HTML ウィンドウは無効になります。\n\nダウンロードページを開きますか？",
+                   webview2_too_old: "Microsoft Edge WebView2 ランタイムが古すぎます。\n\n結果ウィンドウはプレーンテキストのみになり、画面録画、Prompt DJ、リアルタイム字幕などの HTML ウィンドウは無効になります。\n\n更新のためにダウンロードページを開きますか？",
+                   continuous_mode_activated: "✨ プリセット「{preset}」が連続実行されます。ESC または {hotkey} で終了",
+                  },
                 _ => Self {
                  history_btn: "History",
                  history_title: "Result Library",
                  max_items_label: "Max Items:",
                  history_empty: "No history yet.",
+                 history_incomplete: "Incomplete",
+                 history_incomplete_tooltip: "This result never finished streaming: it was interrupted, or is still arriving. What came in so far is kept.",
                  clear_all_history_btn: "Clear All",
                  view_image_btn: "View Image",
                  listen_audio_btn: "Listen Audio",
+                 reopen_result_btn: "Reopen",
                  view_text_btn: "View Text",
 
                  prompt_mode_fixed: "Predefined Prompt",
@@ -1056,6 +1713,53 @@ impl LocaleText {
                 cerebras_api_key_label: "Cerebras API Key:",
                 cerebras_get_key_link: "Get API Key at cloud.cerebras.ai",
                  use_cerebras_checkbox: "Cerebras",
+                 anthropic_api_key_label: "Anthropic API Key:",
+                 anthropic_get_key_link: "Get API Key at console.anthropic.com",
+                 use_anthropic_checkbox: "Claude",
+                 use_custom_openai_checkbox: "OpenAI-compatible",
+                 custom_openai_base_url_label: "OpenAI-compatible Base URL:",
+                 custom_openai_api_key_label: "API Key (leave empty if the server has no auth):",
+                 api_max_attempts_label: "Max attempts on rate limit (429/5xx):",
+                 api_max_concurrent_label: "Concurrent requests per provider (0 = unlimited):",
+                 skip_rate_limited_models_label: "Use a fallback model while the chosen one is rate-limited",
+                 api_timeouts_label: "Timeouts:",
+                 api_connect_timeout_label: "Connect",
+                 api_stall_timeout_label: "Between chunks",
+                 active_window_capture_header: "Active Window Capture",
+                 active_window_capture_desc: "Hotkeys that instantly capture the focused window's content and process it, skipping region selection.",
+                 active_window_capture_preset_label: "Preset:",
+                 realtime_stop_hotkey_header: "Stop Realtime & Save Transcript",
+                 realtime_stop_hotkey_desc: "Hotkeys that stop realtime mode and save the accumulated transcript (and translation) to a timestamped text file.",
+                 realtime_reopen_session_btn: "Reopen Last Session",
+                 selection_history_hotkey_header: "Recent Selections",
+                 selection_history_hotkey_desc: "Hotkeys that cycle back through recently selected-and-translated text (up to 20) without re-selecting it.",
+                 repeat_last_hotkey_header: "Repeat Last",
+                 repeat_last_hotkey_desc: "Hotkeys that run the last image or text translation again with the same input and preset, placing the result where it was before.",
+                 overlay_opacity_header: "Overlay Opacity",
+                 overlay_opacity_desc: "Initial opacity of result windows and overlay backgrounds. Higher reads better over busy screens, lower keeps what is behind visible.",
+                 text_animation_header: "Text Wipe-in Animation",
+                 text_animation_desc: "The wipe effect as new text appears in the realtime overlays. Turn it off or speed it up if it gets distracting.",
+                 text_animation_enabled: "Animate new text",
+                 paste_method_header: "Auto-Paste Method",
+                 paste_method_desc: "Some terminals and games ignore Ctrl+V. Try Shift+Insert, or typing the characters.",
+                 paste_method_type: "Type characters",
+                 paste_key_delay_label: "Delay between keys:",
+                 result_right_click_header: "Right-click on a result window",
+                 result_right_click_desc: "The copy button in the corner always copies the result.",
+                 result_right_click_close: "Close",
+                 result_right_click_menu: "Show menu",
+                 result_right_click_copy: "Copy",
+                 retranslation_layout_label: "Next result window goes:",
+                 retranslation_layout_auto: "Auto",
+                 retranslation_layout_horizontal: "Side by side",
+                 retranslation_layout_vertical: "Stacked",
+                 config_backup_header: "Settings Backup",
+                 export_config_button: "📤 Export settings",
+                 import_config_button: "📥 Import settings",
+                 export_include_api_keys: "Include API keys",
+                 config_exported_msg: "Settings exported to",
+                 config_imported_msg: "Settings imported successfully.",
+                 config_backup_failed_msg: "Failed:",
                 global_settings: "Global Settings",
                  preset_name_label: "Preset Name:",
 
@@ -1101,6 +1805,10 @@ impl LocaleText {
                 usage_model_column: "Model",
                 usage_remaining_column: "Remaining / Total",
                 usage_check_link: "Check Usage ↗",
+                usage_tokens_by_preset_title: "Tokens by preset (this month)",
+                usage_tokens_empty: "No usage recorded this month yet.",
+                usage_preset_column: "Preset",
+                usage_tokens_column: "Tokens",
 
                 footer_admin_text: "Run with admin to translate games",
                 footer_version: "Version",
@@ -1123,6 +1831,8 @@ impl LocaleText {
                 text_input_mode_label: "Mode:",
                 text_mode_select: "Hotkey then Select Text",
                 text_mode_type: "Hotkey then Type",
+                text_mode_clipboard: "Hotkey uses Clipboard",
+                clipboard_empty_error: "Clipboard has no text",
                 continuous_input_label: "Continuous Input",
                 command_mode_label: "Command:",
                 text_input_title_default: "Enter text to process:",
@@ -1212,6 +1922,17 @@ impl LocaleText {
                 realtime_waiting: "Waiting for speech...",
                 realtime_translation: "Translation",
                 realtime_mic: "Mic",
+                realtime_audio_device_tooltip: "Audio Device",
+                realtime_target_language_tooltip: "Target Language",
+                realtime_gemini_live_tooltip: "Gemini Live (Cloud)",
+                realtime_parakeet_tooltip: "Parakeet (Local)",
+                realtime_gemma_tooltip: "AI Translation (Gemma)",
+                realtime_cerebras_tooltip: "Instant AI (Cerebras)",
+                realtime_gtx_tooltip: "Unlimited Translation (Google)",
+                realtime_header_toggle_tooltip: "Toggle header",
+                realtime_cancel_download_tooltip: "Cancel download and return to Gemini Live",
+                realtime_auto_speed_tooltip: "Auto-adjust speed to catch up",
+                realtime_app_list_loading: "Loading...",
                 ollama_url_guide: "View guide at ollama.com",
                 tts_settings_button: "Voice Settings",
                 tts_settings_title: "TTS Settings",
@@ -1268,7 +1989,10 @@ impl LocaleText {
                 tray_favorite_bubble_disabled: "Show favorite bubble (No favorites set yet)",
                 // --- FAVORITE BUBBLE EN ---
                  favorites_empty: "Please add at least one configuration to favorites",
+                 favorites_empty_hint: "In settings, click the ☆ star next to a preset to pin it here. Click here to open settings.",
                  favorites_keep_open: "Keep Open",
+                 favorites_snap_to_edge: "Snap to screen edge",
+                 favorites_hide_in_fullscreen: "Hide while a fullscreen app is active",
                  recording_subtext: "Press ESC/Hotkey to stop",
                  recording_paused: "Paused",
                  // --- AUTO COPY BADGE EN ---
@@ -1277,6 +2001,7 @@ impl LocaleText {
                  live_translate_loading: "⏳ Loading Live Translate...",
                  text_input_loading: "⏳ Loading text input...",
                  recording_loading: "⏳ Loading recording...",
+                 screen_record_loading: "⏳ Starting recorder...",
                  markdown_view_loading: "⏳ Loading result viewer...",
                  preset_wheel_loading: "⏳ Loading preset selector...",
                  prompt_dj_loading: "⏳ Loading Chill Corner...",
@@ -1315,6 +2040,7 @@ impl LocaleText {
                   parakeet_downloading_file: "Downloading {}...",
                    parakeet_supports_english_only: "(Only supports English)",
                    overlay_copy_tooltip: "Copy",
+                   overlay_retry_tooltip: "Retry",
                    overlay_undo_tooltip: "Undo",
                    overlay_redo_tooltip: "Redo",
                    overlay_edit_tooltip: "Edit / Refine",
@@ -1387,6 +2113,8 @@ impl LocaleText {
                    tool_update_retry: "Retry",
                    tool_update_check_btn: "Check Update",
                    tool_update_available: "Update ({})",
+                   webview2_missing: "The Microsoft Edge WebView2 Runtime is not installed.\n\nResult windows will show plain text only; screen recording, Prompt DJ, live captions and other HTML windows are turned off.\n\nOpen the download page?",
+                   webview2_too_old: "The Microsoft Edge WebView2 Runtime is too old.\n\nResult windows will show plain text only; screen recording, Prompt DJ, live captions and other HTML windows are turned off.\n\nOpen the download page to update it?",
                    continuous_mode_activated: "✨ Preset \"{preset}\" will run continuously. Press ESC or {hotkey} to exit",
                   },
                 }
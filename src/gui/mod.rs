@@ -7,6 +7,7 @@ pub mod splash;
 pub mod utils;
 
 pub use app::SettingsApp;
+pub use app::signal_presets_changed;
 pub use app::signal_restore_window;
 pub use utils::configure_fonts;
 
@@ -27,6 +27,8 @@ pub fn render_global_settings(
     show_gemini_api_key: &mut bool,
     show_openrouter_api_key: &mut bool,
     show_cerebras_api_key: &mut bool,
+    show_anthropic_api_key: &mut bool,
+    show_custom_openai_api_key: &mut bool,
     usage_stats: &HashMap<String, String>,
     updater: &Option<Updater>,
     update_status: &UpdateStatus,
@@ -41,6 +43,11 @@ pub fn render_global_settings(
     download_manager: &mut DownloadManager,
     _cached_audio_devices: &std::sync::Arc<std::sync::Mutex<Vec<(String, String)>>>,
     _recording_sr_hotkey: &mut bool,
+    recording_active_window_hotkey: &mut bool,
+    recording_realtime_stop_hotkey: &mut bool,
+    recording_selection_history_hotkey: &mut bool,
+    recording_repeat_last_hotkey: &mut bool,
+    hotkey_conflict_msg: &Option<String>,
 ) -> bool {
     let mut changed = false;
 
@@ -98,6 +105,21 @@ pub fn render_global_settings(
                 {
                     changed = true;
                 }
+                if ui
+                    .checkbox(&mut config.use_anthropic, text.use_anthropic_checkbox)
+                    .changed()
+                {
+                    changed = true;
+                }
+                if ui
+                    .checkbox(
+                        &mut config.use_custom_openai,
+                        text.use_custom_openai_checkbox,
+                    )
+                    .changed()
+                {
+                    changed = true;
+                }
                 if ui.checkbox(&mut config.use_ollama, "Ollama").changed() {
                     changed = true;
                 }
@@ -228,6 +250,77 @@ pub fn render_global_settings(
                 });
             }
 
+            // Anthropic API Key (only show if enabled)
+            if config.use_anthropic {
+                ui.horizontal(|ui| {
+                    ui.label(text.anthropic_api_key_label);
+                    if ui.link(text.anthropic_get_key_link).clicked() {
+                        let _ = open::that("https://console.anthropic.com/settings/keys");
+                    }
+                });
+                ui.horizontal(|ui| {
+                    if ui
+                        .add(
+                            egui::TextEdit::singleline(&mut config.anthropic_api_key)
+                                .id(egui::Id::new("settings_api_key_anthropic"))
+                                .password(!*show_anthropic_api_key)
+                                .desired_width(API_KEY_FIELD_WIDTH),
+                        )
+                        .changed()
+                    {
+                        changed = true;
+                    }
+                    let eye_icon = if *show_anthropic_api_key {
+                        Icon::EyeOpen
+                    } else {
+                        Icon::EyeClosed
+                    };
+                    if icon_button(ui, eye_icon).clicked() {
+                        *show_anthropic_api_key = !*show_anthropic_api_key;
+                    }
+                });
+            }
+
+            // OpenAI-compatible endpoint (only show if enabled)
+            if config.use_custom_openai {
+                ui.label(text.custom_openai_base_url_label);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add(
+                            egui::TextEdit::singleline(&mut config.custom_openai_base_url)
+                                .id(egui::Id::new("settings_custom_openai_base_url"))
+                                .hint_text("http://localhost:8000/v1")
+                                .desired_width(API_KEY_FIELD_WIDTH),
+                        )
+                        .changed()
+                    {
+                        changed = true;
+                    }
+                });
+                ui.label(text.custom_openai_api_key_label);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add(
+                            egui::TextEdit::singleline(&mut config.custom_openai_api_key)
+                                .id(egui::Id::new("settings_api_key_custom_openai"))
+                                .password(!*show_custom_openai_api_key)
+                                .desired_width(API_KEY_FIELD_WIDTH),
+                        )
+                        .changed()
+                    {
+                        changed = true;
+                    }
+                    let eye_icon = if *show_custom_openai_api_key {
+                        Icon::EyeOpen
+                    } else {
+                        Icon::EyeClosed
+                    };
+                    if icon_button(ui, eye_icon).clicked() {
+                        *show_custom_openai_api_key = !*show_custom_openai_api_key;
+                    }
+                });
+            }
+
             // Ollama (Local AI) - only show URL field if enabled
             if config.use_ollama {
                 ui.horizontal(|ui| {
@@ -256,6 +349,445 @@ pub fn render_global_settings(
                     }
                 });
             }
+
+            // Retries for rate-limited (429) / overloaded (5xx) requests
+            ui.horizontal(|ui| {
+                ui.label(text.api_max_attempts_label);
+                if ui
+                    .add(egui::DragValue::new(&mut config.api_max_attempts).range(1..=10))
+                    .changed()
+                {
+                    changed = true;
+                }
+            });
+
+            // Requests allowed in flight at once per provider (0 = unlimited)
+            ui.horizontal(|ui| {
+                ui.label(text.api_max_concurrent_label);
+                if ui
+                    .add(egui::DragValue::new(&mut config.api_max_concurrent).range(0..=32))
+                    .changed()
+                {
+                    changed = true;
+                }
+                if config.use_ollama {
+                    ui.label("Ollama:");
+                    if ui
+                        .add(egui::DragValue::new(&mut config.ollama_max_concurrent).range(0..=32))
+                        .changed()
+                    {
+                        changed = true;
+                    }
+                }
+            });
+
+            if ui
+                .checkbox(
+                    &mut config.skip_rate_limited_models,
+                    text.skip_rate_limited_models_label,
+                )
+                .changed()
+            {
+                changed = true;
+            }
+
+            // Connect / stall timeouts (seconds)
+            ui.horizontal(|ui| {
+                ui.label(text.api_timeouts_label);
+                ui.label(text.api_connect_timeout_label);
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut config.api_connect_timeout_secs)
+                            .range(1..=120)
+                            .suffix(" s"),
+                    )
+                    .changed()
+                {
+                    changed = true;
+                }
+                ui.label(text.api_stall_timeout_label);
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut config.api_stall_timeout_secs)
+                            .range(5..=600)
+                            .suffix(" s"),
+                    )
+                    .changed()
+                {
+                    changed = true;
+                }
+                if config.use_ollama {
+                    ui.label("Ollama:");
+                    if ui
+                        .add(
+                            egui::DragValue::new(&mut config.ollama_stall_timeout_secs)
+                                .range(5..=1800)
+                                .suffix(" s"),
+                        )
+                        .changed()
+                    {
+                        changed = true;
+                    }
+                }
+            });
+        });
+
+    ui.add_space(10.0);
+
+    // === ACTIVE WINDOW CAPTURE CARD ===
+    egui::Frame::new()
+        .fill(card_bg)
+        .stroke(card_stroke)
+        .inner_margin(12.0)
+        .corner_radius(10.0)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(text.active_window_capture_header)
+                    .strong()
+                    .size(14.0),
+            );
+            ui.label(
+                egui::RichText::new(text.active_window_capture_desc)
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(6.0);
+
+            ui.horizontal(|ui| {
+                ui.label(text.active_window_capture_preset_label);
+                let selected_name = crate::gui::settings_ui::get_localized_preset_name(
+                    &config.active_window_capture_preset_id,
+                    &config.ui_language,
+                );
+                egui::ComboBox::from_id_salt("active_window_capture_preset")
+                    .selected_text(selected_name)
+                    .show_ui(ui, |ui| {
+                        for preset in config.presets.iter().filter(|p| p.preset_type == "image") {
+                            let name = crate::gui::settings_ui::get_localized_preset_name(
+                                &preset.id,
+                                &config.ui_language,
+                            );
+                            if ui
+                                .selectable_label(
+                                    config.active_window_capture_preset_id == preset.id,
+                                    name,
+                                )
+                                .clicked()
+                            {
+                                config.active_window_capture_preset_id = preset.id.clone();
+                                changed = true;
+                            }
+                        }
+                    });
+            });
+
+            if render_hotkey_list(
+                ui,
+                text,
+                &mut config.active_window_capture_hotkeys,
+                recording_active_window_hotkey,
+                hotkey_conflict_msg,
+            ) {
+                changed = true;
+            }
+
+            // Bindings shared by several presets/global hotkeys only fire one of them
+            for conflict in config.find_hotkey_conflicts() {
+                ui.colored_label(
+                    egui::Color32::from_rgb(200, 80, 80),
+                    format!("⚠ {}", conflict),
+                );
+            }
+        });
+
+    ui.add_space(10.0);
+
+    // === REALTIME STOP CARD ===
+    egui::Frame::new()
+        .fill(card_bg)
+        .stroke(card_stroke)
+        .inner_margin(12.0)
+        .corner_radius(10.0)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(text.realtime_stop_hotkey_header)
+                    .strong()
+                    .size(14.0),
+            );
+            ui.label(
+                egui::RichText::new(text.realtime_stop_hotkey_desc)
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(6.0);
+
+            if render_hotkey_list(
+                ui,
+                text,
+                &mut config.realtime_stop_hotkeys,
+                recording_realtime_stop_hotkey,
+                hotkey_conflict_msg,
+            ) {
+                changed = true;
+            }
+
+            // Captions of the last session, saved whenever realtime mode stops
+            ui.add_space(6.0);
+            let has_session = crate::overlay::realtime_webview::session::has_last_session();
+            if ui
+                .add_enabled(
+                    has_session,
+                    egui::Button::new(text.realtime_reopen_session_btn),
+                )
+                .clicked()
+            {
+                crate::overlay::realtime_webview::session::reopen_last_session();
+            }
+        });
+
+    ui.add_space(10.0);
+
+    // === RECENT SELECTIONS CARD ===
+    egui::Frame::new()
+        .fill(card_bg)
+        .stroke(card_stroke)
+        .inner_margin(12.0)
+        .corner_radius(10.0)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(text.selection_history_hotkey_header)
+                    .strong()
+                    .size(14.0),
+            );
+            ui.label(
+                egui::RichText::new(text.selection_history_hotkey_desc)
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(6.0);
+
+            if render_hotkey_list(
+                ui,
+                text,
+                &mut config.selection_history_hotkeys,
+                recording_selection_history_hotkey,
+                hotkey_conflict_msg,
+            ) {
+                changed = true;
+            }
+        });
+
+    ui.add_space(10.0);
+
+    // === REPEAT LAST CARD ===
+    egui::Frame::new()
+        .fill(card_bg)
+        .stroke(card_stroke)
+        .inner_margin(12.0)
+        .corner_radius(10.0)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(text.repeat_last_hotkey_header)
+                    .strong()
+                    .size(14.0),
+            );
+            ui.label(
+                egui::RichText::new(text.repeat_last_hotkey_desc)
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(6.0);
+
+            if render_hotkey_list(
+                ui,
+                text,
+                &mut config.repeat_last_hotkeys,
+                recording_repeat_last_hotkey,
+                hotkey_conflict_msg,
+            ) {
+                changed = true;
+            }
+        });
+
+    ui.add_space(10.0);
+
+    // === OVERLAY OPACITY CARD ===
+    egui::Frame::new()
+        .fill(card_bg)
+        .stroke(card_stroke)
+        .inner_margin(12.0)
+        .corner_radius(10.0)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(text.overlay_opacity_header)
+                    .strong()
+                    .size(14.0),
+            );
+            ui.label(
+                egui::RichText::new(text.overlay_opacity_desc)
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(6.0);
+
+            let mut percent = (config.overlay_opacity as f32 / 2.55).round() as u32;
+            if ui
+                .add(egui::Slider::new(&mut percent, 20..=100).suffix("%"))
+                .changed()
+            {
+                config.overlay_opacity = (percent as f32 * 2.55).round() as u8;
+                changed = true;
+            }
+        });
+
+    ui.add_space(10.0);
+
+    // === TEXT ANIMATION CARD ===
+    egui::Frame::new()
+        .fill(card_bg)
+        .stroke(card_stroke)
+        .inner_margin(12.0)
+        .corner_radius(10.0)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(text.text_animation_header)
+                    .strong()
+                    .size(14.0),
+            );
+            ui.label(
+                egui::RichText::new(text.text_animation_desc)
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(6.0);
+
+            ui.horizontal(|ui| {
+                let mut enabled = config.overlay_animation_speed > 0.0;
+                if ui
+                    .checkbox(&mut enabled, text.text_animation_enabled)
+                    .changed()
+                {
+                    config.overlay_animation_speed = if enabled { 1.0 } else { 0.0 };
+                    changed = true;
+                }
+                if enabled
+                    && ui
+                        .add(
+                            egui::Slider::new(&mut config.overlay_animation_speed, 0.5..=3.0)
+                                .step_by(0.25)
+                                .suffix("×"),
+                        )
+                        .changed()
+                {
+                    changed = true;
+                }
+            });
+        });
+
+    ui.add_space(10.0);
+
+    // === AUTO-PASTE METHOD CARD ===
+    egui::Frame::new()
+        .fill(card_bg)
+        .stroke(card_stroke)
+        .inner_margin(12.0)
+        .corner_radius(10.0)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(text.paste_method_header)
+                    .strong()
+                    .size(14.0),
+            );
+            ui.label(
+                egui::RichText::new(text.paste_method_desc)
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(6.0);
+
+            ui.horizontal(|ui| {
+                for (value, label) in [
+                    ("ctrl_v", "Ctrl+V"),
+                    ("shift_insert", "Shift+Insert"),
+                    ("type", text.paste_method_type),
+                ] {
+                    if ui.radio(config.paste_method == value, label).clicked() {
+                        config.paste_method = value.to_string();
+                        changed = true;
+                    }
+                }
+            });
+
+            if config.paste_method != "type" {
+                ui.horizontal(|ui| {
+                    ui.label(text.paste_key_delay_label);
+                    if ui
+                        .add(
+                            egui::Slider::new(&mut config.paste_key_delay_ms, 10..=500)
+                                .suffix(" ms"),
+                        )
+                        .changed()
+                    {
+                        changed = true;
+                    }
+                });
+            }
+        });
+
+    ui.add_space(10.0);
+
+    // === RESULT RIGHT-CLICK CARD ===
+    egui::Frame::new()
+        .fill(card_bg)
+        .stroke(card_stroke)
+        .inner_margin(12.0)
+        .corner_radius(10.0)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(text.result_right_click_header)
+                    .strong()
+                    .size(14.0),
+            );
+            ui.label(
+                egui::RichText::new(text.result_right_click_desc)
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(6.0);
+
+            ui.horizontal(|ui| {
+                for (value, label) in [
+                    ("close", text.result_right_click_close),
+                    ("menu", text.result_right_click_menu),
+                    ("copy", text.result_right_click_copy),
+                ] {
+                    if ui
+                        .radio(config.result_right_click == value, label)
+                        .clicked()
+                    {
+                        config.result_right_click = value.to_string();
+                        changed = true;
+                    }
+                }
+            });
+
+            ui.add_space(6.0);
+            ui.label(egui::RichText::new(text.retranslation_layout_label).size(12.0));
+            ui.horizontal(|ui| {
+                for (value, label) in [
+                    ("auto", text.retranslation_layout_auto),
+                    ("horizontal", text.retranslation_layout_horizontal),
+                    ("vertical", text.retranslation_layout_vertical),
+                ] {
+                    if ui
+                        .radio(config.retranslation_layout == value, label)
+                        .clicked()
+                    {
+                        config.retranslation_layout = value.to_string();
+                        changed = true;
+                    }
+                }
+            });
         });
 
     ui.add_space(10.0);
@@ -346,6 +878,9 @@ pub fn render_global_settings(
         config.use_openrouter,
         config.use_ollama,
         config.use_cerebras,
+        config.use_anthropic,
+        &config.presets,
+        &config.ui_language,
     );
 
     // === TOOLS MODAL ===
@@ -359,6 +894,78 @@ pub fn render_global_settings(
 
     ui.add_space(10.0);
 
+    // === CONFIG BACKUP CARD ===
+    egui::Frame::new()
+        .fill(card_bg)
+        .stroke(card_stroke)
+        .inner_margin(12.0)
+        .corner_radius(10.0)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(text.config_backup_header)
+                    .strong()
+                    .size(14.0),
+            );
+            ui.add_space(6.0);
+
+            let include_keys_id = egui::Id::new("config_export_include_keys");
+            let status_id = egui::Id::new("config_backup_status");
+            let mut include_keys = ui
+                .ctx()
+                .memory(|mem| mem.data.get_temp::<bool>(include_keys_id))
+                .unwrap_or(false);
+
+            ui.horizontal(|ui| {
+                if ui.button(text.export_config_button).clicked() {
+                    let default_name =
+                        format!("sgt-config-{}.json", chrono::Local::now().format("%Y%m%d"));
+                    if let Some(path) = crate::gui::utils::pick_json_file(true, &default_name) {
+                        let status = match crate::config::export_config(config, &path, include_keys)
+                        {
+                            Ok(()) => format!("{} {}", text.config_exported_msg, path.display()),
+                            Err(e) => format!("{} {}", text.config_backup_failed_msg, e),
+                        };
+                        ui.ctx()
+                            .memory_mut(|mem| mem.data.insert_temp(status_id, status));
+                    }
+                }
+                if ui
+                    .checkbox(&mut include_keys, text.export_include_api_keys)
+                    .changed()
+                {
+                    ui.ctx()
+                        .memory_mut(|mem| mem.data.insert_temp(include_keys_id, include_keys));
+                }
+
+                ui.add_space(10.0);
+
+                if ui.button(text.import_config_button).clicked() {
+                    if let Some(path) = crate::gui::utils::pick_json_file(false, "") {
+                        let status = match crate::config::import_config(&path, config) {
+                            Ok(imported) => {
+                                // Saving goes through save_and_sync, which also reloads hotkeys
+                                *config = imported;
+                                changed = true;
+                                text.config_imported_msg.to_string()
+                            }
+                            Err(e) => format!("{} {}", text.config_backup_failed_msg, e),
+                        };
+                        ui.ctx()
+                            .memory_mut(|mem| mem.data.insert_temp(status_id, status));
+                    }
+                }
+            });
+
+            if let Some(status) = ui
+                .ctx()
+                .memory(|mem| mem.data.get_temp::<String>(status_id))
+            {
+                ui.label(egui::RichText::new(&status).size(11.0));
+            }
+        });
+
+    ui.add_space(10.0);
+
     // === SOFTWARE UPDATE CARD ===
     egui::Frame::new()
         .fill(card_bg)
@@ -530,6 +1137,13 @@ pub fn render_global_settings(
                             "표준"
                         }
                     }
+                    "ja" => {
+                        if config.graphics_mode == "minimal" {
+                            "最小"
+                        } else {
+                            "標準"
+                        }
+                    }
                     _ => {
                         if config.graphics_mode == "minimal" {
                             "Minimal"
@@ -609,12 +1223,17 @@ pub fn render_global_settings(
                     let saved_gemini_key = config.gemini_api_key.clone();
                     let saved_openrouter_key = config.openrouter_api_key.clone();
                     let saved_cerebras_key = config.cerebras_api_key.clone();
+                    let saved_anthropic_key = config.anthropic_api_key.clone();
+                    let saved_custom_openai_key = config.custom_openai_api_key.clone();
+                    let saved_custom_openai_base_url = config.custom_openai_base_url.clone();
                     let saved_language = config.ui_language.clone();
                     let saved_use_groq = config.use_groq;
                     let saved_use_gemini = config.use_gemini;
                     let saved_use_openrouter = config.use_openrouter;
                     let saved_use_ollama = config.use_ollama;
                     let saved_use_cerebras = config.use_cerebras;
+                    let saved_use_anthropic = config.use_anthropic;
+                    let saved_use_custom_openai = config.use_custom_openai;
                     let saved_ollama_base_url = config.ollama_base_url.clone();
                     // Realtime model reset to default (google-gemma)
 
@@ -624,12 +1243,17 @@ pub fn render_global_settings(
                     config.gemini_api_key = saved_gemini_key;
                     config.openrouter_api_key = saved_openrouter_key;
                     config.cerebras_api_key = saved_cerebras_key;
+                    config.anthropic_api_key = saved_anthropic_key;
+                    config.custom_openai_api_key = saved_custom_openai_key;
+                    config.custom_openai_base_url = saved_custom_openai_base_url;
                     config.ui_language = saved_language;
                     config.use_groq = saved_use_groq;
                     config.use_gemini = saved_use_gemini;
                     config.use_openrouter = saved_use_openrouter;
                     config.use_ollama = saved_use_ollama;
                     config.use_cerebras = saved_use_cerebras;
+                    config.use_anthropic = saved_use_anthropic;
+                    config.use_custom_openai = saved_use_custom_openai;
                     config.ollama_base_url = saved_ollama_base_url;
                     // config.realtime_translation_model = saved_realtime_model;
                     request_node_graph_view_reset(ui.ctx());
@@ -653,3 +1277,53 @@ pub fn render_global_settings(
 
     changed
 }
+
+/// "Hotkeys: [Add] [A ×] [B ×]" row for a global hotkey list, with the
+/// conflict message while recording. Returns true if a hotkey was removed.
+fn render_hotkey_list(
+    ui: &mut egui::Ui,
+    text: &LocaleText,
+    hotkeys: &mut Vec<crate::config::Hotkey>,
+    recording: &mut bool,
+    hotkey_conflict_msg: &Option<String>,
+) -> bool {
+    let mut changed = false;
+
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new(text.hotkeys_section).strong());
+        if *recording {
+            ui.colored_label(egui::Color32::from_rgb(200, 130, 0), text.press_keys);
+            if ui.button(text.cancel_label).clicked() {
+                *recording = false;
+            }
+        } else if ui
+            .button(text.add_hotkey_button)
+            .on_hover_cursor(egui::CursorIcon::PointingHand)
+            .clicked()
+        {
+            *recording = true;
+        }
+
+        let mut hotkey_to_remove = None;
+        for (h_idx, hotkey) in hotkeys.iter().enumerate() {
+            if ui
+                .small_button(format!("{} ×", hotkey.name))
+                .on_hover_cursor(egui::CursorIcon::PointingHand)
+                .clicked()
+            {
+                hotkey_to_remove = Some(h_idx);
+            }
+        }
+        if let Some(h) = hotkey_to_remove {
+            hotkeys.remove(h);
+            changed = true;
+        }
+    });
+    if *recording {
+        if let Some(msg) = hotkey_conflict_msg {
+            ui.colored_label(egui::Color32::RED, msg);
+        }
+    }
+
+    changed
+}
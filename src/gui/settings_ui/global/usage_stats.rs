@@ -1,8 +1,12 @@
 use eframe::egui;
+use crate::config::Preset;
 use crate::gui::locale::LocaleText;
+use crate::gui::settings_ui::get_localized_preset_name;
 use crate::gui::icons::{Icon, icon_button};
 use crate::model_config::{get_all_models, get_all_models_with_ollama};
+use crate::token_usage::PresetUsage;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 pub fn render_usage_modal(
     ui: &mut egui::Ui, 
@@ -14,10 +18,32 @@ pub fn render_usage_modal(
     use_openrouter: bool,
     use_ollama: bool,
     use_cerebras: bool,
+    use_anthropic: bool,
+    presets: &[Preset],
+    ui_language: &str,
 ) {
+    // Per-preset token totals for this month, read once each time the modal opens
+    let snapshot_id = egui::Id::new("token_usage_snapshot");
     if !*show_modal {
+        ui.ctx().data_mut(|d| d.remove::<Arc<HashMap<String, PresetUsage>>>(snapshot_id));
         return;
     }
+
+    let token_usage = ui
+        .ctx()
+        .data_mut(|d| d.get_temp::<Arc<HashMap<String, PresetUsage>>>(snapshot_id))
+        .unwrap_or_else(|| {
+            let totals = Arc::new(
+                crate::APP
+                    .lock()
+                    .ok()
+                    .map(|app| app.token_usage.clone())
+                    .map(|manager| manager.current_month_totals())
+                    .unwrap_or_default(),
+            );
+            ui.ctx().data_mut(|d| d.insert_temp(snapshot_id, totals.clone()));
+            totals
+        });
     
     egui::Window::new(format!("📊 {}", text.usage_statistics_title))
         .collapsible(false)
@@ -52,6 +78,41 @@ pub fn render_usage_modal(
                 .auto_shrink([false, false])
                 .show(ui, |ui| {
                 ui.set_width(ui.available_width());
+
+                egui::CollapsingHeader::new(egui::RichText::new(format!("📈 {}", text.usage_tokens_by_preset_title)).strong().size(13.0))
+                    .default_open(true)
+                    .show(ui, |ui| {
+                    if token_usage.is_empty() {
+                        ui.label(egui::RichText::new(text.usage_tokens_empty).weak().italics());
+                        return;
+                    }
+
+                    let mut rows: Vec<_> = token_usage.iter().collect();
+                    rows.sort_by(|a, b| b.1.total_tokens().cmp(&a.1.total_tokens()));
+
+                    egui::Grid::new("token_usage_grid").striped(true).show(ui, |ui| {
+                        ui.label(egui::RichText::new(text.usage_preset_column).strong().size(11.0));
+                        ui.label(egui::RichText::new(text.usage_tokens_column).strong().size(11.0));
+                        ui.end_row();
+
+                        for (preset_id, usage) in rows {
+                            let name = match presets.iter().find(|p| &p.id == preset_id) {
+                                Some(p) if !p.id.starts_with("preset_") => p.name.clone(),
+                                _ => get_localized_preset_name(preset_id, ui_language),
+                            };
+                            // "~" marks totals that include character-based estimates
+                            let approx = if usage.estimated { "~" } else { "" };
+                            ui.label(name);
+                            ui.label(format!("{}{}", approx, format_token_count(usage.total_tokens())))
+                                .on_hover_text(format!(
+                                    "{} ↑ / {} ↓ · {} req",
+                                    usage.prompt_tokens, usage.completion_tokens, usage.requests
+                                ));
+                            ui.end_row();
+                        }
+                    });
+                });
+
                 if use_groq {
                     egui::CollapsingHeader::new(egui::RichText::new("⚡ Groq").strong().size(13.0))
                         .default_open(true)
@@ -188,6 +249,27 @@ pub fn render_usage_modal(
                     });
                 }
                 
+                if use_anthropic {
+                    egui::CollapsingHeader::new(egui::RichText::new("🧠 Anthropic Claude").strong().size(13.0))
+                        .default_open(true)
+                        .show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(text.usage_model_column).strong().size(11.0));
+                            ui.add_space(120.0);
+                            ui.hyperlink_to(text.usage_check_link, "https://console.anthropic.com/usage");
+                        });
+                        ui.add_space(4.0);
+                        
+                        for model in &all_models {
+                            if !model.enabled || model.provider != "anthropic" { continue; }
+                            if shown_models.contains(&model.full_name) { continue; }
+                            shown_models.insert(model.full_name.clone());
+                            
+                            ui.label(&model.full_name);
+                        }
+                    });
+                }
+                
                 if use_ollama {
                     egui::CollapsingHeader::new(egui::RichText::new("🏠 Ollama (Local)").strong().size(13.0))
                         .default_open(true)
@@ -211,3 +293,16 @@ pub fn render_usage_modal(
             });
         });
 }
+
+/// 1234 -> "1.2k", 120000 -> "120k", 3400000 -> "3.4M"
+fn format_token_count(tokens: u64) -> String {
+    if tokens >= 1_000_000 {
+        format!("{:.1}M", tokens as f64 / 1_000_000.0)
+    } else if tokens >= 10_000 {
+        format!("{}k", tokens / 1000)
+    } else if tokens >= 1000 {
+        format!("{:.1}k", tokens as f64 / 1000.0)
+    } else {
+        tokens.to_string()
+    }
+}
@@ -93,6 +93,7 @@ pub fn show_help_input() {
     let placeholder = match ui_language.as_str() {
         "vi" => "Hỏi gì về SGT? (VD: Làm sao để dịch vùng màn hình?)",
         "ko" => "SGT에 대해 무엇을 물어볼까요?",
+        "ja" => "SGT について何でも質問してください（例: 画面の一部を翻訳するには？）",
         _ => "Ask anything about SGT (e.g., How do I translate a screen region?)",
     };
 
@@ -119,6 +120,7 @@ pub fn show_help_input() {
                 let loading_msg = match lang.as_str() {
                     "vi" => "⏳ Đang gọi cho tác giả nganlinh4 ... Kkk đùa thôi, đợi tí nha",
                     "ko" => "⏳ 작가 nganlinh4에게 전화 중... ㅋㅋ 농담이고, 잠깐만 기다려",
+                    "ja" => "⏳ 作者 nganlinh4 に電話中... なんてね、少々お待ちを",
                     _ => "⏳ Calling author nganlinh4 ... Kkk joke, wait a bit",
                 };
 
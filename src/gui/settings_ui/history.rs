@@ -189,6 +189,17 @@ pub fn render_history_panel(
                                 };
                                 draw_icon_static(ui, icon, Some(14.0));
                                 ui.label(egui::RichText::new(&item.timestamp).size(10.0).weak());
+                                if item.incomplete {
+                                    ui.label(
+                                        egui::RichText::new(format!(
+                                            "⚠ {}",
+                                            text.history_incomplete
+                                        ))
+                                        .size(10.0)
+                                        .color(egui::Color32::from_rgb(230, 160, 40)),
+                                    )
+                                    .on_hover_text(text.history_incomplete_tooltip);
+                                }
 
                                 ui.with_layout(
                                     egui::Layout::right_to_left(egui::Align::Center),
@@ -207,6 +218,13 @@ pub fn render_history_panel(
                                             crate::gui::utils::copy_to_clipboard_text(&item.text);
                                         }
 
+                                        if item.item_type == HistoryType::Image
+                                            && !item.media_path.is_empty()
+                                            && ui.button(text.reopen_result_btn).clicked()
+                                        {
+                                            reopen_image(item);
+                                        }
+
                                         if !item.media_path.is_empty() {
                                             let btn_text = match item.item_type {
                                                 HistoryType::Image => text.view_image_btn,
@@ -214,19 +232,33 @@ pub fn render_history_panel(
                                                 HistoryType::Text => text.view_text_btn,
                                             };
                                             if ui.button(btn_text).clicked() {
-                                                let config_dir = dirs::config_dir()
-                                                    .unwrap()
-                                                    .join("screen-goated-toolbox")
-                                                    .join("history_media");
-                                                let path = config_dir.join(&item.media_path);
-                                                let _ = open::that(path);
+                                                let _ = open::that(media_file(item));
                                             }
                                         }
                                     },
                                 );
                             });
 
-                            ui.label(egui::RichText::new(&item.text).size(13.0));
+                            if item.item_type == HistoryType::Image && !item.media_path.is_empty() {
+                                ui.horizontal_top(|ui| {
+                                    let uri = format!("file://{}", media_file(item).display());
+                                    let thumb = ui
+                                        .add(
+                                            egui::Image::new(uri)
+                                                .max_size(egui::vec2(96.0, 64.0))
+                                                .corner_radius(4.0)
+                                                .sense(egui::Sense::click()),
+                                        )
+                                        .on_hover_cursor(egui::CursorIcon::PointingHand)
+                                        .on_hover_text(text.reopen_result_btn);
+                                    if thumb.clicked() {
+                                        reopen_image(item);
+                                    }
+                                    ui.label(egui::RichText::new(&item.text).size(13.0));
+                                });
+                            } else {
+                                ui.label(egui::RichText::new(&item.text).size(13.0));
+                            }
                         });
                     ui.add_space(4.0);
                 }
@@ -240,3 +272,16 @@ pub fn render_history_panel(
 
     changed
 }
+
+fn media_file(item: &HistoryItem) -> std::path::PathBuf {
+    dirs::config_dir()
+        .unwrap_or_default()
+        .join("screen-goated-toolbox")
+        .join("history_media")
+        .join(&item.media_path)
+}
+
+/// Open the saved capture in a result window so it can be refined again
+fn reopen_image(item: &HistoryItem) {
+    crate::overlay::history_result::reopen_image_result(media_file(item), item.text.clone());
+}
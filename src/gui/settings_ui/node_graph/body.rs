@@ -1,13 +1,21 @@
 use super::node::ChainNode;
-use super::utils::{insert_next_language_tag, model_supports_search, show_language_vars};
+use super::utils::{
+    insert_next_language_tag, insert_prompt_snippet, model_supports_search, prompt_vars_hint,
+    show_language_vars,
+};
 use super::viewer::ChainViewer;
+use crate::api::client::{format_cooldown, model_cooldown};
+use crate::config::PromptSnippet;
 use crate::gui::icons::{icon_button, Icon};
+use crate::gui::settings_ui::preset::text_input_mode_label;
 use crate::model_config::{
-    get_all_models_with_ollama, get_model_by_id, is_ollama_scan_in_progress, model_is_non_llm,
-    trigger_ollama_model_scan, ModelType,
+    custom_openai_model_id, get_all_models_with_ollama, get_model_by_id,
+    is_ollama_scan_in_progress, model_is_non_llm, trigger_ollama_model_scan, ModelConfig,
+    ModelType,
 };
 use eframe::egui;
 use egui_snarl::{NodeId, Snarl};
+use std::collections::HashMap;
 
 pub fn show_body(
     viewer: &mut ChainViewer,
@@ -87,6 +95,7 @@ pub fn show_body(
                                     match viewer.ui_language.as_str() {
                                         "vi" => "Đẹp",
                                         "ko" => "마크다운",
+                                        "ja" => "Markdown",
                                         _ => "Markdown",
                                     }
                                 } else {
@@ -97,6 +106,7 @@ pub fn show_body(
                                     match viewer.ui_language.as_str() {
                                         "vi" => "Thường",
                                         "ko" => "일반",
+                                        "ja" => "通常",
                                         _ => "Normal",
                                     }
                                 };
@@ -110,11 +120,13 @@ pub fn show_body(
                                 } else {
                                     egui::Color32::from_rgba_unmultiplied(220, 220, 220, 200)
                                 };
-                                let btn = ui.add(
-                                    egui::Button::new(current_mode_label)
-                                        .fill(btn_bg)
-                                        .corner_radius(4.0),
-                                );
+                                let btn = ui
+                                    .add(
+                                        egui::Button::new(current_mode_label)
+                                            .fill(btn_bg)
+                                            .corner_radius(4.0),
+                                    )
+                                    .on_hover_text(streaming_override_hint(&viewer.ui_language));
                                 if btn.clicked() {
                                     ui.memory_mut(|mem| mem.toggle_popup(popup_id));
                                 }
@@ -128,6 +140,7 @@ pub fn show_body(
                                         let (lbl_norm, lbl_md) = match viewer.ui_language.as_str() {
                                             "vi" => ("Thường", "Đẹp"),
                                             "ko" => ("일반", "마크다운"),
+                                            "ja" => ("通常", "Markdown"),
                                             _ => ("Normal", "Markdown"),
                                         };
 
@@ -214,6 +227,28 @@ pub fn show_body(
                                 }
                             }
                         });
+
+                        // Text source: selection, typing or the clipboard as-is
+                        if actual_type == "text" {
+                            let text = viewer.text;
+                            egui::ComboBox::from_id_salt(format!("input_text_mode_{:?}", node_id))
+                                .selected_text(text_input_mode_label(text, &viewer.text_input_mode))
+                                .show_ui(ui, |ui| {
+                                    for (mode, label) in [
+                                        ("select", text.text_mode_select),
+                                        ("type", text.text_mode_type),
+                                        ("clipboard", text.text_mode_clipboard),
+                                    ] {
+                                        if ui
+                                            .selectable_label(viewer.text_input_mode == mode, label)
+                                            .clicked()
+                                        {
+                                            viewer.text_input_mode = mode.to_string();
+                                            viewer.changed = true;
+                                        }
+                                    }
+                                });
+                        }
                     }
                     ChainNode::Special {
                         model,
@@ -224,6 +259,7 @@ pub fn show_body(
                         render_mode,
                         auto_copy,
                         auto_speak,
+                        on_error,
                         ..
                     } => {
                         // Special nodes use different model types based on preset type
@@ -237,6 +273,7 @@ pub fn show_body(
                         let model_label = match viewer.ui_language.as_str() {
                             "vi" => "Mô hình:",
                             "ko" => "모델:",
+                            "ja" => "モデル:",
                             _ => "Model:",
                         };
                         ui.horizontal(|ui| {
@@ -270,6 +307,7 @@ pub fn show_body(
                                     let loading_text = match viewer.ui_language.as_str() {
                                         "vi" => "⏳ Đang quét các model local...",
                                         "ko" => "⏳ 로컬 모델 스캔 중...",
+                                        "ja" => "⏳ ローカルモデルをスキャン中...",
                                         _ => "⏳ Scanning local models...",
                                     };
                                     ui.label(egui::RichText::new(loading_text).weak().italics());
@@ -297,6 +335,8 @@ pub fn show_body(
                                             "groq" => "⚡ ",
                                             "cerebras" => "🔥 ",
                                             "openrouter" => "🌐 ",
+                                            "anthropic" => "🧠 ",
+                                            "custom-openai" => "🖥 ",
                                             "ollama" => "🏠 ",
                                             "qrserver" => "🔳 ",
                                             "parakeet" => "🐦 ",
@@ -313,15 +353,31 @@ pub fn show_body(
                                         );
                                         let is_selected = *model == m.id;
 
-                                        if ui.selectable_label(is_selected, label).clicked() {
+                                        if model_option(ui, viewer, &m, label, is_selected)
+                                            .clicked()
+                                        {
                                             *model = m.id.clone();
                                             viewer.changed = true;
                                             egui::Popup::toggle_id(ui.ctx(), popup_layer_id);
                                         }
                                     }
                                 }
+
+                                // Free-form model name for the OpenAI-compatible endpoint
+                                if viewer.use_custom_openai
+                                    && show_custom_model_entry(
+                                        ui,
+                                        viewer,
+                                        model,
+                                        &target_model_type,
+                                        &current_node_uuid,
+                                    )
+                                {
+                                    egui::Popup::toggle_id(ui.ctx(), popup_layer_id);
+                                }
                             });
                         });
+                        show_model_warning(ui, viewer, model);
 
                         // Only show prompt UI for LLM models (not QR scanner, GTX, Whisper, etc.)
                         if !model_is_non_llm(model) {
@@ -330,6 +386,7 @@ pub fn show_body(
                                 let prompt_label = match viewer.ui_language.as_str() {
                                     "vi" => "Lệnh:",
                                     "ko" => "프롬프트:",
+                                    "ja" => "プロンプト:",
                                     _ => "Prompt:",
                                 };
                                 ui.label(prompt_label);
@@ -337,6 +394,7 @@ pub fn show_body(
                                 let btn_label = match viewer.ui_language.as_str() {
                                     "vi" => "+ Ngôn ngữ",
                                     "ko" => "+ 언어",
+                                    "ja" => "+ 言語",
                                     _ => "+ Language",
                                 };
                                 let is_dark = ui.visuals().dark_mode;
@@ -360,6 +418,7 @@ pub fn show_body(
                                     insert_next_language_tag(prompt, language_vars);
                                     viewer.changed = true;
                                 }
+                                show_prompt_snippets(ui, viewer, prompt, language_vars);
                             });
 
                             // Row 3: Prompt TextEdit
@@ -369,6 +428,7 @@ pub fn show_body(
                                         .desired_width(152.0)
                                         .desired_rows(2),
                                 )
+                                .on_hover_text(prompt_vars_hint(&viewer.ui_language))
                                 .changed()
                             {
                                 viewer.changed = true;
@@ -405,21 +465,25 @@ pub fn show_body(
                                         {
                                             "vi" => "Đẹp+Str",
                                             "ko" => "마크다운+스트림",
+                                            "ja" => "MD+ストリーム",
                                             _ => "MD+Stream",
                                         },
                                         ("markdown", _) => match viewer.ui_language.as_str() {
                                             "vi" => "Đẹp",
                                             "ko" => "마크다운",
+                                            "ja" => "Markdown",
                                             _ => "Markdown",
                                         },
                                         (_, true) => match viewer.ui_language.as_str() {
                                             "vi" => "Stream",
                                             "ko" => "스트림",
+                                            "ja" => "ストリーム",
                                             _ => "Stream",
                                         },
                                         (_, false) => match viewer.ui_language.as_str() {
                                             "vi" => "Thường",
                                             "ko" => "일반",
+                                            "ja" => "通常",
                                             _ => "Normal",
                                         },
                                     };
@@ -431,11 +495,13 @@ pub fn show_body(
                                 } else {
                                     egui::Color32::from_rgba_unmultiplied(220, 220, 220, 200)
                                 };
-                                let btn = ui.add(
-                                    egui::Button::new(current_mode_label)
-                                        .fill(btn_bg)
-                                        .corner_radius(4.0),
-                                );
+                                let btn = ui
+                                    .add(
+                                        egui::Button::new(current_mode_label)
+                                            .fill(btn_bg)
+                                            .corner_radius(4.0),
+                                    )
+                                    .on_hover_text(streaming_override_hint(&viewer.ui_language));
                                 if btn.clicked() {
                                     ui.memory_mut(|mem| mem.toggle_popup(popup_id));
                                 }
@@ -454,6 +520,9 @@ pub fn show_body(
                                             "ko" => {
                                                 ("일반", "스트림", "마크다운", "마크다운+스트림")
                                             }
+                                            "ja" => {
+                                                ("通常", "ストリーム", "Markdown", "MD+ストリーム")
+                                            }
                                             _ => ("Normal", "Stream", "Markdown", "MD+Stream"),
                                         };
 
@@ -545,6 +614,8 @@ pub fn show_body(
                                     viewer.changed = true;
                                 }
                             }
+
+                            show_on_error_toggle(ui, viewer, on_error);
                         });
                     }
                     ChainNode::Process {
@@ -556,6 +627,7 @@ pub fn show_body(
                         render_mode,
                         auto_copy,
                         auto_speak,
+                        on_error,
                         ..
                     } => {
                         // Process nodes always use Text models (text-to-text transformation)
@@ -565,6 +637,7 @@ pub fn show_body(
                         let model_label = match viewer.ui_language.as_str() {
                             "vi" => "Mô hình:",
                             "ko" => "모델:",
+                            "ja" => "モデル:",
                             _ => "Model:",
                         };
                         ui.horizontal(|ui| {
@@ -594,6 +667,7 @@ pub fn show_body(
                                     let loading_text = match viewer.ui_language.as_str() {
                                         "vi" => "⏳ Đang quét các model local...",
                                         "ko" => "⏳ 로컬 모델 스캔 중...",
+                                        "ja" => "⏳ ローカルモデルをスキャン中...",
                                         _ => "⏳ Scanning local models...",
                                     };
                                     ui.label(egui::RichText::new(loading_text).weak().italics());
@@ -621,6 +695,8 @@ pub fn show_body(
                                             "groq" => "⚡ ",
                                             "cerebras" => "🔥 ",
                                             "openrouter" => "🌐 ",
+                                            "anthropic" => "🧠 ",
+                                            "custom-openai" => "🖥 ",
                                             "ollama" => "🏠 ",
                                             "qrserver" => "🔳 ",
                                             "parakeet" => "🐦 ",
@@ -637,15 +713,31 @@ pub fn show_body(
                                         );
                                         let is_selected = *model == m.id;
 
-                                        if ui.selectable_label(is_selected, label).clicked() {
+                                        if model_option(ui, viewer, &m, label, is_selected)
+                                            .clicked()
+                                        {
                                             *model = m.id.clone();
                                             viewer.changed = true;
                                             egui::Popup::toggle_id(ui.ctx(), popup_layer_id);
                                         }
                                     }
                                 }
+
+                                // Free-form model name for the OpenAI-compatible endpoint
+                                if viewer.use_custom_openai
+                                    && show_custom_model_entry(
+                                        ui,
+                                        viewer,
+                                        model,
+                                        &target_model_type,
+                                        &current_node_uuid,
+                                    )
+                                {
+                                    egui::Popup::toggle_id(ui.ctx(), popup_layer_id);
+                                }
                             });
                         });
+                        show_model_warning(ui, viewer, model);
 
                         // Only show prompt UI for LLM models (not GTX, etc.)
                         if !model_is_non_llm(model) {
@@ -654,6 +746,7 @@ pub fn show_body(
                                 let prompt_label = match viewer.ui_language.as_str() {
                                     "vi" => "Lệnh:",
                                     "ko" => "프롬프트:",
+                                    "ja" => "プロンプト:",
                                     _ => "Prompt:",
                                 };
                                 ui.label(prompt_label);
@@ -661,6 +754,7 @@ pub fn show_body(
                                 let btn_label = match viewer.ui_language.as_str() {
                                     "vi" => "+ Ngôn ngữ",
                                     "ko" => "+ 언어",
+                                    "ja" => "+ 言語",
                                     _ => "+ Language",
                                 };
                                 let is_dark = ui.visuals().dark_mode;
@@ -684,6 +778,7 @@ pub fn show_body(
                                     insert_next_language_tag(prompt, language_vars);
                                     viewer.changed = true;
                                 }
+                                show_prompt_snippets(ui, viewer, prompt, language_vars);
                             });
 
                             // Row 3: Prompt TextEdit
@@ -693,6 +788,7 @@ pub fn show_body(
                                         .desired_width(152.0)
                                         .desired_rows(2),
                                 )
+                                .on_hover_text(prompt_vars_hint(&viewer.ui_language))
                                 .changed()
                             {
                                 viewer.changed = true;
@@ -729,21 +825,25 @@ pub fn show_body(
                                         {
                                             "vi" => "Đẹp+Str",
                                             "ko" => "마크다운+스트림",
+                                            "ja" => "MD+ストリーム",
                                             _ => "MD+Stream",
                                         },
                                         ("markdown", _) => match viewer.ui_language.as_str() {
                                             "vi" => "Đẹp",
                                             "ko" => "마크다운",
+                                            "ja" => "Markdown",
                                             _ => "Markdown",
                                         },
                                         (_, true) => match viewer.ui_language.as_str() {
                                             "vi" => "Stream",
                                             "ko" => "스트림",
+                                            "ja" => "ストリーム",
                                             _ => "Stream",
                                         },
                                         (_, false) => match viewer.ui_language.as_str() {
                                             "vi" => "Thường",
                                             "ko" => "일반",
+                                            "ja" => "通常",
                                             _ => "Normal",
                                         },
                                     };
@@ -755,11 +855,13 @@ pub fn show_body(
                                 } else {
                                     egui::Color32::from_rgba_unmultiplied(220, 220, 220, 200)
                                 };
-                                let btn = ui.add(
-                                    egui::Button::new(current_mode_label)
-                                        .fill(btn_bg)
-                                        .corner_radius(4.0),
-                                );
+                                let btn = ui
+                                    .add(
+                                        egui::Button::new(current_mode_label)
+                                            .fill(btn_bg)
+                                            .corner_radius(4.0),
+                                    )
+                                    .on_hover_text(streaming_override_hint(&viewer.ui_language));
                                 if btn.clicked() {
                                     ui.memory_mut(|mem| mem.toggle_popup(popup_id));
                                 }
@@ -778,6 +880,9 @@ pub fn show_body(
                                             "ko" => {
                                                 ("일반", "스트림", "마크다운", "마크다운+스트림")
                                             }
+                                            "ja" => {
+                                                ("通常", "ストリーム", "Markdown", "MD+ストリーム")
+                                            }
                                             _ => ("Normal", "Stream", "Markdown", "MD+Stream"),
                                         };
 
@@ -866,6 +971,8 @@ pub fn show_body(
                                     viewer.changed = true;
                                 }
                             }
+
+                            show_on_error_toggle(ui, viewer, on_error);
                         });
                     }
                 }
@@ -882,3 +989,294 @@ pub fn show_body(
         }
     }
 }
+
+/// Tooltip of the render mode button
+fn streaming_override_hint(ui_language: &str) -> &'static str {
+    match ui_language {
+        "vi" => "Giữ Shift khi bấm phím tắt của preset để chờ kết quả đầy đủ thay vì stream",
+        "ko" => "프리셋 단축키를 누를 때 Shift를 누르고 있으면 스트리밍 대신 전체 결과를 기다립니다",
+        "ja" => "プリセットのホットキーを押すときに Shift を押し続けると、ストリーミングせずに結果全体を待ちます",
+        _ => "Hold Shift while pressing the preset's hotkey to wait for the full result instead of streaming",
+    }
+}
+
+/// Small button showing what the chain does if this node's request fails;
+/// clicking cycles stop -> pass input on -> skip node
+fn show_on_error_toggle(ui: &mut egui::Ui, viewer: &mut ChainViewer, on_error: &mut String) {
+    let glyph = match on_error.as_str() {
+        "pass_input" => "↪",
+        "skip" => "⏭",
+        _ => "⏹",
+    };
+    let tooltip = match (on_error.as_str(), viewer.ui_language.as_str()) {
+        ("pass_input", "vi") => "Khi lỗi: hiện lỗi và chuyển đầu vào sang node tiếp theo",
+        ("pass_input", "ko") => "오류 시: 오류를 표시하고 입력을 다음 노드로 전달",
+        ("pass_input", "ja") => "エラー時: エラーを表示し、入力を次のノードへ渡す",
+        ("pass_input", _) => "On error: show the error and pass this node's input on",
+        ("skip", "vi") => "Khi lỗi: bỏ qua node này (đóng cửa sổ) và chuyển đầu vào đi tiếp",
+        ("skip", "ko") => "오류 시: 이 노드를 건너뛰고(창 닫기) 입력을 전달",
+        ("skip", "ja") => "エラー時: このノードをスキップし（ウィンドウを閉じる）、入力を渡す",
+        ("skip", _) => "On error: skip this node (close its window) and pass its input on",
+        (_, "vi") => "Khi lỗi: dừng chuỗi xử lý",
+        (_, "ko") => "오류 시: 체인 중지",
+        (_, "ja") => "エラー時: チェーンを停止",
+        _ => "On error: stop the chain",
+    };
+
+    if ui
+        .add(egui::Button::new(glyph).frame(false))
+        .on_hover_text(tooltip)
+        .clicked()
+    {
+        *on_error = match on_error.as_str() {
+            "stop" => "pass_input",
+            "pass_input" => "skip",
+            _ => "stop",
+        }
+        .to_string();
+        viewer.changed = true;
+    }
+}
+
+/// "Snippets" button next to the prompt label: insert a saved prompt, delete one,
+/// or save the current prompt under a new name
+fn show_prompt_snippets(
+    ui: &mut egui::Ui,
+    viewer: &mut ChainViewer,
+    prompt: &mut String,
+    language_vars: &mut HashMap<String, String>,
+) {
+    let (btn_label, empty_label, name_hint, save_label, delete_tip) =
+        match viewer.ui_language.as_str() {
+            "vi" => (
+                "Mẫu",
+                "Chưa có mẫu lệnh nào",
+                "Tên mẫu",
+                "Lưu lệnh hiện tại",
+                "Xóa mẫu",
+            ),
+            "ko" => (
+                "스니펫",
+                "저장된 스니펫이 없습니다",
+                "스니펫 이름",
+                "현재 프롬프트 저장",
+                "스니펫 삭제",
+            ),
+            "ja" => (
+                "スニペット",
+                "保存済みのスニペットはありません",
+                "スニペット名",
+                "現在のプロンプトを保存",
+                "スニペットを削除",
+            ),
+            _ => (
+                "Snippets",
+                "No saved snippets yet",
+                "Snippet name",
+                "Save current prompt",
+                "Delete snippet",
+            ),
+        };
+
+    let response =
+        ui.add(egui::Button::new(egui::RichText::new(btn_label).small()).corner_radius(8.0));
+    if response.clicked() {
+        egui::Popup::toggle_id(ui.ctx(), response.id);
+    }
+
+    let popup_id = response.id;
+    let name_id = popup_id.with("snippet_name");
+    egui::Popup::from_toggle_button_response(&response)
+        .close_behavior(egui::PopupCloseBehavior::CloseOnClickOutside)
+        .show(|ui| {
+            ui.set_min_width(200.0);
+
+            if viewer.prompt_snippets.is_empty() {
+                ui.weak(empty_label);
+            }
+            let mut delete_idx = None;
+            egui::ScrollArea::vertical()
+                .max_height(200.0)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    for (idx, snippet) in viewer.prompt_snippets.iter().enumerate() {
+                        ui.horizontal(|ui| {
+                            if ui.small_button("🗑").on_hover_text(delete_tip).clicked() {
+                                delete_idx = Some(idx);
+                            }
+                            if ui
+                                .selectable_label(false, &snippet.name)
+                                .on_hover_text(&snippet.prompt)
+                                .clicked()
+                            {
+                                insert_prompt_snippet(prompt, language_vars, snippet);
+                                viewer.changed = true;
+                                egui::Popup::toggle_id(ui.ctx(), popup_id);
+                            }
+                        });
+                    }
+                });
+            if let Some(idx) = delete_idx {
+                viewer.prompt_snippets.remove(idx);
+                viewer.changed = true;
+            }
+
+            ui.separator();
+            let mut name: String = ui.data_mut(|d| d.get_temp(name_id).unwrap_or_default());
+            ui.add(
+                egui::TextEdit::singleline(&mut name)
+                    .hint_text(name_hint)
+                    .desired_width(190.0),
+            );
+            let can_save = !name.trim().is_empty() && !prompt.trim().is_empty();
+            if ui
+                .add_enabled(can_save, egui::Button::new(save_label))
+                .clicked()
+            {
+                // Only the languages the prompt actually uses travel with it
+                let used_vars = language_vars
+                    .iter()
+                    .filter(|(key, _)| prompt.contains(&format!("{{{}}}", key)))
+                    .map(|(key, lang)| (key.clone(), lang.clone()))
+                    .collect();
+                let snippet = PromptSnippet {
+                    name: name.trim().to_string(),
+                    prompt: prompt.trim().to_string(),
+                    language_vars: used_vars,
+                };
+                // Saving under an existing name replaces that snippet
+                match viewer
+                    .prompt_snippets
+                    .iter_mut()
+                    .find(|s| s.name == snippet.name)
+                {
+                    Some(existing) => *existing = snippet,
+                    None => viewer.prompt_snippets.push(snippet),
+                }
+                viewer.changed = true;
+                name.clear();
+            }
+            ui.data_mut(|d| d.insert_temp(name_id, name));
+        });
+}
+
+/// Text field + button for picking a model served by the OpenAI-compatible endpoint.
+/// Returns true when a model was applied (caller closes the popup).
+fn show_custom_model_entry(
+    ui: &mut egui::Ui,
+    viewer: &mut ChainViewer,
+    model: &mut String,
+    target_model_type: &ModelType,
+    node_uuid: &str,
+) -> bool {
+    let (hint, add_label) = match viewer.ui_language.as_str() {
+        "vi" => ("Tên model trên máy chủ...", "Dùng"),
+        "ko" => ("서버의 모델 이름...", "사용"),
+        "ja" => ("サーバー上のモデル名...", "使用"),
+        _ => ("Model name on server...", "Use"),
+    };
+
+    let id = egui::Id::new("custom_openai_model_entry").with(node_uuid);
+    let mut name: String = ui.data_mut(|d| d.get_temp(id).unwrap_or_default());
+    let mut applied = false;
+
+    ui.separator();
+    ui.horizontal(|ui| {
+        ui.label("🖥");
+        ui.add(
+            egui::TextEdit::singleline(&mut name)
+                .hint_text(hint)
+                .desired_width(180.0),
+        );
+        if ui
+            .add_enabled(!name.trim().is_empty(), egui::Button::new(add_label))
+            .clicked()
+        {
+            *model = custom_openai_model_id(&name, target_model_type);
+            viewer.changed = true;
+            applied = true;
+        }
+    });
+
+    ui.data_mut(|d| d.insert_temp(id, name));
+    applied
+}
+
+/// A model entry in the picker: grayed out, with the reason on hover, when it can't run
+fn model_option(
+    ui: &mut egui::Ui,
+    viewer: &ChainViewer,
+    m: &ModelConfig,
+    label: String,
+    is_selected: bool,
+) -> egui::Response {
+    // Hit its rate limit: still pickable, with the time until it resets
+    let label = match model_cooldown(&m.provider, &m.full_name) {
+        Some(left) => {
+            ui.ctx()
+                .request_repaint_after(std::time::Duration::from_secs(1));
+            format!(
+                "{}  ⏳ {}",
+                label,
+                format_cooldown(left, &viewer.ui_language)
+            )
+        }
+        None => label,
+    };
+    if let Some(reason) = viewer.model_unavailable_reason(&m.id, &m.provider) {
+        return ui
+            .selectable_label(
+                is_selected,
+                egui::RichText::new(label).color(ui.visuals().weak_text_color()),
+            )
+            .on_hover_text(reason);
+    }
+    if m.provider == "ollama" {
+        // Listed Ollama models come from the scan of what is pulled locally
+        let installed = match viewer.ui_language.as_str() {
+            "vi" => "đã cài",
+            "ko" => "설치됨",
+            "ja" => "インストール済み",
+            _ => "installed",
+        };
+        return ui.selectable_label(is_selected, format!("{} ✓ {}", label, installed));
+    }
+    ui.selectable_label(is_selected, label)
+}
+
+/// Inline warning under the model picker when the chosen model can't run
+fn show_model_warning(ui: &mut egui::Ui, viewer: &ChainViewer, model: &str) {
+    let config = get_model_by_id(model);
+    let provider = config
+        .as_ref()
+        .map(|m| m.provider.clone())
+        .unwrap_or_default();
+    if let Some(reason) = viewer.model_unavailable_reason(model, &provider) {
+        ui.label(
+            egui::RichText::new(format!("⚠ {}", reason))
+                .size(11.0)
+                .color(ui.visuals().warn_fg_color),
+        );
+    }
+    // e.g. "⏳ Gemini Flash: resets in 3m"
+    if let Some(m) = config {
+        if let Some(left) = model_cooldown(&m.provider, &m.full_name) {
+            let name = match viewer.ui_language.as_str() {
+                "vi" => &m.name_vi,
+                "ko" => &m.name_ko,
+                _ => &m.name_en,
+            };
+            ui.label(
+                egui::RichText::new(format!(
+                    "⏳ {}: {}",
+                    name,
+                    format_cooldown(left, &viewer.ui_language)
+                ))
+                .size(11.0)
+                .color(ui.visuals().warn_fg_color),
+            );
+            ui.ctx()
+                .request_repaint_after(std::time::Duration::from_secs(1));
+        }
+    }
+}
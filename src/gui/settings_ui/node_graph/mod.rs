@@ -6,9 +6,10 @@ pub mod viewer;
 
 pub use conversion::{blocks_to_snarl, snarl_to_graph};
 pub use node::ChainNode;
-pub use utils::request_node_graph_view_reset;
+pub use utils::{request_node_graph_view_reset, request_node_graph_view_restore};
 pub use viewer::ChainViewer;
 
+use crate::config::{NodeGraphView, PromptSnippet};
 use crate::gui::locale::LocaleText;
 use eframe::egui;
 use egui_snarl::ui::SnarlStyle;
@@ -25,9 +26,22 @@ pub fn render_node_graph(
     use_gemini: bool,
     use_openrouter: bool,
     use_ollama: bool,
+    use_anthropic: bool,
+    use_custom_openai: bool,
+    unconfigured_providers: Vec<String>,
     preset_type: &str,
+    text_input_mode: &mut String,
     text: &LocaleText,
+    prompt_snippets: &mut Vec<PromptSnippet>,
+    preset_id: &str,
+    graph_views: &mut HashMap<String, NodeGraphView>,
 ) -> bool {
+    // Just opened: reuse the preset's last framing, or fit the view if it has none
+    let restore_view = utils::take_node_graph_view_restore(ui.ctx());
+    if restore_view && !graph_views.contains_key(preset_id) {
+        request_node_graph_view_reset(ui.ctx());
+    }
+
     let mut viewer = ChainViewer::new(
         text,
         ui_language,
@@ -36,8 +50,16 @@ pub fn render_node_graph(
         use_gemini,
         use_openrouter,
         use_ollama,
+        use_anthropic,
+        use_custom_openai,
+        unconfigured_providers,
         preset_type,
+        text_input_mode,
+        prompt_snippets,
+        preset_id,
+        graph_views,
     );
+    viewer.restore_view = restore_view;
     let style = SnarlStyle::default();
 
     snarl.show(&mut viewer, &style, egui::Id::new("chain_graph"), ui);
@@ -71,6 +93,10 @@ pub fn render_node_graph(
         }
     }
 
+    if viewer.text_input_mode != *text_input_mode {
+        *text_input_mode = viewer.text_input_mode.clone();
+    }
+
     let mut cleanup_changed = false;
     for (out, inp) in to_disconnect {
         snarl.disconnect(out, inp);
@@ -25,6 +25,7 @@ pub enum ChainNode {
         render_mode: String,
         auto_copy: bool,
         auto_speak: bool,
+        on_error: String,
     },
     /// Processing node (transforms text)
     Process {
@@ -38,6 +39,7 @@ pub enum ChainNode {
         render_mode: String,
         auto_copy: bool,
         auto_speak: bool,
+        on_error: String,
     },
 }
 
@@ -60,6 +62,7 @@ impl Default for ChainNode {
             render_mode: "markdown_stream".to_string(),
             auto_copy: false,
             auto_speak: false,
+            on_error: "stop".to_string(),
         }
     }
 }
@@ -96,6 +99,7 @@ impl ChainNode {
                     render_mode: render_mode.clone(),
                     auto_copy: *auto_copy,
                     auto_speak: *auto_speak,
+                    on_error: "stop".to_string(), // Input adapters can't fail
                 }
             }
             ChainNode::Special {
@@ -109,6 +113,7 @@ impl ChainNode {
                 render_mode,
                 auto_copy,
                 auto_speak,
+                on_error,
             }
             | ChainNode::Process {
                 id,
@@ -121,6 +126,7 @@ impl ChainNode {
                 render_mode,
                 auto_copy,
                 auto_speak,
+                on_error,
             } => ProcessingBlock {
                 id: id.clone(),
                 block_type: block_type.clone(),
@@ -133,6 +139,7 @@ impl ChainNode {
                 render_mode: render_mode.clone(),
                 auto_copy: *auto_copy,
                 auto_speak: *auto_speak,
+                on_error: on_error.clone(),
             },
         }
     }
@@ -179,6 +186,7 @@ impl ChainNode {
                 render_mode: block.render_mode.clone(),
                 auto_copy: block.auto_copy,
                 auto_speak: block.auto_speak,
+                on_error: block.on_error.clone(),
             },
             _ => ChainNode::Process {
                 id: block.id.clone(),
@@ -191,6 +199,7 @@ impl ChainNode {
                 render_mode: block.render_mode.clone(),
                 auto_copy: block.auto_copy,
                 auto_speak: block.auto_speak,
+                on_error: block.on_error.clone(),
             },
         }
     }
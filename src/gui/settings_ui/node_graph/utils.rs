@@ -1,4 +1,4 @@
-use crate::config::get_all_languages;
+use crate::config::{get_all_languages, PromptSnippet, SYSTEM_LANGUAGE};
 use crate::model_config::model_supports_search_by_id;
 use eframe::egui;
 use std::collections::HashMap;
@@ -15,14 +15,41 @@ pub fn request_node_graph_view_reset(ctx: &egui::Context) {
     ctx.data_mut(|d| d.insert_temp(reset_id, true));
 }
 
+/// Request the opened preset's saved zoom/pan on the next frame
+/// (falls back to a view reset when the preset has none)
+pub fn request_node_graph_view_restore(ctx: &egui::Context) {
+    let restore_id = egui::Id::new("snarl_restore_view");
+    ctx.data_mut(|d| d.insert_temp(restore_id, true));
+}
+
+/// Take a pending restore request (see `request_node_graph_view_restore`)
+pub fn take_node_graph_view_restore(ctx: &egui::Context) -> bool {
+    let restore_id = egui::Id::new("snarl_restore_view");
+    ctx.data_mut(|d| d.remove_temp::<bool>(restore_id))
+        .unwrap_or(false)
+}
+
 pub fn show_language_vars(
     ui: &mut egui::Ui,
-    _ui_language: &str,
+    ui_language: &str,
     prompt: &str,
     language_vars: &mut HashMap<String, String>,
     changed: &mut bool,
     _search_query: &mut String,
 ) {
+    let keyboard_label = match ui_language {
+        "vi" => "⌨ Ngôn ngữ bàn phím",
+        "ko" => "⌨ 키보드 언어",
+        "ja" => "⌨ キーボードの言語",
+        _ => "⌨ Keyboard language",
+    };
+    let keyboard_tip = match ui_language {
+        "vi" => "Dùng ngôn ngữ của bố cục bàn phím đang bật trong ứng dụng bạn đang dùng",
+        "ko" => "사용 중인 앱에서 켜져 있는 키보드 레이아웃의 언어를 사용",
+        "ja" => "使用中のアプリで有効なキーボードレイアウトの言語を使う",
+        _ => "Use the language of the keyboard layout active in the app you were in",
+    };
+
     // Find {languageN} tags in prompt
     let mut detected_vars = Vec::new();
     for k in 1..=10 {
@@ -55,8 +82,13 @@ pub fn show_language_vars(
             } else {
                 egui::Color32::from_rgb(150, 140, 180)
             };
+            let shown_val = if current_val == SYSTEM_LANGUAGE {
+                keyboard_label
+            } else {
+                current_val.as_str()
+            };
             let button_response = ui.add(
-                egui::Button::new(egui::RichText::new(&current_val).color(egui::Color32::WHITE))
+                egui::Button::new(egui::RichText::new(shown_val).color(egui::Color32::WHITE))
                     .fill(lang_var_bg)
                     .corner_radius(8.0),
             );
@@ -87,6 +119,18 @@ pub fn show_language_vars(
 
                     ui.separator();
 
+                    // Follows the keyboard layout at run time
+                    if ui
+                        .selectable_label(current_val == SYSTEM_LANGUAGE, keyboard_label)
+                        .on_hover_text(keyboard_tip)
+                        .clicked()
+                    {
+                        language_vars.insert(key.clone(), SYSTEM_LANGUAGE.to_string());
+                        *changed = true;
+                        egui::Popup::toggle_id(ui.ctx(), popup_layer_id);
+                    }
+                    ui.separator();
+
                     // Language list in scroll area
                     egui::ScrollArea::vertical()
                         .max_height(200.0)
@@ -116,14 +160,19 @@ pub fn show_language_vars(
     }
 }
 
-pub fn insert_next_language_tag(prompt: &mut String, language_vars: &mut HashMap<String, String>) {
+/// The highest `{languageN}` tag in the prompt plus one
+fn next_language_number(prompt: &str) -> usize {
     let mut max_num = 0;
     for k in 1..=10 {
         if prompt.contains(&format!("{{language{}}}", k)) {
             max_num = k;
         }
     }
-    let next_num = max_num + 1;
+    max_num + 1
+}
+
+pub fn insert_next_language_tag(prompt: &mut String, language_vars: &mut HashMap<String, String>) {
+    let next_num = next_language_number(prompt);
     let tag = format!(" {{language{}}} ", next_num);
     prompt.push_str(&tag);
 
@@ -132,3 +181,50 @@ pub fn insert_next_language_tag(prompt: &mut String, language_vars: &mut HashMap
         language_vars.insert(key, "Vietnamese".to_string());
     }
 }
+
+/// Append a saved snippet to the prompt. Its `{languageN}` tags are renumbered
+/// to follow the prompt's own, and each gets the language the snippet was saved with.
+pub fn insert_prompt_snippet(
+    prompt: &mut String,
+    language_vars: &mut HashMap<String, String>,
+    snippet: &PromptSnippet,
+) {
+    let mut next_num = next_language_number(prompt);
+    // Two passes through placeholders so {language1} -> {language2} can't be renumbered twice
+    let mut text = snippet.prompt.clone();
+    let mut renumbered = Vec::new();
+    for k in 1..=10 {
+        let tag = format!("{{language{}}}", k);
+        if !text.contains(&tag) {
+            continue;
+        }
+        let placeholder = format!("\u{0}{}\u{0}", k);
+        text = text.replace(&tag, &placeholder);
+        renumbered.push((placeholder, k, next_num));
+        next_num += 1;
+    }
+    for (placeholder, old_num, new_num) in renumbered {
+        text = text.replace(&placeholder, &format!("{{language{}}}", new_num));
+        let language = snippet
+            .language_vars
+            .get(&format!("language{}", old_num))
+            .cloned()
+            .unwrap_or_else(|| "Vietnamese".to_string());
+        language_vars.insert(format!("language{}", new_num), language);
+    }
+
+    if !prompt.is_empty() && !prompt.ends_with(char::is_whitespace) {
+        prompt.push(' ');
+    }
+    prompt.push_str(&text);
+}
+
+/// Tooltip for prompt editors listing the variables filled in at run time
+pub fn prompt_vars_hint(ui_language: &str) -> &'static str {
+    match ui_language {
+        "vi" => "Biến có sẵn: {language1}…, {date}, {time}, {active_app} (tên ứng dụng đang dùng)",
+        "ko" => "기본 변수: {language1}…, {date}, {time}, {active_app} (사용 중인 앱 이름)",
+        "ja" => "組み込み変数: {language1}…, {date}, {time}, {active_app}(使用中のアプリ名)",
+        _ => "Built-in variables: {language1}…, {date}, {time}, {active_app} (the app you were in)",
+    }
+}
@@ -1,10 +1,13 @@
 use super::body::show_body;
 use super::node::ChainNode;
+use crate::config::{NodeGraphView, PromptSnippet};
 use crate::gui::icons::{draw_icon_static, Icon};
 use crate::gui::locale::LocaleText;
 use eframe::egui;
+use eframe::egui::emath::TSTransform;
 use egui_snarl::ui::{PinInfo, SnarlViewer};
 use egui_snarl::{InPin, NodeId, OutPin, Snarl};
+use std::collections::HashMap;
 
 pub struct ChainViewer<'a> {
     pub text: &'a LocaleText,
@@ -15,7 +18,21 @@ pub struct ChainViewer<'a> {
     pub use_gemini: bool,
     pub use_openrouter: bool,
     pub use_ollama: bool,
+    pub use_anthropic: bool,
+    pub use_custom_openai: bool,
+    /// Providers whose API key (or endpoint) is missing in Config
+    pub unconfigured_providers: Vec<String>,
     pub preset_type: String, // "image", "audio", "text"
+    /// Text presets' input mode, editable from the Input node
+    pub text_input_mode: String,
+    /// Config's saved prompts, offered in every prompt row
+    pub prompt_snippets: &'a mut Vec<PromptSnippet>,
+    /// Preset whose graph is shown, the key into `graph_views`
+    pub preset_id: String,
+    /// Config's saved zoom/pan per preset, kept up to date while the graph is shown
+    pub graph_views: &'a mut HashMap<String, NodeGraphView>,
+    /// Apply the saved zoom/pan instead of recording the current one (this frame only)
+    pub restore_view: bool,
 }
 
 impl<'a> ChainViewer<'a> {
@@ -27,7 +44,14 @@ impl<'a> ChainViewer<'a> {
         use_gemini: bool,
         use_openrouter: bool,
         use_ollama: bool,
+        use_anthropic: bool,
+        use_custom_openai: bool,
+        unconfigured_providers: Vec<String>,
         preset_type: &str,
+        text_input_mode: &str,
+        prompt_snippets: &'a mut Vec<PromptSnippet>,
+        preset_id: &str,
+        graph_views: &'a mut HashMap<String, NodeGraphView>,
     ) -> Self {
         Self {
             text,
@@ -38,7 +62,15 @@ impl<'a> ChainViewer<'a> {
             use_gemini,
             use_openrouter,
             use_ollama,
+            use_anthropic,
+            use_custom_openai,
+            unconfigured_providers,
             preset_type: preset_type.to_string(),
+            text_input_mode: text_input_mode.to_string(),
+            prompt_snippets,
+            preset_id: preset_id.to_string(),
+            graph_views,
+            restore_view: false,
         }
     }
 
@@ -49,9 +81,37 @@ impl<'a> ChainViewer<'a> {
             "google" | "gemini-live" => self.use_gemini,
             "openrouter" => self.use_openrouter,
             "ollama" => self.use_ollama,
+            "anthropic" => self.use_anthropic,
+            "custom-openai" => self.use_custom_openai,
             _ => true, // Unknown providers are enabled by default
         }
     }
+
+    /// Why a model can't run right now: its provider has no API key, or it is an
+    /// Ollama model the last scan did not find installed
+    pub fn model_unavailable_reason(&self, model_id: &str, provider: &str) -> Option<&'static str> {
+        if self.unconfigured_providers.iter().any(|p| p == provider) {
+            return Some(match self.ui_language.as_str() {
+                "vi" => "Chưa nhập API key cho nhà cung cấp này",
+                "ko" => "이 제공자의 API 키가 없습니다",
+                "ja" => "このプロバイダーの API キーが未設定です",
+                _ => "API key for this provider is missing",
+            });
+        }
+        let is_ollama = provider == "ollama" || model_id.starts_with("ollama-");
+        if is_ollama
+            && self.use_ollama
+            && crate::model_config::is_ollama_model_installed(model_id) == Some(false)
+        {
+            return Some(match self.ui_language.as_str() {
+                "vi" => "Model chưa được tải về Ollama (ollama pull)",
+                "ko" => "Ollama에 받지 않은 모델입니다 (ollama pull)",
+                "ja" => "Ollama に未取得のモデルです (ollama pull)",
+                _ => "Model not pulled in Ollama (ollama pull)",
+            });
+        }
+        None
+    }
 }
 
 impl<'a> SnarlViewer<ChainNode> for ChainViewer<'a> {
@@ -178,6 +238,23 @@ impl<'a> SnarlViewer<ChainNode> for ChainViewer<'a> {
         PinInfo::circle().with_fill(egui::Color32::from_rgb(100, 150, 255))
     }
 
+    fn current_transform(&mut self, to_global: &mut TSTransform, _snarl: &mut Snarl<ChainNode>) {
+        if std::mem::take(&mut self.restore_view) {
+            if let Some(view) = self.graph_views.get(&self.preset_id) {
+                *to_global = TSTransform::new(egui::vec2(view.pan_x, view.pan_y), view.scale);
+                return;
+            }
+        }
+        let view = NodeGraphView {
+            scale: to_global.scaling,
+            pan_x: to_global.translation.x,
+            pan_y: to_global.translation.y,
+        };
+        if self.graph_views.get(&self.preset_id) != Some(&view) {
+            self.graph_views.insert(self.preset_id.clone(), view);
+        }
+    }
+
     fn has_body(&mut self, _node: &ChainNode) -> bool {
         true
     }
@@ -230,6 +307,7 @@ impl<'a> SnarlViewer<ChainNode> for ChainViewer<'a> {
                     render_mode,
                     auto_copy,
                     auto_speak,
+                    on_error,
                 } = node
                 {
                     node = ChainNode::Special {
@@ -243,6 +321,7 @@ impl<'a> SnarlViewer<ChainNode> for ChainViewer<'a> {
                         render_mode,
                         auto_copy,
                         auto_speak,
+                        on_error,
                     };
                 }
                 snarl.insert_node(pos, node);
@@ -267,6 +346,7 @@ impl<'a> SnarlViewer<ChainNode> for ChainViewer<'a> {
         let delete_label = match self.ui_language.as_str() {
             "vi" => "🗑 Xóa node",
             "ko" => "🗑 노드 삭제",
+            "ja" => "🗑 ノードを削除",
             _ => "🗑 Delete Node",
         };
 
@@ -80,7 +80,21 @@ pub fn render_preset_editor(
                         changed = true;
                     }
                 }
-                
+
+                // Section this favorite is grouped under in the bubble panel
+                if preset.is_favorite {
+                    let (category_label, category_hint) = match config.ui_language.as_str() {
+                        "vi" => ("Nhóm:", "Chung"),
+                        "ko" => ("분류:", "일반"),
+                        "ja" => ("カテゴリ:", "一般"),
+                        _ => ("Category:", "General"),
+                    };
+                    ui.label(category_label);
+                    if ui.add(egui::TextEdit::singleline(&mut preset.category).hint_text(category_hint).desired_width(90.0)).changed() {
+                        changed = true;
+                    }
+                }
+
                 if is_default_preset {
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         // Restore button with subtle styling
@@ -163,20 +177,31 @@ pub fn render_preset_editor(
                 } else if preset.preset_type == "text" {
                     ui.label(text.text_input_mode_label);
                     egui::ComboBox::from_id_salt("text_input_mode_combo")
-                        .selected_text(if preset.text_input_mode == "type" { text.text_mode_type } else { text.text_mode_select })
+                        .selected_text(text_input_mode_label(text, &preset.text_input_mode))
                         .show_ui(ui, |ui| {
                             if ui.selectable_value(&mut preset.text_input_mode, "select".to_string(), text.text_mode_select).clicked() { changed = true; }
                             if ui.selectable_value(&mut preset.text_input_mode, "type".to_string(), text.text_mode_type).clicked() { changed = true; }
+                            if ui.selectable_value(&mut preset.text_input_mode, "clipboard".to_string(), text.text_mode_clipboard).clicked() { changed = true; }
                         });
                     
                     if preset.text_input_mode == "type" && !preset.show_controller_ui {
                         if ui.checkbox(&mut preset.continuous_input, text.continuous_input_label).clicked() { changed = true; }
                     }
+                    if preset.text_input_mode == "select" && preset.prompt_mode != "dynamic" && !preset.show_controller_ui {
+                        let confirm_label = match config.ui_language.as_str() {
+                            "vi" => "Xem & sửa trước khi gửi",
+                            "ko" => "보내기 전에 확인 및 편집",
+                            "ja" => "送信前に確認・編集",
+                            _ => "Review & edit before sending",
+                        };
+                        if ui.checkbox(&mut preset.confirm_selection, confirm_label).clicked() { changed = true; }
+                    }
                 } else if preset.preset_type == "audio" {
                     if !preset.show_controller_ui {
                         let mode_label = match config.ui_language.as_str() {
                             "vi" => "Phương thức:",
                             "ko" => "작동 방식:",
+                            "ja" => "動作モード:",
                             _ => "Mode:",
                         };
                         ui.label(mode_label);
@@ -184,11 +209,13 @@ pub fn render_preset_editor(
                         let mode_record = match config.ui_language.as_str() {
                             "vi" => "Thu âm rồi xử lý",
                             "ko" => "녹음 후 처리",
+                            "ja" => "録音後に処理",
                             _ => "Record then Process",
                         };
                         let mode_realtime = match config.ui_language.as_str() {
                             "vi" => "Xử lý thời gian thực",
                             "ko" => "실시간 처리",
+                            "ja" => "リアルタイム処理",
                             _ => "Realtime Processing",
                         };
                         
@@ -217,6 +244,7 @@ pub fn render_preset_editor(
                       let window_mode_label = match config.ui_language.as_str() {
                           "vi" => "Giao diện:",
                           "ko" => "인터페이스:",
+                          "ja" => "インターフェース:",
                           _ => "Interface:",
                       };
                       ui.label(window_mode_label);
@@ -224,11 +252,13 @@ pub fn render_preset_editor(
                       let mode_standard = match config.ui_language.as_str() {
                           "vi" => "Tiêu chuẩn",
                           "ko" => "표준",
+                          "ja" => "標準",
                           _ => "Standard",
                       };
                       let mode_minimal = match config.ui_language.as_str() {
                           "vi" => "Tối giản",
                           "ko" => "최소",
+                          "ja" => "最小",
                           _ => "Minimal",
                       };
 
@@ -281,6 +311,94 @@ pub fn render_preset_editor(
                         });
                 });
             }
+
+            // Row 3c: Fixed region / aspect lock for repeated captures of the same spot
+            if preset.preset_type == "image" && !preset.is_master && !preset.show_controller_ui {
+                ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    let region_label = match config.ui_language.as_str() {
+                        "vi" => "Vùng chụp cố định:",
+                        "ko" => "고정 캡처 영역:",
+                        "ja" => "固定キャプチャ範囲:",
+                        _ => "Fixed region:",
+                    };
+                    ui.label(region_label);
+                    match preset.fixed_region {
+                        Some(r) => {
+                            ui.label(format!("{}×{} @ ({}, {})", r.right - r.left, r.bottom - r.top, r.left, r.top));
+                        }
+                        None => {
+                            let none_label = match config.ui_language.as_str() {
+                                "vi" => "Không (kéo chọn)",
+                                "ko" => "없음 (드래그)",
+                                "ja" => "なし (ドラッグ)",
+                                _ => "None (drag)",
+                            };
+                            ui.weak(none_label);
+                        }
+                    }
+
+                    // The region of the last image capture, from any preset
+                    let last_region = crate::APP.lock().ok().and_then(|app| {
+                        app.last_run.as_ref().and_then(|last| match last.input {
+                            crate::overlay::last_run::LastRunInput::Image(_) => Some(crate::config::CaptureRegion::from_rect(last.rect)),
+                            _ => None,
+                        })
+                    });
+                    let (use_last_label, use_last_tooltip) = match config.ui_language.as_str() {
+                        "vi" => ("Dùng vùng vừa chọn", "Giữ Shift khi nhấn phím tắt để kéo chọn vùng khác một lần"),
+                        "ko" => ("마지막 영역 사용", "단축키를 누를 때 Shift를 누르고 있으면 한 번만 자유롭게 드래그합니다"),
+                        "ja" => ("前回の範囲を使う", "ホットキーを押すときに Shift を押していると、その回だけ自由に範囲を選べます"),
+                        _ => ("Use last region", "Hold Shift when pressing the hotkey to drag a different region once"),
+                    };
+                    if ui.add_enabled(last_region.is_some(), egui::Button::new(use_last_label)).on_hover_text(use_last_tooltip).clicked() {
+                        preset.fixed_region = last_region;
+                        changed = true;
+                    }
+                    if preset.fixed_region.is_some() && ui.small_button("✕").clicked() {
+                        preset.fixed_region = None;
+                        changed = true;
+                    }
+                });
+
+                if preset.fixed_region.is_none() {
+                    ui.horizontal(|ui| {
+                        let aspect_label = match config.ui_language.as_str() {
+                            "vi" => "Khóa tỉ lệ khung:",
+                            "ko" => "비율 고정:",
+                            "ja" => "縦横比の固定:",
+                            _ => "Lock aspect ratio:",
+                        };
+                        let free_label = match config.ui_language.as_str() {
+                            "vi" => "Tự do",
+                            "ko" => "자유",
+                            "ja" => "自由",
+                            _ => "Free",
+                        };
+                        ui.label(aspect_label);
+                        const RATIOS: [(&str, f32); 5] = [("16:9", 16.0 / 9.0), ("4:3", 4.0 / 3.0), ("1:1", 1.0), ("21:9", 21.0 / 9.0), ("9:16", 9.0 / 16.0)];
+                        let selected = preset
+                            .lock_aspect
+                            .map(|a| RATIOS.iter().find(|(_, r)| (r - a).abs() < 0.01).map_or_else(|| format!("{:.2}", a), |(name, _)| name.to_string()))
+                            .unwrap_or_else(|| free_label.to_string());
+                        egui::ComboBox::from_id_salt("lock_aspect_combo")
+                            .selected_text(selected)
+                            .show_ui(ui, |ui| {
+                                if ui.selectable_label(preset.lock_aspect.is_none(), free_label).clicked() {
+                                    preset.lock_aspect = None;
+                                    changed = true;
+                                }
+                                for (name, ratio) in RATIOS {
+                                    let is_selected = preset.lock_aspect.is_some_and(|a| (a - ratio).abs() < 0.01);
+                                    if ui.selectable_label(is_selected, name).clicked() {
+                                        preset.lock_aspect = Some(ratio);
+                                        changed = true;
+                                    }
+                                }
+                            });
+                    });
+                }
+            }
         });
 
     ui.add_space(8.0);
@@ -296,6 +414,18 @@ pub fn render_preset_editor(
             // Auto Newline: visible when any (non-input-adapter) block has auto_copy
             // Since has_any_auto_copy already excludes input_adapter, we can show it directly
             if ui.checkbox(&mut preset.auto_paste_newline, text.auto_paste_newline_label).clicked() { changed = true; }
+
+            let mut append = preset.auto_copy_mode == "append";
+            let append_label = match config.ui_language.as_str() {
+                "vi" => "Nối vào clipboard",
+                "ko" => "클립보드에 이어 붙이기",
+                "ja" => "クリップボードに追記",
+                _ => "Append to clipboard",
+            };
+            if ui.checkbox(&mut append, append_label).clicked() {
+                preset.auto_copy_mode = if append { "append" } else { "replace" }.to_string();
+                changed = true;
+            }
         });
     } else if !has_any_auto_copy {
         // No auto_copy means auto_paste must be off
@@ -305,6 +435,42 @@ pub fn render_preset_editor(
         }
     }
 
+    // Overlay accent color
+    if !preset.show_controller_ui {
+        ui.horizontal(|ui| {
+            let mut custom = !preset.glow_color.is_empty();
+            let label = match config.ui_language.as_str() {
+                "vi" => "Màu viền riêng cho cửa sổ kết quả",
+                "ko" => "결과 창 강조 색상 지정",
+                "ja" => "結果ウィンドウのアクセント色を指定",
+                _ => "Custom overlay accent color",
+            };
+            if ui.checkbox(&mut custom, label).clicked() {
+                preset.glow_color = if custom { "#00c8ff".to_string() } else { String::new() };
+                changed = true;
+            }
+            if let Some(rgb) = preset.glow_color_rgb() {
+                let mut color = [(rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8];
+                if ui.color_edit_button_srgb(&mut color).changed() {
+                    preset.glow_color = format!("#{:02x}{:02x}{:02x}", color[0], color[1], color[2]);
+                    changed = true;
+                }
+            }
+        });
+
+        // Result window auto-close (0 = off)
+        ui.horizontal(|ui| {
+            let label = match config.ui_language.as_str() {
+                "vi" => "Tự đóng cửa sổ kết quả sau (giây, 0 = tắt):",
+                "ko" => "결과 창 자동 닫기 (초, 0 = 끄기):",
+                "ja" => "結果ウィンドウを自動で閉じる (秒、0 = オフ):",
+                _ => "Auto-close result windows after (s, 0 = off):",
+            };
+            ui.label(label);
+            if ui.add(egui::DragValue::new(&mut preset.auto_close_secs).range(0..=600)).changed() { changed = true; }
+        });
+    }
+
     ui.add_space(10.0);
 
     // Hotkeys - always visible, even when controller UI is enabled
@@ -357,11 +523,17 @@ pub fn render_preset_editor(
         
         let mut hotkey_to_remove = None;
         for (h_idx, hotkey) in preset.hotkeys.iter().enumerate() {
-            if ui.add(egui::Button::new(egui::RichText::new(format!("{} ×", hotkey.name)).color(egui::Color32::WHITE).small())
-                .fill(hotkey_bg)
+            // Bindings shared with another preset or a global hotkey only fire once - flag them
+            let conflict = config.check_hotkey_conflict(hotkey.code, hotkey.modifiers, Some(preset_idx));
+            let bg = if conflict.is_some() { egui::Color32::from_rgb(180, 60, 60) } else { hotkey_bg };
+            let mut btn = ui.add(egui::Button::new(egui::RichText::new(format!("{} ×", hotkey.name)).color(egui::Color32::WHITE).small())
+                .fill(bg)
                 .corner_radius(10.0))
-                .on_hover_cursor(egui::CursorIcon::PointingHand)
-                .clicked() { 
+                .on_hover_cursor(egui::CursorIcon::PointingHand);
+            if let Some(msg) = conflict {
+                btn = btn.on_hover_text(format!("⚠ {}", msg));
+            }
+            if btn.clicked() { 
                 hotkey_to_remove = Some(h_idx); 
             }
         }
@@ -391,7 +563,8 @@ pub fn render_preset_editor(
                 .corner_radius(8.0)
                 .show(ui, |ui| {
                     ui.set_min_height(325.0); // Allocate space for the graph
-                    if render_node_graph(ui, snarl, &config.ui_language, &preset.prompt_mode, config.use_groq, config.use_gemini, config.use_openrouter, config.use_ollama, &preset.preset_type, text) {
+                    let unconfigured = crate::model_config::unconfigured_providers(config);
+                    if render_node_graph(ui, snarl, &config.ui_language, &preset.prompt_mode, config.use_groq, config.use_gemini, config.use_openrouter, config.use_ollama, config.use_anthropic, config.use_custom_openai, unconfigured, &preset.preset_type, &mut preset.text_input_mode, text, &mut config.prompt_snippets, &preset.id, &mut config.node_graph_views) {
                         changed = true;
                     }
                 });
@@ -434,12 +607,14 @@ pub fn render_preset_editor(
                     match config.ui_language.as_str() {
                         "vi" => "Xử lý âm thanh (Thời gian thực)",
                         "ko" => "오디오 처리 (실시간)",
+                        "ja" => "音声処理（リアルタイム）",
                         _ => "Audio Processing (Realtime)",
                     }
                 } else {
                     match config.ui_language.as_str() {
                         "vi" => "Chế độ Bộ điều khiển",
                         "ko" => "컨트롤러 모드",
+                        "ja" => "コントローラーモード",
                         _ => "Controller Mode",
                     }
                 };
@@ -452,16 +627,33 @@ pub fn render_preset_editor(
                     match config.ui_language.as_str() {
                         "vi" => "Chế độ này cung cấp phụ đề và dịch thuật trực tiếp theo thời gian thực.\nMã API của Gemini là bắt buộc, tính năng chỉ hoạt động tốt trên âm thanh có lời nói to rõ như podcast!\n\nBạn có thể điều chỉnh cỡ chữ, nguồn âm thanh và ngôn ngữ dịch ngay trong cửa sổ kết quả.",
                         "ko" => "이 모드는 실시간 자막 및 번역을 제공합니다.\nGemini API 키가 필수이며, 명확한 음성이 있는 팟캐스트 같은 오디오에서 잘 작동합니다!\n\n결과 창에서 글꼴 크기, 오디오 소스, 번역 언어를 직접 조정할 수 있습니다.",
+                        "ja" => "このモードはリアルタイムの文字起こしと翻訳を提供します。\nGemini API キーが必要で、ポッドキャストのように音声が明瞭なオーディオで最もよく動作します！\n\n結果ウィンドウで文字サイズ、音声ソース、翻訳言語を直接調整できます。",
                         _ => "This mode provides real-time transcription and translation.\nGemini API key is required, works best on audio with clear speech like podcasts!\n\nYou can adjust font size, audio source, and translation language directly in the result window.",
                     }
                 } else {
                     match config.ui_language.as_str() {
                         "vi" => "Đây là cấu hình MASTER. Khi kích hoạt, một bánh xe chọn sẽ xuất hiện để bạn chọn cấu hình muốn sử dụng.\n\nChỉ cần gán một phím tắt để truy cập nhanh nhiều cấu hình khác nhau.",
                         "ko" => "이것은 MASTER 프리셋입니다. 활성화하면 프리셋 휠이 나타나 사용할 프리셋을 선택할 수 있습니다.\n\n하나의 단축키로 여러 프리셋에 빠르게 접근하세요.",
+                        "ja" => "これは MASTER プリセットです。起動すると選択ホイールが表示され、使用するプリセットを選べます。\n\n1 つのホットキーで複数のプリセットにすばやくアクセスできます。",
                         _ => "This is a MASTER preset. When activated, a selection wheel will appear letting you choose which preset to use.\n\nAssign a single hotkey for quick access to multiple presets.",
                     }
                 };
                 ui.label(egui::RichText::new(desc).color(text_color));
+
+                if preset.is_master && !is_realtime {
+                    ui.add_space(16.0);
+                    let (mode_label, mode_wheel, mode_cycle) = match config.ui_language.as_str() {
+                        "vi" => ("Cách chọn:", "Bánh xe", "Nhấn phím tắt lặp lại để chuyển"),
+                        "ko" => ("선택 방식:", "휠", "단축키를 반복해서 눌러 순환"),
+                        "ja" => ("選択方法:", "ホイール", "ホットキーを繰り返し押して切り替え"),
+                        _ => ("Pick with:", "Wheel", "Repeated hotkey presses (cycle)"),
+                    };
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new(mode_label).color(text_color));
+                        if ui.radio_value(&mut preset.master_mode, "wheel".to_string(), mode_wheel).clicked() { changed = true; }
+                        if ui.radio_value(&mut preset.master_mode, "cycle".to_string(), mode_cycle).clicked() { changed = true; }
+                    });
+                }
             });
     }
 
@@ -476,6 +668,15 @@ pub fn render_preset_editor(
     changed
 }
 
+/// Display name of a text preset's input mode
+pub fn text_input_mode_label(text: &LocaleText, mode: &str) -> &'static str {
+    match mode {
+        "type" => text.text_mode_type,
+        "clipboard" => text.text_mode_clipboard,
+        _ => text.text_mode_select,
+    }
+}
+
 /// Creates a default processing block based on preset type
 fn create_default_block_for_type(preset_type: &str) -> ProcessingBlock {
     match preset_type {
@@ -11,6 +11,7 @@ pub fn get_localized_preset_name(preset_id: &str, lang_code: &str) -> String {
         return match lang_code {
             "vi" => "Tất tần tật".to_string(),
             "ko" => "이것의 모든 것".to_string(),
+            "ja" => "使い方ガイド".to_string(),
             _ => "101 on this".to_string(),
         };
     }
@@ -25,6 +26,7 @@ pub fn get_localized_preset_name(preset_id: &str, lang_code: &str) -> String {
         ("preset_ocr", "vi") => "Lấy text từ ảnh".to_string(),
         ("preset_quick_screenshot", "vi") => "Chụp MH nhanh".to_string(),
         ("preset_quick_screenshot", "ko") => "빠른 스크린샷".to_string(),
+        ("preset_quick_screenshot", "ja") => "クイックスクリーンショット".to_string(),
         ("preset_quick_screenshot", _) => "Quick screenshot".to_string(),
         ("preset_ocr_read", "vi") => "Đọc vùng này".to_string(),
         ("preset_summarize", "vi") => "Tóm tắt vùng".to_string(),
@@ -125,6 +127,59 @@ pub fn get_localized_preset_name(preset_id: &str, lang_code: &str) -> String {
         ("preset_audio_mic_master", "ko") => "마이크 마스터".to_string(),
         ("preset_audio_device_master", "ko") => "사운드 마스터".to_string(),
 
+        // Japanese
+        ("preset_translate", "ja") => "範囲翻訳".to_string(),
+        ("preset_extract_retranslate", "ja") => "範囲翻訳（高精度）".to_string(),
+        ("preset_translate_auto_paste", "ja") => "範囲翻訳（自動貼付）".to_string(),
+        ("preset_translate_retranslate", "ja") => "範囲翻訳+再翻訳".to_string(),
+        ("preset_extract_retrans_retrans", "ja") => "範囲翻訳（高精度）+再翻訳".to_string(),
+        ("preset_ocr", "ja") => "テキスト抽出".to_string(),
+        ("preset_ocr_read", "ja") => "範囲を読み上げ".to_string(),
+        ("preset_summarize", "ja") => "範囲を要約".to_string(),
+        ("preset_desc", "ja") => "画像を説明".to_string(),
+        ("preset_ask_image", "ja") => "画像について質問".to_string(),
+        ("preset_translate_select", "ja") => "翻訳（選択テキスト）".to_string(),
+        ("preset_translate_arena", "ja") => "翻訳（アリーナ）".to_string(),
+        ("preset_read_aloud", "ja") => "読み上げ".to_string(),
+        ("preset_trans_retrans_select", "ja") => "翻訳+再翻訳（選択）".to_string(),
+        ("preset_select_translate_replace", "ja") => "選択-翻訳-置換".to_string(),
+        ("preset_fix_grammar", "ja") => "文法を修正".to_string(),
+        ("preset_rephrase", "ja") => "言い換え".to_string(),
+        ("preset_make_formal", "ja") => "丁寧な文体に".to_string(),
+        ("preset_explain", "ja") => "説明".to_string(),
+        ("preset_ask_text", "ja") => "テキストについて質問...".to_string(),
+        ("preset_edit_as_follows", "ja") => "次のように編集:".to_string(),
+        ("preset_extract_table", "ja") => "表を抽出".to_string(),
+        ("preset_qr_scanner", "ja") => "QR スキャン".to_string(),
+        ("preset_trans_retrans_typing", "ja") => "翻訳+再翻訳（入力）".to_string(),
+        ("preset_ask_ai", "ja") => "AI に質問".to_string(),
+        ("preset_internet_search", "ja") => "インターネット検索".to_string(),
+        ("preset_make_game", "ja") => "ゲームを作る".to_string(),
+        ("preset_transcribe", "ja") => "音声を文字起こし".to_string(),
+        ("preset_fix_pronunciation", "ja") => "発音を矯正".to_string(),
+        ("preset_study_language", "ja") => "語学学習".to_string(),
+        ("preset_transcribe_retranslate", "ja") => "外国人へのクイック返信 1".to_string(),
+        ("preset_quicker_foreigner_reply", "ja") => "外国人へのクイック返信 2".to_string(),
+        ("preset_fact_check", "ja") => "ファクトチェック".to_string(),
+        ("preset_omniscient_god", "ja") => "全知全能の神".to_string(),
+
+        ("preset_realtime_audio_translate", "ja") => "リアルタイム音声翻訳".to_string(),
+        ("preset_quick_ai_question", "ja") => "AI にクイック質問".to_string(),
+        ("preset_voice_search", "ja") => "音声検索".to_string(),
+        ("preset_hang_image", "ja") => "画像オーバーレイ".to_string(),
+        ("preset_hang_text", "ja") => "テキストオーバーレイ".to_string(),
+        ("preset_quick_note", "ja") => "クイックメモ".to_string(),
+        ("preset_quick_record", "ja") => "クイック録音".to_string(),
+        ("preset_record_device", "ja") => "システム音声を録音".to_string(),
+        ("preset_continuous_writing_online", "ja") => "連続入力".to_string(),
+        ("preset_transcribe_english_offline", "ja") => "英語の文字起こし".to_string(),
+        // MASTER presets - Japanese
+        ("preset_image_master", "ja") => "画像マスター".to_string(),
+        ("preset_text_select_master", "ja") => "選択マスター".to_string(),
+        ("preset_text_type_master", "ja") => "入力マスター".to_string(),
+        ("preset_audio_mic_master", "ja") => "マイクマスター".to_string(),
+        ("preset_audio_device_master", "ja") => "サウンドマスター".to_string(),
+
         // English (default)
         ("preset_translate", _) => "Translate region".to_string(),
         ("preset_extract_retranslate", _) => "Trans reg (ACCURATE)".to_string(),
@@ -460,7 +515,8 @@ pub fn render_sidebar(
     }
 
     if let Some(idx) = preset_idx_to_delete {
-        config.presets.remove(idx);
+        let removed = config.presets.remove(idx);
+        crate::overlay::last_run::forget_preset(&removed.id);
         if let ViewMode::Preset(curr) = *view_mode {
             if curr >= idx && curr > 0 {
                 *view_mode = ViewMode::Preset(curr - 1);
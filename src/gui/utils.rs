@@ -181,6 +181,22 @@ pub fn configure_fonts(ctx: &egui::Context) {
         }
     }
 
+    // Kana/kanji fallback for the Japanese UI (Yu Gothic on Win 8.1+, Meiryo before that)
+    let japanese_font_name = "yu_gothic";
+    let japanese_data = std::fs::read(font_dir.join("YuGothM.ttc"))
+        .or_else(|_| std::fs::read(font_dir.join("meiryo.ttc")));
+    if let Ok(data) = japanese_data {
+        fonts.font_data.insert(
+            japanese_font_name.to_owned(),
+            std::sync::Arc::new(egui::FontData::from_owned(data)),
+        );
+        for family in [egui::FontFamily::Proportional, egui::FontFamily::Monospace] {
+            if let Some(vec) = fonts.families.get_mut(&family) {
+                vec.push(japanese_font_name.to_owned());
+            }
+        }
+    }
+
     // Force Google Sans Flex to front
     if let Some(vec) = fonts.families.get_mut(&egui::FontFamily::Proportional) {
         vec.insert(0, gs_font_name.to_owned());
@@ -436,3 +452,62 @@ pub fn apply_window_shadow_native(hwnd: HWND) {
         );
     }
 }
+
+// --- JSON File Dialogs (config import/export) ---
+
+/// Show a native Save (`save = true`) or Open dialog filtered to JSON files.
+/// Returns `None` if the user cancelled.
+pub fn pick_json_file(save: bool, default_name: &str) -> Option<std::path::PathBuf> {
+    use windows::core::HSTRING;
+    use windows::Win32::System::Com::{
+        CoCreateInstance, CoInitializeEx, CoUninitialize, CLSCTX_ALL, COINIT_APARTMENTTHREADED,
+    };
+    use windows::Win32::UI::Shell::Common::COMDLG_FILTERSPEC;
+    use windows::Win32::UI::Shell::{
+        FileOpenDialog, FileSaveDialog, IFileDialog, IFileOpenDialog, IFileSaveDialog,
+        FOS_FILEMUSTEXIST, FOS_OVERWRITEPROMPT, FOS_STRICTFILETYPES, SIGDN_FILESYSPATH,
+    };
+
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+
+        let dialog: Option<IFileDialog> = if save {
+            CoCreateInstance::<_, IFileSaveDialog>(&FileSaveDialog, None, CLSCTX_ALL)
+                .ok()
+                .and_then(|d| windows_core::Interface::cast(&d).ok())
+        } else {
+            CoCreateInstance::<_, IFileOpenDialog>(&FileOpenDialog, None, CLSCTX_ALL)
+                .ok()
+                .and_then(|d| windows_core::Interface::cast(&d).ok())
+        };
+
+        let path = dialog.and_then(|dialog| {
+            let filter_name = HSTRING::from("JSON Files (*.json)");
+            let filter_pattern = HSTRING::from("*.json");
+            let file_types = [COMDLG_FILTERSPEC {
+                pszName: windows::core::PCWSTR(filter_name.as_ptr()),
+                pszSpec: windows::core::PCWSTR(filter_pattern.as_ptr()),
+            }];
+            let _ = dialog.SetFileTypes(&file_types);
+            let _ = dialog.SetDefaultExtension(&HSTRING::from("json"));
+
+            if save {
+                let _ = dialog.SetFileName(&HSTRING::from(default_name));
+                let _ = dialog.SetOptions(FOS_OVERWRITEPROMPT | FOS_STRICTFILETYPES);
+            } else {
+                let _ = dialog.SetOptions(FOS_FILEMUSTEXIST);
+            }
+
+            // Show returns an error when the user cancels
+            dialog.Show(None).ok()?;
+            let item = dialog.GetResult().ok()?;
+            let raw = item.GetDisplayName(SIGDN_FILESYSPATH).ok()?;
+            let path = raw.to_string().ok();
+            windows::Win32::System::Com::CoTaskMemFree(Some(raw.0 as *const _));
+            path.map(std::path::PathBuf::from)
+        });
+
+        CoUninitialize();
+        path
+    }
+}
@@ -6,6 +6,10 @@ use std::path::PathBuf;
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often a streaming entry's progress is written to disk
+const PARTIAL_SAVE_INTERVAL: Duration = Duration::from_secs(1);
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum HistoryType {
@@ -21,6 +25,10 @@ pub struct HistoryItem {
     pub item_type: HistoryType,
     pub text: String,
     pub media_path: String, // Empty for Text type
+    /// Still streaming when last saved. Left set if the app quit or crashed
+    /// mid-stream, so the partial result is kept but flagged.
+    #[serde(default)]
+    pub incomplete: bool,
 }
 
 pub enum HistoryAction {
@@ -36,6 +44,17 @@ pub enum HistoryAction {
         result_text: String,
         input_text: String,
     }, // NEW: Save text-only entry
+    /// Start an incomplete image entry from the captured PNG while its result streams in
+    BeginImage {
+        id: i64,
+        png: Vec<u8>,
+        text: String,
+    },
+    UpdateText {
+        id: i64,
+        text: String,
+        complete: bool,
+    },
     Delete(i64),
     ClearAll,
     Prune(usize),
@@ -76,6 +95,30 @@ impl HistoryManager {
         let _ = self.tx.send(HistoryAction::SaveImage { img, text });
     }
 
+    /// Save an image entry as soon as its result starts streaming; keep it up to date
+    /// with `update_partial` and call `finish` once the full text has arrived
+    pub fn begin_image(&self, png: Vec<u8>, text: String) -> i64 {
+        let id = Local::now().timestamp_nanos_opt().unwrap_or(0);
+        let _ = self.tx.send(HistoryAction::BeginImage { id, png, text });
+        id
+    }
+
+    pub fn update_partial(&self, id: i64, text: String) {
+        let _ = self.tx.send(HistoryAction::UpdateText {
+            id,
+            text,
+            complete: false,
+        });
+    }
+
+    pub fn finish(&self, id: i64, text: String) {
+        let _ = self.tx.send(HistoryAction::UpdateText {
+            id,
+            text,
+            complete: true,
+        });
+    }
+
     pub fn save_audio(&self, wav_data: Vec<u8>, text: String) {
         let _ = self.tx.send(HistoryAction::SaveAudio { wav_data, text });
     }
@@ -131,6 +174,7 @@ fn process_queue(
     mut max_items: usize,
 ) {
     let (_, _, media_dir) = get_paths();
+    let mut last_partial_save = Instant::now();
 
     while let Ok(action) = rx.recv() {
         let mut should_save = false;
@@ -153,6 +197,7 @@ fn process_queue(
                             item_type: HistoryType::Image,
                             text,
                             media_path: filename,
+                            incomplete: false,
                         },
                     );
                     should_save = true;
@@ -174,6 +219,7 @@ fn process_queue(
                             item_type: HistoryType::Audio,
                             text,
                             media_path: filename,
+                            incomplete: false,
                         },
                     );
                     should_save = true;
@@ -198,9 +244,43 @@ fn process_queue(
                             item_type: HistoryType::Text,
                             text: result_text,
                             media_path: filename,
+                            incomplete: false,
+                        },
+                    );
+                    should_save = true;
+                }
+            }
+            HistoryAction::BeginImage { id, png, text } => {
+                let now = Local::now();
+                let timestamp = now.format("%Y-%m-%d %H:%M:%S").to_string();
+                let filename = format!("img_{}.png", now.format("%Y%m%d_%H%M%S_%f"));
+                let path = media_dir.join(&filename);
+
+                if fs::write(&path, png).is_ok() {
+                    items.insert(
+                        0,
+                        HistoryItem {
+                            id,
+                            timestamp,
+                            item_type: HistoryType::Image,
+                            text,
+                            media_path: filename,
+                            incomplete: true,
                         },
                     );
                     should_save = true;
+                    last_partial_save = Instant::now();
+                }
+            }
+            HistoryAction::UpdateText { id, text, complete } => {
+                if let Some(item) = items.iter_mut().find(|x| x.id == id) {
+                    item.text = text;
+                    item.incomplete = !complete;
+                    // Streaming updates are frequent: write them out at most once a second
+                    if complete || last_partial_save.elapsed() >= PARTIAL_SAVE_INTERVAL {
+                        should_save = true;
+                        last_partial_save = Instant::now();
+                    }
                 }
             }
             HistoryAction::Delete(id) => {
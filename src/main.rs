@@ -2,6 +2,7 @@
 
 mod api;
 mod assets;
+mod cli;
 mod config;
 mod debug_log;
 pub mod gui;
@@ -10,6 +11,7 @@ mod icon_gen;
 mod model_config;
 mod overlay;
 mod registry_integration;
+mod token_usage;
 mod updater;
 pub mod win_types;
 
@@ -20,6 +22,7 @@ use lazy_static::lazy_static;
 use std::collections::HashMap;
 use std::panic;
 use std::sync::{Arc, Mutex};
+use token_usage::TokenUsageManager;
 use tray_icon::menu::{CheckMenuItem, Menu, MenuItem};
 use windows::core::*;
 use windows::Win32::Foundation::*;
@@ -83,13 +86,19 @@ pub struct AppState {
     // New: Track API usage limits (Key: Model Full Name, Value: "Remaining / Total")
     pub model_usage_stats: HashMap<String, String>,
     pub history: Arc<HistoryManager>,         // NEW
+    pub token_usage: Arc<TokenUsageManager>,  // Per-preset monthly token totals
     pub last_active_window: Option<SendHwnd>, // NEW: Store window handle for auto-paste focus restoration
+    // Recent text-selection source/translation pairs, newest first
+    pub selection_history: std::collections::VecDeque<overlay::selection_history::SelectionSnippet>,
+    // Inputs of the last image/text pipeline, for the repeat-last hotkey
+    pub last_run: Option<overlay::last_run::LastRun>,
 }
 
 lazy_static! {
     pub static ref APP: Arc<Mutex<AppState>> = Arc::new(Mutex::new({
         let config = load_config();
         let history = Arc::new(HistoryManager::new(config.max_history_items));
+        let token_usage = Arc::new(TokenUsageManager::new());
         AppState {
             config,
             screenshot_handle: None,
@@ -97,7 +106,10 @@ lazy_static! {
             registered_hotkey_ids: Vec::new(),
             model_usage_stats: HashMap::new(),
             history,
+            token_usage,
             last_active_window: None, // NEW
+            selection_history: std::collections::VecDeque::new(),
+            last_run: None,
         }
     }));
 }
@@ -186,6 +198,12 @@ fn main() -> eframe::Result<()> {
     // Extract embedded CRT and DirectML DLLs so the app is truly portable
     unpack_dlls::unpack_dlls();
 
+    // --- HEADLESS CLI MODE ---
+    // e.g. `--translate-image shot.png`: print the result and exit, no windows
+    if let Some(code) = cli::run_from_args() {
+        std::process::exit(code);
+    }
+
     // --- CLEANUP TEMP FILES ---
     // Remove leftover restart scripts or partial downloads
     cleanup_temporary_files();
@@ -333,6 +351,16 @@ fn main() -> eframe::Result<()> {
                 return Ok(());
             }
             Some(handle)
+        } else if instance
+            .as_ref()
+            .is_err_and(|e| e.code() == ERROR_ACCESS_DENIED.to_hresult())
+        {
+            // The mutex exists but belongs to an elevated instance: it is still running,
+            // so don't start a second set of hotkeys and overlays next to it
+            if let Some(event) = RESTORE_EVENT.as_ref() {
+                let _ = SetEvent(event.0);
+            }
+            return Ok(());
         } else {
             None
         }
@@ -391,8 +419,18 @@ fn main() -> eframe::Result<()> {
         }
     }
 
+    // --- WEBVIEW2 RUNTIME CHECK ---
+    // Fresh Windows installs may lack it: say so instead of failing silently
+    overlay::webview_runtime::check_at_startup();
+
     // Offload warmups to a sequenced thread to prevent splash screen lag
     std::thread::spawn(|| {
+        // Without WebView2 only the native GDI windows can be warmed up
+        if !overlay::webview_runtime::is_available() {
+            overlay::text_selection::warmup();
+            return;
+        }
+
         // 0. Warmup fonts first (download/cache for instant display)
         // This runs in background and should complete before first WebView loads
         overlay::html_components::font_manager::warmup_fonts();
@@ -437,6 +475,23 @@ fn main() -> eframe::Result<()> {
         // 9. Warmup Recording Overlay
         wait_for_popup_close();
         overlay::recording::warmup_recording_overlay();
+
+        // 10. Pre-create the hidden screen recorder and Prompt DJ WebViews
+        // so their first open doesn't pay the WebView2 cold start.
+        // --no-overlay-warmup leaves them cold, to time the difference.
+        if std::env::args().any(|arg| arg == "--no-overlay-warmup") {
+            crate::log_info!(
+                "[Warmup] Screen recorder / Prompt DJ left cold (--no-overlay-warmup)"
+            );
+            return;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(2000));
+        wait_for_popup_close();
+        overlay::screen_record::warmup();
+
+        std::thread::sleep(std::time::Duration::from_millis(2000));
+        wait_for_popup_close();
+        overlay::prompt_dj::warmup();
     });
 
     // 1. Load config early to get theme setting and language for tray i18n
@@ -570,6 +625,82 @@ pub fn register_all_hotkeys(hwnd: HWND) {
             );
         }
     }
+
+    // Register Active Window Capture Hotkeys (IDs: 9800-9899)
+    for (idx, aw_hotkey) in app.config.active_window_capture_hotkeys.iter().enumerate() {
+        if idx >= 100 {
+            break;
+        }
+        if [0x04, 0x05, 0x06].contains(&aw_hotkey.code) {
+            continue;
+        }
+        let id = 9800 + idx as i32;
+        unsafe {
+            let _ = RegisterHotKey(
+                Some(hwnd),
+                id,
+                HOT_KEY_MODIFIERS(aw_hotkey.modifiers),
+                aw_hotkey.code,
+            );
+        }
+    }
+
+    // Register Realtime Stop Hotkeys (IDs: 9700-9799)
+    for (idx, rt_hotkey) in app.config.realtime_stop_hotkeys.iter().enumerate() {
+        if idx >= 100 {
+            break;
+        }
+        if [0x04, 0x05, 0x06].contains(&rt_hotkey.code) {
+            continue;
+        }
+        let id = 9700 + idx as i32;
+        unsafe {
+            let _ = RegisterHotKey(
+                Some(hwnd),
+                id,
+                HOT_KEY_MODIFIERS(rt_hotkey.modifiers),
+                rt_hotkey.code,
+            );
+        }
+    }
+
+    // Register Selection History Hotkeys (IDs: 9600-9699)
+    for (idx, sh_hotkey) in app.config.selection_history_hotkeys.iter().enumerate() {
+        if idx >= 100 {
+            break;
+        }
+        if [0x04, 0x05, 0x06].contains(&sh_hotkey.code) {
+            continue;
+        }
+        let id = 9600 + idx as i32;
+        unsafe {
+            let _ = RegisterHotKey(
+                Some(hwnd),
+                id,
+                HOT_KEY_MODIFIERS(sh_hotkey.modifiers),
+                sh_hotkey.code,
+            );
+        }
+    }
+
+    // Register Repeat Last Hotkeys (IDs: 9500-9599)
+    for (idx, rl_hotkey) in app.config.repeat_last_hotkeys.iter().enumerate() {
+        if idx >= 100 {
+            break;
+        }
+        if [0x04, 0x05, 0x06].contains(&rl_hotkey.code) {
+            continue;
+        }
+        let id = 9500 + idx as i32;
+        unsafe {
+            let _ = RegisterHotKey(
+                Some(hwnd),
+                id,
+                HOT_KEY_MODIFIERS(rl_hotkey.modifiers),
+                rl_hotkey.code,
+            );
+        }
+    }
 }
 
 pub fn unregister_all_hotkeys(hwnd: HWND) {
@@ -579,10 +710,14 @@ pub fn unregister_all_hotkeys(hwnd: HWND) {
             let _ = UnregisterHotKey(Some(hwnd), id);
         }
     }
-    // Unregister Global SR Hotkeys
+    // Unregister Global SR, Active Window Capture, Realtime Stop, Selection History and Repeat Last Hotkeys
     for idx in 0..100 {
         unsafe {
             let _ = UnregisterHotKey(Some(hwnd), 9900 + idx);
+            let _ = UnregisterHotKey(Some(hwnd), 9800 + idx);
+            let _ = UnregisterHotKey(Some(hwnd), 9700 + idx);
+            let _ = UnregisterHotKey(Some(hwnd), 9600 + idx);
+            let _ = UnregisterHotKey(Some(hwnd), 9500 + idx);
         }
     }
 }
@@ -595,6 +730,17 @@ unsafe extern "system" fn mouse_hook_proc(code: i32, wparam: WPARAM, lparam: LPA
             WM_LBUTTONDOWN | WM_RBUTTONDOWN | WM_MBUTTONDOWN => {
                 crate::overlay::screen_record::engine::IS_MOUSE_CLICKED
                     .store(true, std::sync::atomic::Ordering::SeqCst);
+                if msg != WM_MBUTTONDOWN {
+                    let info = *(lparam.0 as *const MSLLHOOKSTRUCT);
+                    let kind = if msg == WM_LBUTTONDOWN {
+                        "left_down"
+                    } else {
+                        "right_down"
+                    };
+                    crate::overlay::screen_record::engine::record_click_event(
+                        info.pt.x, info.pt.y, kind,
+                    );
+                }
                 if msg == WM_MBUTTONDOWN {
                     Some(0x04)
                 } else {
@@ -675,6 +821,47 @@ unsafe extern "system" fn mouse_hook_proc(code: i32, wparam: WPARAM, lparam: LPA
                         }
                     }
                 }
+
+                // Check Active Window Capture Hotkeys
+                if found_id.is_none() {
+                    for (idx, aw_hk) in app.config.active_window_capture_hotkeys.iter().enumerate()
+                    {
+                        if aw_hk.code == vk && aw_hk.modifiers == mods {
+                            found_id = Some(9800 + idx as i32);
+                            break;
+                        }
+                    }
+                }
+
+                // Check Realtime Stop Hotkeys
+                if found_id.is_none() {
+                    for (idx, rt_hk) in app.config.realtime_stop_hotkeys.iter().enumerate() {
+                        if rt_hk.code == vk && rt_hk.modifiers == mods {
+                            found_id = Some(9700 + idx as i32);
+                            break;
+                        }
+                    }
+                }
+
+                // Check Selection History Hotkeys
+                if found_id.is_none() {
+                    for (idx, sh_hk) in app.config.selection_history_hotkeys.iter().enumerate() {
+                        if sh_hk.code == vk && sh_hk.modifiers == mods {
+                            found_id = Some(9600 + idx as i32);
+                            break;
+                        }
+                    }
+                }
+
+                // Check Repeat Last Hotkeys
+                if found_id.is_none() {
+                    for (idx, rl_hk) in app.config.repeat_last_hotkeys.iter().enumerate() {
+                        if rl_hk.code == vk && rl_hk.modifiers == mods {
+                            found_id = Some(9500 + idx as i32);
+                            break;
+                        }
+                    }
+                }
             }
 
             if let Some(id) = found_id {
@@ -843,6 +1030,28 @@ unsafe extern "system" fn hotkey_proc(
                 crate::overlay::screen_record::toggle_recording();
                 return LRESULT(0);
             }
+            if (9800..=9899).contains(&id) {
+                // Capture the focused window and process it without region selection
+                std::thread::spawn(crate::overlay::active_window_capture::capture_active_window);
+                return LRESULT(0);
+            }
+            if (9700..=9799).contains(&id) {
+                // Stop realtime mode and save the transcript
+                std::thread::spawn(
+                    crate::overlay::realtime_webview::stop_and_save_realtime_transcript,
+                );
+                return LRESULT(0);
+            }
+            if (9600..=9699).contains(&id) {
+                // Re-show the next older text-selection result
+                crate::overlay::selection_history::show_next_snippet();
+                return LRESULT(0);
+            }
+            if (9500..=9599).contains(&id) {
+                // Run the last translation again
+                std::thread::spawn(crate::overlay::last_run::rerun_last);
+                return LRESULT(0);
+            }
             if id > 0 {
                 // debounce logic
                 static mut LAST_HOTKEY_TIMESTAMP: Option<std::time::Instant> = None;
@@ -890,6 +1099,12 @@ unsafe extern "system" fn hotkey_proc(
                     // Do NOT return. Proceed to trigger logic below.
                 }
 
+                // Cycling a MASTER preset: its hotkey steps to the next preset
+                if overlay::preset_wheel::is_cycle_active() {
+                    overlay::preset_wheel::on_cycle_hotkey(((id - 1) / 1000) as usize);
+                    return LRESULT(0);
+                }
+
                 // CRITICAL: If preset wheel is active, dismiss it and return early
                 // This allows pressing the hotkey again to dismiss the wheel
                 if overlay::preset_wheel::is_wheel_active() {
@@ -948,6 +1163,22 @@ unsafe extern "system" fn hotkey_proc(
                     }
                 };
 
+                // Shift held on top of the hotkey: this run waits for the full result
+                // (not when the hotkey already includes Shift, or a fixed-region preset
+                // takes Shift to mean "draw a free-form region")
+                let shift_down = (GetAsyncKeyState(VK_SHIFT.0 as i32) as u16 & 0x8000) != 0;
+                let streaming_override = APP.lock().ok().and_then(|app| {
+                    let preset = app.config.presets.get(preset_idx)?;
+                    let hotkey_uses_shift = preset
+                        .hotkeys
+                        .get(((id - 1) % 1000) as usize)
+                        .is_some_and(|hk| hk.modifiers & MOD_SHIFT != 0);
+                    let shift_picks_region = preset.preset_type == "image"
+                        && preset.fixed_region.is_some()
+                        && !preset.is_master;
+                    (shift_down && !hotkey_uses_shift && !shift_picks_region).then_some(false)
+                });
+
                 // FIX: Only capture target window if we are NOT stopping an audio recording.
                 if !is_audio_stopping {
                     let target_window = crate::overlay::utils::get_target_window_for_paste();
@@ -1026,11 +1257,13 @@ unsafe extern "system" fn hotkey_proc(
 
                             std::thread::spawn(move || {
                                 // 1. Show Badge IMMEDIATELY (Decoupled)
-                                overlay::show_text_selection_tag(preset_idx);
+                                overlay::show_text_selection_tag(preset_idx, streaming_override);
 
                                 // 2. Try processing in background
-                                let success =
-                                    overlay::text_selection::try_instant_process(preset_idx);
+                                let success = overlay::text_selection::try_instant_process(
+                                    preset_idx,
+                                    streaming_override,
+                                );
 
                                 if success {
                                     // If we successfully processed text, we don't need the badge anymore.
@@ -1038,6 +1271,14 @@ unsafe extern "system" fn hotkey_proc(
                                 }
                             });
                         }
+                    } else if text_mode == "clipboard" {
+                        // Clipboard Mode - Feed the current clipboard text straight in
+                        std::thread::spawn(move || {
+                            overlay::text_selection::process_clipboard(
+                                preset_idx,
+                                streaming_override,
+                            );
+                        });
                     } else {
                         // Type Mode - Toggle Logic for Input Window
                         if overlay::text_input::is_active() {
@@ -1070,6 +1311,7 @@ unsafe extern "system" fn hotkey_proc(
                                         preset,
                                         localized_name,
                                         hotkey_name_clone,
+                                        streaming_override,
                                     );
                                 });
                             }
@@ -1090,6 +1332,11 @@ unsafe extern "system" fn hotkey_proc(
                     let app_clone = APP.clone();
                     let mut p_idx = preset_idx;
                     std::thread::spawn(move || {
+                        // Presets with a fixed region capture it without the drag overlay
+                        if overlay::active_window_capture::capture_fixed_region(p_idx) {
+                            overlay::set_is_busy(false);
+                            return;
+                        }
                         loop {
                             // 1. Capture Logic
                             match capture_screen_fast() {
@@ -1101,7 +1348,7 @@ unsafe extern "system" fn hotkey_proc(
                                     }
 
                                     // 2. Show Overlay (BLOCKING)
-                                    overlay::show_selection_overlay(p_idx);
+                                    overlay::show_selection_overlay(p_idx, streaming_override);
                                 }
                                 Err(e) => {
                                     eprintln!("Capture Error: {}", e);
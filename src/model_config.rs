@@ -213,6 +213,32 @@ lazy_static::lazy_static! {
             "50 공유 요청/일",
             "50 shared requests/day"
         ),
+        ModelConfig::new(
+            "claude-haiku-vision",
+            "anthropic",
+            "Claude-Nhanh",
+            "Claude-빠름",
+            "Claude-Fast",
+            "claude-haiku-4-5",
+            ModelType::Vision,
+            true,
+            "Trả phí theo token",
+            "토큰당 과금",
+            "Pay per token"
+        ),
+        ModelConfig::new(
+            "claude-sonnet-vision",
+            "anthropic",
+            "Claude-Chính xác",
+            "Claude-정확함",
+            "Claude-Accurate",
+            "claude-sonnet-4-5",
+            ModelType::Vision,
+            true,
+            "Trả phí theo token",
+            "토큰당 과금",
+            "Pay per token"
+        ),
         ModelConfig::new(
             "text_fast_120b",
             "groq",
@@ -461,6 +487,32 @@ lazy_static::lazy_static! {
             "50 공유 요청/일",
             "50 shared requests/day"
         ),
+        ModelConfig::new(
+            "claude-haiku",
+            "anthropic",
+            "Claude-Nhanh",
+            "Claude-빠름",
+            "Claude-Fast",
+            "claude-haiku-4-5",
+            ModelType::Text,
+            true,
+            "Trả phí theo token",
+            "토큰당 과금",
+            "Pay per token"
+        ),
+        ModelConfig::new(
+            "claude-sonnet",
+            "anthropic",
+            "Claude-Chính xác",
+            "Claude-정확함",
+            "Claude-Accurate",
+            "claude-sonnet-4-5",
+            ModelType::Text,
+            true,
+            "Trả phí theo token",
+            "토큰당 과금",
+            "Pay per token"
+        ),
 
         ModelConfig::new(
             "whisper-fast",
@@ -576,7 +628,58 @@ pub fn get_all_models() -> &'static [ModelConfig] {
 }
 
 pub fn get_model_by_id(id: &str) -> Option<ModelConfig> {
-    get_all_models().iter().find(|m| m.id == id).cloned()
+    get_all_models()
+        .iter()
+        .find(|m| m.id == id)
+        .cloned()
+        .or_else(|| get_custom_openai_model(id))
+}
+
+// === OPENAI-COMPATIBLE ENDPOINT MODELS ===
+
+/// ID prefix for text models served by the OpenAI-compatible endpoint.
+/// The rest of the ID is the model's full name on the server (e.g. "custom:qwen2.5-7b").
+pub const CUSTOM_OPENAI_TEXT_PREFIX: &str = "custom:";
+/// ID prefix for vision models served by the OpenAI-compatible endpoint
+pub const CUSTOM_OPENAI_VISION_PREFIX: &str = "custom-vision:";
+
+/// Build the model ID for a full model name on the OpenAI-compatible endpoint
+pub fn custom_openai_model_id(full_name: &str, model_type: &ModelType) -> String {
+    let prefix = if *model_type == ModelType::Vision {
+        CUSTOM_OPENAI_VISION_PREFIX
+    } else {
+        CUSTOM_OPENAI_TEXT_PREFIX
+    };
+    format!("{}{}", prefix, full_name.trim())
+}
+
+/// Resolve a "custom:"/"custom-vision:" model ID into a config entry
+fn get_custom_openai_model(id: &str) -> Option<ModelConfig> {
+    let (full_name, model_type) = if let Some(name) = id.strip_prefix(CUSTOM_OPENAI_VISION_PREFIX) {
+        (name, ModelType::Vision)
+    } else if let Some(name) = id.strip_prefix(CUSTOM_OPENAI_TEXT_PREFIX) {
+        (name, ModelType::Text)
+    } else {
+        return None;
+    };
+
+    if full_name.is_empty() {
+        return None;
+    }
+
+    Some(ModelConfig {
+        id: id.to_string(),
+        provider: "custom-openai".to_string(),
+        name_vi: full_name.to_string(),
+        name_ko: full_name.to_string(),
+        name_en: full_name.to_string(),
+        full_name: full_name.to_string(),
+        model_type,
+        enabled: true,
+        quota_limit_vi: "Máy chủ riêng".to_string(),
+        quota_limit_ko: "자체 서버".to_string(),
+        quota_limit_en: "Self-hosted".to_string(),
+    })
 }
 
 /// Resolve a fallback model for retry logic
@@ -603,20 +706,6 @@ pub fn resolve_fallback_model(
         .map(|m| m.provider.as_str())
         .unwrap_or("");
 
-    // Helper to check if a provider is configured
-    let is_provider_configured = |provider: &str| -> bool {
-        match provider {
-            "groq" => !config.api_key.is_empty(),
-            "google" => !config.gemini_api_key.is_empty(),
-            "openai" => false, // We don't have openai_api_key in config struct (only openrouter/cerebras) - wait, checking Config struct..
-            // Ah, standard OpenAI is not in the Config struct I saw.
-            "openrouter" => !config.openrouter_api_key.is_empty(),
-            "cerebras" => !config.cerebras_api_key.is_empty(),
-            "ollama" => config.use_ollama, // No key needed, just enabled
-            _ => true, // Assume others (like internal ones) are "configured" or we can't check
-        }
-    };
-
     // 1. Determine requirements from the failed model
     // If the failed model supported search, the fallback MUST also support search
     let must_support_search = model_supports_search_by_id(failed_model_id);
@@ -647,7 +736,7 @@ pub fn resolve_fallback_model(
             m.provider != current_provider
                 && m.model_type == *current_model_type
                 && !failed_model_ids.contains(&m.id)
-                && is_provider_configured(&m.provider)
+                && is_provider_configured(&m.provider, config)
                 && (!must_support_search || model_supports_search_by_name(&m.full_name))
         })
         .collect();
@@ -660,6 +749,34 @@ pub fn resolve_fallback_model(
     None
 }
 
+/// Check if the provider is actually configured (has API key), so its models can be called
+pub fn is_provider_configured(provider: &str, config: &Config) -> bool {
+    match provider {
+        "groq" => !config.api_key.is_empty(),
+        "google" | "gemini-live" => !config.gemini_api_key.is_empty(),
+        "openai" => false, // Standard OpenAI has no key in Config
+        "openrouter" => !config.openrouter_api_key.is_empty(),
+        "cerebras" => !config.cerebras_api_key.is_empty(),
+        "ollama" => config.use_ollama, // No key needed, just enabled
+        "anthropic" => !config.anthropic_api_key.is_empty(),
+        "custom-openai" => config.use_custom_openai && !config.custom_openai_base_url.is_empty(),
+        _ => true, // Assume others (like internal ones) are "configured" or we can't check
+    }
+}
+
+/// Providers of known models that can't be called with the current config
+pub fn unconfigured_providers(config: &Config) -> Vec<String> {
+    let mut providers: Vec<String> = get_all_models_with_ollama()
+        .into_iter()
+        .map(|m| m.provider)
+        .chain(std::iter::once("custom-openai".to_string()))
+        .filter(|p| !is_provider_configured(p, config))
+        .collect();
+    providers.sort();
+    providers.dedup();
+    providers
+}
+
 /// Get all models including dynamically fetched Ollama models
 /// This combines static models with Ollama models (if Ollama is enabled)
 pub fn get_all_models_with_ollama() -> Vec<ModelConfig> {
@@ -726,6 +843,9 @@ lazy_static::lazy_static! {
     /// Whether a scan is currently in progress
     static ref OLLAMA_SCAN_IN_PROGRESS: AtomicBool = AtomicBool::new(false);
 
+    /// Whether a scan has finished at least once, so the cache reflects what is installed
+    static ref OLLAMA_SCANNED: AtomicBool = AtomicBool::new(false);
+
     /// Last scan time (for debouncing) - initialized to 10s ago so first scan works immediately
     static ref OLLAMA_LAST_SCAN: Mutex<std::time::Instant> = Mutex::new(
         std::time::Instant::now().checked_sub(std::time::Duration::from_secs(10)).unwrap_or_else(std::time::Instant::now)
@@ -737,6 +857,15 @@ pub fn is_ollama_scan_in_progress() -> bool {
     OLLAMA_SCAN_IN_PROGRESS.load(Ordering::SeqCst)
 }
 
+/// Whether the Ollama model is pulled locally, per the last scan (None before any scan)
+pub fn is_ollama_model_installed(id: &str) -> Option<bool> {
+    if !OLLAMA_SCANNED.load(Ordering::SeqCst) {
+        return None;
+    }
+    let cache = OLLAMA_MODEL_CACHE.lock().unwrap();
+    Some(cache.iter().any(|m| m.id == id))
+}
+
 /// Trigger background scan for Ollama models (non-blocking)
 /// Returns immediately, models will be populated in cache when ready
 pub fn trigger_ollama_model_scan() {
@@ -838,6 +967,7 @@ pub fn trigger_ollama_model_scan() {
             // Update cache
             let mut cache = OLLAMA_MODEL_CACHE.lock().unwrap();
             *cache = new_models;
+            OLLAMA_SCANNED.store(true, Ordering::SeqCst);
         }
 
         OLLAMA_SCAN_IN_PROGRESS.store(false, Ordering::SeqCst);
@@ -0,0 +1,184 @@
+//! One-shot capture of the focused window's client area, or of a preset's fixed region.
+//! Skips the region-drag overlay and feeds the pixels straight into the image pipeline.
+
+use windows::Win32::Foundation::*;
+use windows::Win32::Graphics::Gdi::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+
+use super::process::start_processing_pipeline;
+use crate::config::{Config, Preset};
+use crate::APP;
+
+/// Capture the foreground window's client rect and process it with the
+/// configured active-window preset. Runs on a worker thread.
+pub fn capture_active_window() {
+    if super::is_busy() || super::is_selection_overlay_active() {
+        return;
+    }
+
+    let hwnd = unsafe { GetForegroundWindow() };
+    if hwnd.is_invalid() {
+        return;
+    }
+
+    let rect = match client_rect_on_screen(hwnd) {
+        Some(r) => r,
+        None => {
+            crate::log_info!("[ActiveWindowCapture] Foreground window has no visible client area");
+            return;
+        }
+    };
+
+    let (config, preset) = {
+        let mut app = match APP.lock() {
+            Ok(app) => app,
+            Err(_) => return,
+        };
+        let Some(idx) = resolve_preset_idx(&app.config) else {
+            crate::log_info!("[ActiveWindowCapture] No image preset available");
+            return;
+        };
+        // Keep auto-paste pointed at the window we just captured
+        app.config.active_preset_idx = idx;
+        app.last_active_window = Some(crate::win_types::SendHwnd(hwnd));
+        (app.config.clone(), app.config.presets[idx].clone())
+    };
+
+    super::set_is_busy(true);
+    let captured = capture_screen_rect(rect);
+    super::set_is_busy(false);
+
+    match captured {
+        Some(img) => {
+            std::thread::spawn(move || {
+                start_processing_pipeline(img, rect, config, preset, None);
+            });
+        }
+        None => eprintln!("Capture Error: failed to read active window pixels"),
+    }
+}
+
+/// Capture the preset's fixed region instead of showing the region-drag overlay.
+/// Returns false when the preset has none, or Shift (when not part of the hotkey)
+/// is held to draw a free-form region this once.
+pub fn capture_fixed_region(preset_idx: usize) -> bool {
+    let (config, preset, region) = {
+        let Ok(mut app) = APP.lock() else {
+            return false;
+        };
+        let Some(preset) = app.config.presets.get(preset_idx) else {
+            return false;
+        };
+        let Some(region) = preset.fixed_region.filter(|_| !preset.is_master) else {
+            return false;
+        };
+        if free_form_override_held() {
+            return false;
+        }
+        // Keep auto-paste pointed at this preset, like a dragged selection does
+        app.config.active_preset_idx = preset_idx;
+        (
+            app.config.clone(),
+            app.config.presets[preset_idx].clone(),
+            region,
+        )
+    };
+
+    let Some(rect) = clip_to_virtual_screen(region.to_rect()) else {
+        crate::log_info!("[FixedRegion] Region is off screen");
+        return true;
+    };
+    match capture_screen_rect(rect) {
+        Some(img) => {
+            std::thread::spawn(move || {
+                start_processing_pipeline(img, rect, config, preset, None);
+            });
+        }
+        None => eprintln!("Capture Error: failed to read fixed region pixels"),
+    }
+    true
+}
+
+fn free_form_override_held() -> bool {
+    const MOD_SHIFT: u32 = 0x0004;
+    let hotkey_uses_shift = super::continuous_mode::get_current_hotkey_info()
+        .is_some_and(|(mods, _)| mods & MOD_SHIFT != 0);
+    let shift_down = unsafe {
+        windows::Win32::UI::Input::KeyboardAndMouse::GetAsyncKeyState(
+            windows::Win32::UI::Input::KeyboardAndMouse::VK_SHIFT.0 as i32,
+        ) as u16
+            & 0x8000
+            != 0
+    };
+    shift_down && !hotkey_uses_shift
+}
+
+/// Preset chosen in settings, falling back to the first image preset
+fn resolve_preset_idx(config: &Config) -> Option<usize> {
+    let is_usable = |p: &Preset| p.preset_type == "image";
+    config
+        .presets
+        .iter()
+        .position(|p| p.id == config.active_window_capture_preset_id && is_usable(p))
+        .or_else(|| config.presets.iter().position(is_usable))
+}
+
+/// Client rect of `hwnd` in screen coordinates, clipped to the virtual screen
+fn client_rect_on_screen(hwnd: HWND) -> Option<RECT> {
+    unsafe {
+        if IsIconic(hwnd).as_bool() {
+            return None;
+        }
+
+        let mut client = RECT::default();
+        GetClientRect(hwnd, &mut client).ok()?;
+
+        let mut origin = POINT { x: 0, y: 0 };
+        if !ClientToScreen(hwnd, &mut origin).as_bool() {
+            return None;
+        }
+
+        clip_to_virtual_screen(RECT {
+            left: origin.x,
+            top: origin.y,
+            right: origin.x + client.right,
+            bottom: origin.y + client.bottom,
+        })
+    }
+}
+
+/// `rect` cut down to the virtual screen, if enough of it is left to capture
+fn clip_to_virtual_screen(rect: RECT) -> Option<RECT> {
+    let (v_x, v_y, v_w, v_h) = unsafe {
+        (
+            GetSystemMetrics(SM_XVIRTUALSCREEN),
+            GetSystemMetrics(SM_YVIRTUALSCREEN),
+            GetSystemMetrics(SM_CXVIRTUALSCREEN),
+            GetSystemMetrics(SM_CYVIRTUALSCREEN),
+        )
+    };
+
+    let rect = RECT {
+        left: rect.left.max(v_x),
+        top: rect.top.max(v_y),
+        right: rect.right.min(v_x + v_w),
+        bottom: rect.bottom.min(v_y + v_h),
+    };
+
+    if rect.right - rect.left < 2 || rect.bottom - rect.top < 2 {
+        return None;
+    }
+    Some(rect)
+}
+
+/// Crop a screen-space rect out of a fresh screenshot, the same way a dragged
+/// selection is cut from the capture taken before the overlay shows
+fn capture_screen_rect(rect: RECT) -> Option<image::ImageBuffer<image::Rgba<u8>, Vec<u8>>> {
+    match crate::capture_screen_fast() {
+        Ok(capture) => Some(unsafe { super::selection::extract_crop_from_hbitmap(&capture, rect) }),
+        Err(e) => {
+            eprintln!("Capture Error: {}", e);
+            None
+        }
+    }
+}
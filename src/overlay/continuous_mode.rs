@@ -98,6 +98,7 @@ pub fn show_activation_notification(preset_id: &str, hotkey_name: &str) {
     let suffix = match lang.as_str() {
         "vi" => "Chế độ liên tục",
         "ko" => "연속 모드",
+        "ja" => "連続モード",
         _ => "Continuous Mode",
     };
     let title = format!("{} - {}", localized_name, suffix);
@@ -6,12 +6,22 @@ pub fn generate_panel_html(
     lang: &str,
     is_dark: bool,
     keep_open: bool,
+    snap_to_edge: bool,
+    hide_in_fullscreen: bool,
 ) -> String {
     let css = generate_panel_css(is_dark);
     let favorites_html = get_favorite_presets_html(presets, lang, is_dark);
-    let keep_open_label = crate::gui::locale::LocaleText::get(lang).favorites_keep_open;
+    let locale = crate::gui::locale::LocaleText::get(lang);
+    let keep_open_label = locale.favorites_keep_open;
+    let search_placeholder = html_escape(locale.search_placeholder);
     let keep_open_js = if keep_open { "true" } else { "false" };
     let keep_open_class = if keep_open { " active" } else { "" };
+    let snap_label = html_escape(locale.favorites_snap_to_edge);
+    let snap_js = if snap_to_edge { "true" } else { "false" };
+    let snap_class = if snap_to_edge { " active" } else { "" };
+    let fullscreen_label = html_escape(locale.favorites_hide_in_fullscreen);
+    let fullscreen_js = if hide_in_fullscreen { "true" } else { "false" };
+    let fullscreen_class = if hide_in_fullscreen { " active" } else { "" };
 
     format!(
         r#"<!DOCTYPE html>
@@ -29,8 +39,14 @@ pub fn generate_panel_html(
         <span class="keep-open-label{keep_open_class}" id="keepOpenLabel">{keep_open_label}</span>
         <button class="size-btn" onclick="resizeBubble('desc')">-</button>
         <button class="size-btn" onclick="resizeBubble('inc')">+</button>
+        <button class="size-btn snap-btn{snap_class}" id="snapBtn" title="{snap_label}" onclick="toggleSnap()"><svg width="14" height="14" viewBox="0 0 24 24" fill="currentColor"><path d="M3 3h2v18H3z M8 11h9.17l-3.58-3.59L15 6l6 6-6 6-1.41-1.41L17.17 13H8z"/></svg></button>
+        <button class="size-btn fullscreen-btn{fullscreen_class}" id="fullscreenBtn" title="{fullscreen_label}" onclick="toggleHideInFullscreen()"><svg width="14" height="14" viewBox="0 0 24 24" fill="currentColor"><path d="M7 14H5v5h5v-2H7v-3zm-2-4h2V7h3V5H5v5zm12 7h-3v2h5v-5h-2v3zM14 5v2h3v3h2V5h-5z"/></svg></button>
+    </div>
+    <div class="search-row" id="searchRow">
+        <input type="text" class="search-input" id="searchInput" placeholder="{search_placeholder}" onmousedown="window.ipc.postMessage('focus_search')" oninput="applyFilter()" onkeydown="onSearchKey(event)" spellcheck="false" autocomplete="off">
     </div>
     <div class="list">{favorites}</div>
+    <div class="preset-tooltip" id="presetTooltip"></div>
 </div>
 <script>
 function fitText() {{
@@ -55,6 +71,51 @@ function resizeBubble(dir) {{
 }}
 window.onload = fitText;
 
+// Filter favorites by their (localized) display name
+function applyFilter() {{
+    const input = document.getElementById('searchInput');
+    const query = input ? input.value.trim().toLowerCase() : '';
+    document.querySelectorAll('.preset-item').forEach(item => {{
+        const nameEl = item.querySelector('.name');
+        const name = nameEl ? nameEl.textContent.toLowerCase() : '';
+        item.classList.toggle('filtered', query.length > 0 && !name.includes(query));
+    }});
+    // Hide section headers left without a match
+    document.querySelectorAll('.section-header').forEach(header => {{
+        const sel = '.preset-item[data-section="' + header.dataset.section + '"]:not(.filtered)';
+        header.classList.toggle('filtered', !document.querySelector(sel));
+    }});
+    sendHeight();
+}}
+
+function onSearchKey(e) {{
+    if (e.key === 'Escape') {{
+        if (e.target.value) {{
+            e.target.value = '';
+            applyFilter();
+        }} else {{
+            window.ipc.postMessage('close');
+        }}
+    }} else if (e.key === 'Enter') {{
+        // Trigger the first match
+        const first = document.querySelector('.preset-item:not(.filtered)');
+        if (first) {{
+            const idx = parseInt(first.dataset.idx, 10);
+            if (!isNaN(idx)) triggerNormal(idx);
+        }}
+    }}
+}}
+
+function resetSearch() {{
+    const input = document.getElementById('searchInput');
+    if (!input) return;
+    input.value = '';
+    applyFilter();
+    // Hide search when there is nothing to filter
+    document.getElementById('searchRow').style.display =
+        document.querySelector('.preset-item') ? '' : 'none';
+}}
+
 function sendHeight() {{
     const container = document.querySelector('.container');
     if (container) {{
@@ -78,6 +139,22 @@ function toggleKeepOpen() {{
     window.ipc.postMessage('set_keep_open:' + (keepOpen ? '1' : '0'));
 }}
 
+let snapToEdge = {snap_js};
+
+function toggleSnap() {{
+    snapToEdge = !snapToEdge;
+    document.getElementById('snapBtn').classList.toggle('active', snapToEdge);
+    window.ipc.postMessage('set_snap:' + (snapToEdge ? '1' : '0'));
+}}
+
+let hideInFullscreen = {fullscreen_js};
+
+function toggleHideInFullscreen() {{
+    hideInFullscreen = !hideInFullscreen;
+    document.getElementById('fullscreenBtn').classList.toggle('active', hideInFullscreen);
+    window.ipc.postMessage('set_hide_fullscreen:' + (hideInFullscreen ? '1' : '0'));
+}}
+
 let holdTimer = null;
 let holdIdx = null;
 const HOLD_THRESHOLD = 500;
@@ -108,7 +185,33 @@ function onMouseUp(idx) {{
     resetFill();
 }}
 
+// Hover tooltip with the preset's model / language / render mode
+function showTooltip(item) {{
+    const tip = document.getElementById('presetTooltip');
+    const parts = [item.dataset.model, item.dataset.lang, item.dataset.render].filter(Boolean);
+    if (!tip || parts.length === 0) return;
+    tip.textContent = parts.join(' · ');
+    tip.classList.add('shown');
+
+    // Below the item, or above it when it would run off the bottom
+    const rect = item.getBoundingClientRect();
+    const tipRect = tip.getBoundingClientRect();
+    let top = rect.bottom + 4;
+    if (top + tipRect.height > window.innerHeight) {{
+        top = rect.top - tipRect.height - 4;
+    }}
+    tip.style.left = Math.max(4, rect.left) + 'px';
+    tip.style.maxWidth = rect.width + 'px';
+    tip.style.top = Math.max(0, top) + 'px';
+}}
+
+function hideTooltip() {{
+    const tip = document.getElementById('presetTooltip');
+    if (tip) tip.classList.remove('shown');
+}}
+
 function onMouseLeave() {{
+    hideTooltip();
     if (holdTimer) {{
         clearTimeout(holdTimer);
         holdTimer = null;
@@ -151,8 +254,9 @@ function animateIn(bx, by) {{
         currentTimeout = null;
     }}
     lastBubblePos = {{ x: bx, y: by }};
+    resetSearch();
     
-    const items = document.querySelectorAll('.preset-item, .empty');
+    const items = document.querySelectorAll('.preset-item, .section-header, .empty, .search-row');
     if (items.length === 0) return;
 
     items.forEach((item, i) => {{
@@ -196,12 +300,22 @@ function animateIn(bx, by) {{
             }}, 300 + (i * 15));
         }});
     }});
+
+    const input = document.getElementById('searchInput');
+    if (input && document.getElementById('searchRow').style.display !== 'none') {{
+        // The panel never activates on its own; ask for keyboard focus first
+        setTimeout(() => {{
+            window.ipc.postMessage('focus_search');
+            input.focus();
+        }}, 50);
+    }}
 }}
 
 function closePanel() {{
     if (currentTimeout) clearTimeout(currentTimeout);
+    hideTooltip();
     
-    const items = Array.from(document.querySelectorAll('.preset-item, .empty'));
+    const items = Array.from(document.querySelectorAll('.preset-item, .section-header, .empty, .search-row'));
     const {{ x: bx, y: by }} = lastBubblePos;
 
     items.forEach((item, i) => {{
@@ -238,7 +352,14 @@ window.setSide = (side) => {{
         favorites = favorites_html,
         keep_open_label = keep_open_label,
         keep_open_class = keep_open_class,
-        keep_open_js = keep_open_js
+        keep_open_js = keep_open_js,
+        search_placeholder = search_placeholder,
+        snap_label = snap_label,
+        snap_js = snap_js,
+        snap_class = snap_class,
+        fullscreen_label = fullscreen_label,
+        fullscreen_js = fullscreen_js,
+        fullscreen_class = fullscreen_class
     )
 }
 
@@ -446,6 +567,106 @@ html, body {{
     background: {empty_bg};
     border-radius: 12px;
     border: 1px solid {empty_border};
+    cursor: pointer;
+}}
+
+.empty-hint {{
+    margin-top: 6px;
+    font-size: 11px;
+    opacity: 0.8;
+}}
+
+.preset-item.filtered, .section-header.filtered {{
+    display: none;
+}}
+
+/* Category header above a group of favorites */
+.section-header {{
+    padding: 6px 12px 2px;
+    color: {label_color};
+    font-size: 11px;
+    font-variation-settings: 'wght' 650, 'wdth' 100, 'ROND' 100;
+    letter-spacing: 0.5px;
+    text-transform: uppercase;
+    text-shadow: {label_shadow};
+    white-space: nowrap;
+    overflow: hidden;
+    text-overflow: ellipsis;
+    break-inside: avoid;
+    break-after: avoid;
+
+    /* Animation state - similar to preset-item */
+    opacity: 0;
+    transform: scale(0.01);
+    transition:
+        transform 0.3s cubic-bezier(0.22, 1, 0.36, 1),
+        opacity 0.25s ease-out;
+    will-change: transform, opacity;
+}}
+.section-header.visible {{
+    opacity: 1;
+    transform: scale(1) translate(0px, 0px);
+}}
+
+/* Hover tooltip (positioned from JS) */
+.preset-tooltip {{
+    position: fixed;
+    z-index: 10;
+    padding: 4px 8px;
+    border-radius: 8px;
+    background: {item_hover_bg};
+    box-shadow: {item_hover_shadow};
+    color: {empty_text_color};
+    font-size: 11px;
+    white-space: nowrap;
+    overflow: hidden;
+    text-overflow: ellipsis;
+    pointer-events: none;
+    opacity: 0;
+    transition: opacity 0.15s ease;
+}}
+.preset-tooltip.shown {{
+    opacity: 1;
+    transition-delay: 0.4s;
+}}
+
+/* Search Row */
+.search-row {{
+    margin-bottom: 8px;
+
+    /* Animation state - similar to preset-item */
+    opacity: 0;
+    pointer-events: none;
+    transform: scale(0.01);
+    transition:
+        transform 0.3s cubic-bezier(0.22, 1, 0.36, 1),
+        opacity 0.25s ease-out;
+    will-change: transform, opacity;
+}}
+.search-row.visible {{
+    opacity: 1;
+    transform: scale(1) translate(0px, 0px);
+    pointer-events: auto;
+}}
+.search-input {{
+    width: 100%;
+    padding: 8px 12px;
+    border-radius: 12px;
+    border: 1px solid {empty_border};
+    outline: none;
+    background: {item_bg};
+    backdrop-filter: blur(12px);
+    box-shadow: {item_shadow};
+    color: {text_color};
+    font-family: inherit;
+    font-size: 13px;
+    user-select: text;
+}}
+.search-input::placeholder {{
+    color: {empty_text_color};
+}}
+.search-input:focus {{
+    border-color: {toggle_active_bg};
 }}
 
 .condense {{ letter-spacing: -0.5px; }}
@@ -562,6 +783,10 @@ html, body {{
 .size-btn:active {{
     transform: scale(0.95);
 }}
+.snap-btn.active, .fullscreen-btn.active {{
+    background: {toggle_active_bg};
+    color: white;
+}}
 "#,
         font_css = font_css,
         text_color = text_color,
@@ -593,63 +818,229 @@ pub fn get_favorite_presets_html(presets: &[Preset], lang: &str, is_dark: bool)
     let icon_device = r#"<svg width="20" height="20" viewBox="0 0 24 24" fill="currentColor"><path d="M3 9v6h4l5 5V4L7 9H3zm13.5 3c0-1.77-1.02-3.29-2.5-4.03v8.05c1.48-.73 2.5-2.25 2.5-4.02zM14 3.23v2.06c2.89.86 5 3.54 5 6.71s-2.11 5.85-5 6.71v2.06c4.01-.91 7-4.49 7-8.77s-2.99-7.86-7-8.77z"/></svg>"#;
     let icon_realtime = r#"<svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M2 12h3 l1.5-3 l2 10 l3.5-14 l3.5 10 l2-3 h4.5"/></svg>"#;
 
-    for (idx, preset) in presets.iter().enumerate() {
-        if preset.is_favorite && !preset.is_upcoming {
+    let favorites: Vec<(usize, &Preset, String)> = presets
+        .iter()
+        .enumerate()
+        .filter(|(_, preset)| preset.is_favorite && !preset.is_upcoming)
+        .map(|(idx, preset)| {
             let name = if preset.id.starts_with("preset_") {
                 get_localized_preset_name(&preset.id, lang)
             } else {
                 preset.name.clone()
             };
+            (idx, preset, name)
+        })
+        .collect();
+    let labels = favorite_labels(&favorites, lang);
+    let sections = favorite_sections(presets, lang);
+    let mut items: Vec<(usize, String)> = Vec::new();
 
-            let (icon_svg, color_hex) = match preset.preset_type.as_str() {
-                "audio" => {
-                    if preset.audio_processing_mode == "realtime" {
-                        // Realtime/Live: Red
-                        (icon_realtime, if is_dark { "#ff5555" } else { "#d32f2f" })
-                    } else if preset.audio_source == "device" {
-                        // Device/Speaker: Orange
-                        (icon_device, if is_dark { "#ffaa33" } else { "#f57c00" })
-                    } else {
-                        // Mic: Orange
-                        (icon_mic, if is_dark { "#ffaa33" } else { "#f57c00" })
-                    }
+    for (&(idx, preset, _), name) in favorites.iter().zip(labels) {
+        let (icon_svg, color_hex) = match preset.preset_type.as_str() {
+            "audio" => {
+                if preset.audio_processing_mode == "realtime" {
+                    // Realtime/Live: Red
+                    (icon_realtime, if is_dark { "#ff5555" } else { "#d32f2f" })
+                } else if preset.audio_source == "device" {
+                    // Device/Speaker: Orange
+                    (icon_device, if is_dark { "#ffaa33" } else { "#f57c00" })
+                } else {
+                    // Mic: Orange
+                    (icon_mic, if is_dark { "#ffaa33" } else { "#f57c00" })
                 }
-                "text" => {
-                    // Text: Green
-                    let c = if is_dark { "#55ff88" } else { "#388e3c" };
-                    if preset.text_input_mode == "select" {
-                        (icon_text_select, c)
-                    } else {
-                        (icon_text_type, c)
-                    }
+            }
+            "text" => {
+                // Text: Green
+                let c = if is_dark { "#55ff88" } else { "#388e3c" };
+                if preset.text_input_mode == "select" {
+                    (icon_text_select, c)
+                } else {
+                    (icon_text_type, c)
                 }
-                _ => (icon_image, if is_dark { "#44ccff" } else { "#1976d2" }), // Image: Blue
-            };
+            }
+            _ => (icon_image, if is_dark { "#44ccff" } else { "#1976d2" }), // Image: Blue
+        };
+
+        let (model, language, render_mode) = preset_details(preset, lang);
+        let section = section_name(preset, lang);
+        let section_idx = sections.iter().position(|s| *s == section).unwrap_or(0);
+
+        let item = format!(
+            r#"<div class="preset-item" data-idx="{}" data-section="{}" data-model="{}" data-lang="{}" data-render="{}" onmousedown="onMouseDown({})" onmouseup="onMouseUp({})" onmouseenter="showTooltip(this)" onmouseleave="onMouseLeave()"><div class="progress-fill"></div><span class="icon" style="color: {};">{}</span><span class="name">{}</span></div>"#,
+            idx,
+            section_idx,
+            html_escape(&model),
+            html_escape(&language),
+            html_escape(&render_mode),
+            idx,
+            idx,
+            color_hex,
+            icon_svg,
+            html_escape(&name)
+        );
 
-            let item = format!(
-                r#"<div class="preset-item" onmousedown="onMouseDown({})" onmouseup="onMouseUp({})" onmouseleave="onMouseLeave()"><div class="progress-fill"></div><span class="icon" style="color: {};">{}</span><span class="name">{}</span></div>"#,
-                idx,
-                idx,
-                color_hex,
-                icon_svg,
-                html_escape(&name)
-            );
+        items.push((section_idx, item));
+    }
 
-            html_items.push_str(&item);
+    if sections.is_empty() {
+        for (_, item) in &items {
+            html_items.push_str(item);
+        }
+    } else {
+        // Each section keeps the favorites' order within it
+        for (section_idx, section) in sections.iter().enumerate() {
+            html_items.push_str(&format!(
+                r#"<div class="section-header" data-section="{}">{}</div>"#,
+                section_idx,
+                html_escape(section)
+            ));
+            for (_, item) in items.iter().filter(|(s, _)| *s == section_idx) {
+                html_items.push_str(item);
+            }
         }
     }
 
     if html_items.is_empty() {
         let locale = crate::gui::locale::LocaleText::get(lang);
+        // Clicking the hint opens settings, where presets are starred
         html_items = format!(
-            r#"<div class="empty">{}</div>"#,
-            html_escape(locale.favorites_empty)
+            r#"<div class="empty" onclick="window.ipc.postMessage('open_settings')"><div>{}</div><div class="empty-hint">{}</div></div>"#,
+            html_escape(locale.favorites_empty),
+            html_escape(locale.favorites_empty_hint)
         );
     }
 
     html_items
 }
 
+/// Section headers for the favorites, in order of first appearance. Empty
+/// when no favorite has a category, so the panel stays a flat list.
+pub fn favorite_sections(presets: &[Preset], lang: &str) -> Vec<String> {
+    let favorites: Vec<&Preset> = presets
+        .iter()
+        .filter(|p| p.is_favorite && !p.is_upcoming)
+        .collect();
+    if favorites.iter().all(|p| p.category.trim().is_empty()) {
+        return Vec::new();
+    }
+
+    let mut sections: Vec<String> = Vec::new();
+    for preset in favorites {
+        let section = section_name(preset, lang);
+        if !sections.contains(&section) {
+            sections.push(section);
+        }
+    }
+    sections
+}
+
+/// The preset's category, or the default section for uncategorized ones
+fn section_name(preset: &Preset, lang: &str) -> String {
+    let category = preset.category.trim();
+    if !category.is_empty() {
+        return category.to_string();
+    }
+    match lang {
+        "vi" => "Chung",
+        "ko" => "일반",
+        "ja" => "一般",
+        _ => "General",
+    }
+    .to_string()
+}
+
+/// Names to show for the favorites. Favorites sharing a name (e.g. a duplicated
+/// preset) get what tells them apart appended: the target language, else the
+/// model, else their position among the twins.
+fn favorite_labels(favorites: &[(usize, &Preset, String)], lang: &str) -> Vec<String> {
+    let details: Vec<_> = favorites
+        .iter()
+        .map(|(_, preset, _)| preset_details(preset, lang))
+        .collect();
+
+    favorites
+        .iter()
+        .enumerate()
+        .map(|(i, (_, _, name))| {
+            let twins: Vec<usize> = (0..favorites.len())
+                .filter(|&j| favorites[j].2 == *name)
+                .collect();
+            if twins.len() < 2 {
+                return name.clone();
+            }
+
+            let tells_apart = |value: &String, field: fn(&(String, String, String)) -> &String| {
+                !value.is_empty()
+                    && twins
+                        .iter()
+                        .filter(|&&j| field(&details[j]) == value)
+                        .count()
+                        == 1
+            };
+            let (model, language, _) = &details[i];
+            if tells_apart(language, |d| &d.1) {
+                format!("{} ({})", name, language)
+            } else if tells_apart(model, |d| &d.0) {
+                format!("{} ({})", name, model)
+            } else {
+                let n = twins.iter().position(|&j| j == i).unwrap_or(0) + 1;
+                format!("{} #{}", name, n)
+            }
+        })
+        .collect()
+}
+
+/// Model, target language and render mode of the preset's first processing
+/// block, for the hover tooltip. Empty strings are left out of the tooltip.
+fn preset_details(preset: &Preset, lang: &str) -> (String, String, String) {
+    let Some(block) = preset
+        .blocks
+        .iter()
+        .find(|b| b.block_type != "input_adapter")
+    else {
+        return (String::new(), String::new(), String::new());
+    };
+
+    let model = crate::model_config::get_model_by_id(&block.model)
+        .map(|m| m.full_name)
+        .unwrap_or_else(|| block.model.clone());
+
+    let language = block
+        .language_vars
+        .get("language1")
+        .filter(|l| !l.is_empty())
+        .unwrap_or(&block.selected_language);
+    // Shown as the setting, not resolved: the layout in use is only known at run time
+    let language = if language == crate::config::SYSTEM_LANGUAGE {
+        match lang {
+            "vi" => "⌨ Ngôn ngữ bàn phím",
+            "ko" => "⌨ 키보드 언어",
+            "ja" => "⌨ キーボードの言語",
+            _ => "⌨ Keyboard language",
+        }
+        .to_string()
+    } else {
+        language.clone()
+    };
+
+    let (normal, stream, markdown, markdown_stream) = match lang {
+        "vi" => ("Thường", "Stream", "Đẹp", "Đẹp+Str"),
+        "ko" => ("일반", "스트림", "마크다운", "마크다운+스트림"),
+        "ja" => ("通常", "ストリーム", "Markdown", "MD+ストリーム"),
+        _ => ("Normal", "Stream", "Markdown", "MD+Stream"),
+    };
+    let render_mode = match block.render_mode.as_str() {
+        "plain" => normal,
+        "stream" => stream,
+        "markdown" => markdown,
+        "markdown_stream" => markdown_stream,
+        _ => "",
+    }
+    .to_string();
+
+    (model, language, render_mode)
+}
+
 fn html_escape(s: &str) -> String {
     s.replace('&', "&amp;")
         .replace('<', "&lt;")
@@ -6,4 +6,6 @@ pub mod utils;
 pub mod window;
 
 pub use panel::update_favorites_panel;
-pub use window::{hide_favorite_bubble, show_favorite_bubble, trigger_blink_animation};
+pub use window::{
+    hide_favorite_bubble, set_bubble_size, show_favorite_bubble, trigger_blink_animation,
+};
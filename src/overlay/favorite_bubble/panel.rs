@@ -1,4 +1,7 @@
-use super::html::{escape_js, generate_panel_css, generate_panel_html, get_favorite_presets_html};
+use super::html::{
+    escape_js, favorite_sections, generate_panel_css, generate_panel_html,
+    get_favorite_presets_html,
+};
 use super::render::update_bubble_visual;
 use super::state::*;
 use super::utils::HwndWrapper;
@@ -9,18 +12,19 @@ use windows::Win32::Foundation::*;
 use windows::Win32::Graphics::Dwm::{
     DwmExtendFrameIntoClientArea, DwmSetWindowAttribute, DWMWA_WINDOW_CORNER_PREFERENCE,
 };
-use windows::Win32::Graphics::Gdi::HBRUSH;
+use windows::Win32::Graphics::Gdi::{
+    GetMonitorInfoW, MonitorFromWindow, HBRUSH, MONITORINFO, MONITOR_DEFAULTTONEAREST,
+};
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
 use windows::Win32::UI::Controls::MARGINS;
 use windows::Win32::UI::HiDpi::GetDpiForWindow;
 use windows::Win32::UI::WindowsAndMessaging::{
-    CreateWindowExW, DefWindowProcW, DestroyWindow, FindWindowW, GetClientRect,
-    GetForegroundWindow, GetSystemMetrics, GetWindowRect, LoadCursorW, PostMessageW,
-    RegisterClassW, SendMessageW, SetForegroundWindow, SetWindowPos, ShowWindow, HTCAPTION,
-    IDC_ARROW, SM_CXSCREEN, SWP_NOACTIVATE, SWP_NOCOPYBITS, SWP_NOSIZE, SWP_NOZORDER, SW_HIDE,
-    SW_SHOWNOACTIVATE, WM_ACTIVATE, WM_APP, WM_CLOSE, WM_HOTKEY, WM_KILLFOCUS, WM_NCCALCSIZE,
-    WM_NCLBUTTONDOWN, WNDCLASSW, WS_EX_LAYERED, WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW, WS_EX_TOPMOST,
-    WS_POPUP, WS_VISIBLE,
+    CreateWindowExW, DefWindowProcW, DestroyWindow, GetClientRect, GetForegroundWindow,
+    GetSystemMetrics, GetWindowRect, LoadCursorW, PostMessageW, RegisterClassW, SendMessageW,
+    SetForegroundWindow, SetWindowPos, ShowWindow, HTCAPTION, IDC_ARROW, SM_CXSCREEN,
+    SWP_NOACTIVATE, SWP_NOCOPYBITS, SWP_NOSIZE, SWP_NOZORDER, SW_HIDE, SW_SHOWNOACTIVATE,
+    WM_ACTIVATE, WM_APP, WM_CLOSE, WM_KILLFOCUS, WM_NCCALCSIZE, WM_NCLBUTTONDOWN, WNDCLASSW,
+    WS_EX_LAYERED, WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_POPUP, WS_VISIBLE,
 };
 use wry::{Rect, WebContext, WebViewBuilder};
 
@@ -40,7 +44,8 @@ pub fn show_panel(bubble_hwnd: HWND) {
     // to the original window for text-select presets to work (they send Ctrl+C).
     unsafe {
         let fg = GetForegroundWindow();
-        if !fg.is_invalid() {
+        // The panel itself may still be active from its search box
+        if !fg.is_invalid() && fg.0 as isize != PANEL_HWND.load(Ordering::SeqCst) {
             LAST_FOREGROUND_HWND.store(fg.0 as isize, Ordering::SeqCst);
         }
     }
@@ -164,6 +169,10 @@ fn close_panel_internal() {
     if panel_val != 0 {
         unsafe {
             let panel_hwnd = HWND(panel_val as *mut std::ffi::c_void);
+            // Typing in the search box activated the panel; hand focus back
+            if GetForegroundWindow() == panel_hwnd {
+                restore_foreground();
+            }
             let _ = ShowWindow(panel_hwnd, SW_HIDE);
         }
     }
@@ -303,6 +312,7 @@ unsafe fn refresh_panel_layout_and_content(
     let _ = GetWindowRect(bubble_hwnd, &mut bubble_rect);
 
     let height_per_item = 48;
+    let height_per_header = 28;
 
     let favs: Vec<_> = presets
         .iter()
@@ -310,43 +320,71 @@ unsafe fn refresh_panel_layout_and_content(
         .collect();
 
     let fav_count = favs.len();
-    let num_cols = if fav_count > 15 {
-        (fav_count + 14) / 15
-    } else {
-        1
-    };
+    let section_count = favorite_sections(presets, lang).len();
 
-    let items_per_col = if fav_count > 0 {
-        (fav_count + num_cols - 1) / num_cols
-    } else {
-        0
-    };
+    // Item rows plus one header row per category section
+    let list_height = fav_count * height_per_item + section_count * height_per_header;
 
     // Buffer for padding (no bounce overshoot with smooth easing)
     let buffer_x = 40;
     let buffer_y = 60;
 
+    // Height for the keep-open toggle row
+    let keep_open_row_height = 40;
+
+    // Height for the search row (hidden when there are no favorites)
+    let search_row_height = 44;
+
+    // Everything but the item rows (+100 extra buffer)
+    let list_overhead = (24 + buffer_y + keep_open_row_height + search_row_height + 100) as usize;
+
+    // Get DPI scale
+    let dpi = unsafe { GetDpiForWindow(panel_hwnd) };
+    let scale = if dpi == 0 { 1.0 } else { dpi as f32 / 96.0 };
+
+    // Work area of the bubble's monitor, so the panel never runs past the taskbar
+    let monitor = MonitorFromWindow(bubble_hwnd, MONITOR_DEFAULTTONEAREST);
+    let mut mi = MONITORINFO {
+        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+        ..Default::default()
+    };
+    let work = if GetMonitorInfoW(monitor, &mut mi).as_bool() {
+        mi.rcWork
+    } else {
+        RECT {
+            left: 0,
+            top: 0,
+            right: GetSystemMetrics(SM_CXSCREEN),
+            bottom: GetSystemMetrics(SM_CYSCREEN),
+        }
+    };
+
+    // As many rows as fit in the work area (in logical px), then flow into more columns
+    let available_height = ((work.bottom - work.top) as f32 / scale) as i32 - 20;
+    let max_rows = ((available_height - list_overhead as i32) / height_per_item as i32).max(1);
+    let max_list_height = max_rows as usize * height_per_item;
+    let num_cols = list_height.div_ceil(max_list_height).max(1);
+
+    let column_height = if section_count == 0 {
+        fav_count.div_ceil(num_cols) * height_per_item
+    } else {
+        // Columns only break between rows, so leave room for an uneven split
+        list_height.div_ceil(num_cols) + if num_cols > 1 { height_per_item } else { 0 }
+    };
+
     let panel_width = if fav_count == 0 {
         (PANEL_WIDTH as i32 * 2).max(320)
     } else {
         (PANEL_WIDTH as usize * num_cols) as i32 + buffer_x
     };
 
-    // Height for the keep-open toggle row
-    let keep_open_row_height = 40;
-
     let panel_height = if fav_count == 0 {
         80 + buffer_y + keep_open_row_height
     } else {
-        (items_per_col as i32 * height_per_item) + 24 + buffer_y + keep_open_row_height + 100
-        // Extra buffer
+        (column_height + list_overhead) as i32
     };
     let panel_height = panel_height.max(50);
 
-    // Get DPI scale
-    let dpi = unsafe { GetDpiForWindow(panel_hwnd) };
-    let scale = if dpi == 0 { 1.0 } else { dpi as f32 / 96.0 };
-
     let panel_width_physical = (panel_width as f32 * scale).ceil() as i32;
     let panel_height_physical = (panel_height as f32 * scale).ceil() as i32;
 
@@ -368,7 +406,9 @@ unsafe fn refresh_panel_layout_and_content(
     };
 
     // Use the actual clamped panel_y for positioning
-    let actual_panel_y = panel_y.max(10);
+    let actual_panel_y = panel_y
+        .min(work.bottom - panel_height_physical - 10)
+        .max(work.top + 10);
 
     let _ = SetWindowPos(
         panel_hwnd,
@@ -458,6 +498,8 @@ fn create_panel_webview(panel_hwnd: HWND) {
             &app.config.ui_language,
             is_dark,
             app.config.favorites_keep_open,
+            app.config.favorite_bubble_snap_to_edge,
+            app.config.favorite_bubble_hide_in_fullscreen,
         )
     } else {
         String::new()
@@ -518,10 +560,15 @@ fn create_panel_webview(panel_hwnd: HWND) {
                                 Some(LPARAM(0)),
                             );
                         }
+                    } else if body == "focus_search" {
+                        focus_search(panel_hwnd);
                     } else if body == "close" {
                         close_panel();
                     } else if body == "close_now" {
                         close_panel_internal();
+                    } else if body == "open_settings" {
+                        close_panel();
+                        crate::gui::signal_restore_window();
                     } else if body.starts_with("trigger:") {
                         if let Ok(idx) = body[8..].parse::<usize>() {
                             // trigger() in JS starts the close animation and will send close_now when done.
@@ -558,22 +605,31 @@ fn create_panel_webview(panel_hwnd: HWND) {
                             resize_panel_height(h);
                         }
                     } else if body == "increase_size" {
-                        if let Ok(mut app) = APP.lock() {
-                            let new_size = (app.config.favorite_bubble_size + 8).min(80);
-                            app.config.favorite_bubble_size = new_size;
-                            crate::config::save_config(&app.config);
-                            BUBBLE_SIZE.store(new_size as i32, Ordering::SeqCst);
-                        }
+                        let current = APP
+                            .lock()
+                            .map(|app| app.config.favorite_bubble_size)
+                            .unwrap_or(40);
+                        super::window::set_bubble_size(current + BUBBLE_SIZE_STEP);
                         update_favorites_panel();
                     } else if body == "decrease_size" {
+                        let current = APP
+                            .lock()
+                            .map(|app| app.config.favorite_bubble_size)
+                            .unwrap_or(40);
+                        super::window::set_bubble_size(current.saturating_sub(BUBBLE_SIZE_STEP));
+                        update_favorites_panel();
+                    } else if body.starts_with("set_snap:") {
+                        if let Ok(val) = body[9..].parse::<u32>() {
+                            if let Ok(mut app) = APP.lock() {
+                                app.config.favorite_bubble_snap_to_edge = val == 1;
+                                crate::config::save_config(&app.config);
+                            }
+                        }
+                    } else if let Some(val) = body.strip_prefix("set_hide_fullscreen:") {
                         if let Ok(mut app) = APP.lock() {
-                            let new_size =
-                                (app.config.favorite_bubble_size.saturating_sub(8)).max(24);
-                            app.config.favorite_bubble_size = new_size;
+                            app.config.favorite_bubble_hide_in_fullscreen = val == "1";
                             crate::config::save_config(&app.config);
-                            BUBBLE_SIZE.store(new_size as i32, Ordering::SeqCst);
                         }
-                        update_favorites_panel();
                     }
                 })
                 .with_background_color((0, 0, 0, 0))
@@ -658,34 +714,52 @@ unsafe extern "system" fn panel_wnd_proc(
     }
 }
 
-fn trigger_preset(preset_idx: usize) {
+// The panel is WS_EX_NOACTIVATE so clicking a preset never takes focus from the
+// window it should act on. The search box needs key input, so it activates the
+// panel explicitly; trigger_preset / close_panel_internal give focus back.
+fn focus_search(panel_hwnd: HWND) {
     unsafe {
-        // CRITICAL: Restore focus to the original foreground window before triggering.
-        // This ensures that text-select presets can send Ctrl+C to the correct window
-        // (the one that had text selected before the user clicked on the bubble panel).
-        let saved_fg = LAST_FOREGROUND_HWND.load(Ordering::SeqCst);
-        if saved_fg != 0 {
-            let fg_hwnd = HWND(saved_fg as *mut std::ffi::c_void);
-            if !fg_hwnd.is_invalid() {
-                // SetForegroundWindow may not always work due to Windows focus stealing prevention,
-                // but SetFocus on a window that's already visible should work.
-                // We use a combination approach for best results.
-                let _ = SetForegroundWindow(fg_hwnd);
-                let _ = SetFocus(Some(fg_hwnd));
-                // Small delay to allow focus to settle before triggering the preset
-                std::thread::sleep(std::time::Duration::from_millis(30));
-            }
+        let _ = SetForegroundWindow(panel_hwnd);
+    }
+    PANEL_WEBVIEW.with(|wv| {
+        if let Some(webview) = wv.borrow().as_ref() {
+            let _ = webview.focus();
         }
+    });
+}
 
-        let class = w!("HotkeyListenerClass");
-        let title = w!("Listener");
-        let hwnd = FindWindowW(class, title).unwrap_or_default();
+/// Re-activate the window that was in front before the panel opened.
+/// Returns false if there was none to restore.
+fn restore_foreground() -> bool {
+    let saved_fg = LAST_FOREGROUND_HWND.load(Ordering::SeqCst);
+    if saved_fg == 0 {
+        return false;
+    }
+    let fg_hwnd = HWND(saved_fg as *mut std::ffi::c_void);
+    if fg_hwnd.is_invalid() {
+        return false;
+    }
+    unsafe {
+        // SetForegroundWindow may not always work due to Windows focus stealing prevention,
+        // but SetFocus on a window that's already visible should work.
+        // We use a combination approach for best results.
+        let _ = SetForegroundWindow(fg_hwnd);
+        let _ = SetFocus(Some(fg_hwnd));
+    }
+    true
+}
 
-        if !hwnd.is_invalid() {
-            let hotkey_id = (preset_idx as i32 * 1000) + 1;
-            let _ = PostMessageW(Some(hwnd), WM_HOTKEY, WPARAM(hotkey_id as usize), LPARAM(0));
-        }
+fn trigger_preset(preset_idx: usize) {
+    // CRITICAL: Restore focus to the original foreground window before triggering.
+    // This ensures that text-select presets can send Ctrl+C to the correct window
+    // (the one that had text selected before the user clicked on the bubble panel,
+    // or before the search box activated the panel).
+    if restore_foreground() {
+        // Small delay to allow focus to settle before triggering the preset
+        std::thread::sleep(std::time::Duration::from_millis(30));
     }
+
+    crate::overlay::preset_launcher::post_preset_hotkey(preset_idx);
 }
 
 fn activate_continuous_from_panel(preset_idx: usize) {
@@ -815,7 +889,7 @@ fn update_panel_content(html: &str, cols: usize) {
         if let Some(webview) = wv.borrow().as_ref() {
             let escaped = escape_js(html);
             let script = format!(
-                "document.querySelector('.list').style.columnCount = '{}'; document.querySelector('.list').innerHTML = \"{}\"; if(window.fitText) window.fitText();",
+                "document.querySelector('.list').style.columnCount = '{}'; document.querySelector('.list').innerHTML = \"{}\"; if(window.applyFilter) window.applyFilter(); if(window.fitText) window.fitText();",
                 cols, escaped
             );
             let _ = webview.evaluate_script(&script);
@@ -7,6 +7,9 @@ use wry::{WebContext, WebView};
 
 // Constants
 pub static BUBBLE_SIZE: AtomicI32 = AtomicI32::new(40);
+pub const MIN_BUBBLE_SIZE: u32 = 32;
+pub const MAX_BUBBLE_SIZE: u32 = 64;
+pub const BUBBLE_SIZE_STEP: u32 = 8;
 pub const PANEL_WIDTH: i32 = 260;
 pub const DRAG_THRESHOLD: i32 = 5; // Pixels of movement before counting as a drag
 
@@ -18,6 +21,18 @@ pub const OPACITY_ACTIVE: u8 = 255; // 100% opacity when hovered/expanded
 
 pub const PHYSICS_TIMER_ID: usize = 2;
 
+// Snap-to-edge animation (runs after throw physics settle)
+pub const SNAP_TIMER_ID: usize = 3;
+pub const SNAP_EASING: f32 = 0.25; // Fraction of remaining distance covered per frame
+
+// Single clicks are deferred by the system double-click interval so a
+// double-click (open settings) doesn't also toggle the panel
+pub const CLICK_TIMER_ID: usize = 4;
+
+// Periodic check for a fullscreen foreground app to hide the bubble behind
+pub const FULLSCREEN_TIMER_ID: usize = 5;
+pub const FULLSCREEN_CHECK_MS: u32 = 1000;
+
 // Statics / Atomics
 pub static REGISTER_BUBBLE_CLASS: Once = Once::new();
 pub static REGISTER_PANEL_CLASS: Once = Once::new();
@@ -35,6 +50,7 @@ pub static DRAG_START_Y: AtomicIsize = AtomicIsize::new(0);
 pub static CURRENT_OPACITY: AtomicU8 = AtomicU8::new(80); // Start at inactive opacity
 pub static BLINK_STATE: AtomicU8 = AtomicU8::new(0); // 0=None, 1..4=Blink Phases
 pub static FADE_OUT_STATE: AtomicBool = AtomicBool::new(false); // True = fading out before close
+pub static HIDDEN_FOR_FULLSCREEN: AtomicBool = AtomicBool::new(false);
 
 // Focus restoration: Track the foreground window before any bubble interaction
 // This is critical for text-select presets, which need to send Ctrl+C to the original window
@@ -47,6 +63,9 @@ pub static LAST_THEME_IS_DARK: AtomicBool = AtomicBool::new(true);
 thread_local! {
     pub static PANEL_WEBVIEW: RefCell<Option<WebView>> = RefCell::new(None);
     pub static PHYSICS_STATE: RefCell<(f32, f32)> = RefCell::new((0.0, 0.0));
+    pub static SNAP_TARGET: RefCell<(i32, i32)> = RefCell::new((0, 0));
+    /// Time of the last click still waiting to become a single or double click
+    pub static LAST_CLICK_TIME: RefCell<Option<std::time::Instant>> = RefCell::new(None);
     // Shared WebContext for this thread using common data directory
     pub static PANEL_WEB_CONTEXT: RefCell<Option<WebContext>> = RefCell::new(None);
 
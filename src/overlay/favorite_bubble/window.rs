@@ -6,13 +6,15 @@ use super::render::update_bubble_visual;
 use super::state::*;
 use crate::APP;
 use std::sync::atomic::Ordering;
-use windows::core::w;
+use windows::core::{w, HSTRING, PCWSTR};
 use windows::Win32::Foundation::*;
-
+use windows::Win32::Graphics::Gdi::{
+    GetMonitorInfoW, MonitorFromWindow, MONITORINFO, MONITOR_DEFAULTTONEAREST,
+};
 use windows::Win32::System::Com::{CoInitialize, CoUninitialize};
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
 use windows::Win32::UI::Input::KeyboardAndMouse::{
-    ReleaseCapture, SetCapture, TrackMouseEvent, TME_LEAVE, TRACKMOUSEEVENT,
+    GetDoubleClickTime, ReleaseCapture, SetCapture, TrackMouseEvent, TME_LEAVE, TRACKMOUSEEVENT,
 };
 use windows::Win32::UI::WindowsAndMessaging::*;
 
@@ -20,6 +22,9 @@ use windows::Win32::UI::WindowsAndMessaging::*;
 // However, we know it's WM_APP + 42. It's safe to use the constant here.
 const WM_REFRESH_PANEL: u32 = WM_APP + 42;
 
+// Sent to the bubble thread when the bubble size changes at runtime
+pub const WM_BUBBLE_RESIZE: u32 = WM_APP + 44;
+
 // Show the favorite bubble overlay
 pub fn show_favorite_bubble() {
     // Prevent duplicates
@@ -55,6 +60,27 @@ pub fn hide_favorite_bubble() {
     }
 }
 
+/// Apply a new bubble size without restarting the bubble.
+/// The size is clamped, persisted to config, and the bubble is re-rendered on its own thread.
+pub fn set_bubble_size(size: u32) {
+    let size = size.clamp(MIN_BUBBLE_SIZE, MAX_BUBBLE_SIZE);
+    if let Ok(mut app) = APP.lock() {
+        if app.config.favorite_bubble_size != size {
+            app.config.favorite_bubble_size = size;
+            crate::config::save_config(&app.config);
+        }
+    }
+    BUBBLE_SIZE.store(size as i32, Ordering::SeqCst);
+
+    let hwnd_val = BUBBLE_HWND.load(Ordering::SeqCst);
+    if hwnd_val != 0 {
+        let hwnd = HWND(hwnd_val as *mut std::ffi::c_void);
+        unsafe {
+            let _ = PostMessageW(Some(hwnd), WM_BUBBLE_RESIZE, WPARAM(0), LPARAM(0));
+        }
+    }
+}
+
 pub fn trigger_blink_animation() {
     let hwnd_val = BUBBLE_HWND.load(Ordering::SeqCst);
     if hwnd_val != 0 {
@@ -86,7 +112,10 @@ fn create_bubble_window() {
 
         // Get saved position or use default
         let (initial_x, initial_y, current_size) = if let Ok(app) = APP.lock() {
-            let size = app.config.favorite_bubble_size as i32;
+            let size = app
+                .config
+                .favorite_bubble_size
+                .clamp(MIN_BUBBLE_SIZE, MAX_BUBBLE_SIZE) as i32;
             BUBBLE_SIZE.store(size, Ordering::SeqCst);
 
             let v_x = GetSystemMetrics(SM_XVIRTUALSCREEN);
@@ -144,6 +173,8 @@ fn create_bubble_window() {
 
         // Start fade-in animation immediately
         let _ = SetTimer(Some(hwnd), OPACITY_TIMER_ID, 16, None);
+        HIDDEN_FOR_FULLSCREEN.store(false, Ordering::SeqCst);
+        let _ = SetTimer(Some(hwnd), FULLSCREEN_TIMER_ID, FULLSCREEN_CHECK_MS, None);
 
         // Warmup: Create panel window AND WebView2 process immediately.
         // We do this here (hidden) so the first click shows the panel instantly.
@@ -169,6 +200,133 @@ fn create_bubble_window() {
     }
 }
 
+/// Hide the bubble while a fullscreen app is in front (if enabled), show it again after
+unsafe fn update_fullscreen_visibility(hwnd: HWND) {
+    let enabled = APP
+        .lock()
+        .map(|app| app.config.favorite_bubble_hide_in_fullscreen)
+        .unwrap_or(false);
+    let hidden = HIDDEN_FOR_FULLSCREEN.load(Ordering::SeqCst);
+    let should_hide = match foreground_is_fullscreen() {
+        Some(fullscreen) => enabled && fullscreen,
+        // Our own windows (capture overlays, results) leave it as it is
+        None => hidden && enabled,
+    };
+    if should_hide == hidden || IS_DRAGGING.load(Ordering::SeqCst) {
+        return;
+    }
+
+    HIDDEN_FOR_FULLSCREEN.store(should_hide, Ordering::SeqCst);
+    if should_hide {
+        if IS_EXPANDED.load(Ordering::SeqCst) {
+            close_panel();
+        }
+        let _ = ShowWindow(hwnd, SW_HIDE);
+    } else {
+        let _ = ShowWindow(hwnd, SW_SHOWNOACTIVATE);
+    }
+}
+
+/// Whether the foreground window covers its whole monitor; None if it is one of ours
+unsafe fn foreground_is_fullscreen() -> Option<bool> {
+    let fg = GetForegroundWindow();
+    if fg.is_invalid() || fg == GetDesktopWindow() || fg == GetShellWindow() {
+        return Some(false);
+    }
+    let mut pid = 0u32;
+    GetWindowThreadProcessId(fg, Some(&mut pid));
+    if pid == std::process::id() {
+        return None;
+    }
+
+    // The desktop (icons) is a fullscreen window too
+    let mut class = [0u16; 64];
+    let len = GetClassNameW(fg, &mut class) as usize;
+    let class = String::from_utf16_lossy(&class[..len]);
+    if class == "WorkerW" || class == "Progman" {
+        return Some(false);
+    }
+
+    let mut rect = RECT::default();
+    if GetWindowRect(fg, &mut rect).is_err() {
+        return Some(false);
+    }
+    let mut mi = MONITORINFO {
+        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+        ..Default::default()
+    };
+    if !GetMonitorInfoW(MonitorFromWindow(fg, MONITOR_DEFAULTTONEAREST), &mut mi).as_bool() {
+        return Some(false);
+    }
+    let monitor = mi.rcMonitor;
+    Some(
+        rect.left <= monitor.left
+            && rect.top <= monitor.top
+            && rect.right >= monitor.right
+            && rect.bottom >= monitor.bottom,
+    )
+}
+
+/// Right-click menu: open settings, hide the bubble, reload favorites
+unsafe fn show_bubble_menu(hwnd: HWND) {
+    const ID_SETTINGS: usize = 1;
+    const ID_HIDE: usize = 2;
+    const ID_RELOAD: usize = 3;
+
+    let ui_language = APP
+        .lock()
+        .map(|app| app.config.ui_language.clone())
+        .unwrap_or_default();
+    let (settings, hide, reload) = match ui_language.as_str() {
+        "vi" => ("Mở cài đặt", "Ẩn bong bóng", "Tải lại mục yêu thích"),
+        "ko" => ("설정 열기", "버블 숨기기", "즐겨찾기 새로고침"),
+        "ja" => ("設定を開く", "バブルを隠す", "お気に入りを再読み込み"),
+        _ => ("Open settings", "Hide bubble", "Reload favorites"),
+    };
+
+    let Ok(hmenu) = CreatePopupMenu() else {
+        return;
+    };
+    for (id, label) in [(ID_SETTINGS, settings), (ID_RELOAD, reload)] {
+        let label = HSTRING::from(label);
+        let _ = AppendMenuW(hmenu, MF_STRING, id, PCWSTR(label.as_ptr()));
+    }
+    let _ = AppendMenuW(hmenu, MF_SEPARATOR, 0, PCWSTR::null());
+    let hide = HSTRING::from(hide);
+    let _ = AppendMenuW(hmenu, MF_STRING, ID_HIDE, PCWSTR(hide.as_ptr()));
+
+    let mut pt = POINT::default();
+    let _ = GetCursorPos(&mut pt);
+
+    // Without this the menu stays open when clicking elsewhere
+    let _ = SetForegroundWindow(hwnd);
+    let cmd = TrackPopupMenu(
+        hmenu,
+        TPM_RETURNCMD | TPM_NONOTIFY | TPM_RIGHTBUTTON,
+        pt.x,
+        pt.y,
+        None,
+        hwnd,
+        None,
+    );
+    let _ = PostMessageW(Some(hwnd), WM_NULL, WPARAM(0), LPARAM(0));
+    let _ = DestroyMenu(hmenu);
+
+    match cmd.0 as usize {
+        ID_SETTINGS => crate::gui::signal_restore_window(),
+        ID_RELOAD => super::panel::update_favorites_panel(),
+        ID_HIDE => {
+            // Same as unticking the bubble in the tray menu
+            if let Ok(mut app) = APP.lock() {
+                app.config.show_favorite_bubble = false;
+                crate::config::save_config(&app.config);
+            }
+            hide_favorite_bubble();
+        }
+        _ => {}
+    }
+}
+
 unsafe extern "system" fn bubble_wnd_proc(
     hwnd: HWND,
     msg: u32,
@@ -179,8 +337,9 @@ unsafe extern "system" fn bubble_wnd_proc(
 
     match msg {
         WM_LBUTTONDOWN => {
-            // Stop any ongoing physics
+            // Stop any ongoing physics / snapping
             let _ = KillTimer(Some(hwnd), PHYSICS_TIMER_ID);
+            let _ = KillTimer(Some(hwnd), SNAP_TIMER_ID);
             PHYSICS_STATE.with(|p| *p.borrow_mut() = (0.0, 0.0));
 
             IS_DRAGGING.store(true, Ordering::SeqCst);
@@ -203,20 +362,50 @@ unsafe extern "system" fn bubble_wnd_proc(
 
             // Only toggle if we didn't drag/move the bubble
             if !was_dragging_moved {
-                if IS_EXPANDED.load(Ordering::SeqCst) {
-                    close_panel();
+                let double_click_ms = GetDoubleClickTime();
+                let is_double_click = LAST_CLICK_TIME.with(|t| {
+                    let mut last = t.borrow_mut();
+                    match last.take() {
+                        Some(prev) if prev.elapsed().as_millis() <= double_click_ms as u128 => true,
+                        _ => {
+                            *last = Some(std::time::Instant::now());
+                            false
+                        }
+                    }
+                });
+
+                if is_double_click {
+                    // Open the main settings window instead of toggling the panel
+                    let _ = KillTimer(Some(hwnd), CLICK_TIMER_ID);
+                    crate::gui::signal_restore_window();
                 } else {
-                    show_panel(hwnd);
+                    // Toggle once the double-click interval has passed
+                    let _ = SetTimer(Some(hwnd), CLICK_TIMER_ID, double_click_ms, None);
                 }
             } else {
+                // A drag cancels any click still waiting for its partner
+                let _ = KillTimer(Some(hwnd), CLICK_TIMER_ID);
+                LAST_CLICK_TIME.with(|t| *t.borrow_mut() = None);
                 // Start physics inertia if we were moving
-                let _ = SetTimer(Some(hwnd), PHYSICS_TIMER_ID, 16, None);
+                if crate::overlay::utils::animations_enabled() {
+                    let _ = SetTimer(Some(hwnd), PHYSICS_TIMER_ID, 16, None);
+                } else {
+                    // Reduced motion: no throw, the bubble stays where it was let go
+                    start_snap_to_edge(hwnd);
+                }
             }
             // Always save current position after movement interaction ends
             save_bubble_position();
             LRESULT(0)
         }
 
+        WM_RBUTTONUP => {
+            if !IS_DRAGGING.load(Ordering::SeqCst) {
+                show_bubble_menu(hwnd);
+            }
+            LRESULT(0)
+        }
+
         WM_MOUSEMOVE => {
             if IS_DRAGGING.load(Ordering::SeqCst) && (wparam.0 & 0x0001) != 0 {
                 // Left button held - check for drag
@@ -373,6 +562,17 @@ unsafe extern "system" fn bubble_wnd_proc(
                         let _ = KillTimer(Some(hwnd), OPACITY_TIMER_ID);
                     }
                 }
+            } else if wparam.0 == FULLSCREEN_TIMER_ID {
+                update_fullscreen_visibility(hwnd);
+            } else if wparam.0 == CLICK_TIMER_ID {
+                // No second click arrived: treat it as a single click
+                let _ = KillTimer(Some(hwnd), CLICK_TIMER_ID);
+                LAST_CLICK_TIME.with(|t| *t.borrow_mut() = None);
+                if IS_EXPANDED.load(Ordering::SeqCst) {
+                    close_panel();
+                } else {
+                    show_panel(hwnd);
+                }
             } else if wparam.0 == PHYSICS_TIMER_ID {
                 PHYSICS_STATE.with(|p| {
                     let (mut vx, mut vy) = *p.borrow();
@@ -388,6 +588,7 @@ unsafe extern "system" fn bubble_wnd_proc(
                         *p.borrow_mut() = (0.0, 0.0);
                         // Save the final resting position
                         save_bubble_position();
+                        start_snap_to_edge(hwnd);
                         return;
                     }
 
@@ -444,6 +645,44 @@ unsafe extern "system" fn bubble_wnd_proc(
                         move_panel_to_bubble(next_x as i32, next_y as i32);
                     }
                 });
+            } else if wparam.0 == SNAP_TIMER_ID {
+                let (target_x, target_y) = SNAP_TARGET.with(|t| *t.borrow());
+
+                let mut rect = RECT::default();
+                let _ = GetWindowRect(hwnd, &mut rect);
+
+                let dx = (target_x - rect.left) as f32;
+                let dy = (target_y - rect.top) as f32;
+
+                let (next_x, next_y) = if (dx.abs() <= 1.0 && dy.abs() <= 1.0)
+                    || !crate::overlay::utils::animations_enabled()
+                {
+                    let _ = KillTimer(Some(hwnd), SNAP_TIMER_ID);
+                    (target_x, target_y)
+                } else {
+                    (
+                        rect.left + (dx * SNAP_EASING).round() as i32,
+                        rect.top + (dy * SNAP_EASING).round() as i32,
+                    )
+                };
+
+                let _ = SetWindowPos(
+                    hwnd,
+                    None,
+                    next_x,
+                    next_y,
+                    0,
+                    0,
+                    SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+                );
+
+                if IS_EXPANDED.load(Ordering::SeqCst) {
+                    move_panel_to_bubble(next_x, next_y);
+                }
+
+                if next_x == target_x && next_y == target_y {
+                    save_bubble_position();
+                }
             }
             LRESULT(0)
         }
@@ -459,6 +698,36 @@ unsafe extern "system" fn bubble_wnd_proc(
             LRESULT(0)
         }
 
+        WM_BUBBLE_RESIZE => {
+            let bubble_size = BUBBLE_SIZE.load(Ordering::SeqCst);
+
+            // Keep the bubble inside the virtual screen at its new size
+            let mut rect = RECT::default();
+            let _ = GetWindowRect(hwnd, &mut rect);
+            let v_x = GetSystemMetrics(SM_XVIRTUALSCREEN);
+            let v_y = GetSystemMetrics(SM_YVIRTUALSCREEN);
+            let v_w = GetSystemMetrics(SM_CXVIRTUALSCREEN);
+            let v_h = GetSystemMetrics(SM_CYVIRTUALSCREEN);
+            let new_x = rect.left.clamp(v_x, v_x + v_w - bubble_size);
+            let new_y = rect.top.clamp(v_y, v_y + v_h - bubble_size);
+
+            let _ = SetWindowPos(
+                hwnd,
+                None,
+                new_x,
+                new_y,
+                bubble_size,
+                bubble_size,
+                SWP_NOZORDER | SWP_NOACTIVATE,
+            );
+            update_bubble_visual(hwnd);
+
+            if IS_EXPANDED.load(Ordering::SeqCst) {
+                move_panel_to_bubble(new_x, new_y);
+            }
+            LRESULT(0)
+        }
+
         WM_FORCE_SHOW_PANEL => {
             // Received request from main thread to show/refresh update panel
             if !IS_EXPANDED.load(Ordering::SeqCst) {
@@ -478,3 +747,52 @@ unsafe extern "system" fn bubble_wnd_proc(
         _ => DefWindowProcW(hwnd, msg, wparam, lparam),
     }
 }
+
+/// Animate the bubble to the nearest edge of its monitor's work area,
+/// if snapping is enabled in config.
+unsafe fn start_snap_to_edge(hwnd: HWND) {
+    let snap_enabled = APP
+        .lock()
+        .map(|app| app.config.favorite_bubble_snap_to_edge)
+        .unwrap_or(false);
+    if !snap_enabled {
+        return;
+    }
+
+    let mut rect = RECT::default();
+    let _ = GetWindowRect(hwnd, &mut rect);
+
+    let monitor = MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST);
+    let mut mi = MONITORINFO {
+        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+        ..Default::default()
+    };
+    if !GetMonitorInfoW(monitor, &mut mi).as_bool() {
+        return;
+    }
+    let work = mi.rcWork;
+    let bubble_size = BUBBLE_SIZE.load(Ordering::SeqCst);
+
+    let dist_left = (rect.left - work.left).abs();
+    let dist_right = (work.right - rect.right).abs();
+    let dist_top = (rect.top - work.top).abs();
+    let dist_bottom = (work.bottom - rect.bottom).abs();
+
+    // Clamp the other axis so the bubble stays fully inside the work area
+    let clamped_x = rect.left.clamp(work.left, work.right - bubble_size);
+    let clamped_y = rect.top.clamp(work.top, work.bottom - bubble_size);
+
+    let min_dist = dist_left.min(dist_right).min(dist_top).min(dist_bottom);
+    let target = if min_dist == dist_left {
+        (work.left, clamped_y)
+    } else if min_dist == dist_right {
+        (work.right - bubble_size, clamped_y)
+    } else if min_dist == dist_top {
+        (clamped_x, work.top)
+    } else {
+        (clamped_x, work.bottom - bubble_size)
+    };
+
+    SNAP_TARGET.with(|t| *t.borrow_mut() = target);
+    let _ = SetTimer(Some(hwnd), SNAP_TIMER_ID, 16, None);
+}
@@ -0,0 +1,81 @@
+//! Re-open a saved image translation from the history panel in a result
+//! window, with the original capture attached so it can be refined again.
+
+use std::path::PathBuf;
+use windows::Win32::Foundation::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+
+use crate::overlay::result::{create_result_window, get_chain_color, RefineContext, WindowType};
+use crate::APP;
+
+/// Show `text` for the capture saved at `image_path` in a new result window
+pub fn reopen_image_result(image_path: PathBuf, text: String) {
+    std::thread::spawn(move || {
+        let Ok(png_data) = std::fs::read(&image_path) else {
+            return;
+        };
+        let (width, height) = image::image_dimensions(&image_path).unwrap_or((700, 300));
+        let (model_id, provider) = refine_vision_model();
+
+        unsafe {
+            let screen_w = GetSystemMetrics(SM_CXSCREEN);
+            let screen_h = GetSystemMetrics(SM_CYSCREEN);
+            // Same size as the original capture, within reason
+            let w = (width as i32).clamp(300, screen_w * 2 / 3);
+            let h = (height as i32).clamp(150, screen_h * 2 / 3);
+            let rect = RECT {
+                left: (screen_w - w) / 2,
+                top: (screen_h - h) / 2,
+                right: (screen_w + w) / 2,
+                bottom: (screen_h + h) / 2,
+            };
+
+            let hwnd = create_result_window(
+                rect,
+                WindowType::Primary,
+                RefineContext::Image(png_data),
+                model_id,
+                provider,
+                false,
+                false,
+                String::new(),
+                get_chain_color(0),
+                "plain",
+                text,
+            );
+            let _ = ShowWindow(hwnd, SW_SHOWNA);
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+                if !IsWindow(Some(hwnd)).as_bool() {
+                    break;
+                }
+            }
+        }
+    });
+}
+
+/// History doesn't record which model produced an entry: refine with the
+/// active preset's vision model, or the first one any preset uses
+fn refine_vision_model() -> (String, String) {
+    let model_id = APP
+        .lock()
+        .ok()
+        .and_then(|app| {
+            let presets = &app.config.presets;
+            presets
+                .get(app.config.active_preset_idx)
+                .into_iter()
+                .chain(presets.iter())
+                .flat_map(|p| p.blocks.iter())
+                .find(|b| b.block_type == "image")
+                .map(|b| b.model.clone())
+        })
+        .unwrap_or_else(|| "gemini-flash-lite".to_string());
+    let provider = crate::model_config::get_model_by_id(&model_id)
+        .map(|m| m.provider)
+        .unwrap_or_else(|| "google".to_string());
+    (model_id, provider)
+}
@@ -1,4 +1,16 @@
-pub fn get(glow_color: &str, font_size: u32, is_dark: bool) -> String {
+/// `opacity` is the user's overlay opacity (0-255). Webviews have no layered
+/// alpha of their own, so the background is drawn a little denser than the
+/// GDI windows to read the same (217 -> the old 0.95).
+pub fn get(
+    glow_color: &str,
+    font_size: u32,
+    is_dark: bool,
+    opacity: u8,
+    animation_speed: f32,
+) -> String {
+    let bg_alpha = (opacity as f32 / 255.0 + 0.1).min(1.0);
+    let bg_dark = format!("rgba(26, 26, 26, {:.2})", bg_alpha);
+    let bg_light = format!("rgba(255, 255, 255, {:.2})", bg_alpha);
     let (
         bg_color,
         text_color,
@@ -17,7 +29,7 @@ pub fn get(glow_color: &str, font_size: u32, is_dark: bool) -> String {
         icon_inactive_color,
     ) = if is_dark {
         (
-            "rgba(26, 26, 26, 0.95)",    // bg_color
+            bg_dark.as_str(),            // bg_color
             "#fff",                      // text_color
             "rgba(26, 26, 26, 0.6)",     // header_bg
             format!("{}40", glow_color), // border_color
@@ -35,7 +47,7 @@ pub fn get(glow_color: &str, font_size: u32, is_dark: bool) -> String {
         )
     } else {
         (
-            "rgba(255, 255, 255, 0.95)",
+            bg_light.as_str(),
             "#202124",
             "rgba(255, 255, 255, 0.8)",
             format!("{}80", glow_color),
@@ -65,8 +77,25 @@ pub fn get(glow_color: &str, font_size: u32, is_dark: bool) -> String {
         "rgba(0,0,0,0.1)"
     };
 
+    // Streaming text transitions scale with the animation speed; 0 turns them off
+    let animation_speed = if crate::overlay::utils::animations_enabled() {
+        animation_speed
+    } else {
+        0.0
+    };
+    let (wipe_s, fade_s) = if animation_speed > 0.0 {
+        (0.35 / animation_speed, 0.6 / animation_speed)
+    } else {
+        (0.0, 0.0)
+    };
+    let wipe_ms = (wipe_s * 1000.0).round() as u32;
+
     format!(
         r###"        * {{ margin: 0; padding: 0; box-sizing: border-box; }}
+        :root {{
+            /* Read by updateText() to time the wipe-in */
+            --wipe-ms: {wipe_ms};
+        }}
         html, body {{
             height: 100%;
             overflow: hidden;
@@ -351,6 +380,18 @@ pub fn get(glow_color: &str, font_size: u32, is_dark: bool) -> String {
             color: {text_color};
             padding: 4px 8px;
         }}
+        /* Device picker: a pill wide enough to hint at the device name */
+        #audio-device-select {{
+            width: auto;
+            max-width: 96px;
+            border-radius: 13px;
+            padding: 0 8px;
+            text-align: left;
+            text-align-last: left;
+            white-space: nowrap;
+            overflow: hidden;
+            text-overflow: ellipsis;
+        }}
         select option:checked {{
             background: linear-gradient(0deg, {glow_color}40, {glow_color}40);
         }}
@@ -402,12 +443,12 @@ pub fn get(glow_color: &str, font_size: u32, is_dark: bool) -> String {
             font-optical-sizing: auto;
             display: inline;
             transition: 
-                color 0.6s cubic-bezier(0.2, 0, 0.2, 1),
-                font-variation-settings 0.6s cubic-bezier(0.2, 0, 0.2, 1),
-                -webkit-mask-position 0.35s cubic-bezier(0.2, 0, 0.2, 1),
-                mask-position 0.35s cubic-bezier(0.2, 0, 0.2, 1),
-                opacity 0.35s ease-out,
-                filter 0.35s ease-out;
+                color {fade_s:.3}s cubic-bezier(0.2, 0, 0.2, 1),
+                font-variation-settings {fade_s:.3}s cubic-bezier(0.2, 0, 0.2, 1),
+                -webkit-mask-position {wipe_s:.3}s cubic-bezier(0.2, 0, 0.2, 1),
+                mask-position {wipe_s:.3}s cubic-bezier(0.2, 0, 0.2, 1),
+                opacity {wipe_s:.3}s ease-out,
+                filter {wipe_s:.3}s ease-out;
         }}
         
         /* Old/committed text styling */
@@ -601,5 +642,21 @@ pub fn get(glow_color: &str, font_size: u32, is_dark: bool) -> String {
         ctrl_hover_bg = ctrl_hover_bg,
         ctrl_hover_text = ctrl_hover_text,
         icon_inactive_color = icon_inactive_color,
-    )
+    ) + reduced_motion_css()
+}
+
+/// Stops keyframe animations and transitions when the system asks for reduced
+/// motion; empty otherwise. Append after the rest of a page's CSS.
+pub fn reduced_motion_css() -> &'static str {
+    if crate::overlay::utils::animations_enabled() {
+        ""
+    } else {
+        r#"
+        *, *::before, *::after {
+            animation-duration: 0.01ms !important;
+            animation-iteration-count: 1 !important;
+            transition-duration: 0.01ms !important;
+        }
+"#
+    }
 }
@@ -108,25 +108,30 @@ pub fn get(placeholder_text: &str) -> String {
             }} else if (fullText.length > totalChunkText.length && fullText.startsWith(totalChunkText)) {{
                 // Normal append mode - text grew
                 const delta = fullText.substring(totalChunkText.length);
+                const chunkStart = totalChunkText.length;
+                const settledClass = () => chunkStart < currentOldTextLength ? 'old' : 'new';
+                const wipeVar = parseFloat(getComputedStyle(document.documentElement).getPropertyValue('--wipe-ms'));
+                const wipeMs = isNaN(wipeVar) ? 350 : wipeVar;
                 
                 const chunk = document.createElement('span');
-                chunk.className = 'text-chunk appearing';
                 chunk.textContent = delta;
-                content.appendChild(chunk);
-                
-                // Trigger wipe animation
-                requestAnimationFrame(() => {{
-                    chunk.classList.add('show');
-                    setTimeout(() => {{
-                        chunk.classList.remove('appearing', 'show');
-                        const chunkStart = totalChunkText.length;
-                        if (chunkStart < currentOldTextLength) {{
-                            chunk.classList.add('old');
-                        }} else {{
-                            chunk.classList.add('new');
-                        }}
-                    }}, 350);
-                }});
+                if (wipeMs <= 0) {{
+                    // Animations off: show the text in its final style right away
+                    chunk.className = 'text-chunk ' + settledClass();
+                    content.appendChild(chunk);
+                }} else {{
+                    chunk.className = 'text-chunk appearing';
+                    content.appendChild(chunk);
+                    
+                    // Trigger wipe animation
+                    requestAnimationFrame(() => {{
+                        chunk.classList.add('show');
+                        setTimeout(() => {{
+                            chunk.classList.remove('appearing', 'show');
+                            chunk.classList.add(settledClass());
+                        }}, wipeMs);
+                    }});
+                }}
             }} else if (fullText !== totalChunkText) {{
                 // General rebuild for other cases
                 content.innerHTML = '';
@@ -180,10 +185,24 @@ pub fn get(placeholder_text: &str) -> String {
         let scrollProgress = 0; // 0 to 1, represents progress to next bar shift
         let lastTime = 0;
         
+        // Bar colors per capture source: blue for mic, violet for device audio
+        const VOLUME_COLORS = {{
+            mic: ['#00a8e0', '#00c8ff', '#40e0ff'],
+            device: ['#8a4fe0', '#a66bff', '#c79bff']
+        }};
+        let volumeColors = VOLUME_COLORS[volumeCanvas ? volumeCanvas.dataset.source : 'mic'] || VOLUME_COLORS.mic;
+        
         function updateVolume(rms) {{
             latestRMS = rms;
         }}
         
+        // Called when capture (re)starts on a source; old bars belong to the previous one
+        function setVolumeSource(source) {{
+            volumeColors = VOLUME_COLORS[source] || VOLUME_COLORS.mic;
+            latestRMS = 0;
+            barHeights.fill(3);
+        }}
+        
         function drawWaveform(timestamp) {{
             if (!volumeCtx) return;
             
@@ -213,9 +232,9 @@ pub fn get(placeholder_text: &str) -> String {
             
             // Gradient
             const grad = volumeCtx.createLinearGradient(0, h, 0, 0);
-            grad.addColorStop(0, '#00a8e0');
-            grad.addColorStop(0.5, '#00c8ff');
-            grad.addColorStop(1, '#40e0ff');
+            grad.addColorStop(0, volumeColors[0]);
+            grad.addColorStop(0.5, volumeColors[1]);
+            grad.addColorStop(1, volumeColors[2]);
             volumeCtx.fillStyle = grad;
             
             // Pixel offset for smooth scroll
@@ -265,6 +284,7 @@ pub fn get(placeholder_text: &str) -> String {
         }}
         
         window.updateVolume = updateVolume;
+        window.setVolumeSource = setVolumeSource;
         
         // Model switch animation (called when 429 fallback switches models)
         function switchModel(modelName) {{
@@ -254,6 +254,15 @@ pub fn get(font_size: u32) -> String {
         // Audio source toggle buttons
         const micBtn = document.getElementById('mic-btn');
         const deviceBtn = document.getElementById('device-btn');
+        const deviceSelect = document.getElementById('audio-device-select');
+
+        // Point the device list at the source's default unless it already
+        // holds a specific device of that source
+        function syncDeviceSelect(source) {{
+            if (deviceSelect && !deviceSelect.value.startsWith(source + '|')) {{
+                deviceSelect.value = source + '|';
+            }}
+        }}
         
         if (micBtn) {{
             micBtn.addEventListener('click', (e) => {{
@@ -263,6 +272,7 @@ pub fn get(font_size: u32) -> String {
                 // Switch to mic mode
                 micBtn.classList.add('active');
                 if (deviceBtn) deviceBtn.classList.remove('active');
+                if (deviceSelect) deviceSelect.value = 'mic|';
                 
                 window.ipc.postMessage('audioSource:mic');
             }});
@@ -276,11 +286,24 @@ pub fn get(font_size: u32) -> String {
                 // Switch to device mode
                 if (micBtn) micBtn.classList.remove('active');
                 deviceBtn.classList.add('active');
+                if (deviceSelect) deviceSelect.value = 'device|';
                 
                 window.ipc.postMessage('audioSource:device');
             }});
         }}
 
+        // Specific microphone / speaker ("<source>|<endpoint id>")
+        if (deviceSelect) {{
+            deviceSelect.addEventListener('mousedown', (e) => e.stopPropagation());
+            deviceSelect.addEventListener('change', (e) => {{
+                e.stopPropagation();
+                const isDevice = deviceSelect.value.startsWith('device|');
+                if (micBtn) micBtn.classList.toggle('active', !isDevice);
+                if (deviceBtn) deviceBtn.classList.toggle('active', isDevice);
+                window.ipc.postMessage('audioSource:' + deviceSelect.value);
+            }});
+        }}
+
 
 
         // Language Select Logic - show short code when collapsed, full name when open
@@ -409,6 +432,7 @@ pub fn get(font_size: u32) -> String {
                     micBtn.classList.add('active');
                     deviceBtn.classList.remove('active');
                 }}
+                syncDeviceSelect(settings.audioSource === 'device' ? 'device' : 'mic');
             }}
             
             // Update language select
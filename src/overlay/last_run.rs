@@ -0,0 +1,121 @@
+//! The most recent image/text pipeline run, kept on `APP` so a hotkey can run
+//! it again without re-capturing or re-selecting.
+
+use image::{ImageBuffer, Rgba};
+use windows::Win32::Foundation::{HWND, RECT};
+use windows::Win32::UI::WindowsAndMessaging::GetWindowRect;
+
+use super::process::{start_processing_pipeline, start_text_processing};
+use crate::APP;
+
+#[derive(Clone)]
+pub enum LastRunInput {
+    Image(ImageBuffer<Rgba<u8>, Vec<u8>>),
+    Text(String),
+}
+
+#[derive(Clone)]
+pub struct LastRun {
+    pub input: LastRunInput,
+    /// Resolved back to an index on replay, so reordering presets is harmless
+    pub preset_id: String,
+    /// The region the run started from (the capture area for images)
+    pub rect: RECT,
+    /// Where the run's first result window was actually placed, once it opened
+    pub result_rect: Option<RECT>,
+}
+
+/// Remember the inputs of a pipeline that is about to run
+pub fn record(input: LastRunInput, preset_id: &str, rect: RECT) {
+    if let Ok(mut app) = APP.lock() {
+        app.last_run = Some(LastRun {
+            input,
+            preset_id: preset_id.to_string(),
+            rect,
+            result_rect: None,
+        });
+    }
+}
+
+/// Called when a chain opens its first result window. The first chain of the
+/// recorded preset to get there is the recorded run; later ones are ignored.
+pub fn record_result_window(preset_id: &str, hwnd: HWND) {
+    let mut rect = RECT::default();
+    if unsafe { GetWindowRect(hwnd, &mut rect) }.is_err() {
+        return;
+    }
+    if let Ok(mut app) = APP.lock() {
+        if let Some(last) = app.last_run.as_mut() {
+            if last.preset_id == preset_id && last.result_rect.is_none() {
+                last.result_rect = Some(rect);
+            }
+        }
+    }
+}
+
+/// Drop the last run if it belongs to a preset that is being deleted
+pub fn forget_preset(preset_id: &str) {
+    if let Ok(mut app) = APP.lock() {
+        if app
+            .last_run
+            .as_ref()
+            .is_some_and(|last| last.preset_id == preset_id)
+        {
+            app.last_run = None;
+        }
+    }
+}
+
+/// Run the last pipeline again with the same input, preset and position.
+/// Blocks like the pipelines themselves; call from a worker thread.
+pub fn rerun_last() {
+    if super::is_busy() || super::is_selection_overlay_active() {
+        return;
+    }
+
+    let (last, config, preset) = {
+        let Ok(mut app) = APP.lock() else {
+            return;
+        };
+        let Some(last) = app.last_run.clone() else {
+            return;
+        };
+        let Some(idx) = app
+            .config
+            .presets
+            .iter()
+            .position(|p| p.id == last.preset_id)
+        else {
+            // The preset was deleted since: nothing sensible to replay
+            app.last_run = None;
+            return;
+        };
+        // Keep auto-paste logic on the replayed preset
+        app.config.active_preset_idx = idx;
+        (last, app.config.clone(), app.config.presets[idx].clone())
+    };
+
+    // Open where the result window was, falling back to the start region if it never opened
+    let rect = last.result_rect.unwrap_or(last.rect);
+    match last.input {
+        LastRunInput::Image(img) => start_processing_pipeline(img, rect, config, preset, None),
+        LastRunInput::Text(text) => {
+            let localized_name =
+                crate::gui::settings_ui::get_localized_preset_name(&preset.id, &config.ui_language);
+            let cancel_hotkey = preset
+                .hotkeys
+                .first()
+                .map(|h| h.name.clone())
+                .unwrap_or_default();
+            start_text_processing(
+                text,
+                rect,
+                config,
+                preset,
+                localized_name,
+                cancel_hotkey,
+                None,
+            );
+        }
+    }
+}
@@ -1,8 +1,11 @@
+pub mod active_window_capture; // One-shot capture of the focused window
 pub mod auto_copy_badge; // Auto-copy notification badge
 pub mod broom_assets;
 pub mod continuous_mode; // Continuous mode for image/text presets (hold-to-activate)
 pub mod input_history; // Persistent input history for arrow up/down navigation
+pub mod last_run; // Last pipeline inputs, replayed by the repeat-last hotkey
 pub mod paint_utils;
+pub mod preset_launcher; // List/run presets from embedded WebView UIs
 pub mod preset_wheel;
 pub mod process;
 pub mod prompt_dj;
@@ -10,6 +13,7 @@ pub mod recording;
 pub mod result;
 pub mod screen_record;
 mod selection;
+pub mod selection_history; // Recent text-selection results, cycled by hotkey
 pub mod text_input; // NEW MODULE
 pub mod text_selection;
 
@@ -28,11 +32,13 @@ pub fn set_is_busy(busy: bool) {
 pub mod utils; // MASTER preset wheel
                // realtime_overlay module removed (was old GDI-based, now using realtime_webview)
 pub mod favorite_bubble; // Floating bubble for favorite presets
+pub mod history_result; // Re-open saved image results from the history panel
 pub mod html_components; // Split HTML components (CSS/JS)
 pub mod realtime_egui; // Minimal mode (native egui)
 pub mod realtime_html; // HTML generation for realtime overlay
 pub mod realtime_webview; // New WebView2-based with smooth scrolling
 pub mod tray_popup; // Custom non-blocking tray popup menu
+pub mod webview_runtime; // WebView2 runtime detection
 
 pub use recording::{
     is_recording_overlay_active, show_recording_overlay, stop_recording_and_submit,
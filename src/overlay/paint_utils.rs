@@ -1,6 +1,17 @@
+use windows::Win32::Foundation::HWND;
+use windows::Win32::UI::HiDpi::GetDpiForWindow;
 
 const CORNER_RADIUS: f32 = 12.0;
 
+/// Scale of the monitor `hwnd` is on (1.0 = 96 DPI). Overlays are placed over
+/// their capture rect, so this is the DPI the capture was taken at.
+pub fn window_dpi_scale(hwnd: HWND) -> f32 {
+    match unsafe { GetDpiForWindow(hwnd) } {
+        0 => 1.0,
+        dpi => dpi as f32 / 96.0,
+    }
+}
+
 #[inline(always)]
 pub fn hsv_to_rgb(h: f32, s: f32, v: f32) -> u32 {
     let c = v * s;
@@ -22,6 +33,26 @@ pub fn hsv_to_rgb(h: f32, s: f32, v: f32) -> u32 {
     (r_u << 16) | (g_u << 8) | b_u 
 }
 
+/// Hue (degrees), saturation and value of a 0xRRGGBB color
+pub fn rgb_to_hsv(rgb: u32) -> (f32, f32, f32) {
+    let r = ((rgb >> 16) & 0xFF) as f32 / 255.0;
+    let g = ((rgb >> 8) & 0xFF) as f32 / 255.0;
+    let b = (rgb & 0xFF) as f32 / 255.0;
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    let sat = if max == 0.0 { 0.0 } else { delta / max };
+    (hue, sat, max)
+}
+
 #[inline(always)]
 pub fn sd_rounded_box(px: f32, py: f32, bx: f32, by: f32, r: f32) -> f32 {
     let qx = px.abs() - bx + r;
@@ -33,15 +64,20 @@ pub fn sd_rounded_box(px: f32, py: f32, bx: f32, by: f32, r: f32) -> f32 {
 
 // OPTIMIZED DRAWING: Writes directly to cached buffer
 // Defers expensive atan2 until after visibility check
+// `tint` (0xRRGGBB) swaps the rainbow for shades around that color
+#[allow(clippy::too_many_arguments)]
 pub unsafe fn draw_direct_sdf_glow(
     pixels_ptr: *mut u32, 
     w: i32, 
     h: i32, 
     time_offset: f32,
     alpha_mult: f32,
-    is_glowing: bool
+    is_glowing: bool,
+    glow_scale: f32,
+    tint: Option<u32>
 ) {
     if pixels_ptr.is_null() { return; }
+    let tint_hsv = tint.map(rgb_to_hsv);
     
     let pixels = std::slice::from_raw_parts_mut(pixels_ptr, (w * h) as usize);
     let bx = (w as f32) / 2.0;
@@ -53,12 +89,13 @@ pub unsafe fn draw_direct_sdf_glow(
     // ADAPTIVE GLOW SCALING: Scale based on window size to keep center hollow
     // For small windows (e.g., 100px): scales to ~20px glow
     // For large windows (e.g., 600px+): scales to 60px glow
+    // glow_scale converts those (96 DPI) sizes to buffer pixels
     let min_dim = (w as f32).min(h as f32);
-    let dynamic_base_scale = (min_dim * 0.2).clamp(20.0, 60.0);
+    let dynamic_base_scale = (min_dim * 0.2).clamp(20.0 * glow_scale, 60.0 * glow_scale);
 
     // Optimization: Skip the middle of the box to save CPU
     // Increase margin slightly to ensure we don't process internal pixels
-    let safe_margin = 85.0;
+    let safe_margin = 85.0 * glow_scale.max(1.0);
     let skip_min_x = center_x - bx + safe_margin;
     let skip_max_x = center_x + bx - safe_margin;
     let skip_min_y = center_y - by + safe_margin;
@@ -139,10 +176,18 @@ pub unsafe fn draw_direct_sdf_glow(
                     let final_alpha = if dist_in < 3.0 { 1.0 } else { intensity };
                     
                     if final_alpha > 0.005 {
-                         let deg = angle.to_degrees() + 180.0;
-                         let hue = (deg + time_offset) % 360.0;
-                         
-                         let rgb = if dist_in < 2.5 { 0x00FFFFFF } else { hsv_to_rgb(hue, 0.8, 1.0) };
+                         let rgb = if dist_in < 2.5 {
+                             0x00FFFFFF
+                         } else if let Some((tint_h, tint_s, tint_v)) = tint_hsv {
+                             // Sway a little around the tint so the glow still moves
+                             let sway = (angle * 2.0 + time_rad).sin();
+                             let hue = (tint_h + sway * 20.0).rem_euclid(360.0);
+                             hsv_to_rgb(hue, tint_s.max(0.5), (tint_v * (0.9 + sway * 0.1)).max(0.6))
+                         } else {
+                             let deg = angle.to_degrees() + 180.0;
+                             let hue = (deg + time_offset) % 360.0;
+                             hsv_to_rgb(hue, 0.8, 1.0)
+                         };
                          
                          let a = (final_alpha * 255.0 * alpha_mult) as u32;
                          let r = ((rgb >> 16) & 0xFF) * a / 255;
@@ -161,7 +206,7 @@ pub unsafe fn draw_direct_sdf_glow(
 
 // === MINIMAL GRAPHICS MODE ===
 // Super lightweight rendering for weak computers.
-// Only draws: white border + bouncing green scan line.
+// Only draws: white border + bouncing scan line (green, or `tint` as 0xRRGGBB).
 // NO per-pixel SDF calculations, NO trigonometry, NO expensive math.
 // This is inspired by the old working version that never crashed.
 pub unsafe fn draw_minimal_glow(
@@ -170,7 +215,8 @@ pub unsafe fn draw_minimal_glow(
     h: i32, 
     time_offset: f32,
     _alpha_mult: f32,
-    is_glowing: bool
+    is_glowing: bool,
+    tint: Option<u32>
 ) {
     if pixels_ptr.is_null() { return; }
     
@@ -207,13 +253,13 @@ pub unsafe fn draw_minimal_glow(
         if scan_range > 0 {
             let scan_y = margin + ((t * scan_range as f32) as i32).clamp(0, scan_range - 1);
             
-            // Draw 2px thick green line
-            let green: u32 = 0xFF00FF00; // ARGB: fully opaque green
+            // Draw 2px thick line
+            let line_color: u32 = 0xFF000000 | tint.unwrap_or(0x00FF00); // ARGB: fully opaque
             for line_offset in 0..2 {
                 let y = scan_y + line_offset;
                 if y > 0 && y < h - 1 {
                     for x in margin..(w - margin) {
-                        pixels[(y * w + x) as usize] = green;
+                        pixels[(y * w + x) as usize] = line_color;
                     }
                 }
             }
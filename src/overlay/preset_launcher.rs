@@ -0,0 +1,61 @@
+//! Listing and launching presets from embedded WebView UIs (screen recorder,
+//! Prompt DJ), the same way the favorite bubble runs them.
+
+use crate::APP;
+use windows::core::w;
+use windows::Win32::Foundation::{LPARAM, WPARAM};
+use windows::Win32::UI::WindowsAndMessaging::{FindWindowW, PostMessageW, WM_HOTKEY};
+
+/// Runnable presets as JSON, with names in the UI language
+pub fn list_presets() -> serde_json::Value {
+    let Ok(app) = APP.lock() else {
+        return serde_json::Value::Array(Vec::new());
+    };
+    let ui_lang = app.config.ui_language.clone();
+    let presets: Vec<serde_json::Value> = app
+        .config
+        .presets
+        .iter()
+        .filter(|p| !p.is_upcoming)
+        .map(|p| {
+            serde_json::json!({
+                "id": p.id,
+                "name": crate::gui::settings_ui::get_localized_preset_name(&p.id, &ui_lang),
+                "type": p.preset_type,
+                "isFavorite": p.is_favorite,
+                "isMaster": p.is_master,
+                "hotkeys": p.hotkeys.iter().map(|h| h.name.clone()).collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+    serde_json::Value::Array(presets)
+}
+
+/// Run the preset with this ID as if its hotkey had been pressed
+pub fn trigger_preset(id: &str) -> Result<(), String> {
+    let preset_idx = {
+        let app = APP.lock().map_err(|e| e.to_string())?;
+        app.config
+            .presets
+            .iter()
+            .position(|p| p.id == id && !p.is_upcoming)
+            .ok_or_else(|| format!("Unknown preset: {}", id))?
+    };
+    if post_preset_hotkey(preset_idx) {
+        Ok(())
+    } else {
+        Err("Hotkey listener is not running".to_string())
+    }
+}
+
+/// Hand the preset to the hotkey listener; false if it is not running
+pub fn post_preset_hotkey(preset_idx: usize) -> bool {
+    unsafe {
+        let hwnd = FindWindowW(w!("HotkeyListenerClass"), w!("Listener")).unwrap_or_default();
+        if hwnd.is_invalid() {
+            return false;
+        }
+        let hotkey_id = (preset_idx as i32 * 1000) + 1;
+        PostMessageW(Some(hwnd), WM_HOTKEY, WPARAM(hotkey_id as usize), LPARAM(0)).is_ok()
+    }
+}
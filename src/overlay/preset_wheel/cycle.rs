@@ -0,0 +1,306 @@
+// Preset Cycle - keyboard alternative to the wheel for MASTER presets.
+// Each press of the master hotkey steps to the next preset in a small HUD;
+// letting go of the hotkey's modifiers runs the one shown (like Alt+Tab).
+
+use super::window::{show_preset_wheel, wheel_candidates};
+use crate::config::Preset;
+use crate::APP;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Mutex, Once};
+use std::time::{Duration, Instant};
+use windows::core::w;
+use windows::Win32::Foundation::*;
+use windows::Win32::Graphics::Gdi::*;
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use windows::Win32::UI::Input::KeyboardAndMouse::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+
+static REGISTER_HUD_CLASS: Once = Once::new();
+
+// HUD size in logical pixels
+const HUD_WIDTH: i32 = 360;
+const HUD_HEIGHT: i32 = 52;
+
+/// Hotkeys without modifiers have nothing to let go of: run the shown preset
+/// once the key has been left alone this long
+const CONFIRM_DELAY: Duration = Duration::from_millis(800);
+
+static CYCLE_ACTIVE: AtomicBool = AtomicBool::new(false);
+/// Hotkey presses since the cycle started; the shown preset is this modulo the count
+static CYCLE_STEPS: AtomicUsize = AtomicUsize::new(0);
+static CYCLE_MASTER_ID: Mutex<String> = Mutex::new(String::new());
+
+static HUD_TEXT: Mutex<String> = Mutex::new(String::new());
+static HUD_IS_DARK: AtomicBool = AtomicBool::new(true);
+
+/// Let the MASTER preset pick the preset to run, the way it is set up to:
+/// the radial wheel or the hotkey cycle
+pub fn choose_preset(
+    master: &Preset,
+    filter_type: &str,
+    filter_mode: Option<&str>,
+    center_pos: POINT,
+) -> Option<usize> {
+    if master.master_mode == "cycle" {
+        cycle_presets(master, filter_type, filter_mode, center_pos)
+    } else {
+        show_preset_wheel(filter_type, filter_mode, center_pos)
+    }
+}
+
+pub fn is_cycle_active() -> bool {
+    CYCLE_ACTIVE.load(Ordering::SeqCst)
+}
+
+/// A preset hotkey was pressed while cycling: step on if it is the master's
+pub fn on_cycle_hotkey(preset_idx: usize) {
+    let is_master = APP
+        .lock()
+        .ok()
+        .and_then(|app| app.config.presets.get(preset_idx).map(|p| p.id.clone()))
+        .is_some_and(|id| id == *CYCLE_MASTER_ID.lock().unwrap());
+    if is_master {
+        CYCLE_STEPS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Blocks until a preset is picked (Some) or the cycle is cancelled with Esc (None)
+fn cycle_presets(
+    master: &Preset,
+    filter_type: &str,
+    filter_mode: Option<&str>,
+    center_pos: POINT,
+) -> Option<usize> {
+    let (presets, ui_lang) = {
+        let app = APP.lock().unwrap();
+        (app.config.presets.clone(), app.config.ui_language.clone())
+    };
+    let candidates = wheel_candidates(&presets, filter_type, filter_mode);
+    if candidates.is_empty() {
+        return None;
+    }
+    let names: Vec<String> = candidates
+        .iter()
+        .map(|(_, p)| crate::gui::settings_ui::get_localized_preset_name(&p.id, &ui_lang))
+        .collect();
+
+    // The hotkey that started this master, to tell when it is let go
+    let hotkey = crate::overlay::continuous_mode::get_current_hotkey_info()
+        .and_then(|(mods, code)| {
+            master
+                .hotkeys
+                .iter()
+                .find(|h| h.modifiers == mods && h.code == code)
+        })
+        .or_else(|| master.hotkeys.first())
+        .cloned();
+
+    *CYCLE_MASTER_ID.lock().unwrap() = master.id.clone();
+    CYCLE_STEPS.store(0, Ordering::SeqCst);
+    CYCLE_ACTIVE.store(true, Ordering::SeqCst);
+    HUD_IS_DARK.store(crate::overlay::is_dark_mode(), Ordering::SeqCst);
+
+    let hud = unsafe { create_hud(center_pos) };
+
+    let mut shown = usize::MAX;
+    let mut held_seen = false;
+    // Enter may still be down from submitting the input that got us here
+    let mut enter_armed = false;
+    let mut last_change = Instant::now();
+    let result = loop {
+        unsafe {
+            let mut msg = MSG::default();
+            while PeekMessageW(&mut msg, None, 0, 0, PM_REMOVE).as_bool() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        }
+
+        let current = CYCLE_STEPS.load(Ordering::SeqCst) % candidates.len();
+        if current != shown {
+            shown = current;
+            last_change = Instant::now();
+            *HUD_TEXT.lock().unwrap() = format!(
+                "‹  {}  ›    {}/{}",
+                names[current],
+                current + 1,
+                candidates.len()
+            );
+            if let Some(hud) = hud {
+                unsafe {
+                    let _ = InvalidateRect(Some(hud), None, true);
+                }
+            }
+        }
+
+        if key_down(VK_ESCAPE.0 as i32) {
+            break None;
+        }
+        if !key_down(VK_RETURN.0 as i32) {
+            enter_armed = true;
+        } else if enter_armed {
+            break Some(candidates[current].0);
+        }
+
+        match &hotkey {
+            Some(hk) if hk.modifiers != 0 => {
+                // Run the shown preset once the modifiers are let go
+                if modifiers_down(hk.modifiers) {
+                    held_seen = true;
+                } else if held_seen {
+                    break Some(candidates[current].0);
+                }
+            }
+            Some(hk) => {
+                if key_down(hk.code as i32) {
+                    held_seen = true;
+                    last_change = Instant::now();
+                } else if held_seen && last_change.elapsed() >= CONFIRM_DELAY {
+                    break Some(candidates[current].0);
+                }
+            }
+            // Nothing to press: Enter or Esc decide
+            None => {}
+        }
+
+        std::thread::sleep(Duration::from_millis(10));
+    };
+
+    CYCLE_ACTIVE.store(false, Ordering::SeqCst);
+    if let Some(hud) = hud {
+        unsafe {
+            let _ = DestroyWindow(hud);
+        }
+    }
+    result
+}
+
+fn key_down(vk: i32) -> bool {
+    unsafe { (GetAsyncKeyState(vk) as u16 & 0x8000) != 0 }
+}
+
+/// Whether any of the hotkey's modifiers is still held
+fn modifiers_down(modifiers: u32) -> bool {
+    let alt = (modifiers & 0x0001) != 0 && key_down(VK_MENU.0 as i32); // MOD_ALT
+    let ctrl = (modifiers & 0x0002) != 0 && key_down(VK_CONTROL.0 as i32); // MOD_CONTROL
+    let shift = (modifiers & 0x0004) != 0 && key_down(VK_SHIFT.0 as i32); // MOD_SHIFT
+    let win = (modifiers & 0x0008) != 0 // MOD_WIN
+        && (key_down(VK_LWIN.0 as i32) || key_down(VK_RWIN.0 as i32));
+    alt || ctrl || shift || win
+}
+
+unsafe fn create_hud(center_pos: POINT) -> Option<HWND> {
+    let instance = GetModuleHandleW(None).unwrap_or_default();
+    let class_name = w!("SGTPresetCycleHud");
+    REGISTER_HUD_CLASS.call_once(|| {
+        let wc = WNDCLASSW {
+            lpfnWndProc: Some(hud_wnd_proc),
+            hInstance: instance.into(),
+            lpszClassName: class_name,
+            hCursor: LoadCursorW(None, IDC_ARROW).unwrap_or_default(),
+            ..Default::default()
+        };
+        RegisterClassW(&wc);
+    });
+
+    let hwnd = CreateWindowExW(
+        WS_EX_TOPMOST | WS_EX_TOOLWINDOW | WS_EX_NOACTIVATE,
+        class_name,
+        w!("PresetCycle"),
+        WS_POPUP,
+        0,
+        0,
+        HUD_WIDTH,
+        HUD_HEIGHT,
+        None,
+        None,
+        Some(instance.into()),
+        None,
+    )
+    .ok()?;
+
+    // Center on the cursor, kept inside that monitor's work area
+    let scale = crate::overlay::paint_utils::window_dpi_scale(hwnd);
+    let w = (HUD_WIDTH as f32 * scale) as i32;
+    let h = (HUD_HEIGHT as f32 * scale) as i32;
+    let mut mi = MONITORINFO {
+        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+        ..Default::default()
+    };
+    let monitor = MonitorFromPoint(center_pos, MONITOR_DEFAULTTONEAREST);
+    let _ = GetMonitorInfoW(monitor, &mut mi);
+    let work = mi.rcWork;
+    let x = (center_pos.x - w / 2).clamp(work.left, (work.right - w).max(work.left));
+    let y = (center_pos.y - h / 2).clamp(work.top, (work.bottom - h).max(work.top));
+
+    let _ = SetWindowPos(hwnd, Some(HWND_TOPMOST), x, y, w, h, SWP_NOACTIVATE);
+    let radius = (16.0 * scale) as i32;
+    SetWindowRgn(
+        hwnd,
+        Some(CreateRoundRectRgn(0, 0, w + 1, h + 1, radius, radius)),
+        true,
+    );
+    let _ = ShowWindow(hwnd, SW_SHOWNOACTIVATE);
+    Some(hwnd)
+}
+
+unsafe extern "system" fn hud_wnd_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    match msg {
+        WM_PAINT => {
+            let mut ps = PAINTSTRUCT::default();
+            let hdc = BeginPaint(hwnd, &mut ps);
+            let mut rect = RECT::default();
+            let _ = GetClientRect(hwnd, &mut rect);
+
+            let (bg, fg) = if HUD_IS_DARK.load(Ordering::SeqCst) {
+                (COLORREF(0x001E1414), COLORREF(0x00EEEEEE))
+            } else {
+                (COLORREF(0x00FAF5F5), COLORREF(0x00222222))
+            };
+            let brush = CreateSolidBrush(bg);
+            FillRect(hdc, &rect, brush);
+            let _ = DeleteObject(brush.into());
+
+            let scale = crate::overlay::paint_utils::window_dpi_scale(hwnd);
+            let hfont = CreateFontW(
+                (18.0 * scale) as i32,
+                0,
+                0,
+                0,
+                FW_MEDIUM.0 as i32,
+                0,
+                0,
+                0,
+                DEFAULT_CHARSET,
+                OUT_DEFAULT_PRECIS,
+                CLIP_DEFAULT_PRECIS,
+                CLEARTYPE_QUALITY,
+                (VARIABLE_PITCH.0 | FF_SWISS.0) as u32,
+                w!("Google Sans Flex"),
+            );
+            let old_font = SelectObject(hdc, hfont.into());
+            SetBkMode(hdc, TRANSPARENT);
+            SetTextColor(hdc, fg);
+
+            let mut text: Vec<u16> = HUD_TEXT.lock().unwrap().encode_utf16().collect();
+            DrawTextW(
+                hdc,
+                &mut text,
+                &mut rect,
+                DT_CENTER | DT_VCENTER | DT_SINGLELINE | DT_END_ELLIPSIS,
+            );
+
+            SelectObject(hdc, old_font);
+            let _ = DeleteObject(hfont.into());
+            let _ = EndPaint(hwnd, &ps);
+            LRESULT(0)
+        }
+        WM_MOUSEACTIVATE => LRESULT(MA_NOACTIVATE as isize),
+        _ => DefWindowProcW(hwnd, msg, wparam, lparam),
+    }
+}
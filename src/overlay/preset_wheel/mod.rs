@@ -1,7 +1,9 @@
 // Preset Wheel Overlay - Modern WebView2 implementation
 // Shows a beautiful wheel of preset options for MASTER presets
 
+mod cycle;
 mod html;
 mod window;
 
+pub use cycle::{choose_preset, is_cycle_active, on_cycle_hotkey};
 pub use window::{dismiss_wheel, is_wheel_active, show_preset_wheel, warmup};
@@ -140,41 +140,7 @@ pub fn show_preset_wheel(
         // Generate themed CSS for injection
         let themed_css = generate_css(is_dark);
 
-        let filtered: Vec<(usize, Preset)> = presets
-            .iter()
-            .enumerate()
-            .filter(|(_, p)| {
-                if p.is_master {
-                    return false;
-                }
-                if p.is_upcoming {
-                    return false;
-                }
-                if p.preset_type != filter_type {
-                    return false;
-                }
-                if filter_type == "audio" && p.audio_processing_mode == "realtime" {
-                    return false;
-                }
-                if let Some(mode) = filter_mode {
-                    match filter_type {
-                        "text" => {
-                            if p.text_input_mode != mode {
-                                return false;
-                            }
-                        }
-                        "audio" => {
-                            if p.audio_source != mode {
-                                return false;
-                            }
-                        }
-                        _ => {}
-                    }
-                }
-                true
-            })
-            .map(|(i, p)| (i, p.clone()))
-            .collect();
+        let filtered = wheel_candidates(&presets, filter_type, filter_mode);
 
         if filtered.is_empty() {
             WHEEL_ACTIVE.store(false, Ordering::SeqCst);
@@ -184,6 +150,7 @@ pub fn show_preset_wheel(
         let dismiss_label = match ui_lang.as_str() {
             "vi" => "HỦY",
             "ko" => "취소",
+            "ja" => "キャンセル",
             _ => "CANCEL",
         };
 
@@ -236,6 +203,50 @@ pub fn show_preset_wheel(
     }
 }
 
+/// Presets the master preset can pick from: same type (and input mode) and
+/// not masters themselves
+pub(super) fn wheel_candidates(
+    presets: &[Preset],
+    filter_type: &str,
+    filter_mode: Option<&str>,
+) -> Vec<(usize, Preset)> {
+    presets
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| {
+            if p.is_master {
+                return false;
+            }
+            if p.is_upcoming {
+                return false;
+            }
+            if p.preset_type != filter_type {
+                return false;
+            }
+            if filter_type == "audio" && p.audio_processing_mode == "realtime" {
+                return false;
+            }
+            if let Some(mode) = filter_mode {
+                match filter_type {
+                    "text" => {
+                        if p.text_input_mode != mode {
+                            return false;
+                        }
+                    }
+                    "audio" => {
+                        if p.audio_source != mode {
+                            return false;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            true
+        })
+        .map(|(i, p)| (i, p.clone()))
+        .collect()
+}
+
 pub fn dismiss_wheel() {
     unsafe {
         let hwnd_val = WHEEL_HWND.load(Ordering::SeqCst);
@@ -3,7 +3,7 @@ use crate::config::{Config, Preset, ProcessingBlock};
 use crate::gui::settings_ui::get_localized_preset_name;
 use crate::overlay::result::{
     create_result_window, get_chain_color, link_windows, update_window_text, RefineContext,
-    WindowType, WINDOW_STATES,
+    RetryRequest, WindowType, WINDOW_STATES,
 };
 use crate::overlay::text_input;
 use crate::win_types::SendHwnd;
@@ -18,6 +18,17 @@ use windows::Win32::UI::WindowsAndMessaging::*;
 use super::types::{generate_chain_id, get_next_window_position_for_chain};
 use super::window::create_processing_window;
 
+/// The preset's blocks for one run, with the run's streaming override (Shift held on the hotkey) applied
+pub fn blocks_for_run(preset: &Preset, streaming_override: Option<bool>) -> Vec<ProcessingBlock> {
+    let mut blocks = preset.blocks.clone();
+    if let Some(streaming) = streaming_override {
+        for block in &mut blocks {
+            block.streaming_enabled = streaming;
+        }
+    }
+    blocks
+}
+
 // --- CORE PIPELINE LOGIC ---
 
 pub fn execute_chain_pipeline(
@@ -26,11 +37,20 @@ pub fn execute_chain_pipeline(
     config: Config,
     preset: Preset,
     context: RefineContext,
+    streaming_override: Option<bool>,
 ) {
     // 1. Create Processing Window (Gradient Glow)
     // This window stays on the current thread (UI thread context for this operation)
     let graphics_mode = config.graphics_mode.clone();
-    let processing_hwnd = unsafe { create_processing_window(rect, graphics_mode) };
+    let cancel_token = Arc::new(AtomicBool::new(false));
+    let processing_hwnd = unsafe {
+        create_processing_window(
+            rect,
+            graphics_mode,
+            preset.glow_color_rgb(),
+            cancel_token.clone(),
+        )
+    };
     unsafe {
         let _ = SendMessageW(processing_hwnd, WM_TIMER, Some(WPARAM(1)), Some(LPARAM(0)));
     }
@@ -38,7 +58,7 @@ pub fn execute_chain_pipeline(
     // 2. Start the chain execution on a BACKGROUND thread
     // We pass the processing_hwnd so the background thread can close it when appropriate
     let conf_clone = config.clone();
-    let blocks = preset.blocks.clone();
+    let blocks = blocks_for_run(&preset, streaming_override);
     let connections = preset.block_connections.clone();
     let preset_id = preset.id.clone();
 
@@ -58,7 +78,7 @@ pub fn execute_chain_pipeline(
             context,
             false,
             Some(processing_hwnd_send), // Pass the handle to be closed later
-            Arc::new(AtomicBool::new(false)), // New chains start with cancellation = false
+            cancel_token,               // Set if the user presses Esc on the overlay
             preset_id,
             false,    // disable_auto_paste
             chain_id, // Per-chain position tracking
@@ -91,11 +111,12 @@ pub fn execute_chain_pipeline_with_token(
     context: RefineContext,
     cancel_token: Arc<AtomicBool>,
     input_hwnd_refocus: Option<SendHwnd>,
+    streaming_override: Option<bool>,
 ) {
     // For text presets: NO processing window (gradient glow).
     // The result window itself shows the refining animation.
 
-    let blocks = preset.blocks.clone();
+    let blocks = blocks_for_run(&preset, streaming_override);
     let connections = preset.block_connections.clone();
 
     // Generate unique chain ID for this processing chain
@@ -120,6 +141,82 @@ pub fn execute_chain_pipeline_with_token(
     );
 }
 
+/// The block's prompt with its language placeholders filled in
+pub fn resolve_block_prompt(block: &ProcessingBlock) -> String {
+    let mut final_prompt = block.prompt.clone();
+    let (target, fallback) = crate::overlay::utils::keyboard_language_source();
+    let resolve =
+        |value: &str| crate::overlay::utils::resolve_language_var(value, target, &fallback);
+    for (key, value) in &block.language_vars {
+        final_prompt = final_prompt.replace(&format!("{{{}}}", key), &resolve(value));
+    }
+    // Fallback: if {language1} is still in prompt but not in language_vars, use selected_language
+    if final_prompt.contains("{language1}") && !block.language_vars.contains_key("language1") {
+        final_prompt = final_prompt.replace("{language1}", &resolve(&block.selected_language));
+    }
+    if final_prompt.contains("{language}") {
+        final_prompt = final_prompt.replace("{language}", &resolve(&block.selected_language));
+    }
+    fill_builtin_prompt_vars(final_prompt)
+}
+
+/// "JA → VI" for blocks that translate to `{language1}`. The source is detected
+/// from the input text, so image blocks (no text in) only get "→ VI".
+fn language_label(block: &ProcessingBlock, input_text: &str) -> Option<String> {
+    if !block.prompt.contains("{language1}") {
+        return None;
+    }
+    let (keyboard_target, fallback) = crate::overlay::utils::keyboard_language_source();
+    let target = crate::overlay::utils::resolve_language_var(
+        block
+            .language_vars
+            .get("language1")
+            .unwrap_or(&block.selected_language),
+        keyboard_target,
+        &fallback,
+    );
+    let target_code = isolang::Language::from_name(&target)
+        .and_then(|l| l.to_639_1())
+        .map(|c| c.to_uppercase())
+        .unwrap_or_else(|| target.chars().take(2).collect::<String>().to_uppercase());
+
+    let source_code = (block.block_type != "image")
+        .then(|| whatlang::detect(input_text))
+        .flatten()
+        .filter(|info| info.confidence() > 0.5)
+        .map(|info| {
+            isolang::Language::from_639_3(info.lang().code())
+                .and_then(|l| l.to_639_1())
+                .unwrap_or(info.lang().code())
+                .to_uppercase()
+        });
+
+    Some(match source_code {
+        Some(source) => format!("{} → {}", source, target_code),
+        None => format!("→ {}", target_code),
+    })
+}
+
+/// Substitute `{date}`, `{time}` and `{active_app}` (the process name of the
+/// window the user was in when the preset was triggered)
+fn fill_builtin_prompt_vars(mut prompt: String) -> String {
+    let now = chrono::Local::now();
+    prompt = prompt
+        .replace("{date}", &now.format("%Y-%m-%d").to_string())
+        .replace("{time}", &now.format("%H:%M").to_string());
+    if prompt.contains("{active_app}") {
+        let target = crate::APP
+            .lock()
+            .ok()
+            .and_then(|app| app.last_active_window);
+        let app_name = target
+            .and_then(|h| crate::overlay::utils::get_window_process_name(h.0))
+            .unwrap_or_default();
+        prompt = prompt.replace("{active_app}", &app_name);
+    }
+    prompt
+}
+
 /// Recursive step to run a block in the chain (now supports graph with connections)
 pub fn run_chain_step(
     block_idx: usize,
@@ -169,15 +266,7 @@ pub fn run_chain_step(
         .unwrap_or("groq".to_string());
     let model_full_name = model_conf.map(|m| m.full_name).unwrap_or(model_id.clone());
 
-    let mut final_prompt = block.prompt.clone();
-    for (key, value) in &block.language_vars {
-        final_prompt = final_prompt.replace(&format!("{{{}}}", key), value);
-    }
-    // Fallback: if {language1} is still in prompt but not in language_vars, use selected_language
-    if final_prompt.contains("{language1}") && !block.language_vars.contains_key("language1") {
-        final_prompt = final_prompt.replace("{language1}", &block.selected_language);
-    }
-    final_prompt = final_prompt.replace("{language}", &block.selected_language);
+    let final_prompt = resolve_block_prompt(block);
 
     // 2. Determine Visibility & Position
     let visible_count_before = blocks
@@ -190,7 +279,7 @@ pub fn run_chain_step(
     // For visible windows: use per-chain queue for sequential snake positioning (first-come-first-serve)
     // Windows in the same chain use snake placement, different chains are independent
     let my_rect = if block.show_overlay {
-        get_next_window_position_for_chain(&chain_id, current_rect)
+        get_next_window_position_for_chain(&chain_id, current_rect, &config.retranslation_layout)
     } else {
         current_rect // Hidden blocks don't consume a position
     };
@@ -621,6 +710,7 @@ progressBar.onclick = (e) => {{
 
         let cancel_token_thread = cancel_token.clone();
         let input_hwnd_refocus_thread = input_hwnd_refocus.clone();
+        let preset_id_thread = preset_id.clone();
         std::thread::spawn(move || {
             // NOTE: wry handles COM internally, explicit initialization may interfere
 
@@ -646,6 +736,10 @@ progressBar.onclick = (e) => {{
                     st.cancellation_token = Some(cancel_token_thread.clone());
                 }
             }
+            crate::overlay::result::set_window_preset(hwnd, &preset_id_thread);
+            if visible_count_before == 0 {
+                crate::overlay::last_run::record_result_window(&preset_id_thread, hwnd);
+            }
 
             if let Ok(p_guard) = parent_clone.lock() {
                 if let Some(ph) = *p_guard {
@@ -762,6 +856,9 @@ progressBar.onclick = (e) => {{
     // 4. Execution (API Call)
     // 4. Execution (API Call)
     let input_text_for_history = input_text.clone();
+    let mut block_failed = false;
+    // History entry saved while an image result streams in, so a crash keeps the partial text
+    let history_entry: Arc<Mutex<Option<i64>>> = Arc::new(Mutex::new(None));
     let result_text = if block.block_type == "input_adapter" {
         // Pass-through: return input as-is immediately
         input_text.clone()
@@ -803,6 +900,37 @@ progressBar.onclick = (e) => {{
         let mut retry_count = 0;
         const MAX_RETRIES: usize = 2;
 
+        // The chosen model is still cooling down from a rate limit: start on a fallback
+        if config.skip_rate_limited_models {
+            let model_type = if block.block_type == "image" {
+                crate::model_config::ModelType::Vision
+            } else {
+                crate::model_config::ModelType::Text
+            };
+            while let Some(left) =
+                crate::api::client::model_cooldown(&current_provider, &current_model_full_name)
+            {
+                failed_model_ids.push(current_model_id.clone());
+                let Some(next_model) = crate::model_config::resolve_fallback_model(
+                    &current_model_id,
+                    &failed_model_ids,
+                    &model_type,
+                    &config,
+                ) else {
+                    break;
+                };
+                crate::log_info!(
+                    "[Chain] {} is rate-limited for {}s more - using {}",
+                    current_model_full_name,
+                    left.as_secs(),
+                    next_model.full_name
+                );
+                current_model_id = next_model.id;
+                current_provider = next_model.provider;
+                current_model_full_name = next_model.full_name;
+            }
+        }
+
         // For image blocks: track if window has been shown and share processing_hwnd
         let window_shown = Arc::new(Mutex::new(block.block_type != "image")); // true for text, false for image
         let window_shown_clone = window_shown.clone();
@@ -811,6 +939,9 @@ progressBar.onclick = (e) => {{
 
         // RETRY LOOP
         let res = loop {
+            // Collect the provider's token report for this attempt
+            let usage_scope = crate::api::client::UsageScope::enter();
+
             let res_inner = if is_first_processing_block
                 && block.block_type == "image"
                 && matches!(context, RefineContext::Image(_))
@@ -825,6 +956,8 @@ progressBar.onclick = (e) => {{
                     let my_hwnd_inner = my_hwnd;
                     let window_shown_inner = window_shown_clone.clone();
                     let proc_hwnd_inner = processing_hwnd_clone.clone();
+                    let history_entry_inner = history_entry.clone();
+                    let history_png = block.show_overlay.then(|| img_data.clone());
 
                     // CLEAR ACCUMULATOR ON RETRY
                     if retry_count > 0 {
@@ -843,6 +976,7 @@ progressBar.onclick = (e) => {{
                         Some(img_data),
                         actual_streaming_enabled,
                         use_json,
+                        &cancel_token,
                         move |chunk| {
                             let _now = std::time::SystemTime::now()
                                 .duration_since(std::time::UNIX_EPOCH)
@@ -858,6 +992,20 @@ progressBar.onclick = (e) => {{
                                 t.push_str(chunk);
                             }
 
+                            if let Some(png) = &history_png {
+                                let mut entry = history_entry_inner.lock().unwrap();
+                                if let Ok(app) = crate::APP.lock() {
+                                    match *entry {
+                                        Some(id) => app.history.update_partial(id, t.clone()),
+                                        None => {
+                                            *entry = Some(
+                                                app.history.begin_image(png.clone(), t.clone()),
+                                            )
+                                        }
+                                    }
+                                }
+                            }
+
                             if let Some(h) = my_hwnd_inner {
                                 // On first chunk for image blocks: show window and close processing indicator
                                 {
@@ -920,6 +1068,7 @@ progressBar.onclick = (e) => {{
                     false,
                     search_label,
                     &config.ui_language,
+                    &cancel_token,
                     move |chunk| {
                         let _now = std::time::SystemTime::now()
                             .duration_since(std::time::UNIX_EPOCH)
@@ -952,8 +1101,22 @@ progressBar.onclick = (e) => {{
 
             // CHECK RESULT AND RETRY IF NEEDED
             match res_inner {
-                Ok(val) => break Ok(val),
+                Ok(val) => {
+                    let prompt_text = if block.block_type == "image" {
+                        final_prompt.clone()
+                    } else {
+                        format!("{}{}", final_prompt, input_text)
+                    };
+                    let usage = usage_scope.finish(&prompt_text, &val);
+                    crate::token_usage::record_preset_usage(&preset_id, usage);
+                    break Ok(val);
+                }
                 Err(e) => {
+                    // Window closed mid-request: don't fall back to another model
+                    if cancel_token.load(Ordering::Relaxed) {
+                        break Err(e);
+                    }
+
                     // Check if retryable
                     if retry_count < MAX_RETRIES
                         && crate::overlay::utils::is_retryable_error(&e.to_string())
@@ -1003,12 +1166,28 @@ progressBar.onclick = (e) => {{
             }
         };
 
+        // Cancelled with Esc on the processing overlay before anything was shown:
+        // drop the hidden result window instead of revealing an error in it
+        if cancel_token.load(Ordering::Relaxed) && !*window_shown.lock().unwrap() {
+            unsafe {
+                if let Some(h) = my_hwnd {
+                    let _ = PostMessageW(Some(h), WM_CLOSE, WPARAM(0), LPARAM(0));
+                }
+                if let Some(ph) = processing_hwnd_shared.lock().unwrap().take() {
+                    let _ = PostMessageW(Some(ph.0), WM_CLOSE, WPARAM(0), LPARAM(0));
+                }
+            }
+            return;
+        }
+
         // CRITICAL: Set is_streaming_active = false AND pending_text atomically in the same lock
         // to prevent race condition where the timer detects streaming_just_ended but pending_text
         // hasn't been set yet (causing the final text to be throttled and not rendered)
         match res {
             Ok(txt) => {
                 if let Some(h) = my_hwnd {
+                    // Resolved before WINDOW_STATES is taken: it may lock APP
+                    let label = language_label(block, &input_text);
                     let mut s = WINDOW_STATES.lock().unwrap();
                     if let Some(st) = s.get_mut(&(h.0 as isize)) {
                         st.is_refining = false;
@@ -1017,18 +1196,33 @@ progressBar.onclick = (e) => {{
                         // Set pending_text in same lock to avoid race condition
                         st.pending_text = Some(txt.clone());
                         st.full_text = txt.clone();
+                        st.language_label = label;
+                        st.source_text = (block.block_type != "image" && !input_text.is_empty())
+                            .then(|| input_text.clone());
                     }
                 }
                 txt
             }
             Err(e) => {
+                block_failed = true;
                 let lang = config.ui_language.clone();
                 let err = crate::overlay::utils::get_error_message(
                     &e.to_string(),
                     &lang,
                     Some(&current_model_full_name),
                 );
-                if let Some(h) = my_hwnd {
+                if block.on_error == "skip" {
+                    // Optional step: drop its window quietly. Detach the token first so
+                    // closing it doesn't cancel the rest of the chain.
+                    if let Some(h) = my_hwnd.take() {
+                        if let Some(st) = WINDOW_STATES.lock().unwrap().get_mut(&(h.0 as isize)) {
+                            st.cancellation_token = None;
+                        }
+                        unsafe {
+                            let _ = PostMessageW(Some(h), WM_CLOSE, WPARAM(0), LPARAM(0));
+                        }
+                    }
+                } else if let Some(h) = my_hwnd {
                     // CRITICAL: For image blocks, the window may still be hidden if on_chunk was never called
                     // We must show it now to display the error message
                     {
@@ -1048,6 +1242,25 @@ progressBar.onclick = (e) => {{
                             }
                         }
                     }
+                    // Keep what was sent so the window can retry without a new capture
+                    let image = match &context {
+                        RefineContext::Image(png)
+                            if is_first_processing_block && block.block_type == "image" =>
+                        {
+                            Some(png.clone())
+                        }
+                        _ => None,
+                    };
+                    let retry_request = RetryRequest {
+                        prompt: final_prompt.clone(),
+                        input_text: input_text.clone(),
+                        image,
+                        model_full_name: current_model_full_name.clone(),
+                        provider: current_provider.clone(),
+                        streaming_enabled: actual_streaming_enabled,
+                        use_json,
+                        search_label: Some(get_localized_preset_name(&preset_id, &lang)),
+                    };
                     // Set is_streaming_active = false AND pending_text atomically
                     let mut s = WINDOW_STATES.lock().unwrap();
                     if let Some(st) = s.get_mut(&(h.0 as isize)) {
@@ -1056,6 +1269,8 @@ progressBar.onclick = (e) => {{
                         st.font_cache_dirty = true;
                         st.pending_text = Some(err.clone());
                         st.full_text = err.clone();
+                        st.is_error = true;
+                        st.retry_request = Some(retry_request);
                     }
                 }
                 String::new()
@@ -1092,12 +1307,23 @@ progressBar.onclick = (e) => {{
         if has_content {
             let txt_c = result_text.clone();
             let txt_for_badge = result_text.clone();
+            // Append mode never applies to the pass-through source text
+            let append = !is_input_adapter
+                && config
+                    .presets
+                    .iter()
+                    .find(|p| p.id == preset_id)
+                    .is_some_and(|p| p.auto_copy_mode == "append");
             // Only show badge for actual processed results, NOT for input_adapter blocks
             // because input_adapter just passes through text that was already copied to clipboard
             // by text_selection.rs (the "b?? ??? d?" copy for processing)
             let should_show_badge = !is_input_adapter;
             std::thread::spawn(move || {
-                crate::overlay::utils::copy_to_clipboard(&txt_c, HWND::default());
+                if append {
+                    crate::overlay::utils::append_to_clipboard(&txt_c, HWND::default());
+                } else {
+                    crate::overlay::utils::copy_to_clipboard(&txt_c, HWND::default());
+                }
                 // Show auto-copy badge notification with text snippet (skip for input_adapter)
                 if should_show_badge {
                     crate::overlay::auto_copy_badge::show_auto_copy_badge_text(&txt_for_badge);
@@ -1172,7 +1398,7 @@ progressBar.onclick = (e) => {{
                     if txt_c.trim().is_empty() {
                         // Image-only paste path
                         if let Some(target) = target_window {
-                            crate::overlay::utils::force_focus_and_paste(target.0);
+                            crate::overlay::utils::force_focus_and_paste(target.0, "");
                         }
                     } else {
                         // Text paste path (supports injection)
@@ -1190,7 +1416,7 @@ progressBar.onclick = (e) => {{
                             }
                         } else if let Some(target) = target_window {
                             // Normal paste to last active window
-                            crate::overlay::utils::force_focus_and_paste(target.0);
+                            crate::overlay::utils::force_focus_and_paste(target.0, &final_text);
                         }
                     }
                 }
@@ -1214,14 +1440,23 @@ progressBar.onclick = (e) => {{
         if block.block_type == "text" {
             let input_text_clone = input_text_for_history.clone();
             std::thread::spawn(move || {
+                crate::overlay::selection_history::record_result(
+                    &input_text_clone,
+                    &text_for_history,
+                );
                 if let Ok(app) = crate::APP.lock() {
                     app.history.save_text(text_for_history, input_text_clone);
                 }
             });
         } else if block.block_type == "image" {
             // For image blocks, we need to grab the image data from the context
-            // context is RefineContext::Image(Vec<u8>) for the first block
-            if let RefineContext::Image(img_bytes) = context.clone() {
+            // context is RefineContext::Image(Vec<u8>) for the first block.
+            // An entry started while streaming only needs marking complete.
+            if let Some(id) = history_entry.lock().unwrap().take() {
+                if let Ok(app) = crate::APP.lock() {
+                    app.history.finish(id, text_for_history);
+                }
+            } else if let RefineContext::Image(img_bytes) = context.clone() {
                 std::thread::spawn(move || {
                     // Decode PNG bytes back to ImageBuffer for the history saver
                     // (HistoryManager::save_image expects ImageBuffer<Rgba<u8>, ...>)
@@ -1247,6 +1482,13 @@ progressBar.onclick = (e) => {{
         return;
     }
 
+    // A failed block may hand its own input downstream instead of ending the chain
+    let result_text = if block_failed && block.on_error != "stop" {
+        input_text_for_history
+    } else {
+        result_text
+    };
+
     // For input_adapter blocks, ALWAYS continue to downstream blocks even if result_text is empty
     // This is critical for image presets where the image data is in context, not input_text
     let should_continue = !result_text.trim().is_empty() || block.block_type == "input_adapter";
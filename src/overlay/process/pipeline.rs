@@ -13,7 +13,9 @@ use crate::overlay::preset_wheel;
 use crate::overlay::result::{self, RefineContext};
 use crate::overlay::text_input;
 
-use super::chain::{execute_chain_pipeline, execute_chain_pipeline_with_token, run_chain_step};
+use super::chain::{
+    blocks_for_run, execute_chain_pipeline, execute_chain_pipeline_with_token, run_chain_step,
+};
 use super::types::generate_chain_id;
 use super::window::create_processing_window;
 
@@ -29,9 +31,19 @@ pub fn start_text_processing(
     screen_rect: RECT,
     config: Config,
     preset: Preset,
-    localized_preset_name: String, // Already localized by caller
-    cancel_hotkey_name: String,    // The actual hotkey name like "Ctrl+Shift+D"
+    localized_preset_name: String,    // Already localized by caller
+    cancel_hotkey_name: String,       // The actual hotkey name like "Ctrl+Shift+D"
+    streaming_override: Option<bool>, // Set for this run only (e.g. Shift held on the hotkey)
 ) {
+    // Typed input isn't known until submit; only selected/pasted text can be replayed
+    if preset.text_input_mode != "type" {
+        crate::overlay::last_run::record(
+            crate::overlay::last_run::LastRunInput::Text(initial_text_content.clone()),
+            &preset.id,
+            screen_rect,
+        );
+    }
+
     if preset.text_input_mode == "type" {
         // Use blocks[0].prompt instead of legacy preset.prompt
         let first_block_prompt = preset
@@ -113,8 +125,12 @@ pub fn start_text_processing(
                     }
 
                     // Show preset wheel - this blocks until user makes selection
-                    let selected =
-                        preset_wheel::show_preset_wheel("text", Some("type"), cursor_pos);
+                    let selected = preset_wheel::choose_preset(
+                        &preset_shared,
+                        "text",
+                        Some("type"),
+                        cursor_pos,
+                    );
 
                     if let Some(idx) = selected {
                         // Store the selected preset index for subsequent submissions
@@ -230,6 +246,7 @@ pub fn start_text_processing(
                                 crate::overlay::result::layout::calculate_next_window_rect(
                                     last_rect,
                                     monitor_rect,
+                                    &final_config.retranslation_layout,
                                 )
                             } else {
                                 // First window: use ideal position directly
@@ -275,6 +292,7 @@ pub fn start_text_processing(
                         RefineContext::None,
                         new_token,
                         Some(input_hwnd_send),
+                        streaming_override,
                     );
                 });
             },
@@ -323,10 +341,43 @@ pub fn start_text_processing(
                         config_clone,
                         modified_preset,
                         RefineContext::None,
+                        streaming_override,
+                    );
+                });
+            },
+        );
+    } else if preset.text_input_mode == "select" && preset.confirm_selection {
+        // Review the selection in the input window first; cancelling it aborts the chain
+        let ui_lang = config.ui_language.clone();
+        let config = Arc::new(config);
+        let preset = Arc::new(preset);
+
+        text_input::show(
+            localized_preset_name,
+            ui_lang,
+            cancel_hotkey_name,
+            false,
+            move |edited_text, input_hwnd| {
+                unsafe {
+                    let _ = PostMessageW(Some(input_hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
+                }
+
+                let config_clone = (*config).clone();
+                let preset_clone = (*preset).clone();
+                std::thread::spawn(move || {
+                    execute_chain_pipeline(
+                        edited_text,
+                        screen_rect,
+                        config_clone,
+                        preset_clone,
+                        RefineContext::None,
+                        streaming_override,
                     );
                 });
             },
         );
+        // Queued after the show message, so it lands in the freshly shown editor
+        text_input::set_editor_text(&format!("__REPLACE_ALL__{}", initial_text_content));
     } else {
         execute_chain_pipeline(
             initial_text_content,
@@ -334,6 +385,7 @@ pub fn start_text_processing(
             config,
             preset,
             RefineContext::None,
+            streaming_override,
         );
     }
 }
@@ -394,7 +446,14 @@ pub fn start_processing_pipeline(
     screen_rect: RECT,
     config: Config,
     preset: Preset,
+    streaming_override: Option<bool>,
 ) {
+    crate::overlay::last_run::record(
+        crate::overlay::last_run::LastRunInput::Image(cropped_img.clone()),
+        &preset.id,
+        screen_rect,
+    );
+
     // If dynamic prompt mode, use WebView-based text input
     if preset.prompt_mode == "dynamic" && !preset.blocks.is_empty() {
         // For dynamic mode, encode PNG first (user will type prompt)
@@ -453,8 +512,15 @@ pub fn start_processing_pipeline(
                 let graphics_mode = config_clone.graphics_mode.clone();
 
                 // Create processing window IMMEDIATELY
-                let processing_hwnd =
-                    unsafe { create_processing_window(screen_rect, graphics_mode) };
+                let cancel_token = Arc::new(AtomicBool::new(false));
+                let processing_hwnd = unsafe {
+                    create_processing_window(
+                        screen_rect,
+                        graphics_mode,
+                        modified_preset.glow_color_rgb(),
+                        cancel_token.clone(),
+                    )
+                };
                 unsafe {
                     let _ =
                         SendMessageW(processing_hwnd, WM_TIMER, Some(WPARAM(1)), Some(LPARAM(0)));
@@ -464,7 +530,7 @@ pub fn start_processing_pipeline(
                 let chain_id = generate_chain_id();
 
                 // Spawn chain execution - reusing existing run_chain_step!
-                let blocks = modified_preset.blocks.clone();
+                let blocks = blocks_for_run(&modified_preset, streaming_override);
                 let connections = modified_preset.block_connections.clone();
                 let preset_id = modified_preset.id.clone();
 
@@ -481,7 +547,7 @@ pub fn start_processing_pipeline(
                         context,
                         false,
                         Some(processing_hwnd_send),
-                        Arc::new(AtomicBool::new(false)),
+                        cancel_token,
                         preset_id,
                         false,    // disable_auto_paste
                         chain_id, // Per-chain position tracking
@@ -510,14 +576,22 @@ pub fn start_processing_pipeline(
 
     // 1. Create Processing Window FIRST (instant, no delay)
     let graphics_mode = config.graphics_mode.clone();
-    let processing_hwnd = unsafe { create_processing_window(screen_rect, graphics_mode) };
+    let cancel_token = Arc::new(AtomicBool::new(false));
+    let processing_hwnd = unsafe {
+        create_processing_window(
+            screen_rect,
+            graphics_mode,
+            preset.glow_color_rgb(),
+            cancel_token.clone(),
+        )
+    };
     unsafe {
         let _ = SendMessageW(processing_hwnd, WM_TIMER, Some(WPARAM(1)), Some(LPARAM(0)));
     }
 
     // 2. Spawn background thread to encode PNG and start chain execution
     let conf_clone = config.clone();
-    let blocks = preset.blocks.clone();
+    let blocks = blocks_for_run(&preset, streaming_override);
     let connections = preset.block_connections.clone();
     let preset_id = preset.id.clone();
 
@@ -547,7 +621,7 @@ pub fn start_processing_pipeline(
             context,
             false,
             Some(SendHwnd(processing_hwnd)), // Pass the handle to be closed later
-            Arc::new(AtomicBool::new(false)), // New chains start with cancellation = false
+            cancel_token,                    // Set if the user presses Esc on the overlay
             preset_id,
             false,    // disable_auto_paste
             chain_id, // Per-chain position tracking
@@ -578,7 +652,7 @@ pub fn start_processing_pipeline_parallel(
     // Fallback to blocking wait for dynamic mode (usually user input is the bottleneck anyway)
     if preset.prompt_mode == "dynamic" {
         if let Ok(Some((img, _))) = rx.recv() {
-            start_processing_pipeline(img, screen_rect, config, preset);
+            start_processing_pipeline(img, screen_rect, config, preset, None);
         }
         return;
     }
@@ -586,7 +660,15 @@ pub fn start_processing_pipeline_parallel(
     // STANDARD PIPELINE PARALLEL
     // 1. Create Processing Window FIRST (instant, no delay)
     let graphics_mode = config.graphics_mode.clone();
-    let processing_hwnd = unsafe { create_processing_window(screen_rect, graphics_mode) };
+    let cancel_token = Arc::new(AtomicBool::new(false));
+    let processing_hwnd = unsafe {
+        create_processing_window(
+            screen_rect,
+            graphics_mode,
+            preset.glow_color_rgb(),
+            cancel_token.clone(),
+        )
+    };
     unsafe {
         let _ = SendMessageW(processing_hwnd, WM_TIMER, Some(WPARAM(1)), Some(LPARAM(0)));
     }
@@ -602,7 +684,13 @@ pub fn start_processing_pipeline_parallel(
         let processing_hwnd = HWND(processing_hwnd_val as *mut std::ffi::c_void);
 
         // WAIT FOR DATA - delays here won't freeze UI!
-        if let Ok(Some((_cropped_img, original_bytes))) = rx.recv() {
+        if let Ok(Some((cropped_img, original_bytes))) = rx.recv() {
+            crate::overlay::last_run::record(
+                crate::overlay::last_run::LastRunInput::Image(cropped_img),
+                &preset_id,
+                screen_rect,
+            );
+
             // Use original bytes directly (Zero-Copy/Zero-Encode)
             // This preserves JPEG format if input was JPEG
             let context = RefineContext::Image(original_bytes);
@@ -622,7 +710,7 @@ pub fn start_processing_pipeline_parallel(
                 context,
                 false,
                 Some(SendHwnd(processing_hwnd)), // Pass the handle to be closed later
-                Arc::new(AtomicBool::new(false)),
+                cancel_token,
                 preset_id,
                 false,    // disable_auto_paste
                 chain_id, // Per-chain position tracking
@@ -1,6 +1,7 @@
 use crate::overlay::result::layout::calculate_next_window_rect;
 use std::collections::HashMap;
-use std::sync::Mutex;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
 use windows::Win32::Foundation::*;
 use windows::Win32::Graphics::Gdi::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
@@ -17,6 +18,12 @@ pub struct ProcessingState {
     pub scaled_h: i32,
     pub timer_killed: bool,
     pub graphics_mode: String,
+    /// Set by Esc to abort the chain this overlay belongs to
+    pub cancel_token: Option<Arc<AtomicBool>>,
+    /// Esc state at the last tick, so only a fresh press cancels
+    pub escape_was_down: bool,
+    /// Preset glow color (0xRRGGBB); None keeps the rainbow
+    pub glow_color: Option<u32>,
 }
 
 unsafe impl Send for ProcessingState {}
@@ -34,6 +41,9 @@ impl ProcessingState {
             scaled_h: 0,
             timer_killed: false,
             graphics_mode,
+            cancel_token: None,
+            escape_was_down: false,
+            glow_color: None,
         }
     }
 
@@ -73,7 +83,11 @@ pub fn generate_chain_id() -> String {
 /// Get the next window position using snake algorithm (first-come-first-serve)
 /// This is mutex-protected so parallel branches within the SAME chain get sequential positions
 /// Different chains (different chain_id) are completely independent
-pub fn get_next_window_position_for_chain(chain_id: &str, initial_rect: RECT) -> RECT {
+pub fn get_next_window_position_for_chain(
+    chain_id: &str,
+    initial_rect: RECT,
+    layout: &str,
+) -> RECT {
     let mut positions = CHAIN_WINDOW_POSITIONS.lock().unwrap();
 
     let monitor_rect = unsafe {
@@ -99,7 +113,7 @@ pub fn get_next_window_position_for_chain(chain_id: &str, initial_rect: RECT) ->
         }
         Some(&prev) => {
             // Subsequent windows in this chain: use snake algorithm from last position
-            calculate_next_window_rect(prev, monitor_rect)
+            calculate_next_window_rect(prev, monitor_rect, layout)
         }
     };
 
@@ -3,8 +3,10 @@ use windows::Win32::UI::WindowsAndMessaging::*;
 use windows::Win32::Graphics::Gdi::*;
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
 use windows::core::*;
-use std::sync::{Mutex, Once};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Once};
 use std::collections::HashMap;
+use windows::Win32::UI::Input::KeyboardAndMouse::{GetAsyncKeyState, VK_ESCAPE};
 
 use super::types::{ProcessingState, MAX_GLOW_BUFFER_DIM};
 
@@ -16,7 +18,9 @@ lazy_static::lazy_static! {
 }
 
 // --- WINDOW PROC FOR OVERLAY ---
-pub unsafe fn create_processing_window(rect: RECT, graphics_mode: String) -> HWND {
+/// Pressing Esc while the overlay is up sets `cancel_token` and fades it out.
+/// `glow_color` (the preset's, 0xRRGGBB) tints the glow like its result window's border.
+pub unsafe fn create_processing_window(rect: RECT, graphics_mode: String, glow_color: Option<u32>, cancel_token: Arc<AtomicBool>) -> HWND {
     let instance = GetModuleHandleW(None).unwrap();
     let class_name = w!("SGTProcessingOverlay");
 
@@ -40,16 +44,38 @@ pub unsafe fn create_processing_window(rect: RECT, graphics_mode: String) -> HWN
         WS_EX_LAYERED | WS_EX_TOPMOST | WS_EX_TOOLWINDOW | WS_EX_TRANSPARENT | WS_EX_NOACTIVATE, 
         class_name, w!("Processing"), WS_POPUP, rect.left, rect.top, w, h, None, None, Some(instance.into()), None
     ).unwrap_or_default();
+    let mut state = ProcessingState::new(graphics_mode);
+    state.cancel_token = Some(cancel_token);
+    state.glow_color = glow_color;
+    // An Esc still held from the selection step must not cancel right away
+    state.escape_was_down = is_escape_down();
     let mut states = PROC_STATES.lock().unwrap();
-    states.insert(hwnd.0 as isize, ProcessingState::new(graphics_mode));
+    states.insert(hwnd.0 as isize, state);
     drop(states);
     SetTimer(Some(hwnd), 1, timer_interval, None);
     let _ = ShowWindow(hwnd, SW_SHOWNOACTIVATE);
     hwnd
 }
 
+unsafe fn is_escape_down() -> bool {
+    (GetAsyncKeyState(VK_ESCAPE.0 as i32) as u16 & 0x8000) != 0
+}
+
+/// Abort the chain and fade the overlay out; the chain then skips its result window
+unsafe fn cancel_processing(hwnd: HWND) {
+    let token = PROC_STATES.lock().unwrap().get(&(hwnd.0 as isize)).and_then(|s| s.cancel_token.clone());
+    if let Some(token) = token {
+        token.store(true, Ordering::SeqCst);
+    }
+    let _ = PostMessageW(Some(hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
+}
+
 unsafe extern "system" fn processing_wnd_proc(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
     match msg {
+        WM_KEYDOWN if wparam.0 == VK_ESCAPE.0 as usize => {
+            cancel_processing(hwnd);
+            LRESULT(0)
+        }
         WM_CLOSE => {
             let mut states = PROC_STATES.lock().unwrap();
             let state = states.entry(hwnd.0 as isize).or_insert(ProcessingState::new("standard".to_string()));
@@ -63,6 +89,20 @@ unsafe extern "system" fn processing_wnd_proc(hwnd: HWND, msg: u32, wparam: WPAR
             LRESULT(0)
         }
         WM_TIMER => {
+            // The overlay never takes focus (click-through, no-activate), so watch Esc here
+            let escape_down = is_escape_down();
+            let escape_pressed = {
+                let mut states = PROC_STATES.lock().unwrap();
+                states.get_mut(&(hwnd.0 as isize)).is_some_and(|s| {
+                    let pressed = escape_down && !s.escape_was_down && !s.is_fading_out;
+                    s.escape_was_down = escape_down;
+                    pressed
+                })
+            };
+            if escape_pressed {
+                cancel_processing(hwnd);
+            }
+
             let (should_destroy, anim_offset, alpha, is_fading) = {
                 let mut states = PROC_STATES.lock().unwrap();
                 let state = states.entry(hwnd.0 as isize).or_insert(ProcessingState::new("standard".to_string()));
@@ -99,8 +139,12 @@ unsafe extern "system" fn processing_wnd_proc(hwnd: HWND, msg: u32, wparam: WPAR
                     if let Ok(hbm) = res { if !hbm.is_invalid() && !state.cache_bits.is_null() { state.cache_hbm = hbm; state.scaled_w = buf_w; state.scaled_h = buf_h; } else { return LRESULT(0); } } else { return LRESULT(0); }
                 }
                 if !is_fading && !state.cache_bits.is_null() {
-                    if state.graphics_mode == "minimal" { crate::overlay::paint_utils::draw_minimal_glow(state.cache_bits as *mut u32, state.scaled_w, state.scaled_h, anim_offset, 1.0, true); }
-                    else { crate::overlay::paint_utils::draw_direct_sdf_glow(state.cache_bits as *mut u32, state.scaled_w, state.scaled_h, anim_offset, 1.0, true); }
+                    if state.graphics_mode == "minimal" { crate::overlay::paint_utils::draw_minimal_glow(state.cache_bits as *mut u32, state.scaled_w, state.scaled_h, anim_offset, 1.0, true, state.glow_color); }
+                    else {
+                        // Glow width follows the capture monitor's DPI, in (possibly downscaled) buffer pixels
+                        let glow_scale = crate::overlay::paint_utils::window_dpi_scale(hwnd) * scale_factor;
+                        crate::overlay::paint_utils::draw_direct_sdf_glow(state.cache_bits as *mut u32, state.scaled_w, state.scaled_h, anim_offset, 1.0, true, glow_scale, state.glow_color);
+                    }
                 }
                 let screen_dc = GetDC(None);
                 let needs_scaling = state.scaled_w != w || state.scaled_h != h;
@@ -29,6 +29,10 @@ static REGISTER_PDJ_CLASS: Once = Once::new();
 static mut PDJ_HWND: SendHwnd = SendHwnd(HWND(std::ptr::null_mut()));
 static mut IS_WARMED_UP: bool = false;
 static mut IS_INITIALIZING: bool = false;
+/// When the pending show was requested and whether the WebView had to be created
+/// first; logged as the open time once the window shows
+static OPEN_REQUESTED: std::sync::Mutex<Option<(std::time::Instant, bool)>> =
+    std::sync::Mutex::new(None);
 const WM_APP_SHOW: u32 = WM_USER + 101;
 const WM_APP_UPDATE_SETTINGS: u32 = WM_USER + 102;
 
@@ -198,6 +202,13 @@ unsafe extern "system" fn pdj_wnd_proc(
             let _ = ShowWindow(hwnd, SW_SHOW);
             let _ = SetForegroundWindow(hwnd);
             let _ = SetFocus(Some(hwnd));
+            if let Some((requested, cold)) = OPEN_REQUESTED.lock().unwrap().take() {
+                crate::log_info!(
+                    "[PromptDJ] {} open took {} ms",
+                    if cold { "Cold" } else { "Warm" },
+                    requested.elapsed().as_millis()
+                );
+            }
             LRESULT(0)
         }
         WM_APP_UPDATE_SETTINGS => {
@@ -308,6 +319,30 @@ impl HasWindowHandle for HwndWrapper {
     }
 }
 
+/// Write a prompt composed in the DJ into the first processing block
+/// (skipping input adapters) of the active preset, then save.
+fn apply_prompt_to_active_preset(prompt: &str) {
+    let prompt = prompt.trim();
+    if prompt.is_empty() {
+        return;
+    }
+    let Ok(mut app) = crate::APP.lock() else {
+        return;
+    };
+    let idx = app.config.active_preset_idx;
+    let Some(block) = app.config.presets.get_mut(idx).and_then(|p| {
+        p.blocks
+            .iter_mut()
+            .find(|b| b.block_type != "input_adapter")
+    }) else {
+        return;
+    };
+    block.prompt = prompt.to_string();
+    crate::config::save_config(&app.config);
+    drop(app);
+    crate::gui::signal_presets_changed();
+}
+
 fn wnd_http_response(
     status: u16,
     content_type: &str,
@@ -326,8 +361,27 @@ fn wnd_http_response(
         })
 }
 
+/// Create the hidden Prompt DJ window and its WebView ahead of time so the first
+/// `show_prompt_dj` only has to show it. Called from the startup warmup sequence.
+pub fn warmup() {
+    unsafe {
+        if IS_WARMED_UP || IS_INITIALIZING {
+            return;
+        }
+        IS_INITIALIZING = true;
+        std::thread::spawn(|| {
+            internal_create_pdj_loop();
+        });
+    }
+}
+
 pub fn show_prompt_dj() {
+    if !crate::overlay::webview_runtime::ensure_available() {
+        return;
+    }
     unsafe {
+        *OPEN_REQUESTED.lock().unwrap() = Some((std::time::Instant::now(), !IS_WARMED_UP));
+
         // Initialize on-demand if not warmed up
         if !IS_WARMED_UP {
             if !IS_INITIALIZING {
@@ -338,7 +392,7 @@ pub fn show_prompt_dj() {
             }
 
             // Polling thread to auto-show once ready
-            std::thread::spawn(|| {
+            std::thread::spawn(move || {
                 // Poll for 10 seconds (100 * 100ms)
                 for _ in 0..100 {
                     std::thread::sleep(std::time::Duration::from_millis(100));
@@ -688,6 +742,23 @@ unsafe fn internal_create_pdj_loop() {
                         if let Ok(val) = body.trim_start_matches("set_volume:").parse::<f32>() {
                             let _ = set_app_volume(val);
                         }
+                    } else if let Some(prompt) = body.strip_prefix("use_prompt:") {
+                        apply_prompt_to_active_preset(prompt);
+                    } else if body == "list_presets" {
+                        // Fire-and-forget channel: answer with a message event
+                        let presets = crate::overlay::preset_launcher::list_presets();
+                        PDJ_WEBVIEW.with(|wv| {
+                            if let Some(webview) = wv.borrow().as_ref() {
+                                let _ = webview.evaluate_script(&format!(
+                                    "window.postMessage({{ type: 'pm-dj-presets', presets: {} }}, '*')",
+                                    presets
+                                ));
+                            }
+                        });
+                    } else if let Some(id) = body.strip_prefix("trigger_preset:") {
+                        if let Err(e) = crate::overlay::preset_launcher::trigger_preset(id) {
+                            crate::log_info!("[PromptDJ] Could not run preset: {}", e);
+                        }
                     }
                 })
                 .with_url("promptdj://localhost/index.html");
@@ -705,10 +776,11 @@ unsafe fn internal_create_pdj_loop() {
     let webview = match webview_result {
         Ok(wv) => wv,
         Err(e) => {
-            eprintln!("Failed to create PromptDJ WebView: {:?}", e);
-            // Clean up and exit gracefully
+            crate::log_info!("[PromptDJ] Failed to create WebView: {:?}", e);
+            // Clean up and exit gracefully; the next open may try again
             let _ = DestroyWindow(hwnd);
             PDJ_HWND = SendHwnd::default();
+            IS_INITIALIZING = false;
             return;
         }
     };
@@ -79,6 +79,7 @@ pub fn show_realtime_egui_overlay(preset_idx: usize) {
     let font_size = app.config.realtime_font_size as f32;
     let config_language = app.config.realtime_target_language.clone();
     let config_audio_source = app.config.realtime_audio_source.clone();
+    let config_audio_device = app.config.realtime_audio_device.clone();
     drop(app);
     
     let is_device_saved = config_audio_source == "device";
@@ -103,6 +104,9 @@ pub fn show_realtime_egui_overlay(preset_idx: usize) {
     if let Ok(mut new_source) = NEW_AUDIO_SOURCE.lock() {
         *new_source = effective_source.clone();
     }
+    if let Ok(mut new_device) = NEW_AUDIO_DEVICE.lock() {
+        *new_device = config_audio_device;
+    }
     
     if !config_language.is_empty() {
         if let Ok(mut new_lang) = NEW_TARGET_LANGUAGE.lock() {
@@ -315,10 +319,11 @@ fn render_main_ui(ui: &mut egui::Ui, state: &mut RealtimeUiState) {
             // Audio source toggle
             if ui.selectable_label(!is_device_mode, "🎤").on_hover_text(locale.audio_src_mic).clicked() {
                 if let Ok(mut s) = NEW_AUDIO_SOURCE.lock() { *s = "mic".to_string(); }
+                if let Ok(mut d) = NEW_AUDIO_DEVICE.lock() { d.clear(); }
                 SELECTED_APP_PID.store(0, Ordering::SeqCst);
                 if let Ok(mut name) = SELECTED_APP_NAME.lock() { name.clear(); }
                 AUDIO_SOURCE_CHANGE.store(true, Ordering::SeqCst);
-                if let Ok(mut app) = APP.lock() { app.config.realtime_audio_source = "mic".to_string(); }
+                if let Ok(mut app) = APP.lock() { app.config.realtime_audio_source = "mic".to_string(); app.config.realtime_audio_device.clear(); }
                 state.show_app_picker = false;
             }
             
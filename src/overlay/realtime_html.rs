@@ -3,6 +3,7 @@ use crate::gui::locale::LocaleText;
 pub fn get_realtime_html(
     is_translation: bool,
     audio_source: &str,
+    audio_device: &str,
     languages: &[String],
     current_language: &str,
     translation_model: &str,
@@ -10,6 +11,8 @@ pub fn get_realtime_html(
     font_size: u32,
     text: &LocaleText,
     is_dark: bool,
+    opacity: u8,
+    animation_speed: f32,
 ) -> String {
     let _title_icon = if is_translation {
         "translate"
@@ -28,11 +31,14 @@ pub fn get_realtime_html(
         format!("{}", title_text)
     } else {
         // Canvas-based volume visualizer for smooth 60fps animation
-        r#"<canvas id="volume-canvas" width="90" height="24"></canvas>"#.to_string()
+        format!(
+            r#"<canvas id="volume-canvas" width="90" height="24" data-source="{}"></canvas>"#,
+            escape_attr(audio_source)
+        )
     };
 
-    let _mic_text = text.realtime_mic;
-    let _device_text = text.realtime_device;
+    let mic_text = text.realtime_mic;
+    let device_text = text.realtime_device;
     let placeholder_text = text.realtime_waiting;
 
     // Build language options HTML - show full name in dropdown, but store code for display
@@ -58,9 +64,43 @@ pub fn get_realtime_html(
         .collect::<Vec<_>>()
         .join("\n");
 
-    // Audio source selector (only for transcription window) - simple mic/device toggle
+    // Audio source selector (only for transcription window) - mic/device toggle
+    // plus the specific endpoint to capture ("<source>|<id>", empty id = default)
     let audio_selector = if !is_translation {
         let is_device = audio_source == "device";
+        let device_option = |source: &str, id: &str, name: &str| {
+            let selected = if source == audio_source && id == audio_device {
+                "selected"
+            } else {
+                ""
+            };
+            format!(
+                r#"<option value="{}|{}" {}>{}</option>"#,
+                source,
+                escape_attr(id),
+                selected,
+                escape_attr(name)
+            )
+        };
+        let device_group = |source: &str, label: &str, capture: bool| {
+            let options: String = std::iter::once(device_option(source, "", label))
+                .chain(
+                    crate::api::audio_devices::list_audio_endpoints(capture)
+                        .iter()
+                        .map(|(id, name)| device_option(source, id, name)),
+                )
+                .collect();
+            format!(
+                r#"<optgroup label="{}">{}</optgroup>"#,
+                escape_attr(label),
+                options
+            )
+        };
+        let device_options = format!(
+            "{}{}",
+            device_group("mic", mic_text, true),
+            device_group("device", device_text, false)
+        );
         let gemini_active = if transcription_model == "gemini" {
             "active"
         } else {
@@ -75,14 +115,23 @@ pub fn get_realtime_html(
         format!(
             r#"
             <div class="btn-group">
-                <span class="material-symbols-rounded audio-icon {mic_active}" id="mic-btn" data-value="mic" title="Microphone Input">{mic_svg}</span>
-                <span class="material-symbols-rounded audio-icon {device_active}" id="device-btn" data-value="device" title="Device Audio">{device_svg}</span>
+                <span class="material-symbols-rounded audio-icon {mic_active}" id="mic-btn" data-value="mic" title="{mic_title}">{mic_svg}</span>
+                <span class="material-symbols-rounded audio-icon {device_active}" id="device-btn" data-value="device" title="{device_title}">{device_svg}</span>
             </div>
+            <select id="audio-device-select" title="{device_select_title}">
+                {device_options}
+            </select>
             <div class="btn-group">
-                <span class="material-symbols-rounded trans-model-icon {gemini_active}" data-value="gemini" title="Gemini Live (Cloud)">{auto_awesome_svg}</span>
-                <span class="material-symbols-rounded trans-model-icon {parakeet_active}" data-value="parakeet" title="Parakeet (Local)">{bolt_en_svg}</span>
+                <span class="material-symbols-rounded trans-model-icon {gemini_active}" data-value="gemini" title="{gemini_title}">{auto_awesome_svg}</span>
+                <span class="material-symbols-rounded trans-model-icon {parakeet_active}" data-value="parakeet" title="{parakeet_title}">{bolt_en_svg}</span>
             </div>
         "#,
+            device_options = device_options,
+            mic_title = escape_attr(text.audio_src_mic),
+            device_title = escape_attr(text.audio_src_device),
+            device_select_title = escape_attr(text.realtime_audio_device_tooltip),
+            gemini_title = escape_attr(text.realtime_gemini_live_tooltip),
+            parakeet_title = escape_attr(text.realtime_parakeet_tooltip),
             mic_active = if !is_device { "active" } else { "" },
             device_active = if is_device { "active" } else { "" },
             gemini_active = gemini_active,
@@ -112,17 +161,22 @@ pub fn get_realtime_html(
 
         format!(
             r#"
-            <span class="ctrl-btn speak-btn" id="speak-btn" title="Text-to-Speech Settings"><span class="material-symbols-rounded">{volume_up_svg}</span></span>
+            <span class="ctrl-btn speak-btn" id="speak-btn" title="{speak_title}"><span class="material-symbols-rounded">{volume_up_svg}</span></span>
             <div class="btn-group">
-                <span class="material-symbols-rounded model-icon {gemma_active}" data-value="google-gemma" title="AI Translation (Gemma)">{auto_awesome_svg}</span>
-                <span class="material-symbols-rounded model-icon {cerebras_active}" data-value="cerebras-oss" title="Instant AI (Cerebras)">{speed_svg}</span>
-                <span class="material-symbols-rounded model-icon {gtx_active}" data-value="google-gtx" title="Unlimited Translation (Google)">{language_svg}</span>
+                <span class="material-symbols-rounded model-icon {gemma_active}" data-value="google-gemma" title="{gemma_title}">{auto_awesome_svg}</span>
+                <span class="material-symbols-rounded model-icon {cerebras_active}" data-value="cerebras-oss" title="{cerebras_title}">{speed_svg}</span>
+                <span class="material-symbols-rounded model-icon {gtx_active}" data-value="google-gtx" title="{gtx_title}">{language_svg}</span>
             </div>
-            <select id="language-select" title="Target Language">
+            <select id="language-select" title="{language_title}">
                 {lang_options}
             </select>
         "#,
             lang_options = lang_options,
+            speak_title = escape_attr(text.tts_settings_title),
+            gemma_title = escape_attr(text.realtime_gemma_tooltip),
+            cerebras_title = escape_attr(text.realtime_cerebras_tooltip),
+            gtx_title = escape_attr(text.realtime_gtx_tooltip),
+            language_title = escape_attr(text.realtime_target_language_tooltip),
             gemma_active = gemma_active,
             cerebras_active = cerebras_active,
             gtx_active = gtx_active,
@@ -142,7 +196,13 @@ pub fn get_realtime_html(
     // Construct CSS and JS from components
     let css = format!(
         "{}{}",
-        crate::overlay::html_components::css_main::get(glow_color, font_size, is_dark),
+        crate::overlay::html_components::css_main::get(
+            glow_color,
+            font_size,
+            is_dark,
+            opacity,
+            animation_speed,
+        ),
         crate::overlay::html_components::css_modals::get(is_dark)
     );
     let js = format!(
@@ -171,18 +231,18 @@ pub fn get_realtime_html(
             <div id="title">{title_content}</div>
             <div id="controls">
                 {audio_selector}
-                <span class="ctrl-btn" id="copy-btn" title="Copy text"><span class="material-symbols-rounded">{content_copy_svg}</span></span>
+                <span class="ctrl-btn" id="copy-btn" title="{copy_title}"><span class="material-symbols-rounded">{content_copy_svg}</span></span>
                 <div class="pill-group">
-                    <span class="ctrl-btn" id="font-decrease" title="Decrease font size"><span class="material-symbols-rounded">{remove_svg}</span></span>
-                    <span class="ctrl-btn" id="font-increase" title="Increase font size"><span class="material-symbols-rounded">{add_svg}</span></span>
+                    <span class="ctrl-btn" id="font-decrease" title="{font_minus_title}"><span class="material-symbols-rounded">{remove_svg}</span></span>
+                    <span class="ctrl-btn" id="font-increase" title="{font_plus_title}"><span class="material-symbols-rounded">{add_svg}</span></span>
                 </div>
                 <div class="btn-group">
-                    <span class="vis-btn mic active" id="toggle-mic" title="Toggle Transcription"><span class="material-symbols-rounded">{subtitles_svg}</span></span>
-                    <span class="vis-btn trans active" id="toggle-trans" title="Toggle Translation"><span class="material-symbols-rounded">{translate_svg}</span></span>
+                    <span class="vis-btn mic active" id="toggle-mic" title="{toggle_mic_title}"><span class="material-symbols-rounded">{subtitles_svg}</span></span>
+                    <span class="vis-btn trans active" id="toggle-trans" title="{toggle_trans_title}"><span class="material-symbols-rounded">{translate_svg}</span></span>
                 </div>
             </div>
         </div>
-        <div id="header-toggle" title="Toggle header"><span class="material-symbols-rounded">{expand_less_svg}</span></div>
+        <div id="header-toggle" title="{header_toggle_title}"><span class="material-symbols-rounded">{expand_less_svg}</span></div>
         <div id="viewport">
             <div id="content">
                 <span class="placeholder">{placeholder_text}</span>
@@ -195,14 +255,14 @@ pub fn get_realtime_html(
     <div id="download-modal">
         <div class="download-modal-title">
             <span class="material-symbols-rounded">{download_svg}</span>
-            <span id="download-title">Downloading Model</span>
+            <span id="download-title">{download_title}</span>
         </div>
-        <div class="download-modal-msg" id="download-msg">Please wait...</div>
+        <div class="download-modal-msg" id="download-msg">{download_msg}</div>
         <div class="download-progress-bar">
             <div class="download-progress-fill" id="download-fill" style="width: 0%;"></div>
         </div>
         <div class="download-modal-footnote">{supports_english}</div>
-        <button class="download-cancel-btn" id="download-cancel-btn" title="Cancel download and return to Gemini Live">
+        <button class="download-cancel-btn" id="download-cancel-btn" title="{cancel_download_title}">
             <span class="material-symbols-rounded">{close_svg}</span>
             {cancel_text}
         </button>
@@ -220,7 +280,7 @@ pub fn get_realtime_html(
             <div class="speed-slider-container">
                 <input type="range" class="speed-slider" id="speed-slider" min="50" max="200" value="100" step="10">
                 <span class="speed-value" id="speed-value">1.0x</span>
-                <button class="auto-toggle on" id="auto-speed-toggle" title="Auto-adjust speed to catch up">{tts_auto}</button>
+                <button class="auto-toggle on" id="auto-speed-toggle" title="{auto_speed_title}">{tts_auto}</button>
             </div>
     </div>
             </div>
@@ -235,7 +295,7 @@ pub fn get_realtime_html(
         </div>
         <div class="app-modal-hint">{app_select_hint}</div>
         <div id="app-list" class="app-list">
-            <div class="app-loading">Loading...</div>
+            <div class="app-loading">{app_list_loading}</div>
         </div>
     </div>
     <script>
@@ -254,6 +314,17 @@ pub fn get_realtime_html(
         tts_auto = text.realtime_tts_auto,
         app_select_title = text.app_select_title,
         app_select_hint = text.app_select_hint,
+        app_list_loading = text.realtime_app_list_loading,
+        copy_title = escape_attr(text.overlay_copy_tooltip),
+        font_minus_title = escape_attr(text.font_minus_tooltip),
+        font_plus_title = escape_attr(text.font_plus_tooltip),
+        toggle_mic_title = escape_attr(text.toggle_transcription_tooltip),
+        toggle_trans_title = escape_attr(text.toggle_translation_tooltip),
+        header_toggle_title = escape_attr(text.realtime_header_toggle_tooltip),
+        download_title = text.parakeet_downloading_title,
+        download_msg = text.parakeet_downloading_message,
+        cancel_download_title = escape_attr(text.realtime_cancel_download_tooltip),
+        auto_speed_title = escape_attr(text.realtime_auto_speed_tooltip),
         content_copy_svg = crate::overlay::html_components::icons::get_icon_svg("content_copy"),
         remove_svg = crate::overlay::html_components::icons::get_icon_svg("remove"),
         add_svg = crate::overlay::html_components::icons::get_icon_svg("add"),
@@ -269,3 +340,10 @@ pub fn get_realtime_html(
         supports_english = text.parakeet_supports_english_only
     )
 }
+
+fn escape_attr(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
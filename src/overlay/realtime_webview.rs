@@ -1,10 +1,12 @@
 pub mod app_selection;
 pub mod manager;
+pub mod session;
 pub mod state;
 pub mod webview;
 pub mod wndproc;
 
 pub use manager::{
-    is_realtime_overlay_active, show_realtime_overlay, stop_realtime_overlay,
+    is_realtime_overlay_active, show_realtime_overlay, stop_and_save_realtime_transcript,
+    stop_realtime_overlay,
 };
 pub use state::*;
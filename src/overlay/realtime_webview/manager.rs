@@ -49,7 +49,58 @@ pub fn stop_realtime_overlay() {
     }
 }
 
+/// Stop realtime mode and save the accumulated transcript (and translation,
+/// if any) to a timestamped text file. No-op when realtime mode isn't running.
+pub fn stop_and_save_realtime_transcript() {
+    if !is_realtime_overlay_active() {
+        return;
+    }
+
+    REALTIME_STOP_SIGNAL.store(true, Ordering::SeqCst);
+
+    let (transcript, translation) = {
+        let Ok(mut state) = REALTIME_STATE.lock() else {
+            stop_realtime_overlay();
+            return;
+        };
+        // Move any in-flight translation into the committed text
+        state.force_commit_all();
+        (
+            state.full_transcript.trim().to_string(),
+            state.committed_translation.trim().to_string(),
+        )
+    };
+
+    stop_realtime_overlay();
+
+    if transcript.is_empty() {
+        return;
+    }
+
+    let mut content = transcript;
+    if !translation.is_empty() {
+        content.push_str("\n\n---\n\n");
+        content.push_str(&translation);
+    }
+    content.push('\n');
+
+    let mut path = super::session::transcripts_dir();
+    let _ = std::fs::create_dir_all(&path);
+    path.push(format!(
+        "transcript-{}.txt",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+
+    match std::fs::write(&path, content) {
+        Ok(_) => crate::log_info!("[Realtime] Transcript saved to {}", path.display()),
+        Err(e) => crate::log_info!("[Realtime] Failed to save transcript: {}", e),
+    }
+}
+
 pub fn show_realtime_overlay(preset_idx: usize) {
+    if !crate::overlay::webview_runtime::ensure_available() {
+        return;
+    }
     unsafe {
         // Initialize on-demand if not warmed up
         if !IS_WARMED_UP {
@@ -264,6 +315,16 @@ unsafe fn handle_start_overlay(preset_idx: usize) {
         *state = RealtimeState::new();
     }
 
+    // Re-skin both windows in the preset's accent color (or back to the defaults)
+    if let Ok(mut glow) = PRESET_GLOW_COLOR.lock() {
+        *glow = match preset.glow_color_rgb() {
+            Some(rgb) => format!("#{:06x}", rgb),
+            None => String::new(),
+        };
+    }
+    update_webview_theme(REALTIME_HWND);
+    update_webview_theme(TRANSLATION_HWND);
+
     // Fetch config
     let (
         font_size,
@@ -297,12 +358,15 @@ unsafe fn handle_start_overlay(preset_idx: usize) {
     if let Ok(mut new_source) = NEW_AUDIO_SOURCE.lock() {
         *new_source = effective_audio_source.clone();
     }
+    if let Ok(mut new_device) = NEW_AUDIO_DEVICE.lock() {
+        *new_device = APP.lock().unwrap().config.realtime_audio_device.clone();
+    }
 
     let target_language = if !config_language.is_empty() {
         config_language
     } else if preset.blocks.len() > 1 {
         let trans_block = &preset.blocks[1];
-        if !trans_block.selected_language.is_empty() {
+        let language = if !trans_block.selected_language.is_empty() {
             trans_block.selected_language.clone()
         } else {
             trans_block
@@ -311,7 +375,9 @@ unsafe fn handle_start_overlay(preset_idx: usize) {
                 .cloned()
                 .or_else(|| trans_block.language_vars.get("language1").cloned())
                 .unwrap_or_else(|| "English".to_string())
-        }
+        };
+        let (target, fallback) = crate::overlay::utils::keyboard_language_source();
+        crate::overlay::utils::resolve_language_var(&language, target, &fallback)
     } else {
         "English".to_string()
     };
@@ -0,0 +1,185 @@
+//! The last realtime session's captions, saved when it stops so they can be
+//! reopened for review in result windows.
+
+use crate::api::realtime_audio::RealtimeState;
+use crate::overlay::result::{
+    create_result_window, get_chain_color, link_windows, RefineContext, WindowType,
+};
+use crate::APP;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use windows::Win32::Foundation::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+
+const SESSION_FILE: &str = "last_session.json";
+
+#[derive(Serialize, Deserialize)]
+pub struct SavedSession {
+    /// RFC 3339, local time
+    pub started_at: String,
+    pub ended_at: String,
+    pub target_language: String,
+    pub transcript: String,
+    pub translation: String,
+    pub segments: Vec<SavedSegment>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SavedSegment {
+    /// Milliseconds since the session started
+    pub offset_ms: u64,
+    pub source: String,
+    pub translation: String,
+}
+
+/// Where saved transcripts and the last session live
+pub fn transcripts_dir() -> PathBuf {
+    let mut path = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    path.push("SGT");
+    path.push("transcripts");
+    path
+}
+
+/// Overwrite the last session with this one; empty sessions are not kept
+pub fn save_last_session(state: &RealtimeState, target_language: &str) {
+    if state.full_transcript.trim().is_empty() {
+        return;
+    }
+    let session = SavedSession {
+        started_at: state.started_at.to_rfc3339(),
+        ended_at: chrono::Local::now().to_rfc3339(),
+        target_language: target_language.to_string(),
+        transcript: state.full_transcript.trim().to_string(),
+        translation: state.committed_translation.trim().to_string(),
+        segments: state
+            .segments
+            .iter()
+            .map(|s| SavedSegment {
+                offset_ms: s.offset.as_millis() as u64,
+                source: s.source.clone(),
+                translation: s.translation.clone(),
+            })
+            .collect(),
+    };
+
+    let dir = transcripts_dir();
+    let _ = std::fs::create_dir_all(&dir);
+    let result = serde_json::to_string_pretty(&session)
+        .map_err(|e| e.to_string())
+        .and_then(|json| std::fs::write(dir.join(SESSION_FILE), json).map_err(|e| e.to_string()));
+    if let Err(e) = result {
+        crate::log_info!("[Realtime] Failed to save session: {}", e);
+    }
+}
+
+pub fn load_last_session() -> Option<SavedSession> {
+    let json = std::fs::read_to_string(transcripts_dir().join(SESSION_FILE)).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+pub fn has_last_session() -> bool {
+    transcripts_dir().join(SESSION_FILE).exists()
+}
+
+/// Show the last session's transcript and translation side by side
+pub fn reopen_last_session() {
+    std::thread::spawn(|| {
+        let Some(session) = load_last_session() else {
+            return;
+        };
+        let (model_id, provider) = refine_text_model();
+        let header = session_time_range(&session);
+
+        unsafe {
+            let screen_w = GetSystemMetrics(SM_CXSCREEN);
+            let screen_h = GetSystemMetrics(SM_CYSCREEN);
+            let has_translation = !session.translation.is_empty();
+            let w = if has_translation {
+                screen_w / 3
+            } else {
+                screen_w / 2
+            };
+            let h = screen_h / 2;
+            let total_w = if has_translation { w * 2 + 10 } else { w };
+            let left = (screen_w - total_w) / 2;
+            let top = (screen_h - h) / 2;
+
+            let open = |x: i32, text: String| {
+                let hwnd = create_result_window(
+                    RECT {
+                        left: x,
+                        top,
+                        right: x + w,
+                        bottom: top + h,
+                    },
+                    WindowType::Primary,
+                    RefineContext::None,
+                    model_id.clone(),
+                    provider.clone(),
+                    false,
+                    false,
+                    String::new(),
+                    get_chain_color(0),
+                    "plain",
+                    format!("{}\n\n{}", header, text),
+                );
+                let _ = ShowWindow(hwnd, SW_SHOWNA);
+                hwnd
+            };
+
+            let transcript_hwnd = open(left, session.transcript);
+            let translation_hwnd =
+                has_translation.then(|| open(left + w + 10, session.translation));
+            if let Some(translation_hwnd) = translation_hwnd {
+                link_windows(transcript_hwnd, translation_hwnd);
+            }
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+                let any_open = IsWindow(Some(transcript_hwnd)).as_bool()
+                    || translation_hwnd.is_some_and(|h| IsWindow(Some(h)).as_bool());
+                if !any_open {
+                    break;
+                }
+            }
+        }
+    });
+}
+
+/// e.g. "2026-10-16 14:03 – 14:45"
+fn session_time_range(session: &SavedSession) -> String {
+    let parse = |s: &str| chrono::DateTime::parse_from_rfc3339(s).ok();
+    match (parse(&session.started_at), parse(&session.ended_at)) {
+        (Some(start), Some(end)) => format!(
+            "{} – {}",
+            start.format("%Y-%m-%d %H:%M"),
+            end.format("%H:%M")
+        ),
+        _ => session.started_at.clone(),
+    }
+}
+
+/// The session file doesn't say which model to refine with: use the active
+/// preset's text model, or the first one any preset uses (realtime needs a Gemini key)
+fn refine_text_model() -> (String, String) {
+    let model_id = APP
+        .lock()
+        .ok()
+        .and_then(|app| {
+            let presets = &app.config.presets;
+            presets
+                .get(app.config.active_preset_idx)
+                .into_iter()
+                .chain(presets.iter())
+                .flat_map(|p| p.blocks.iter())
+                .find(|b| b.block_type == "text")
+                .map(|b| b.model.clone())
+        })
+        .unwrap_or_else(|| "text_gemini_flash_lite".to_string());
+    let provider = crate::model_config::get_model_by_id(&model_id)
+        .map(|m| m.provider)
+        .unwrap_or_else(|| "google".to_string());
+    (model_id, provider)
+}
@@ -22,6 +22,8 @@ lazy_static::lazy_static! {
     pub static ref AUDIO_SOURCE_CHANGE: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
     /// The new audio source to use ("mic" or "device")
     pub static ref NEW_AUDIO_SOURCE: Mutex<String> = Mutex::new(String::new());
+    /// Endpoint ID for the new audio source (empty = system default)
+    pub static ref NEW_AUDIO_DEVICE: Mutex<String> = Mutex::new(String::new());
     /// Signal to change target language
     pub static ref LANGUAGE_CHANGE: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
     /// The new target language to use
@@ -34,6 +36,10 @@ lazy_static::lazy_static! {
     pub static ref TRANSCRIPTION_MODEL_CHANGE: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
     /// The new transcription model to use ("gemini" or "parakeet")
     pub static ref NEW_TRANSCRIPTION_MODEL: Mutex<String> = Mutex::new(String::new());
+    /// Accent color of the running preset ("#rrggbb"), empty for the built-in blue/orange
+    pub static ref PRESET_GLOW_COLOR: Mutex<String> = Mutex::new(String::new());
+    /// Partner overlay's offset from the one being dragged; None when it stays put
+    pub static ref LINKED_DRAG_OFFSET: Mutex<Option<(i32, i32)>> = Mutex::new(None);
     /// Visibility state for windows
     pub static ref MIC_VISIBLE: Arc<AtomicBool> = Arc::new(AtomicBool::new(true));
     pub static ref TRANS_VISIBLE: Arc<AtomicBool> = Arc::new(AtomicBool::new(true));
@@ -48,9 +48,21 @@ pub fn create_realtime_webview(
         true
     };
 
+    let (opacity, audio_device, animation_speed) = APP
+        .lock()
+        .map(|app| {
+            (
+                app.config.overlay_opacity,
+                app.config.realtime_audio_device.clone(),
+                app.config.overlay_animation_speed,
+            )
+        })
+        .unwrap_or((217, String::new(), 1.0));
+
     let html = get_realtime_html(
         is_translation,
         audio_source,
+        &audio_device,
         &languages,
         current_language,
         translation_model,
@@ -58,6 +70,8 @@ pub fn create_realtime_webview(
         font_size,
         &locale_text,
         is_dark,
+        opacity,
+        animation_speed,
     );
     let wrapper = HwndWrapper(hwnd);
 
@@ -273,11 +287,16 @@ pub fn create_realtime_webview(
                             crate::config::save_config(&app.config);
                         }
                     } else if body.starts_with("audioSource:") {
-                        // Audio source change
-                        let source = body[12..].to_string();
+                        // Audio source change: "mic", "device" or "<source>|<endpoint id>"
+                        let (source, device) =
+                            body[12..].split_once('|').unwrap_or((&body[12..], ""));
+                        let (source, device) = (source.to_string(), device.to_string());
                         if let Ok(mut new_source) = NEW_AUDIO_SOURCE.lock() {
                             *new_source = source.clone();
                         }
+                        if let Ok(mut new_device) = NEW_AUDIO_DEVICE.lock() {
+                            *new_device = device.clone();
+                        }
 
                         if source == "mic" {
                             // Clear app selection when switching to mic
@@ -304,6 +323,7 @@ pub fn create_realtime_webview(
                         {
                             let mut app = APP.lock().unwrap();
                             app.config.realtime_audio_source = source;
+                            app.config.realtime_audio_device = device;
                             crate::config::save_config(&app.config);
                         }
                         AUDIO_SOURCE_CHANGE.store(true, Ordering::SeqCst);
@@ -601,19 +621,39 @@ pub fn update_webview_theme(hwnd: HWND) {
         true
     };
 
-    let font_size = if let Ok(app) = crate::APP.lock() {
-        app.config.realtime_font_size
+    let (font_size, opacity, animation_speed) = if let Ok(app) = crate::APP.lock() {
+        (
+            app.config.realtime_font_size,
+            app.config.overlay_opacity,
+            app.config.overlay_animation_speed,
+        )
     } else {
-        24
+        (24, 217, 1.0)
     };
 
-    // Determine glow color based on whether this is a translation window
+    // Preset accent wins; otherwise the glow depends on which window this is
     let is_translation = unsafe { hwnd == TRANSLATION_HWND };
-    let glow_color = if is_translation { "#ff9633" } else { "#00c8ff" };
+    let preset_glow = PRESET_GLOW_COLOR
+        .lock()
+        .map(|c| c.clone())
+        .unwrap_or_default();
+    let glow_color = if !preset_glow.is_empty() {
+        preset_glow.as_str()
+    } else if is_translation {
+        "#ff9633"
+    } else {
+        "#00c8ff"
+    };
 
     let css = format!(
         "{}{}",
-        crate::overlay::html_components::css_main::get(glow_color, font_size, is_dark),
+        crate::overlay::html_components::css_main::get(
+            glow_color,
+            font_size,
+            is_dark,
+            opacity,
+            animation_speed,
+        ),
         crate::overlay::html_components::css_modals::get(is_dark)
     );
     let css_escaped = css.replace("`", "\\`");
@@ -9,15 +9,82 @@ use crate::api::realtime_audio::{
 };
 use std::sync::atomic::Ordering;
 use windows::Win32::Foundation::*;
-use windows::Win32::UI::Input::KeyboardAndMouse::ReleaseCapture;
+use windows::Win32::UI::Input::KeyboardAndMouse::{GetAsyncKeyState, ReleaseCapture, VK_SHIFT};
 use windows::Win32::UI::WindowsAndMessaging::*;
 use wry::Rect;
+
+/// The other realtime overlay, if it is showing
+unsafe fn drag_partner(hwnd: HWND) -> Option<HWND> {
+    let realtime = std::ptr::addr_of!(REALTIME_HWND).read();
+    let translation = std::ptr::addr_of!(TRANSLATION_HWND).read();
+    let partner = if hwnd == realtime {
+        translation
+    } else {
+        realtime
+    };
+    (!partner.is_invalid() && IsWindowVisible(partner).as_bool()).then_some(partner)
+}
+
+/// Remember where the partner sits relative to `hwnd` as a move starts.
+/// Holding Shift when the drag begins moves `hwnd` alone.
+unsafe fn begin_linked_drag(hwnd: HWND) {
+    let shift_held = (GetAsyncKeyState(VK_SHIFT.0 as i32) as u16 & 0x8000) != 0;
+    let offset = drag_partner(hwnd)
+        .filter(|_| !shift_held)
+        .and_then(|partner| {
+            let mut own = RECT::default();
+            let mut other = RECT::default();
+            GetWindowRect(hwnd, &mut own).ok()?;
+            GetWindowRect(partner, &mut other).ok()?;
+            Some((other.left - own.left, other.top - own.top))
+        });
+    if let Ok(mut linked) = LINKED_DRAG_OFFSET.lock() {
+        *linked = offset;
+    }
+}
+
+/// Keep the partner at the same offset while `hwnd` moves to `rect`
+unsafe fn follow_linked_drag(hwnd: HWND, rect: &RECT) {
+    let offset = LINKED_DRAG_OFFSET.lock().ok().and_then(|linked| *linked);
+    if let (Some((dx, dy)), Some(partner)) = (offset, drag_partner(hwnd)) {
+        let _ = SetWindowPos(
+            partner,
+            None,
+            rect.left + dx,
+            rect.top + dy,
+            0,
+            0,
+            SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+        );
+    }
+}
+
+fn end_linked_drag() {
+    if let Ok(mut linked) = LINKED_DRAG_OFFSET.lock() {
+        *linked = None;
+    }
+}
+
+/// Move-loop messages shared by both overlays so they drag as a pair
+unsafe fn handle_linked_drag(hwnd: HWND, msg: u32, lparam: LPARAM) {
+    match msg {
+        WM_ENTERSIZEMOVE => begin_linked_drag(hwnd),
+        WM_MOVING => {
+            if let Some(rect) = (lparam.0 as *const RECT).as_ref() {
+                follow_linked_drag(hwnd, rect);
+            }
+        }
+        WM_EXITSIZEMOVE => end_linked_drag(),
+        _ => {}
+    }
+}
 pub unsafe extern "system" fn realtime_wnd_proc(
     hwnd: HWND,
     msg: u32,
     wparam: WPARAM,
     lparam: LPARAM,
 ) -> LRESULT {
+    handle_linked_drag(hwnd, msg, lparam);
     match msg {
         WM_START_DRAG => {
             let _ = ReleaseCapture();
@@ -242,6 +309,7 @@ pub unsafe extern "system" fn translation_wnd_proc(
     wparam: WPARAM,
     lparam: LPARAM,
 ) -> LRESULT {
+    handle_linked_drag(hwnd, msg, lparam);
     match msg {
         WM_COPY_TEXT => {
             let ptr = lparam.0 as *mut String;
@@ -779,21 +779,25 @@ fn generate_html() -> String {
             match lang {
                 "vi" => "Đang ghi âm...",
                 "ko" => "녹음 중...",
+                "ja" => "録音中...",
                 _ => "Recording...",
             },
             match lang {
                 "vi" => "Đang xử lý...",
                 "ko" => "처리 중...",
+                "ja" => "処理中...",
                 _ => "Processing...",
             },
             match lang {
                 "vi" => "Chuẩn bị...",
                 "ko" => "준비 중...",
+                "ja" => "準備中...",
                 _ => "Starting...",
             },
             match lang {
                 "vi" => "Đang kết nối...",
                 "ko" => "연결 중...",
+                "ja" => "接続中...",
                 _ => "Connecting...",
             },
             locale.recording_subtext,
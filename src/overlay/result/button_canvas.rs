@@ -336,6 +336,7 @@ fn generate_canvas_html() -> String {
     let locale = crate::gui::locale::LocaleText::get(&lang);
     let l10n_json = serde_json::json!({
         "copy": locale.overlay_copy_tooltip,
+        "retry": locale.overlay_retry_tooltip,
         "undo": locale.overlay_undo_tooltip,
         "redo": locale.overlay_redo_tooltip,
         "edit": locale.overlay_edit_tooltip,
@@ -380,6 +381,7 @@ fn generate_canvas_html() -> String {
         "stop": get_colored_svg("stop"),
         "cleaning_services": get_colored_svg("cleaning_services"),
         "content_copy": get_colored_svg("content_copy"),
+        "refresh": get_colored_svg("refresh"),
         "check": get_colored_svg("check"),
         "download": get_colored_svg("download"),
         "volume_up": get_colored_svg("volume_up"),
@@ -401,6 +403,9 @@ fn generate_canvas_html() -> String {
 {theme_css}
 </style>
 <style>
+{reduced_motion_css}
+</style>
+<style>
 
 .icons {{
     font-family: 'Material Symbols Rounded';
@@ -972,6 +977,13 @@ function generateButtonsHTML(hwnd, state, isVertical) {{
         </div>
     </div>`;
 
+    // Retry - only shown on a failed result
+    if (state.canRetry) {{
+        buttons += `<div class="btn ${{hideClass}}" onclick="action('${{hwnd}}', 'retry')" title="${{window.L10N.retry}}">
+            ${{window.iconSvgs.refresh}}
+        </div>`;
+    }}
+
     // Copy - hidden when browsing but preserves space
     buttons += `<div class="btn ${{state.copySuccess ? 'success' : ''}} ${{hideClass}}" onclick="action('${{hwnd}}', 'copy')" title="${{window.L10N.copy}}">
         ${{window.iconSvgs[state.copySuccess ? 'check' : 'content_copy']}}
@@ -1354,7 +1366,8 @@ window.updateWindows = function(data) {{
 </script>
 </body>
 </html>"#,
-        font_css = font_css
+        font_css = font_css,
+        reduced_motion_css = crate::overlay::html_components::css_main::reduced_motion_css(),
     )
     .replace("#L10N_JSON#", &l10n_json)
     .replace("#ICON_SVGS_JSON#", &icon_svgs_json)
@@ -1601,6 +1614,9 @@ fn handle_ipc_message(body: &str) {
                     LPARAM(0),
                 );
             },
+            "retry" => {
+                crate::overlay::result::trigger_retry(hwnd);
+            }
             "undo" => unsafe {
                 let _ = PostMessageW(
                     Some(hwnd),
@@ -1923,6 +1939,7 @@ fn send_windows_update() {
             let state_obj = serde_json::json!({
                 "copySuccess": state.map(|s| s.copy_success).unwrap_or(false),
                 "hasUndo": state.map(|s| !s.text_history.is_empty()).unwrap_or(false),
+                "canRetry": state.map(|s| s.is_error && s.retry_request.is_some()).unwrap_or(false),
                 "hasRedo": state.map(|s| !s.redo_history.is_empty()).unwrap_or(false),
                 "navDepth": state.map(|s| s.navigation_depth).unwrap_or(0),
                 "maxNavDepth": state.map(|s| s.max_navigation_depth).unwrap_or(0),
@@ -2169,11 +2186,13 @@ unsafe extern "system" fn canvas_wnd_proc(
                     let target_hwnd = HWND(target_val as *mut std::ffi::c_void);
 
                     if is_right_click {
-                        // Right-click: Close linked windows
-                        let group = crate::overlay::result::state::get_window_group(target_hwnd);
-                        for (h, _) in group {
-                            let _ = PostMessageW(Some(h), WM_CLOSE, WPARAM(0), LPARAM(0));
-                        }
+                        // Right-click: whatever the config says, on the window's own thread
+                        let _ = PostMessageW(
+                            Some(target_hwnd),
+                            super::event_handler::misc::WM_RIGHT_CLICK,
+                            WPARAM(0),
+                            LPARAM(0),
+                        );
                     } else if is_middle_click {
                         // Middle-click: Close all
                         crate::overlay::result::trigger_close_all();
@@ -1,9 +1,9 @@
 use std::mem::size_of;
 use windows::Win32::Foundation::*;
-use windows::Win32::UI::Input::KeyboardAndMouse::ReleaseCapture;
+use windows::Win32::UI::Input::KeyboardAndMouse::{ReleaseCapture, SetFocus};
 use windows::Win32::UI::WindowsAndMessaging::*;
 
-use windows::core::PCWSTR;
+use windows::core::{HSTRING, PCWSTR};
 use windows::Win32::Graphics::Gdi::InvalidateRect;
 use windows::Win32::UI::Input::KeyboardAndMouse::{TrackMouseEvent, TME_LEAVE, TRACKMOUSEEVENT};
 
@@ -26,15 +26,26 @@ pub unsafe fn handle_lbutton_up(hwnd: HWND) -> LRESULT {
     let mut is_forward_click = false;
     let mut is_download_click = false;
     let mut is_speaker_click = false;
+    let mut finished_selection = false;
     {
         let mut states = WINDOW_STATES.lock().unwrap();
         if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
             let was_resizing = matches!(state.interaction_mode, InteractionMode::Resizing(_));
+            let was_selecting = state.interaction_mode == InteractionMode::SelectingText;
             state.interaction_mode = InteractionMode::None;
             if was_resizing && state.is_markdown_mode {
                 markdown_view::fit_font_to_window(hwnd);
             }
-            if !state.has_moved_significantly {
+            if was_selecting {
+                finished_selection = state.selected_text().is_some();
+                if !finished_selection {
+                    state.text_selection = None;
+                }
+            } else if !state.has_moved_significantly && state.selected_text().is_some() {
+                // First click after selecting just drops the selection
+                state.text_selection = None;
+                finished_selection = true;
+            } else if !state.has_moved_significantly {
                 perform_click = true;
                 is_copy_click = state.on_copy_btn;
                 is_edit_click = state.on_edit_btn;
@@ -49,7 +60,12 @@ pub unsafe fn handle_lbutton_up(hwnd: HWND) -> LRESULT {
         }
     }
 
-    if perform_click {
+    if finished_selection {
+        // Result windows never activate on their own; take focus so Ctrl+C reaches us
+        let _ = SetForegroundWindow(hwnd);
+        let _ = SetFocus(Some(hwnd));
+        let _ = InvalidateRect(Some(hwnd), None, false);
+    } else if perform_click {
         if is_back_click {
             markdown_view::go_back(hwnd);
         } else if is_forward_click {
@@ -332,12 +348,7 @@ pub unsafe fn handle_rbutton_up(hwnd: HWND) -> LRESULT {
     }
 
     if close_group {
-        let group = crate::overlay::result::state::get_window_group(hwnd);
-        for (h, _) in group {
-            if IsWindow(Some(h)).as_bool() {
-                let _ = PostMessageW(Some(h), WM_CLOSE, WPARAM(0), LPARAM(0));
-            }
-        }
+        handle_right_click(hwnd);
     } else {
         // Post cursor refresh just in case, though the canvas fix should handle it natively
         unsafe {
@@ -372,6 +383,91 @@ pub unsafe fn handle_rbutton_up(hwnd: HWND) -> LRESULT {
     LRESULT(0)
 }
 
+/// A right-click that was not a drag: close, copy or show the menu, as configured
+pub unsafe fn handle_right_click(hwnd: HWND) {
+    let (action, ui_language) = {
+        let app = crate::APP.lock().unwrap();
+        (
+            app.config.result_right_click.clone(),
+            app.config.ui_language.clone(),
+        )
+    };
+    match action.as_str() {
+        "copy" => crate::overlay::result::trigger_copy(hwnd),
+        "menu" => show_context_menu(hwnd, &ui_language),
+        _ => close_group(hwnd),
+    }
+}
+
+unsafe fn close_group(hwnd: HWND) {
+    let group = crate::overlay::result::state::get_window_group(hwnd);
+    for (h, _) in group {
+        if IsWindow(Some(h)).as_bool() {
+            let _ = PostMessageW(Some(h), WM_CLOSE, WPARAM(0), LPARAM(0));
+        }
+    }
+}
+
+unsafe fn show_context_menu(hwnd: HWND, ui_language: &str) {
+    const ID_COPY: usize = 1;
+    const ID_SPEAK: usize = 2;
+    const ID_CLOSE: usize = 3;
+    const ID_COPY_WITH_SOURCE: usize = 4;
+
+    let (copy, copy_with_source, speak, close) = match ui_language {
+        "vi" => ("Sao chép", "Sao chép kèm văn bản gốc", "Đọc to", "Đóng"),
+        "ko" => ("복사", "원문과 함께 복사", "읽어주기", "닫기"),
+        "ja" => ("コピー", "原文と一緒にコピー", "読み上げ", "閉じる"),
+        _ => ("Copy", "Copy with source", "Speak", "Close"),
+    };
+    let has_source = WINDOW_STATES
+        .lock()
+        .unwrap()
+        .get(&(hwnd.0 as isize))
+        .is_some_and(|s| s.source_text.is_some());
+
+    let Ok(hmenu) = CreatePopupMenu() else {
+        return;
+    };
+    let mut items = vec![(ID_COPY, copy)];
+    if has_source {
+        items.push((ID_COPY_WITH_SOURCE, copy_with_source));
+    }
+    items.push((ID_SPEAK, speak));
+    for (id, label) in items {
+        let label = HSTRING::from(label);
+        let _ = AppendMenuW(hmenu, MF_STRING, id, PCWSTR(label.as_ptr()));
+    }
+    let _ = AppendMenuW(hmenu, MF_SEPARATOR, 0, PCWSTR::null());
+    let close = HSTRING::from(close);
+    let _ = AppendMenuW(hmenu, MF_STRING, ID_CLOSE, PCWSTR(close.as_ptr()));
+
+    let mut pt = POINT::default();
+    let _ = GetCursorPos(&mut pt);
+
+    // The menu only dismisses on outside clicks if its owner is in the foreground
+    let _ = SetForegroundWindow(hwnd);
+    let cmd = TrackPopupMenu(
+        hmenu,
+        TPM_RETURNCMD | TPM_NONOTIFY | TPM_RIGHTBUTTON,
+        pt.x,
+        pt.y,
+        None,
+        hwnd,
+        None,
+    );
+    let _ = PostMessageW(Some(hwnd), WM_NULL, WPARAM(0), LPARAM(0));
+    let _ = DestroyMenu(hmenu);
+
+    match cmd.0 as usize {
+        ID_COPY => crate::overlay::result::trigger_copy_selection(hwnd),
+        ID_COPY_WITH_SOURCE => crate::overlay::result::trigger_copy_with_source(hwnd),
+        ID_SPEAK => crate::overlay::result::trigger_speaker(hwnd),
+        ID_CLOSE => close_group(hwnd),
+        _ => {}
+    }
+}
+
 pub unsafe fn handle_mbutton_up(hwnd: HWND) -> LRESULT {
     let _ = ReleaseCapture();
     button_canvas::set_drag_mode(false); // Disable unclipped drag mode
@@ -1,9 +1,13 @@
 use std::sync::Arc;
 use windows::Win32::Foundation::*;
 use windows::Win32::Graphics::Gdi::*;
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    GetKeyState, VK_CONTROL, VK_LEFT, VK_RIGHT, VK_SHIFT,
+};
 use windows::Win32::UI::WindowsAndMessaging::*;
 
 use crate::overlay::result::button_canvas;
+use crate::overlay::result::layout;
 use crate::overlay::result::markdown_view;
 use crate::overlay::result::paint;
 use crate::overlay::result::state::WINDOW_STATES;
@@ -20,6 +24,7 @@ pub const WM_BACK_CLICK: u32 = WM_USER + 214;
 pub const WM_FORWARD_CLICK: u32 = WM_USER + 215;
 pub const WM_SPEAKER_CLICK: u32 = WM_USER + 216;
 pub const WM_DOWNLOAD_CLICK: u32 = WM_USER + 217;
+pub const WM_RIGHT_CLICK: u32 = WM_USER + 218;
 
 pub unsafe fn handle_erase_bkgnd(_hwnd: HWND, _wparam: WPARAM) -> LRESULT {
     LRESULT(1)
@@ -100,10 +105,74 @@ pub unsafe fn handle_paint(hwnd: HWND) -> LRESULT {
     LRESULT(0)
 }
 
-pub unsafe fn handle_keydown() -> LRESULT {
+pub unsafe fn handle_keydown(hwnd: HWND, wparam: WPARAM) -> LRESULT {
+    // Ctrl+C copies the mouse selection, or the whole result if nothing is selected
+    let ctrl_down = GetKeyState(VK_CONTROL.0 as i32) < 0;
+    // Ctrl+Shift+C copies the source text together with the result
+    let shift_down = GetKeyState(VK_SHIFT.0 as i32) < 0;
+    if ctrl_down && wparam.0 as u32 == 'C' as u32 {
+        if shift_down {
+            crate::overlay::result::trigger_copy_with_source(hwnd);
+        } else {
+            crate::overlay::result::trigger_copy_selection(hwnd);
+        }
+    }
+    // Ctrl+E opens the follow-up prompt bar (same as the edit button)
+    if ctrl_down && wparam.0 as u32 == 'E' as u32 {
+        let is_busy = {
+            let states = WINDOW_STATES.lock().unwrap();
+            states
+                .get(&(hwnd.0 as isize))
+                .map(|s| s.is_refining || s.is_streaming_active)
+                .unwrap_or(true)
+        };
+        if !is_busy {
+            crate::overlay::result::trigger_edit(hwnd);
+        }
+    }
+    // Ctrl+Left/Right snaps to a screen half, Ctrl+Shift+Left/Right moves to the
+    // previous/next monitor
+    let key = wparam.0 as u16;
+    if ctrl_down && (key == VK_LEFT.0 || key == VK_RIGHT.0) {
+        let mut rect = RECT::default();
+        if GetWindowRect(hwnd, &mut rect).is_ok() {
+            let forward = key == VK_RIGHT.0;
+            let target = if shift_down {
+                layout::rect_on_adjacent_monitor(rect, forward)
+            } else {
+                layout::rect_for_monitor_half(rect, forward)
+            };
+            move_result_window(hwnd, target);
+        }
+    }
     LRESULT(0)
 }
 
+unsafe fn move_result_window(hwnd: HWND, rect: RECT) {
+    let _ = SetWindowPos(
+        hwnd,
+        None,
+        rect.left,
+        rect.top,
+        rect.right - rect.left,
+        rect.bottom - rect.top,
+        SWP_NOZORDER | SWP_NOACTIVATE,
+    );
+    let (is_markdown, is_hovered) = {
+        let states = WINDOW_STATES.lock().unwrap();
+        states
+            .get(&(hwnd.0 as isize))
+            .map(|s| (s.is_markdown_mode, s.is_hovered))
+            .unwrap_or((false, false))
+    };
+    if is_markdown {
+        markdown_view::resize_markdown_webview(hwnd, is_hovered);
+        markdown_view::fit_font_to_window(hwnd);
+    }
+    button_canvas::update_window_position(hwnd);
+    let _ = InvalidateRect(Some(hwnd), None, false);
+}
+
 pub unsafe fn handle_create_webview(hwnd: HWND) -> LRESULT {
     // Get the text to render
     let (full_text, is_hovered) = {
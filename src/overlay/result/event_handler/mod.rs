@@ -1,6 +1,8 @@
 use windows::Win32::Foundation::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
 
+use super::state::{WindowState, AUTO_CLOSE_TIMER_ID};
+
 pub mod click_actions;
 pub mod misc;
 pub mod mouse_input;
@@ -11,6 +13,14 @@ pub mod timer_tasks;
 pub const MIN_WINDOW_WIDTH: i32 = 40;
 pub const MIN_WINDOW_HEIGHT: i32 = 30;
 
+/// Hovering means the user is reading: stop the auto-close countdown for good
+unsafe fn cancel_auto_close(hwnd: HWND, state: &mut WindowState) {
+    if state.auto_close_secs > 0 {
+        state.auto_close_secs = 0;
+        let _ = KillTimer(Some(hwnd), AUTO_CLOSE_TIMER_ID);
+    }
+}
+
 pub unsafe extern "system" fn result_wnd_proc(
     hwnd: HWND,
     msg: u32,
@@ -29,6 +39,7 @@ pub unsafe extern "system" fn result_wnd_proc(
 
         WM_MOUSEMOVE => mouse_input::handle_mouse_move(hwnd, lparam),
         WM_MBUTTONDOWN => mouse_input::handle_mbutton_down(hwnd, lparam),
+        WM_MOUSEWHEEL => mouse_input::handle_mouse_wheel(hwnd, wparam, lparam),
 
         0x02A3 => mouse_input::handle_mouse_leave(hwnd), // WM_MOUSELEAVE
 
@@ -44,7 +55,7 @@ pub unsafe extern "system" fn result_wnd_proc(
 
         WM_PAINT => misc::handle_paint(hwnd),
 
-        WM_KEYDOWN => misc::handle_keydown(),
+        WM_KEYDOWN => misc::handle_keydown(hwnd, wparam),
 
         // Enforce minimum window size to prevent rendering issues
         WM_GETMINMAXINFO => {
@@ -85,6 +96,10 @@ pub unsafe extern "system" fn result_wnd_proc(
             LRESULT(0)
         }
         msg if msg == misc::WM_DOWNLOAD_CLICK => misc::handle_download_click(hwnd),
+        msg if msg == misc::WM_RIGHT_CLICK => {
+            click_actions::handle_right_click(hwnd);
+            LRESULT(0)
+        }
 
         WM_WINDOWPOSCHANGED => {
             // Update button canvas position when window moves/resizes
@@ -23,12 +23,17 @@ pub unsafe fn handle_set_cursor(hwnd: HWND) -> LRESULT {
     let _ = GetCursorPos(&mut pt);
     let _ = ScreenToClient(hwnd, &mut pt);
 
-    let is_over_edit = false;
+    let mut is_over_edit = false;
     let mut is_streaming_active = false;
     {
         let states = WINDOW_STATES.lock().unwrap();
         if let Some(state) = states.get(&(hwnd.0 as isize)) {
             is_streaming_active = state.is_streaming_active;
+            // Shift turns the body into a text-selection area
+            is_over_edit = state.interaction_mode == InteractionMode::SelectingText
+                || (is_shift_down()
+                    && can_select_text(state)
+                    && get_resize_edge(rect.right, rect.bottom, pt.x, pt.y) == ResizeEdge::None);
         }
     }
 
@@ -155,6 +160,12 @@ pub unsafe fn handle_lbutton_down(hwnd: HWND, lparam: LPARAM) -> LRESULT {
         state.has_moved_significantly = false;
         if edge != ResizeEdge::None {
             state.interaction_mode = InteractionMode::Resizing(edge);
+        } else if is_shift_down() && can_select_text(state) {
+            // Shift+drag selects text instead of moving the window
+            let caret = state.text_layout.as_ref().map_or(0, |l| l.hit_test(x, y));
+            state.text_selection = Some((caret, caret));
+            state.interaction_mode = InteractionMode::SelectingText;
+            let _ = InvalidateRect(Some(hwnd), None, false);
         } else {
             state.interaction_mode = InteractionMode::DraggingWindow;
         }
@@ -259,6 +270,20 @@ pub unsafe fn handle_mbutton_down(hwnd: HWND, lparam: LPARAM) -> LRESULT {
     LRESULT(0)
 }
 
+pub unsafe fn handle_mouse_wheel(hwnd: HWND, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    // Ctrl+scroll zooms the result text; plain scrolling moves through text that
+    // overflows the window, and otherwise keeps default handling
+    let keys = (wparam.0 & 0xFFFF) as u32;
+    let delta = ((wparam.0 >> 16) & 0xFFFF) as i16 as i32;
+    let notches = delta as f32 / WHEEL_DELTA as f32;
+    if keys & MK_CONTROL.0 != 0 {
+        crate::overlay::result::adjust_font_scale(hwnd, notches);
+    } else if !crate::overlay::result::scroll_text(hwnd, notches) {
+        return DefWindowProcW(hwnd, WM_MOUSEWHEEL, wparam, lparam);
+    }
+    LRESULT(0)
+}
+
 pub unsafe fn handle_mouse_move(hwnd: HWND, lparam: LPARAM) -> LRESULT {
     let x = (lparam.0 & 0xFFFF) as i16 as f32;
     let y = ((lparam.0 >> 16) & 0xFFFF) as i16 as f32;
@@ -277,7 +302,8 @@ pub unsafe fn handle_mouse_move(hwnd: HWND, lparam: LPARAM) -> LRESULT {
             let drag_impulse = if matches!(
                 &state.interaction_mode,
                 InteractionMode::DraggingWindow | InteractionMode::DraggingGroup(_)
-            ) {
+            ) || !crate::overlay::utils::animations_enabled()
+            {
                 0.0
             } else {
                 (dx * 1.5).clamp(-20.0, 20.0)
@@ -399,6 +425,7 @@ pub unsafe fn handle_mouse_move(hwnd: HWND, lparam: LPARAM) -> LRESULT {
 
             if handle_hover_in_mousemove && !state.is_hovered {
                 state.is_hovered = true;
+                super::cancel_auto_close(hwnd, state);
                 let mut tme = TRACKMOUSEEVENT {
                     cbSize: size_of::<TRACKMOUSEEVENT>() as u32,
                     dwFlags: TME_LEAVE,
@@ -410,6 +437,17 @@ pub unsafe fn handle_mouse_move(hwnd: HWND, lparam: LPARAM) -> LRESULT {
             }
 
             match &state.interaction_mode {
+                InteractionMode::SelectingText => {
+                    if let (Some(layout), Some((anchor, _))) =
+                        (&state.text_layout, state.text_selection)
+                    {
+                        let caret = layout.hit_test(x as i32, y as i32);
+                        state.text_selection = Some((anchor, caret));
+                        if caret != anchor {
+                            state.has_moved_significantly = true;
+                        }
+                    }
+                }
                 InteractionMode::DraggingWindow => {
                     let mut curr_pt = POINT::default();
                     let _ = GetCursorPos(&mut curr_pt);
@@ -606,3 +644,12 @@ pub unsafe fn handle_mouse_leave(hwnd: HWND) -> LRESULT {
     }
     LRESULT(0)
 }
+
+fn is_shift_down() -> bool {
+    unsafe { GetKeyState(VK_SHIFT.0 as i32) < 0 }
+}
+
+/// Plain-text results can be selected once they have been laid out
+fn can_select_text(state: &crate::overlay::result::state::WindowState) -> bool {
+    !state.is_markdown_mode && !state.is_refining && state.text_layout.is_some()
+}
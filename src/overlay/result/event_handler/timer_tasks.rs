@@ -1,7 +1,7 @@
 use super::super::logic;
 use crate::overlay::result::markdown_view;
 
-use crate::overlay::result::state::WINDOW_STATES;
+use crate::overlay::result::state::{AUTO_CLOSE_TIMER_ID, WINDOW_STATES};
 use crate::overlay::utils::to_wstring;
 use std::time::{SystemTime, UNIX_EPOCH};
 use windows::core::PCWSTR;
@@ -42,6 +42,7 @@ pub unsafe fn handle_timer(hwnd: HWND, wparam: WPARAM) -> LRESULT {
                     let mut states = WINDOW_STATES.lock().unwrap();
                     if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
                         state.is_hovered = true;
+                        super::cancel_auto_close(hwnd, state);
                     }
                 }
                 markdown_view::resize_markdown_webview(hwnd, true);
@@ -70,6 +71,18 @@ pub unsafe fn handle_timer(hwnd: HWND, wparam: WPARAM) -> LRESULT {
         return LRESULT(0);
     }
 
+    // Auto-close countdown finished: the physics tick runs the regular close path
+    if timer_id == AUTO_CLOSE_TIMER_ID {
+        let _ = KillTimer(Some(hwnd), AUTO_CLOSE_TIMER_ID);
+        let mut states = WINDOW_STATES.lock().unwrap();
+        if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
+            if state.auto_close_secs > 0 && !state.is_hovered {
+                state.auto_close_due = true;
+            }
+        }
+        return LRESULT(0);
+    }
+
     // Timer ID 1 and other timers: existing logic
     let mut need_repaint = false;
 
@@ -109,6 +122,15 @@ pub unsafe fn handle_timer(hwnd: HWND, wparam: WPARAM) -> LRESULT {
             let streaming_just_ended = state.was_streaming_active && !state.is_streaming_active;
             if streaming_just_ended {
                 state.was_streaming_active = false;
+                // Start the auto-close countdown (restarts after each refine)
+                if state.auto_close_secs > 0 && !state.is_hovered {
+                    SetTimer(
+                        Some(hwnd),
+                        AUTO_CLOSE_TIMER_ID,
+                        state.auto_close_secs.saturating_mul(1000),
+                        None,
+                    );
+                }
             }
 
             // Safety: If streaming is NOT active, always process pending text immediately
@@ -1,5 +1,10 @@
 use super::state::{ResizeEdge, WINDOW_STATES};
-use windows::Win32::Foundation::{HWND, RECT};
+use windows::core::BOOL;
+use windows::Win32::Foundation::{HWND, LPARAM, RECT};
+use windows::Win32::Graphics::Gdi::{
+    EnumDisplayMonitors, GetMonitorInfoW, MonitorFromRect, HDC, HMONITOR, MONITORINFO,
+    MONITOR_DEFAULTTONEAREST,
+};
 use windows::Win32::UI::WindowsAndMessaging::{GetWindowRect, IsWindow, IsWindowVisible};
 
 /// Determine if overlay buttons should be displayed based on window dimensions.
@@ -55,69 +60,90 @@ fn would_overlap_existing(proposed: &RECT, existing: &[RECT], gap: i32) -> bool
 ///
 /// This improved algorithm:
 /// 1. Collects all active overlay windows from WINDOW_STATES
-/// 2. Tries positions in order: Right -> Bottom -> Left -> Top
+/// 2. Tries Right / Bottom / Left / Top, starting with the side of `prev` that has the
+///    most free monitor area (ties keep that order), so large pairs stay side-by-side.
+///    `layout` "horizontal" tries Right / Left first, "vertical" Bottom / Top.
 /// 3. Checks each candidate against ALL existing windows (not just the previous one)
-/// 4. Falls back to cascade positioning if all directions are blocked
+/// 4. Falls back to diagonals, then cascade positioning if all directions are blocked
+/// 5. Whatever is returned is clamped fully inside `monitor_rect`
 ///
 /// Similar to the intelligent layout in node_graph.rs blocks_to_snarl()
-pub fn calculate_next_window_rect(prev: RECT, monitor_rect: RECT) -> RECT {
+pub fn calculate_next_window_rect(prev: RECT, monitor_rect: RECT, layout: &str) -> RECT {
     let gap = 15;
-    let w = (prev.right - prev.left).abs();
-    let h = (prev.bottom - prev.top).abs();
 
     // Get all active window RECTs for collision detection
     let existing_windows = get_all_active_window_rects();
 
-    // 1. Try RIGHT
-    let right_candidate = RECT {
-        left: prev.right + gap,
-        top: prev.top,
-        right: prev.right + gap + w,
-        bottom: prev.bottom,
-    };
-    if right_candidate.right <= monitor_rect.right
-        && !would_overlap_existing(&right_candidate, &existing_windows, gap)
-    {
-        return right_candidate;
-    }
-
-    // 2. Try BOTTOM
-    let bottom_candidate = RECT {
-        left: prev.left,
-        top: prev.bottom + gap,
-        right: prev.right,
-        bottom: prev.bottom + gap + h,
-    };
-    if bottom_candidate.bottom <= monitor_rect.bottom
-        && !would_overlap_existing(&bottom_candidate, &existing_windows, gap)
-    {
-        return bottom_candidate;
-    }
+    // Prefer the previous window's live bounds if it's still open (the user may have resized it)
+    let prev = existing_windows
+        .iter()
+        .find(|r| r.left == prev.left && r.top == prev.top)
+        .copied()
+        .unwrap_or(prev);
+    let w = (prev.right - prev.left).abs();
+    let h = (prev.bottom - prev.top).abs();
+    let monitor_w = monitor_rect.right - monitor_rect.left;
+    let monitor_h = monitor_rect.bottom - monitor_rect.top;
 
-    // 3. Try LEFT
-    let left_candidate = RECT {
-        left: prev.left - gap - w,
-        top: prev.top,
-        right: prev.left - gap,
-        bottom: prev.bottom,
-    };
-    if left_candidate.left >= monitor_rect.left
-        && !would_overlap_existing(&left_candidate, &existing_windows, gap)
-    {
-        return left_candidate;
+    // 1-4. Cardinal directions, roomiest side first
+    let mut cardinals = [
+        // RIGHT
+        (
+            (monitor_rect.right - prev.right).max(0) as i64 * monitor_h as i64,
+            RECT {
+                left: prev.right + gap,
+                top: prev.top,
+                right: prev.right + gap + w,
+                bottom: prev.bottom,
+            },
+        ),
+        // BOTTOM
+        (
+            (monitor_rect.bottom - prev.bottom).max(0) as i64 * monitor_w as i64,
+            RECT {
+                left: prev.left,
+                top: prev.bottom + gap,
+                right: prev.right,
+                bottom: prev.bottom + gap + h,
+            },
+        ),
+        // LEFT
+        (
+            (prev.left - monitor_rect.left).max(0) as i64 * monitor_h as i64,
+            RECT {
+                left: prev.left - gap - w,
+                top: prev.top,
+                right: prev.left - gap,
+                bottom: prev.bottom,
+            },
+        ),
+        // TOP
+        (
+            (prev.top - monitor_rect.top).max(0) as i64 * monitor_w as i64,
+            RECT {
+                left: prev.left,
+                top: prev.top - gap - h,
+                right: prev.right,
+                bottom: prev.top - gap,
+            },
+        ),
+    ];
+    // Stable sort keeps Right -> Bottom -> Left -> Top among equal areas
+    cardinals.sort_by_key(|&(free_area, _)| std::cmp::Reverse(free_area));
+    // A fixed layout only uses the roomier of its own two sides first
+    let is_vertical = |rect: &RECT| rect.left == prev.left;
+    match layout {
+        "horizontal" => cardinals.sort_by_key(|(_, rect)| is_vertical(rect)),
+        "vertical" => cardinals.sort_by_key(|(_, rect)| !is_vertical(rect)),
+        _ => {}
     }
 
-    // 4. Try TOP
-    let top_candidate = RECT {
-        left: prev.left,
-        top: prev.top - gap - h,
-        right: prev.right,
-        bottom: prev.top - gap,
-    };
-    if top_candidate.top >= monitor_rect.top
-        && !would_overlap_existing(&top_candidate, &existing_windows, gap)
-    {
-        return top_candidate;
+    for &(_, candidate) in &cardinals {
+        if fits_in(&candidate, &monitor_rect)
+            && !would_overlap_existing(&candidate, &existing_windows, gap)
+        {
+            return candidate;
+        }
     }
 
     // 5. Try diagonals if cardinal directions are blocked
@@ -153,12 +179,7 @@ pub fn calculate_next_window_rect(prev: RECT, monitor_rect: RECT) -> RECT {
     ];
 
     for diag in diagonals {
-        if diag.left >= monitor_rect.left
-            && diag.right <= monitor_rect.right
-            && diag.top >= monitor_rect.top
-            && diag.bottom <= monitor_rect.bottom
-            && !would_overlap_existing(&diag, &existing_windows, gap)
-        {
+        if fits_in(&diag, &monitor_rect) && !would_overlap_existing(&diag, &existing_windows, gap) {
             return diag;
         }
     }
@@ -173,21 +194,117 @@ pub fn calculate_next_window_rect(prev: RECT, monitor_rect: RECT) -> RECT {
             bottom: prev.top + offset + h,
         };
 
-        // Clamp to screen bounds
-        if cascade.right <= monitor_rect.right
-            && cascade.bottom <= monitor_rect.bottom
+        if fits_in(&cascade, &monitor_rect)
             && !would_overlap_existing(&cascade, &existing_windows, gap)
         {
             return cascade;
         }
     }
 
-    // 7. Ultimate fallback: just use the simple cascade (may overlap)
+    // 7. Ultimate fallback: the roomiest side, pulled fully on-screen (may overlap)
+    clamp_to_monitor(cardinals[0].1, &monitor_rect)
+}
+
+fn fits_in(rect: &RECT, monitor_rect: &RECT) -> bool {
+    rect.left >= monitor_rect.left
+        && rect.right <= monitor_rect.right
+        && rect.top >= monitor_rect.top
+        && rect.bottom <= monitor_rect.bottom
+}
+
+/// Shift `rect` so it lies inside `monitor_rect`, shrinking it only if it's larger than the monitor
+fn clamp_to_monitor(rect: RECT, monitor_rect: &RECT) -> RECT {
+    let w = (rect.right - rect.left).min(monitor_rect.right - monitor_rect.left);
+    let h = (rect.bottom - rect.top).min(monitor_rect.bottom - monitor_rect.top);
+    let left = rect.left.clamp(monitor_rect.left, monitor_rect.right - w);
+    let top = rect.top.clamp(monitor_rect.top, monitor_rect.bottom - h);
     RECT {
-        left: prev.left + 40,
-        top: prev.top + 40,
-        right: prev.left + 40 + w,
-        bottom: prev.top + 40 + h,
+        left,
+        top,
+        right: left + w,
+        bottom: top + h,
+    }
+}
+
+/// Work areas of all monitors, left to right (then top to bottom)
+fn monitor_work_areas() -> Vec<RECT> {
+    unsafe extern "system" fn enum_proc(
+        hmonitor: HMONITOR,
+        _hdc: HDC,
+        _rect: *mut RECT,
+        data: LPARAM,
+    ) -> BOOL {
+        let areas = &mut *(data.0 as *mut Vec<RECT>);
+        let mut mi = MONITORINFO {
+            cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+            ..Default::default()
+        };
+        if GetMonitorInfoW(hmonitor, &mut mi).as_bool() {
+            areas.push(mi.rcWork);
+        }
+        BOOL::from(true)
+    }
+
+    let mut areas: Vec<RECT> = Vec::new();
+    unsafe {
+        let _ = EnumDisplayMonitors(
+            None,
+            None,
+            Some(enum_proc),
+            LPARAM(&mut areas as *mut _ as isize),
+        );
+    }
+    areas.sort_by_key(|r| (r.left, r.top));
+    areas
+}
+
+/// Work area of the monitor `rect` is mostly on
+fn work_area_for(rect: &RECT) -> RECT {
+    unsafe {
+        let mut mi = MONITORINFO {
+            cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+            ..Default::default()
+        };
+        let _ = GetMonitorInfoW(MonitorFromRect(rect, MONITOR_DEFAULTTONEAREST), &mut mi);
+        mi.rcWork
+    }
+}
+
+/// Same size and relative position on the next (or previous) monitor, kept fully on it
+pub fn rect_on_adjacent_monitor(rect: RECT, forward: bool) -> RECT {
+    let areas = monitor_work_areas();
+    if areas.len() < 2 {
+        return rect;
+    }
+    let current = work_area_for(&rect);
+    let idx = areas.iter().position(|a| *a == current).unwrap_or(0);
+    let next = if forward {
+        (idx + 1) % areas.len()
+    } else {
+        (idx + areas.len() - 1) % areas.len()
+    };
+    let target = areas[next];
+    let left = target.left + (rect.left - current.left);
+    let top = target.top + (rect.top - current.top);
+    clamp_to_monitor(
+        RECT {
+            left,
+            top,
+            right: left + (rect.right - rect.left),
+            bottom: top + (rect.bottom - rect.top),
+        },
+        &target,
+    )
+}
+
+/// Left or right half of the monitor `rect` is on
+pub fn rect_for_monitor_half(rect: RECT, right_half: bool) -> RECT {
+    let area = work_area_for(&rect);
+    let mid = area.left + (area.right - area.left) / 2;
+    if right_half {
+        RECT { left: mid, ..area }
+    } else {
+        RECT { right: mid, ..area }
     }
 }
 
@@ -16,11 +16,12 @@ pub fn handle_timer(hwnd: HWND, wparam: WPARAM) {
     unsafe {
         if wparam.0 == 3 {
             // 60 FPS Physics Loop
-            let should_close = false;
+            let mut should_close = false;
 
             {
                 let mut states = WINDOW_STATES.lock().unwrap();
                 if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
+                    should_close = state.auto_close_due;
                     let p = &mut state.physics;
 
                     // --- 1. MOUSE PHYSICS (Spring System) ---
@@ -32,9 +33,15 @@ pub fn handle_timer(hwnd: HWND, wparam: WPARAM) {
                     let spring_stiffness = 0.15;
                     let damping = 0.85;
 
-                    p.tilt_velocity += (0.0 - p.current_tilt) * spring_stiffness;
-                    p.tilt_velocity *= damping;
-                    p.current_tilt += p.tilt_velocity;
+                    if crate::overlay::utils::animations_enabled() {
+                        p.tilt_velocity += (0.0 - p.current_tilt) * spring_stiffness;
+                        p.tilt_velocity *= damping;
+                        p.current_tilt += p.tilt_velocity;
+                    } else {
+                        // Reduced motion: hold the window level
+                        p.tilt_velocity = 0.0;
+                        p.current_tilt = 0.0;
+                    }
 
                     // Bristle bend follows tilt but lags slightly
                     p.bristle_bend = p.bristle_bend * 0.8 + (p.current_tilt / 10.0) * 0.2;
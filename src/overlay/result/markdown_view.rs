@@ -1256,20 +1256,29 @@ pub fn stream_markdown_content_ex(
                 .replace("${", "\\${");
 
             // Animate only NEW .word spans (markdown_to_html wraps words in <span class="word">)
-            // Track previous word count, add animation only to new words
-            // Animate only NEW .word spans (markdown_to_html wraps words in <span class="word">)
-            // Track previous word count, add animation only to new words
-            // Animate only NEW .word spans (markdown_to_html wraps words in <span class="word">)
-            // Track previous word count, add animation only to new words
-            // Animate only NEW .word spans (markdown_to_html wraps words in <span class="word">)
-            // Track previous word count, add animation only to new words
+            // Track previous word count, add animation only to new words.
+            // Chunks arriving within one frame are coalesced: only the latest is rendered,
+            // with a span still waiting for its closing marker styled as if closed.
             let script = format!(
                 r#"(function() {{
-    const newContent = `{}`;
+    window._streamPendingHtml = `{}`;
+    window._streamApply = applyStreamChunk;
+    if (window._streamFrameQueued) return;
+    window._streamFrameQueued = true;
+    requestAnimationFrame(function() {{
+        window._streamFrameQueued = false;
+        window._streamApply(window._streamPendingHtml);
+    }});
+
+    function applyStreamChunk(newContent) {{
+    // Re-setting identical HTML would restart the word transitions
+    if (newContent === window._streamLastHtml) return;
+    window._streamLastHtml = newContent;
     const prevWordCount = window._streamWordCount || 0;
     
     // Update content
     document.body.innerHTML = newContent;
+    closePartialMarkdown(document.body);
     
     // --- INTEGRATED FONT SIZING (Heuristic Optimized) ---
     var body = document.body;
@@ -1381,6 +1390,48 @@ pub fn stream_markdown_content_ex(
     
     window._streamWordCount = newWordCount;
     window.scrollTo({{ top: document.body.scrollHeight, behavior: 'smooth' }});
+    }}
+
+    // A chunk can end inside a span whose closer hasn't arrived yet ("**bold").
+    // The renderer leaves such a marker as literal text, so it shows and then jumps
+    // once closed; style the rest of the last block as it will end up instead, and
+    // hide a marker with nothing after it yet. Open code fences already run to the end.
+    function closePartialMarkdown(root) {{
+        let block = root.lastElementChild;
+        while (block && block.lastElementChild && /^(UL|OL|LI|BLOCKQUOTE|DIV)$/.test(block.tagName)) {{
+            block = block.lastElementChild;
+        }}
+        if (!block || block.closest('pre')) return;
+
+        const tags = {{ '**': 'strong', '__': 'strong', '~~': 'del', '`': 'code', '*': 'em', '_': 'em' }};
+        const walker = document.createTreeWalker(block, NodeFilter.SHOW_TEXT);
+        let open = null;
+        for (let node = walker.nextNode(); node; node = walker.nextNode()) {{
+            if (node.parentElement.closest('code')) continue;
+            const re = /\*\*|__|~~|`|\*|_/g;
+            let m;
+            while ((m = re.exec(node.data)) !== null) {{
+                const prev = node.data[m.index - 1];
+                const next = node.data[m.index + m[0].length];
+                // snake_case, and markers followed by a space, are not openers
+                if (m[0][0] === '_' && prev && /\w/.test(prev)) continue;
+                if (next !== undefined && /\s/.test(next)) continue;
+                open = {{ node: node, index: m.index, marker: m[0] }};
+            }}
+        }}
+        if (!open) return;
+
+        const range = document.createRange();
+        range.setStart(open.node, open.index + open.marker.length);
+        range.setEnd(block, block.childNodes.length);
+        const rest = range.extractContents();
+        open.node.deleteData(open.index, open.marker.length);
+        if (rest.textContent.trim()) {{
+            const span = document.createElement(tags[open.marker]);
+            span.appendChild(rest);
+            block.appendChild(span);
+        }}
+    }}
 }})()"#,
                 escaped_content
             );
@@ -1400,7 +1451,7 @@ pub fn reset_stream_counter(parent_hwnd: HWND) {
         if let Some(webview) = webviews.borrow().get(&hwnd_key) {
             // Reset stream counters only - font will be reset at start of next session
             let _ = webview.evaluate_script(
-                "window._streamPrevLen = 0; window._streamPrevContent = ''; window._streamWordCount = 0;"
+                "window._streamPrevLen = 0; window._streamPrevContent = ''; window._streamWordCount = 0; window._streamLastHtml = '';"
             );
         }
     });
@@ -5,12 +5,110 @@ mod logic;
 pub mod markdown_view;
 pub mod paint;
 pub mod state;
+mod text_layout;
 mod window;
 
-pub use state::{close_windows_with_token, link_windows, RefineContext, WindowType, WINDOW_STATES};
+pub use state::{
+    close_windows_with_token, link_windows, RefineContext, RetryRequest, WindowType, WINDOW_STATES,
+};
 pub use window::{create_result_window, get_chain_color, update_window_text};
 
+/// Ctrl+scroll zoom limits for result window text
+const MIN_FONT_SCALE: f32 = 0.5;
+const MAX_FONT_SCALE: f32 = 3.0;
+const FONT_SCALE_STEP: f32 = 0.1;
+
+/// Tag a result window with the preset that produced it and apply that preset's
+/// saved zoom, accent color and auto-close delay
+pub fn set_window_preset(hwnd: HWND, preset_id: &str) {
+    let (scale, accent, auto_close_secs) = crate::APP
+        .lock()
+        .ok()
+        .and_then(|app| {
+            app.config
+                .presets
+                .iter()
+                .find(|p| p.id == preset_id)
+                .map(|p| (p.result_font_scale, p.glow_color_rgb(), p.auto_close_secs))
+        })
+        .unwrap_or((1.0, None, 0));
+    let scale = scale.clamp(MIN_FONT_SCALE, MAX_FONT_SCALE);
+
+    let mut states = WINDOW_STATES.lock().unwrap();
+    if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
+        state.preset_id = preset_id.to_string();
+        state.accent_color = accent;
+        state.auto_close_secs = auto_close_secs;
+        if state.font_scale != scale {
+            state.font_scale = scale;
+            state.font_cache_dirty = true;
+        }
+    }
+}
+
+/// Step the text zoom of a result window by `notches` wheel clicks and remember it for the preset
+pub fn adjust_font_scale(hwnd: HWND, notches: f32) {
+    let (preset_id, scale) = {
+        let mut states = WINDOW_STATES.lock().unwrap();
+        let Some(state) = states.get_mut(&(hwnd.0 as isize)) else {
+            return;
+        };
+        let stepped = state.font_scale + notches * FONT_SCALE_STEP;
+        // Snap to the step grid so repeated zooming doesn't accumulate float drift
+        let scale = ((stepped / FONT_SCALE_STEP).round() * FONT_SCALE_STEP)
+            .clamp(MIN_FONT_SCALE, MAX_FONT_SCALE);
+        if scale == state.font_scale {
+            return;
+        }
+        state.font_scale = scale;
+        state.font_cache_dirty = true;
+        (state.preset_id.clone(), scale)
+    };
+
+    unsafe {
+        let _ = windows::Win32::Graphics::Gdi::InvalidateRect(Some(hwnd), None, false);
+    }
+
+    if preset_id.is_empty() {
+        return;
+    }
+    if let Ok(mut app) = crate::APP.lock() {
+        if let Some(preset) = app.config.presets.iter_mut().find(|p| p.id == preset_id) {
+            preset.result_font_scale = scale;
+            crate::config::save_config(&app.config);
+        }
+    }
+}
+
+/// Scroll plain text that overflows its window; false if there is nothing to scroll
+pub fn scroll_text(hwnd: HWND, notches: f32) -> bool {
+    {
+        let mut states = WINDOW_STATES.lock().unwrap();
+        let Some(state) = states.get_mut(&(hwnd.0 as isize)) else {
+            return false;
+        };
+        if state.max_scroll == 0 || state.is_markdown_mode {
+            return false;
+        }
+        // Three lines per notch, like a text box
+        let step = (notches * state.cached_font_size as f32 * 3.0).round() as i32;
+        let scroll = (state.scroll_offset - step).clamp(0, state.max_scroll);
+        if scroll == state.scroll_offset {
+            return true;
+        }
+        state.scroll_offset = scroll;
+        state.font_cache_dirty = true;
+    }
+
+    unsafe {
+        let _ = windows::Win32::Graphics::Gdi::InvalidateRect(Some(hwnd), None, false);
+    }
+    true
+}
+
 // Trigger functions for button canvas IPC
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 use windows::Win32::Foundation::{HWND, LPARAM, WPARAM};
 use windows::Win32::UI::WindowsAndMessaging::{PostMessageW, WM_CLOSE};
 
@@ -43,6 +141,23 @@ pub fn set_refine_text(hwnd: HWND, text: &str, is_insert: bool) {
     }
 }
 
+/// Copy the mouse-selected text of a result window, or everything if nothing is selected
+pub fn trigger_copy_selection(hwnd: HWND) {
+    let selected = {
+        let states = WINDOW_STATES.lock().unwrap();
+        states
+            .get(&(hwnd.0 as isize))
+            .and_then(|s| s.selected_text())
+    };
+
+    match selected {
+        Some(text) if !text.is_empty() => {
+            crate::overlay::utils::copy_to_clipboard(&text, hwnd);
+        }
+        _ => trigger_copy(hwnd),
+    }
+}
+
 /// Trigger copy action on a result window
 pub fn trigger_copy(hwnd: HWND) {
     let hwnd_key = hwnd.0 as isize;
@@ -86,6 +201,25 @@ pub fn trigger_copy(hwnd: HWND) {
     }
 }
 
+/// Copy "source\n---\nresult", or just the result when the window has no
+/// text source (image blocks)
+pub fn trigger_copy_with_source(hwnd: HWND) {
+    let (source, result) = {
+        let states = WINDOW_STATES.lock().unwrap();
+        states
+            .get(&(hwnd.0 as isize))
+            .map(|s| (s.source_text.clone(), s.full_text.clone()))
+            .unwrap_or_default()
+    };
+    match source {
+        Some(source) if !result.is_empty() => {
+            let combined = format!("{}\n---\n{}", source.trim_end(), result);
+            crate::overlay::utils::copy_to_clipboard(&combined, hwnd);
+        }
+        _ => trigger_copy(hwnd),
+    }
+}
+
 /// Trigger undo action on a result window
 pub fn trigger_undo(hwnd: HWND) {
     let hwnd_key = hwnd.0 as isize;
@@ -318,10 +452,18 @@ pub fn trigger_refine_cancel(hwnd: HWND) {
 // Logic extracted from timer_tasks (simplified)
 fn start_refinement(hwnd: HWND, user_prompt: &str) {
     let hwnd_key = hwnd.0 as isize;
-    let (context_data, model_id, provider, streaming, preset_prompt, prev_text) = {
+    let (context_data, model_id, provider, streaming, preset_prompt, prev_text, cancel_token) = {
         let mut states = WINDOW_STATES.lock().unwrap();
         if let Some(s) = states.get_mut(&hwnd_key) {
             let prev = s.full_text.clone();
+            // Closing the window signals this token (see handle_destroy), aborting the refine
+            let cancel_token = s
+                .cancellation_token
+                .get_or_insert_with(|| Arc::new(AtomicBool::new(false)))
+                .clone();
+            // Refining replaces the error text, so the retry no longer applies
+            s.is_error = false;
+            s.retry_request = None;
             // Setup state for processing
             // s.input_text = prev.clone(); // Removed: Don't pollute input UI state with context
             (
@@ -331,6 +473,7 @@ fn start_refinement(hwnd: HWND, user_prompt: &str) {
                 s.streaming_enabled,
                 s.preset_prompt.clone(),
                 prev,
+                cancel_token,
             )
         } else {
             return;
@@ -378,58 +521,179 @@ fn start_refinement(hwnd: HWND, user_prompt: &str) {
                 let app = crate::APP.lock().unwrap();
                 &app.config.ui_language.clone()
             },
+            &cancel_token,
             move |chunk| {
-                // Check cancellation token? refined_text_streaming should handle it if passed.
-                // But here we rely on the callback.
-
-                let mut states = WINDOW_STATES.lock().unwrap();
-                if let Some(state) = states.get_mut(&(capture_hwnd.0 as isize)) {
-                    if first_chunk {
-                        state.is_refining = false; // Stop animation loop??
-                                                   // In timer_tasks: state.is_refining = false;
-                        first_chunk = false;
+                if first_chunk {
+                    // Stop the refining animation once output starts arriving
+                    let mut states = WINDOW_STATES.lock().unwrap();
+                    if let Some(state) = states.get_mut(&(capture_hwnd.0 as isize)) {
+                        state.is_refining = false;
                     }
+                    first_chunk = false;
+                }
 
-                    // Handle WIPE_SIGNAL
-                    if chunk.starts_with(crate::api::WIPE_SIGNAL) {
-                        acc_text.clear();
-                        acc_text.push_str(&chunk[crate::api::WIPE_SIGNAL.len()..]);
-                    } else {
-                        acc_text.push_str(chunk);
-                    }
-                    state.pending_text = Some(acc_text.clone());
-                    state.full_text = acc_text.clone();
+                // Handle WIPE_SIGNAL
+                if chunk.starts_with(crate::api::WIPE_SIGNAL) {
+                    acc_text.clear();
+                    acc_text.push_str(&chunk[crate::api::WIPE_SIGNAL.len()..]);
+                } else {
+                    acc_text.push_str(chunk);
                 }
+                update_window_text(capture_hwnd, &acc_text);
             },
         );
 
+        {
+            let mut states = WINDOW_STATES.lock().unwrap();
+            if let Some(state) = states.get_mut(&(capture_hwnd.0 as isize)) {
+                state.is_refining = false;
+                state.is_streaming_active = false;
+            }
+        }
+
+        match result {
+            Ok(final_text) => update_window_text(capture_hwnd, &final_text),
+            Err(e) => {
+                let (lang, model_full_name) = {
+                    let app = crate::APP.lock().unwrap();
+                    let full_name = crate::model_config::get_model_by_id(&model_id)
+                        .map(|m| m.full_name)
+                        .unwrap_or_else(|| model_id.to_string());
+                    (app.config.ui_language.clone(), full_name)
+                };
+                let err_msg = crate::overlay::utils::get_error_message(
+                    &e.to_string(),
+                    &lang,
+                    Some(&model_full_name),
+                );
+                update_window_text(capture_hwnd, &err_msg);
+            }
+        }
+    });
+}
+
+/// Re-run the API call of a failed result window in place, with the inputs it was made with
+pub fn trigger_retry(hwnd: HWND) {
+    let hwnd_key = hwnd.0 as isize;
+    let (request, cancel_token) = {
         let mut states = WINDOW_STATES.lock().unwrap();
-        if let Some(state) = states.get_mut(&(capture_hwnd.0 as isize)) {
-            state.is_refining = false;
-            state.is_streaming_active = false;
-            match result {
-                Ok(final_text) => {
-                    state.full_text = final_text.clone();
-                    state.pending_text = Some(final_text);
+        let Some(s) = states.get_mut(&hwnd_key) else {
+            return;
+        };
+        if !s.is_error {
+            return;
+        }
+        let Some(request) = s.retry_request.take() else {
+            return;
+        };
+        s.is_error = false;
+        s.is_refining = true;
+        s.is_streaming_active = request.streaming_enabled;
+        s.font_cache_dirty = true;
+        let cancel_token = s
+            .cancellation_token
+            .get_or_insert_with(|| Arc::new(AtomicBool::new(false)))
+            .clone();
+        (request, cancel_token)
+    };
+    update_window_text(hwnd, "");
+    button_canvas::update_window_position(hwnd);
+
+    let hwnd_val = hwnd.0 as usize;
+    std::thread::spawn(move || {
+        let capture_hwnd = HWND(hwnd_val as *mut std::ffi::c_void);
+
+        let (groq_key, gemini_key, lang) = {
+            let app = crate::APP.lock().unwrap();
+            (
+                app.config.api_key.clone(),
+                app.config.gemini_api_key.clone(),
+                app.config.ui_language.clone(),
+            )
+        };
+
+        let mut acc_text = String::new();
+        let mut first_chunk = true;
+        let on_chunk = |chunk: &str| {
+            if first_chunk {
+                let mut states = WINDOW_STATES.lock().unwrap();
+                if let Some(state) = states.get_mut(&(capture_hwnd.0 as isize)) {
+                    state.is_refining = false;
                 }
-                Err(e) => {
-                    let (lang, model_full_name) = {
-                        let app = crate::APP.lock().unwrap();
-                        let full_name = crate::model_config::get_model_by_id(&model_id)
-                            .map(|m| m.full_name)
-                            .unwrap_or_else(|| model_id.to_string());
-                        (app.config.ui_language.clone(), full_name)
-                    };
-                    let err_msg = crate::overlay::utils::get_error_message(
-                        &e.to_string(),
-                        &lang,
-                        Some(&model_full_name),
-                    );
-                    state.pending_text = Some(err_msg.clone());
-                    state.full_text = err_msg;
+                first_chunk = false;
+            }
+
+            if let Some(rest) = chunk.strip_prefix(crate::api::WIPE_SIGNAL) {
+                acc_text.clear();
+                acc_text.push_str(rest);
+            } else {
+                acc_text.push_str(chunk);
+            }
+            update_window_text(capture_hwnd, &acc_text);
+        };
+
+        let result = match &request.image {
+            Some(png) => match image::load_from_memory(png) {
+                Ok(img) => crate::api::translate_image_streaming(
+                    &groq_key,
+                    &gemini_key,
+                    request.prompt.clone(),
+                    request.model_full_name.clone(),
+                    request.provider.clone(),
+                    img.to_rgba8(),
+                    Some(png.clone()),
+                    request.streaming_enabled,
+                    request.use_json,
+                    &cancel_token,
+                    on_chunk,
+                ),
+                Err(e) => Err(anyhow::anyhow!("Failed to load image: {}", e)),
+            },
+            None => crate::api::translate_text_streaming(
+                &groq_key,
+                &gemini_key,
+                request.input_text.clone(),
+                request.prompt.clone(),
+                request.model_full_name.clone(),
+                request.provider.clone(),
+                request.streaming_enabled,
+                false,
+                request.search_label.clone(),
+                &lang,
+                &cancel_token,
+                on_chunk,
+            ),
+        };
+
+        // Closed while retrying: nothing left to update
+        if cancel_token.load(std::sync::atomic::Ordering::Relaxed) {
+            return;
+        }
+
+        let text = match &result {
+            Ok(final_text) => final_text.clone(),
+            Err(e) => crate::overlay::utils::get_error_message(
+                &e.to_string(),
+                &lang,
+                Some(&request.model_full_name),
+            ),
+        };
+        {
+            let mut states = WINDOW_STATES.lock().unwrap();
+            if let Some(state) = states.get_mut(&(capture_hwnd.0 as isize)) {
+                state.is_refining = false;
+                state.is_streaming_active = false;
+                state.font_cache_dirty = true;
+                state.pending_text = Some(text.clone());
+                state.full_text = text;
+                if result.is_err() {
+                    // Failed again: keep offering the retry
+                    state.is_error = true;
+                    state.retry_request = Some(request);
                 }
             }
         }
+        button_canvas::update_window_position(capture_hwnd);
     });
 }
 
@@ -437,12 +701,16 @@ fn start_refinement(hwnd: HWND, user_prompt: &str) {
 pub fn trigger_markdown_toggle(hwnd: HWND) {
     let hwnd_key = hwnd.0 as isize;
 
-    // Check if we can toggle
+    // Check if we can toggle (markdown needs the WebView2 runtime)
     let can_toggle = {
         let states = WINDOW_STATES.lock().unwrap();
         states
             .get(&hwnd_key)
-            .map(|s| !s.is_refining && !s.is_streaming_active)
+            .map(|s| {
+                !s.is_refining
+                    && !s.is_streaming_active
+                    && (s.is_markdown_mode || crate::overlay::webview_runtime::is_available())
+            })
             .unwrap_or(false)
     };
 
@@ -1,13 +1,24 @@
 use super::layout::should_show_buttons;
 use super::state::{ResizeEdge, WINDOW_STATES};
+use super::text_layout::TextLayout;
 use crate::overlay::broom_assets::{render_procedural_broom, BroomRenderParams, BROOM_H, BROOM_W};
-use crate::overlay::paint_utils::{hsv_to_rgb, sd_rounded_box};
+use crate::overlay::paint_utils::{hsv_to_rgb, sd_rounded_box, window_dpi_scale};
 use std::mem::size_of;
 use windows::core::w;
 use windows::Win32::Foundation::*;
 use windows::Win32::Graphics::Gdi::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
 
+/// Minimum growth (UTF-16 units) before a streaming window re-fits its font;
+/// larger texts use 10% of the last fitted length instead.
+const REFIT_MIN_CHARS: usize = 64;
+
+/// Auto-fit never grows plain text past this (96 DPI px), so a short line
+/// doesn't balloon to fill a large window
+const MAX_FIT_FONT_PX: i32 = 48;
+/// ...nor shrinks it below this; text that still doesn't fit scrolls instead
+const MIN_READABLE_FONT_PX: i32 = 13;
+
 // Helper: Measure text dimensions (Height AND Width)
 unsafe fn measure_text_bounds(
     hdc: windows::Win32::Graphics::Gdi::HDC,
@@ -106,6 +117,55 @@ fn sd_box(px: f32, py: f32, cx: f32, cy: f32, w: f32, h: f32) -> f32 {
     (dx.max(0.0).powi(2) + dy.max(0.0).powi(2)).sqrt() + dx.max(dy).min(0.0)
 }
 
+/// Font size of the "JA → VI" line, in 96 DPI pixels
+const LANGUAGE_LABEL_PX: i32 = 11;
+
+/// Draw the language line over the header band, on a background scrolled text
+/// can't show through
+unsafe fn draw_language_label(dc: HDC, label: &str, font_px: i32, band: RECT, bg: COLORREF) {
+    let brush = CreateSolidBrush(bg);
+    FillRect(dc, &band, brush);
+    let _ = DeleteObject(brush.into());
+
+    let hfont = CreateFontW(
+        font_px,
+        0,
+        0,
+        0,
+        FW_MEDIUM.0 as i32,
+        0,
+        0,
+        0,
+        DEFAULT_CHARSET,
+        OUT_DEFAULT_PRECIS,
+        CLIP_DEFAULT_PRECIS,
+        CLEARTYPE_QUALITY,
+        (VARIABLE_PITCH.0 | FF_SWISS.0) as u32,
+        w!("Google Sans Flex"),
+    );
+    let old_font = SelectObject(dc, hfont.into());
+    // Same hue as the text, faded towards the background
+    let fg = GetTextColor(dc).0;
+    let mix = |shift: u32| ((((fg >> shift) & 0xFF) + ((bg.0 >> shift) & 0xFF)) / 2) << shift;
+    SetTextColor(dc, COLORREF(mix(0) | mix(8) | mix(16)));
+
+    let mut text: Vec<u16> = label.encode_utf16().collect();
+    let mut rect = RECT {
+        left: font_px / 2,
+        ..band
+    };
+    DrawTextW(
+        dc,
+        &mut text,
+        &mut rect,
+        DT_LEFT | DT_VCENTER | DT_SINGLELINE | DT_NOPREFIX,
+    );
+
+    SetTextColor(dc, COLORREF(fg));
+    SelectObject(dc, old_font);
+    let _ = DeleteObject(hfont.into());
+}
+
 pub fn paint_window(hwnd: HWND) {
     unsafe {
         let mut ps = PAINTSTRUCT::default();
@@ -391,10 +451,16 @@ pub fn paint_window(hwnd: HWND) {
                 let bg_b = bg_color_u32 & 0xFF;
                 let luminance =
                     (0.299 * bg_r as f32) + (0.587 * bg_g as f32) + (0.114 * bg_b as f32);
-                let text_col = if luminance > 140.0 {
-                    0x00000000 // Black text for light background
-                } else {
-                    0x00FFFFFF // White text for dark background
+                let is_error = WINDOW_STATES
+                    .lock()
+                    .unwrap()
+                    .get(&(hwnd.0 as isize))
+                    .is_some_and(|s| s.is_error);
+                let text_col = match (is_error, luminance > 140.0) {
+                    (true, true) => 0x002828C6,   // Dark red error text for light background
+                    (true, false) => 0x00808AFF,  // Light red error text for dark background
+                    (false, true) => 0x00000000,  // Black text for light background
+                    (false, false) => 0x00FFFFFF, // White text for dark background
                 };
                 SetTextColor(cache_dc, COLORREF(text_col));
 
@@ -418,21 +484,63 @@ pub fn paint_window(hwnd: HWND) {
                     b
                 };
 
-                let h_padding = if is_refining { 20 } else { 2 };
+                // Fixed sizes are in 96 DPI pixels; scale them to the capture monitor
+                let dpi_scale = window_dpi_scale(hwnd);
+                let scaled = |px: i32| (px as f32 * dpi_scale).round() as i32;
+
+                let h_padding = if is_refining { scaled(20) } else { 2 };
                 let available_w = (width - (h_padding * 2)).max(1);
-                let v_safety_margin = 0;
+                // Translations get a small "JA → VI" line above the text
+                let language_label = if is_refining {
+                    None
+                } else {
+                    WINDOW_STATES
+                        .lock()
+                        .unwrap()
+                        .get(&(hwnd.0 as isize))
+                        .and_then(|s| s.language_label.clone())
+                };
+                let header_h = if language_label.is_some() {
+                    scaled(LANGUAGE_LABEL_PX + 4)
+                } else {
+                    0
+                };
+                let v_safety_margin = header_h;
                 let available_h = (height - v_safety_margin).max(1);
 
-                let mut low = if is_refining { 8 } else { 2 };
+                // Ctrl+scroll zoom scales the fitted size (text may then overflow the window).
+                // While streaming, reuse the last fit until the text has grown enough to matter.
+                let (font_scale, reusable_fit) = if is_refining {
+                    (1.0, None)
+                } else {
+                    let states = WINDOW_STATES.lock().unwrap();
+                    states.get(&(hwnd.0 as isize)).map_or((1.0, None), |s| {
+                        let delta = buf.len().abs_diff(s.fitted_text_len);
+                        let small_delta = delta < REFIT_MIN_CHARS.max(s.fitted_text_len / 10);
+                        let reusable = is_streaming_active
+                            && s.fitted_font_size > 0
+                            && s.fitted_dims == (width, height)
+                            && small_delta;
+                        (s.font_scale, reusable.then_some(s.fitted_font_size))
+                    })
+                };
+
+                let mut low = if is_refining {
+                    scaled(8)
+                } else {
+                    scaled(MIN_READABLE_FONT_PX)
+                };
                 let max_possible = if is_refining {
-                    18.min(available_h)
+                    scaled(18).min(available_h)
                 } else {
-                    available_h.max(2).min(150)
+                    available_h.max(2).min(scaled(MAX_FIT_FONT_PX))
                 };
                 let mut high = max_possible;
                 let mut best_fit = low;
 
-                if high < low {
+                if let Some(size) = reusable_fit {
+                    best_fit = size;
+                } else if high < low {
                     best_fit = low;
                 } else {
                     while low <= high {
@@ -446,7 +554,7 @@ pub fn paint_window(hwnd: HWND) {
                         }
                     }
                 }
-                let font_size_val = best_fit;
+                let font_size_val = ((best_fit as f32 * font_scale).round() as i32).max(2);
 
                 let font_weight = if is_refining { FW_NORMAL } else { FW_MEDIUM };
                 let hfont = CreateFontW(
@@ -467,6 +575,10 @@ pub fn paint_window(hwnd: HWND) {
                 );
                 let old_font = SelectObject(cache_dc, hfont.into());
 
+                // Both paths paint with DrawTextW; plain results also keep a matching
+                // layout so mouse selection can map pixels back to characters
+                let mut text_layout = None;
+                let mut max_scroll = 0;
                 let mut measure_rect = RECT {
                     left: 0,
                     top: 0,
@@ -481,23 +593,67 @@ pub fn paint_window(hwnd: HWND) {
                 );
                 let text_h = measure_rect.bottom;
 
-                let offset_y = ((height - text_h) / 2).max(0);
-                let mut draw_rect = RECT {
-                    left: h_padding,
-                    top: offset_y,
-                    right: width - h_padding,
-                    bottom: height,
-                };
-
-                let draw_flags = if is_refining {
-                    DT_CENTER | DT_WORDBREAK | DT_EDITCONTROL
+                if is_refining {
+                    let offset_y = ((height - text_h) / 2).max(0);
+                    let mut draw_rect = RECT {
+                        left: h_padding,
+                        top: offset_y,
+                        right: width - h_padding,
+                        bottom: height,
+                    };
+                    DrawTextW(
+                        cache_dc,
+                        &mut buf,
+                        &mut draw_rect as *mut _,
+                        DT_CENTER | DT_WORDBREAK | DT_EDITCONTROL,
+                    );
                 } else {
-                    DT_LEFT | DT_WORDBREAK | DT_EDITCONTROL
-                };
-                DrawTextW(cache_dc, &mut buf, &mut draw_rect as *mut _, draw_flags);
+                    // Text taller than the window (at the readable floor or zoomed in)
+                    // starts at the top and scrolls; anything shorter is centered
+                    max_scroll = (text_h - available_h).max(0);
+                    let offset_y = header_h
+                        + if max_scroll > 0 {
+                            let scroll = WINDOW_STATES
+                                .lock()
+                                .unwrap()
+                                .get(&(hwnd.0 as isize))
+                                .map_or(0, |s| s.scroll_offset);
+                            -scroll.clamp(0, max_scroll)
+                        } else {
+                            ((available_h - text_h) / 2).max(0)
+                        };
+                    let mut draw_rect = RECT {
+                        left: h_padding,
+                        top: offset_y,
+                        right: width - h_padding,
+                        bottom: offset_y + text_h,
+                    };
+                    DrawTextW(
+                        cache_dc,
+                        &mut buf,
+                        &mut draw_rect as *mut _,
+                        DT_LEFT | DT_WORDBREAK | DT_EDITCONTROL,
+                    );
+
+                    let mut layout = TextLayout::build(cache_dc, buf, available_w);
+                    layout.offset(h_padding, offset_y);
+                    text_layout = Some(layout);
+                }
 
                 SelectObject(cache_dc, old_font);
                 let _ = DeleteObject(hfont.into());
+
+                if let Some(label) = &language_label {
+                    let band = RECT {
+                        left: 0,
+                        top: 0,
+                        right: width,
+                        bottom: header_h,
+                    };
+                    let bg = COLORREF(bg_color_u32);
+                    draw_language_label(cache_dc, label, scaled(LANGUAGE_LABEL_PX), band, bg);
+                }
+
                 SelectObject(cache_dc, old_cache_bm);
                 let _ = DeleteDC(cache_dc);
 
@@ -506,6 +662,22 @@ pub fn paint_window(hwnd: HWND) {
                     state.content_bitmap = cached_text_bm;
                     state.cached_font_size = font_size_val;
                     state.font_cache_dirty = false;
+                    state.max_scroll = max_scroll;
+                    state.scroll_offset = state.scroll_offset.clamp(0, max_scroll);
+                    if !is_refining && reusable_fit.is_none() {
+                        state.fitted_font_size = best_fit;
+                        state.fitted_text_len = text_layout.as_ref().map_or(0, |l| l.text.len());
+                        state.fitted_dims = (width, height);
+                    }
+                    // A selection only survives re-layout if the text itself is unchanged
+                    let same_text = match (&state.text_layout, &text_layout) {
+                        (Some(old), Some(new)) => old.text == new.text,
+                        _ => false,
+                    };
+                    if !same_text {
+                        state.text_selection = None;
+                    }
+                    state.text_layout = text_layout;
                 }
             }
 
@@ -518,11 +690,77 @@ pub fn paint_window(hwnd: HWND) {
             }
         }
 
+        let accent_color = WINDOW_STATES
+            .lock()
+            .unwrap()
+            .get(&(hwnd.0 as isize))
+            .and_then(|s| s.accent_color);
+
+        let selection_rects = if is_markdown_mode {
+            Vec::new()
+        } else {
+            let states = WINDOW_STATES.lock().unwrap();
+            states
+                .get(&(hwnd.0 as isize))
+                .and_then(|s| {
+                    let (anchor, caret) = s.text_selection?;
+                    Some(s.text_layout.as_ref()?.selection_rects(anchor, caret))
+                })
+                .unwrap_or_default()
+        };
+
         // --- PHASE 4: PIXEL MANIPULATION ---
         if !scratch_bits.is_null() {
             let raw_pixels =
                 std::slice::from_raw_parts_mut(scratch_bits as *mut u32, (width * height) as usize);
 
+            // 4.0a Text selection highlight (blend towards accent blue)
+            for sel in &selection_rects {
+                for y in sel.top.max(0)..sel.bottom.min(height) {
+                    for x in sel.left.max(0)..sel.right.min(width) {
+                        let idx = (y * width + x) as usize;
+                        let px = raw_pixels[idx];
+                        let r = (((px >> 16) & 0xFF) + 0x33) / 2;
+                        let g = (((px >> 8) & 0xFF) + 0x99) / 2;
+                        let b = ((px & 0xFF) + 0xFF) / 2;
+                        raw_pixels[idx] = (255 << 24) | (r << 16) | (g << 8) | b;
+                    }
+                }
+            }
+
+            // 4.0b Preset accent border (only the edge bands are visited)
+            if let Some(accent) = accent_color {
+                let a_r = ((accent >> 16) & 0xFF) as f32;
+                let a_g = ((accent >> 8) & 0xFF) as f32;
+                let a_b = (accent & 0xFF) as f32;
+                let band = 10;
+                let bx = width as f32 / 2.0;
+                let by = height as f32 / 2.0;
+                for y in 0..height {
+                    let full_row = y < band || y >= height - band;
+                    let mut x = 0;
+                    while x < width {
+                        if !full_row && x == band && width - band > band {
+                            x = width - band;
+                        }
+                        let (px, py) = (x as f32 - bx + 0.5, y as f32 - by + 0.5);
+                        let d = sd_rounded_box(px, py, bx, by, 8.0);
+                        // 2px stroke just inside the edge, anti-aliased on both sides
+                        let alpha = ((d + 2.5).clamp(0.0, 1.0) * (0.5 - d).clamp(0.0, 1.0)) * 0.9;
+                        if alpha > 0.0 {
+                            let idx = (y * width + x) as usize;
+                            let bg = raw_pixels[idx];
+                            let inv = 1.0 - alpha;
+                            let r = (a_r * alpha + ((bg >> 16) & 0xFF) as f32 * inv) as u32;
+                            let g = (a_g * alpha + ((bg >> 8) & 0xFF) as f32 * inv) as u32;
+                            let b = (a_b * alpha + (bg & 0xFF) as f32 * inv) as u32;
+                            raw_pixels[idx] = (255 << 24) | (r << 16) | (g << 8) | b;
+                        }
+                        x += 1;
+                    }
+                }
+            }
+
             // 4.0 REFINEMENT GLOW
             if is_refining {
                 let is_minimal = graphics_mode == "minimal";
@@ -6,6 +6,8 @@ use std::sync::{
 use windows::Win32::Foundation::*;
 use windows::Win32::Graphics::Gdi::HBITMAP;
 
+use super::text_layout::TextLayout;
+
 // --- DYNAMIC PARTICLES ---
 pub struct DustParticle {
     pub x: f32,
@@ -42,6 +44,7 @@ pub enum InteractionMode {
     DraggingGroup(Vec<(HWND, RECT)>),
     Resizing(ResizeEdge),
     ResizingGroup(Vec<(HWND, RECT)>, ResizeEdge),
+    SelectingText,
 }
 
 pub struct CursorPhysics {
@@ -92,6 +95,20 @@ pub enum RefineContext {
     Audio(Vec<u8>), // WAV Bytes
 }
 
+/// Inputs of the API call that produced a result window, kept so a failed
+/// call can be retried in place without capturing again
+#[derive(Clone)]
+pub struct RetryRequest {
+    pub prompt: String,
+    pub input_text: String,
+    pub image: Option<Vec<u8>>, // PNG bytes for image blocks
+    pub model_full_name: String,
+    pub provider: String,
+    pub streaming_enabled: bool,
+    pub use_json: bool,
+    pub search_label: Option<String>,
+}
+
 pub struct WindowState {
     pub is_hovered: bool,
     pub on_copy_btn: bool,
@@ -129,6 +146,8 @@ pub struct WindowState {
     pub input_text: String,
 
     pub bg_color: u32,
+    /// Preset accent color (0x00RRGGBB) drawn as a thin border, if the preset sets one
+    pub accent_color: Option<u32>,
     pub linked_window: Option<HWND>,
     pub physics: CursorPhysics,
 
@@ -139,9 +158,22 @@ pub struct WindowState {
     pub drag_start_window_rect: RECT,
     pub has_moved_significantly: bool, // To distinguish click vs drag
 
+    // --- TEXT SELECTION ---
+    pub text_layout: Option<TextLayout>, // Layout of the last painted plain text (client coords)
+    pub text_selection: Option<(usize, usize)>, // (anchor, caret) UTF-16 indices into the layout
+
     // --- CACHING & THROTTLING ---
     pub font_cache_dirty: bool,
     pub cached_font_size: i32,
+    // Last binary-search fit (unscaled size, text length and window size it was made for).
+    // While streaming, small text deltas reuse it instead of re-measuring every redraw.
+    pub fitted_font_size: i32,
+    pub fitted_text_len: usize,
+    pub fitted_dims: (i32, i32),
+    pub font_scale: f32, // User zoom (Ctrl+scroll) applied on top of the fitted size
+    pub scroll_offset: i32, // Pixels scrolled down through text taller than the window
+    pub max_scroll: i32, // How far the last layout can scroll (0 = it fits)
+    pub preset_id: String, // Preset that produced this window (font_scale is persisted per preset)
     pub content_bitmap: HBITMAP,
     pub last_w: i32,
     pub last_h: i32,
@@ -149,6 +181,15 @@ pub struct WindowState {
     // Handle pending updates to avoid flooding Paint
     pub pending_text: Option<String>,
 
+    /// The window shows an API error instead of a result
+    pub is_error: bool,
+    /// What to re-run when the user retries a failed result
+    pub retry_request: Option<RetryRequest>,
+    /// "JA → VI" shown above translations: detected source, requested target
+    pub language_label: Option<String>,
+    /// Text the block was given (None for image blocks), for "copy with source"
+    pub source_text: Option<String>,
+
     // Timestamp for throttling text updates (in milliseconds)
     pub last_text_update_time: u32,
 
@@ -191,6 +232,21 @@ pub struct WindowState {
     pub tts_request_id: u64,  // Active TTS request ID (0 = not speaking)
     pub tts_loading: bool,    // True when TTS is loading/connecting (shows spinner)
     pub opacity_percent: u8,  // Transparency level (0-100)
+
+    // Auto-close: seconds after streaming ends (0 = off, also cleared once hovered)
+    pub auto_close_secs: u32,
+    pub auto_close_due: bool, // Set when the countdown fires; the physics tick closes the window
+}
+
+impl WindowState {
+    /// Currently selected text, if the selection is non-empty
+    pub fn selected_text(&self) -> Option<String> {
+        let (anchor, caret) = self.text_selection?;
+        if anchor == caret {
+            return None;
+        }
+        self.text_layout.as_ref().map(|l| l.slice(anchor, caret))
+    }
 }
 
 // SAFETY: Raw pointers are not Send/Sync, but we only use them within the main thread
@@ -198,6 +254,9 @@ pub struct WindowState {
 unsafe impl Send for WindowState {}
 unsafe impl Sync for WindowState {}
 
+/// Timer armed when streaming completes in a window with `auto_close_secs` set
+pub const AUTO_CLOSE_TIMER_ID: usize = 4;
+
 lazy_static::lazy_static! {
     pub static ref WINDOW_STATES: Mutex<HashMap<isize, WindowState>> = Mutex::new(HashMap::new());
 }
@@ -0,0 +1,187 @@
+//! Line layout mirroring `DrawTextW(DT_WORDBREAK | DT_EDITCONTROL)` for the GDI
+//! result window. Painting stays with DrawTextW; this only keeps per-character
+//! x offsets so mouse positions map back to text indices for selection, and
+//! selections map to highlight rects.
+
+use windows::Win32::Foundation::{RECT, SIZE};
+use windows::Win32::Graphics::Gdi::{GetTextExtentExPointW, GetTextMetricsW, HDC, TEXTMETRICW};
+
+const NEWLINE: u16 = b'\n' as u16;
+const CARRIAGE_RETURN: u16 = b'\r' as u16;
+const SPACE: u16 = b' ' as u16;
+
+pub struct LayoutLine {
+    /// UTF-16 index range `[start, end)` into `TextLayout::text`
+    pub start: usize,
+    pub end: usize,
+    pub left: i32,
+    pub top: i32,
+    /// Cumulative x offset before each char (len = end - start + 1)
+    pub x_offsets: Vec<i32>,
+}
+
+pub struct TextLayout {
+    pub text: Vec<u16>,
+    pub lines: Vec<LayoutLine>,
+    pub line_height: i32,
+}
+
+impl TextLayout {
+    /// Wrap `text` to `max_width` using the font currently selected into `hdc`.
+    /// Breaks at spaces where possible, otherwise mid-word (like DT_EDITCONTROL).
+    /// Offsets come from GetTextExtentExPointW over each whole line, so they
+    /// include the same kerning DrawTextW applies when drawing that line.
+    pub unsafe fn build(hdc: HDC, text: Vec<u16>, max_width: i32) -> Self {
+        let mut tm = TEXTMETRICW::default();
+        let _ = GetTextMetricsW(hdc, &mut tm);
+        let line_height = tm.tmHeight.max(1);
+
+        let mut lines = Vec::new();
+        let mut para_start = 0;
+
+        while para_start <= text.len() {
+            let para_end = text[para_start..]
+                .iter()
+                .position(|&c| c == NEWLINE)
+                .map(|p| para_start + p)
+                .unwrap_or(text.len());
+            // Don't count the \r of \r\n line endings
+            let visible_end = if para_end > para_start && text[para_end - 1] == CARRIAGE_RETURN {
+                para_end - 1
+            } else {
+                para_end
+            };
+
+            let mut pos = para_start;
+            loop {
+                let rest = &text[pos..visible_end];
+                let (fit, extents) = fit_extents(hdc, rest, max_width);
+
+                let (line_end, next) = if fit >= rest.len() {
+                    (visible_end, visible_end)
+                } else if let Some(space) = rest[..=fit]
+                    .iter()
+                    .rposition(|&c| c == SPACE)
+                    .filter(|&s| s > 0)
+                {
+                    // A space may hang past the edge, as with DrawTextW
+                    (pos + space, pos + space + 1)
+                } else {
+                    // At least one char per line, and never split a surrogate pair
+                    let mut split = fit.max(1);
+                    if split < rest.len() && is_low_surrogate(rest[split]) && split > 1 {
+                        split -= 1;
+                    }
+                    (pos + split, pos + split)
+                };
+
+                let mut x_offsets = Vec::with_capacity(line_end - pos + 1);
+                x_offsets.push(0);
+                x_offsets.extend_from_slice(&extents[..line_end - pos]);
+                lines.push(LayoutLine {
+                    start: pos,
+                    end: line_end,
+                    left: 0,
+                    top: lines.len() as i32 * line_height,
+                    x_offsets,
+                });
+                pos = next;
+                if pos >= visible_end {
+                    break;
+                }
+            }
+
+            para_start = para_end + 1;
+        }
+
+        Self {
+            text,
+            lines,
+            line_height,
+        }
+    }
+
+    pub fn height(&self) -> i32 {
+        self.lines.len() as i32 * self.line_height
+    }
+
+    pub fn offset(&mut self, dx: i32, dy: i32) {
+        for line in &mut self.lines {
+            line.left += dx;
+            line.top += dy;
+        }
+    }
+
+    /// Text index closest to client point (x, y)
+    pub fn hit_test(&self, x: i32, y: i32) -> usize {
+        let Some(first) = self.lines.first() else {
+            return 0;
+        };
+        let row = ((y - first.top).max(0) / self.line_height) as usize;
+        let line = &self.lines[row.min(self.lines.len() - 1)];
+
+        let rel_x = x - line.left;
+        let mut idx = line.start;
+        for (i, pair) in line.x_offsets.windows(2).enumerate() {
+            let mid = (pair[0] + pair[1]) / 2;
+            if rel_x < mid {
+                break;
+            }
+            idx = line.start + i + 1;
+        }
+        idx
+    }
+
+    /// Highlight rects covering the text range between two indices (any order)
+    pub fn selection_rects(&self, a: usize, b: usize) -> Vec<RECT> {
+        let (start, end) = (a.min(b), a.max(b));
+        if start == end {
+            return Vec::new();
+        }
+        self.lines
+            .iter()
+            .filter(|line| line.start < end && start < line.end.max(line.start + 1))
+            .map(|line| {
+                let from = start.clamp(line.start, line.end) - line.start;
+                let to = end.clamp(line.start, line.end) - line.start;
+                // Empty / trailing-newline rows still get a small marker
+                let right = line.left + line.x_offsets[to].max(line.x_offsets[from] + 4);
+                RECT {
+                    left: line.left + line.x_offsets[from],
+                    top: line.top,
+                    right,
+                    bottom: line.top + self.line_height,
+                }
+            })
+            .collect()
+    }
+
+    pub fn slice(&self, a: usize, b: usize) -> String {
+        let (start, end) = (a.min(b), a.max(b).min(self.text.len()));
+        String::from_utf16_lossy(&self.text[start.min(end)..end])
+    }
+}
+
+fn is_low_surrogate(c: u16) -> bool {
+    (0xDC00..0xE000).contains(&c)
+}
+
+/// How many leading UTF-16 units of `text` fit in `max_width`, and the
+/// cumulative extent after each unit of the whole run
+unsafe fn fit_extents(hdc: HDC, text: &[u16], max_width: i32) -> (usize, Vec<i32>) {
+    if text.is_empty() {
+        return (0, Vec::new());
+    }
+    let mut fit = 0i32;
+    let mut extents = vec![0i32; text.len()];
+    let mut size = SIZE::default();
+    let _ = GetTextExtentExPointW(
+        hdc,
+        text,
+        max_width,
+        Some(&mut fit),
+        Some(extents.as_mut_ptr()),
+        &mut size,
+    );
+    (fit.max(0) as usize, extents)
+}
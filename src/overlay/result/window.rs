@@ -81,6 +81,20 @@ pub fn create_result_window(
         // WindowType logic essentially just sets color now, but we override it via custom_bg_color usually
         let (x, y) = (target_rect.left, target_rect.top);
 
+        // Never start fully invisible, whatever the config says
+        let alpha = crate::APP
+            .lock()
+            .map(|app| app.config.overlay_opacity)
+            .unwrap_or(217)
+            .max(51);
+
+        // Markdown needs a WebView; without the runtime fall back to GDI plain text
+        let render_mode = if crate::overlay::webview_runtime::is_available() {
+            render_mode
+        } else {
+            "plain"
+        };
+
         // WS_CLIPCHILDREN prevents parent from drawing over child (Fixes Blinking)
         // WS_EX_NOACTIVATE prevents stealing focus when window appears
         // NOTE: For markdown modes, we match text_input's working configuration exactly
@@ -120,7 +134,7 @@ pub fn create_result_window(
         if is_any_markdown_mode {
             let _ = SetLayeredWindowAttributes(hwnd, COLORREF(0), 0, LWA_ALPHA);
             let _ = super::markdown_view::create_markdown_webview(hwnd, &initial_text, false);
-            let _ = SetLayeredWindowAttributes(hwnd, COLORREF(0), 217, LWA_ALPHA);
+            let _ = SetLayeredWindowAttributes(hwnd, COLORREF(0), alpha, LWA_ALPHA);
         }
 
         let mut physics = CursorPhysics::default();
@@ -163,6 +177,7 @@ pub fn create_result_window(
                     provider,
                     streaming_enabled,
                     bg_color: custom_bg_color,
+                    accent_color: None,
                     linked_window: None,
                     physics,
                     interaction_mode: InteractionMode::None,
@@ -170,12 +185,25 @@ pub fn create_result_window(
                     drag_start_mouse: POINT { x: 0, y: 0 },
                     drag_start_window_rect: RECT::default(),
                     has_moved_significantly: false,
+                    text_layout: None,
+                    text_selection: None,
                     font_cache_dirty: true,
                     cached_font_size: 72,
+                    fitted_font_size: 0,
+                    fitted_text_len: 0,
+                    fitted_dims: (0, 0),
+                    font_scale: 1.0,
+                    scroll_offset: 0,
+                    max_scroll: 0,
+                    preset_id: String::new(),
                     content_bitmap: HBITMAP::default(),
                     last_w: 0,
                     last_h: 0,
                     pending_text: Some(initial_text),
+                    is_error: false,
+                    retry_request: None,
+                    language_label: None,
+                    source_text: None,
                     last_text_update_time: 0,
                     last_resize_time: 0,
                     last_font_calc_time: 0,
@@ -200,12 +228,14 @@ pub fn create_result_window(
                     on_speaker_btn: false,
                     tts_request_id: 0,
                     tts_loading: false,
-                    opacity_percent: 85,
+                    opacity_percent: (alpha as u32 * 100 / 255) as u8,
+                    auto_close_secs: 0,
+                    auto_close_due: false,
                 },
             );
         }
 
-        let _ = SetLayeredWindowAttributes(hwnd, COLORREF(0), 217, LWA_ALPHA);
+        let _ = SetLayeredWindowAttributes(hwnd, COLORREF(0), alpha, LWA_ALPHA);
 
         let corner_preference = 2u32;
         let _ = DwmSetWindowAttribute(
@@ -236,11 +266,18 @@ pub fn create_result_window(
     }
 }
 
+/// Text containing `WIPE_SIGNAL` (an accumulator that appended a restarted
+/// stream as-is) shows only what follows the last signal
 pub fn update_window_text(hwnd: HWND, text: &str) {
     if !unsafe { IsWindow(Some(hwnd)).as_bool() } {
         return;
     }
 
+    let text = match text.rfind(crate::api::WIPE_SIGNAL) {
+        Some(i) => &text[i + crate::api::WIPE_SIGNAL.len()..],
+        None => text,
+    };
+
     let mut states = WINDOW_STATES.lock().unwrap();
     if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
         state.pending_text = Some(text.to_string());
@@ -3,16 +3,25 @@ use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::mem::zeroed;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use windows::core::BOOL;
-use windows::Win32::Foundation::{LPARAM, POINT, RECT};
+use windows::Win32::Foundation::{HWND, LPARAM, POINT, RECT};
+use windows::Win32::Graphics::Dwm::{
+    DwmGetWindowAttribute, DWMWA_CLOAKED, DWMWA_EXTENDED_FRAME_BOUNDS,
+};
 use windows::Win32::Graphics::Gdi::{
-    EnumDisplayMonitors, GetMonitorInfoW, HDC, HMONITOR, MONITORINFOEXW,
+    BitBlt, CreateCompatibleDC, CreateDIBSection, DeleteDC, DeleteObject, EnumDisplayMonitors,
+    GdiFlush, GetDC, GetMonitorInfoW, ReleaseDC, SelectObject, BITMAPINFO, BITMAPINFOHEADER,
+    BI_RGB, CAPTUREBLT, DIB_RGB_COLORS, HDC, HMONITOR, MONITORINFOEXW, SRCCOPY,
 };
+use windows::Win32::UI::HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI};
 use windows::Win32::UI::WindowsAndMessaging::{
-    GetCursorInfo, GetCursorPos, LoadCursorW, CURSORINFO, IDC_ARROW, IDC_HAND, IDC_IBEAM,
+    DrawIconEx, EnumWindows, GetCursorInfo, GetCursorPos, GetIconInfo, GetSystemMetrics,
+    GetWindowLongW, GetWindowTextW, IsIconic, IsWindow, IsWindowVisible, LoadCursorW, CURSORINFO,
+    CURSOR_SHOWING, DI_NORMAL, GWL_EXSTYLE, ICONINFO, IDC_ARROW, IDC_HAND, IDC_IBEAM,
+    SM_CXVIRTUALSCREEN, SM_CYVIRTUALSCREEN, SM_XVIRTUALSCREEN, SM_YVIRTUALSCREEN, WS_EX_TOOLWINDOW,
 };
 use windows_capture::{
     capture::{Context, GraphicsCaptureApiHandler},
@@ -20,6 +29,7 @@ use windows_capture::{
     frame::Frame,
     graphics_capture_api::InternalCaptureControl,
     monitor::Monitor,
+    settings::DrawBorderSettings,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,17 +39,44 @@ pub struct MousePosition {
     pub timestamp: f64,
     pub is_clicked: bool,
     pub cursor_type: String,
+    /// Set on the sample recorded at a button press ("left_down" / "right_down").
+    /// Plain position samples omit it, so older consumers see the same shape.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonitorInfo {
+    /// Position in `EnumDisplayMonitors` order, i.e. `Monitor::from_index(id + 1)`
     pub id: String,
+    /// Friendly name reported by the display ("DELL U2720Q"), "Display N" if unknown
     pub name: String,
     pub x: i32,
     pub y: i32,
     pub width: u32,
     pub height: u32,
     pub is_primary: bool,
+    /// DPI scale factor (1.0 = 100%)
+    pub scale: f32,
+    /// The monitor to pre-select: the one last recorded from, or the primary
+    /// one if that is disconnected
+    pub is_default: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowInfo {
+    pub id: String,
+    pub title: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// What a `CaptureHandler` records
+#[derive(Debug, Clone, Copy)]
+pub enum CaptureTarget {
+    Monitor(usize),
+    /// Raw HWND of a top-level window; followed wherever it moves
+    Window(isize),
 }
 
 lazy_static::lazy_static! {
@@ -53,20 +90,46 @@ lazy_static::lazy_static! {
     pub static ref IS_MOUSE_CLICKED: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
     // Track if we already captured the click event (to only record one frame as clicked)
     pub static ref CLICK_CAPTURED: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+    // When the current recording's encoder started; mouse timestamps are relative to it
+    pub static ref RECORDING_START: Mutex<Option<Instant>> = Mutex::new(None);
+    // First frame of the current recording (width, height, BGRA), saved as the poster still
+    pub static ref POSTER_FRAME: Mutex<Option<(u32, u32, Vec<u8>)>> = Mutex::new(None);
 }
 
+type CaptureError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Monitor id the editor sends to record the whole virtual desktop at once
+pub const ALL_MONITORS_ID: &str = "all";
+
 pub static mut VIDEO_PATH: Option<String> = None;
 pub static mut AUDIO_PATH: Option<String> = None;
+pub static mut POSTER_PATH: Option<String> = None;
 pub static mut MONITOR_X: i32 = 0;
 pub static mut MONITOR_Y: i32 = 0;
+/// HWND of the window being recorded (0 = monitor / virtual screen recording).
+/// Mouse positions are made relative to its current bounds on every sample.
+pub static TARGET_WINDOW: AtomicIsize = AtomicIsize::new(0);
+/// Stop automatically after this many seconds (0 = unlimited)
+pub static MAX_DURATION_SECS: AtomicU64 = AtomicU64::new(0);
+/// Live progress of the current recording, reported to the recorder UI
+pub static ELAPSED_MS: AtomicU64 = AtomicU64::new(0);
+pub static FRAMES_CAPTURED: AtomicU64 = AtomicU64::new(0);
+/// Frames the encoder rejected or that were missed because capture fell behind
+pub static DROPPED_FRAMES: AtomicU64 = AtomicU64::new(0);
+/// The OS cursor is drawn into the video itself, so playback must not overlay another one
+pub static CURSOR_BAKED: AtomicBool = AtomicBool::new(false);
+/// Mouse samples per second (0 = one per captured frame)
+pub static MOUSE_SAMPLE_HZ: AtomicU32 = AtomicU32::new(30);
 
 pub struct CaptureHandler {
     encoder: Option<VideoEncoder>,
+    /// Encoder frame size; window frames that no longer match are cropped/padded to it
+    width: u32,
+    height: u32,
+    resize_buffer: Vec<u8>,
     start: Instant,
     last_mouse_capture: Instant,
-    frame_count: u32,
-    last_frame_time: Instant,
-    dropped_frames: u32,
+    frame_interval: Duration,
 }
 
 fn get_cursor_type() -> String {
@@ -97,73 +160,36 @@ fn get_cursor_type() -> String {
 }
 
 impl GraphicsCaptureApiHandler for CaptureHandler {
-    type Flags = String;
-    type Error = Box<dyn std::error::Error + Send + Sync>;
+    type Flags = CaptureTarget;
+    type Error = CaptureError;
 
     fn new(ctx: Context<Self::Flags>) -> Result<Self, Self::Error> {
-        let monitor_index = ctx.flags.parse::<usize>().unwrap_or(0);
-
-        let monitor = Monitor::from_index(monitor_index + 1)?;
-        let width = monitor.width()?;
-        let height = monitor.height()?;
-
-        let app_data_dir = dirs::data_local_dir()
-            .unwrap_or_else(|| std::env::temp_dir())
-            .join("screen-goated-toolbox")
-            .join("recordings");
-
-        std::fs::create_dir_all(&app_data_dir)?;
-
-        let video_path = app_data_dir.join(format!(
-            "recording_{}.mp4",
-            std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_millis()
-        ));
-
-        let audio_path = app_data_dir.join(format!(
-            "recording_{}.wav",
-            std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_millis()
-        ));
-
-        unsafe {
-            VIDEO_PATH = Some(video_path.to_string_lossy().to_string());
-            AUDIO_PATH = Some(audio_path.to_string_lossy().to_string());
-        }
-
-        let video_settings = VideoSettingsBuilder::new(width, height)
-            .frame_rate(60)
-            .bitrate(15_000_000);
-
-        let encoder = VideoEncoder::new(
-            video_settings,
-            AudioSettingsBuilder::default().disabled(true),
-            ContainerSettingsBuilder::default(),
-            &video_path,
-        )?;
-
-        SHOULD_STOP_AUDIO.store(false, Ordering::SeqCst);
-        AUDIO_ENCODING_FINISHED.store(false, Ordering::SeqCst);
-        audio_engine::record_audio(
-            audio_path.to_string_lossy().to_string(),
-            SHOULD_STOP_AUDIO.clone(),
-            AUDIO_ENCODING_FINISHED.clone(),
-        );
+        let (width, height) = match ctx.flags {
+            CaptureTarget::Monitor(monitor_index) => {
+                let monitor = Monitor::from_index(monitor_index + 1)?;
+                (monitor.width()?, monitor.height()?)
+            }
+            CaptureTarget::Window(hwnd) => {
+                let rect = window_bounds(HWND(hwnd as *mut _)).ok_or("Window is gone")?;
+                // H.264 needs even dimensions
+                (
+                    ((rect.right - rect.left) & !1).max(2) as u32,
+                    ((rect.bottom - rect.top) & !1).max(2) as u32,
+                )
+            }
+        };
 
-        ENCODER_ACTIVE.store(true, Ordering::SeqCst);
-        ENCODING_FINISHED.store(false, Ordering::SeqCst);
+        const FRAME_RATE: u32 = 60;
+        let encoder = start_encoder(width, height, FRAME_RATE)?;
 
         Ok(Self {
             encoder: Some(encoder),
-            start: Instant::now(),
+            width,
+            height,
+            resize_buffer: Vec::new(),
+            start: RECORDING_START.lock().unwrap_or_else(Instant::now),
             last_mouse_capture: Instant::now(),
-            frame_count: 0,
-            last_frame_time: Instant::now(),
-            dropped_frames: 0,
+            frame_interval: Duration::from_secs_f64(1.0 / FRAME_RATE as f64),
         })
     }
 
@@ -175,41 +201,49 @@ impl GraphicsCaptureApiHandler for CaptureHandler {
         if !ENCODER_ACTIVE.load(Ordering::SeqCst) {
             return Ok(());
         }
+        let frame_start = Instant::now();
 
-        if let Err(e) = self.encoder.as_mut().unwrap().send_frame(frame) {
+        if POSTER_FRAME.lock().is_none() {
+            let (width, height) = (frame.width(), frame.height());
+            let mut poster = Vec::new();
+            if fit_frame(frame, width, height, &mut poster).is_ok() {
+                *POSTER_FRAME.lock() = Some((width, height, poster));
+            }
+        }
+
+        let result = if frame.width() == self.width && frame.height() == self.height {
+            self.encoder.as_mut().unwrap().send_frame(frame)
+        } else {
+            // Recorded window was resized
+            fit_frame(frame, self.width, self.height, &mut self.resize_buffer)?;
+            let timestamp = (self.start.elapsed().as_nanos() / 100) as i64;
+            self.encoder
+                .as_mut()
+                .unwrap()
+                .send_frame_buffer(&self.resize_buffer, timestamp)
+        };
+        if let Err(e) = &result {
             eprintln!("Encoder error: {}", e);
         }
+        record_frame_stats(
+            self.start,
+            result.is_ok(),
+            frame_start.elapsed(),
+            self.frame_interval,
+        );
 
         if self.last_mouse_capture.elapsed().as_millis() >= 16 {
-            unsafe {
-                let mut point = POINT::default();
-                if GetCursorPos(&mut point).is_ok() {
-                    // Record actual held state - cursor should stay squished while held
-                    let is_clicked = IS_MOUSE_CLICKED.load(Ordering::SeqCst);
-                    let cursor_type = get_cursor_type();
-
-                    let mouse_pos = MousePosition {
-                        x: point.x - MONITOR_X,
-                        y: point.y - MONITOR_Y,
-                        timestamp: self.start.elapsed().as_secs_f64(),
-                        is_clicked,
-                        cursor_type,
-                    };
-
-                    MOUSE_POSITIONS.lock().push_back(mouse_pos);
-                }
-            }
+            record_mouse_position(self.start);
             self.last_mouse_capture = Instant::now();
         }
 
+        if reached_max_duration(self.start) {
+            SHOULD_STOP.store(true, Ordering::SeqCst);
+        }
+
         if SHOULD_STOP.load(Ordering::SeqCst) {
-            ENCODER_ACTIVE.store(false, Ordering::SeqCst);
-            SHOULD_STOP_AUDIO.store(true, Ordering::SeqCst);
             if let Some(encoder) = self.encoder.take() {
-                std::thread::spawn(move || {
-                    let _ = encoder.finish();
-                    ENCODING_FINISHED.store(true, Ordering::SeqCst);
-                });
+                finish_encoder(encoder);
             }
             capture_control.stop();
         }
@@ -218,10 +252,380 @@ impl GraphicsCaptureApiHandler for CaptureHandler {
     }
 
     fn on_closed(&mut self) -> Result<(), Self::Error> {
+        // Recorded window was closed: finalize what we have so stop_recording
+        // doesn't wait on a capture that will never deliver another frame
+        if let Some(encoder) = self.encoder.take() {
+            finish_encoder(encoder);
+        }
         Ok(())
     }
 }
 
+/// Copy `frame` into a `width`x`height` BGRA buffer, cropping or padding with black
+fn fit_frame(
+    frame: &mut Frame,
+    width: u32,
+    height: u32,
+    out: &mut Vec<u8>,
+) -> Result<(), CaptureError> {
+    let mut buffer = frame.buffer()?;
+    let row_pitch = buffer.row_pitch() as usize;
+    let copy_width = buffer.width().min(width) as usize * 4;
+    let copy_height = buffer.height().min(height) as usize;
+    let raw = buffer.as_raw_buffer();
+
+    let out_pitch = width as usize * 4;
+    out.clear();
+    out.resize(out_pitch * height as usize, 0);
+    for row in 0..copy_height {
+        let src = &raw[row * row_pitch..row * row_pitch + copy_width];
+        out[row * out_pitch..row * out_pitch + copy_width].copy_from_slice(src);
+    }
+    Ok(())
+}
+
+/// Update the live progress counters after handling one frame. Time spent
+/// past `frame_interval` means the frames due in the meantime were missed.
+fn record_frame_stats(start: Instant, encoded: bool, busy: Duration, frame_interval: Duration) {
+    ELAPSED_MS.store(start.elapsed().as_millis() as u64, Ordering::SeqCst);
+    if encoded {
+        FRAMES_CAPTURED.fetch_add(1, Ordering::SeqCst);
+    } else {
+        DROPPED_FRAMES.fetch_add(1, Ordering::SeqCst);
+    }
+    let missed = (busy.as_nanos() / frame_interval.as_nanos().max(1)) as u64;
+    if missed > 0 {
+        DROPPED_FRAMES.fetch_add(missed, Ordering::SeqCst);
+    }
+}
+
+/// Create the output files and video encoder, and start the audio recorder alongside it
+fn start_encoder(width: u32, height: u32, frame_rate: u32) -> Result<VideoEncoder, CaptureError> {
+    let app_data_dir = dirs::data_local_dir()
+        .unwrap_or_else(|| std::env::temp_dir())
+        .join("screen-goated-toolbox")
+        .join("recordings");
+
+    std::fs::create_dir_all(&app_data_dir)?;
+
+    let video_path = app_data_dir.join(format!(
+        "recording_{}.mp4",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+    ));
+
+    let audio_path = app_data_dir.join(format!(
+        "recording_{}.wav",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+    ));
+
+    unsafe {
+        VIDEO_PATH = Some(video_path.to_string_lossy().to_string());
+        AUDIO_PATH = Some(audio_path.to_string_lossy().to_string());
+    }
+
+    let video_settings = VideoSettingsBuilder::new(width, height)
+        .frame_rate(frame_rate)
+        .bitrate(15_000_000);
+
+    let encoder = VideoEncoder::new(
+        video_settings,
+        AudioSettingsBuilder::default().disabled(true),
+        ContainerSettingsBuilder::default(),
+        &video_path,
+    )?;
+
+    SHOULD_STOP_AUDIO.store(false, Ordering::SeqCst);
+    AUDIO_ENCODING_FINISHED.store(false, Ordering::SeqCst);
+    audio_engine::record_audio(
+        audio_path.to_string_lossy().to_string(),
+        SHOULD_STOP_AUDIO.clone(),
+        AUDIO_ENCODING_FINISHED.clone(),
+    );
+
+    *RECORDING_START.lock() = Some(Instant::now());
+    ELAPSED_MS.store(0, Ordering::SeqCst);
+    FRAMES_CAPTURED.store(0, Ordering::SeqCst);
+    DROPPED_FRAMES.store(0, Ordering::SeqCst);
+    ENCODER_ACTIVE.store(true, Ordering::SeqCst);
+    ENCODING_FINISHED.store(false, Ordering::SeqCst);
+
+    Ok(encoder)
+}
+/// Sample the cursor relative to the recorded area (MONITOR_X/Y is its top-left)
+fn record_mouse_position(start: Instant) {
+    unsafe {
+        let target = TARGET_WINDOW.load(Ordering::SeqCst);
+        if target != 0 {
+            if let Some(rect) = window_bounds(HWND(target as *mut _)) {
+                MONITOR_X = rect.left;
+                MONITOR_Y = rect.top;
+            }
+        }
+
+        let mut point = POINT::default();
+        if GetCursorPos(&mut point).is_ok() {
+            // Record actual held state - cursor should stay squished while held
+            let is_clicked = IS_MOUSE_CLICKED.load(Ordering::SeqCst);
+            let cursor_type = get_cursor_type();
+
+            let mouse_pos = MousePosition {
+                x: point.x - MONITOR_X,
+                y: point.y - MONITOR_Y,
+                timestamp: start.elapsed().as_secs_f64(),
+                is_clicked,
+                cursor_type,
+                event: None,
+            };
+
+            push_mouse_sample(&mut MOUSE_POSITIONS.lock(), mouse_pos);
+        }
+    }
+}
+
+/// Same place and look, so playback shows nothing new
+fn same_mouse_state(a: &MousePosition, b: &MousePosition) -> bool {
+    a.event.is_none()
+        && a.x == b.x
+        && a.y == b.y
+        && a.is_clicked == b.is_clicked
+        && a.cursor_type == b.cursor_type
+}
+
+/// Keep at most MOUSE_SAMPLE_HZ samples per second, except when the click
+/// state or cursor shape changes. A cursor resting in place keeps only the
+/// first and latest sample of the run, so playback does not drift across it.
+fn push_mouse_sample(positions: &mut VecDeque<MousePosition>, sample: MousePosition) {
+    let hz = MOUSE_SAMPLE_HZ.load(Ordering::SeqCst);
+    if let Some(last) = positions.back() {
+        let state_changed =
+            last.is_clicked != sample.is_clicked || last.cursor_type != sample.cursor_type;
+        if hz > 0 && !state_changed && sample.timestamp - last.timestamp < 1.0 / hz as f64 {
+            return;
+        }
+    }
+
+    let len = positions.len();
+    if len >= 2
+        && same_mouse_state(&positions[len - 1], &sample)
+        && same_mouse_state(&positions[len - 2], &sample)
+    {
+        positions[len - 1].timestamp = sample.timestamp;
+        return;
+    }
+    positions.push_back(sample);
+}
+
+/// True once a recording with a duration limit has run past it
+pub fn reached_max_duration(start: Instant) -> bool {
+    let max = MAX_DURATION_SECS.load(Ordering::SeqCst);
+    max > 0 && start.elapsed().as_secs() >= max
+}
+
+/// Record a mouse button press at screen point (x, y). Called from the global mouse hook.
+pub fn record_click_event(x: i32, y: i32, kind: &str) {
+    if !ENCODER_ACTIVE.load(Ordering::SeqCst) {
+        return;
+    }
+    let Some(start) = *RECORDING_START.lock() else {
+        return;
+    };
+    let (origin_x, origin_y) = unsafe { (MONITOR_X, MONITOR_Y) };
+    MOUSE_POSITIONS.lock().push_back(MousePosition {
+        x: x - origin_x,
+        y: y - origin_y,
+        timestamp: start.elapsed().as_secs_f64(),
+        is_clicked: true,
+        cursor_type: get_cursor_type(),
+        event: Some(kind.to_string()),
+    });
+}
+
+/// Stop audio and finalize the video file in the background
+fn finish_encoder(encoder: VideoEncoder) {
+    ENCODER_ACTIVE.store(false, Ordering::SeqCst);
+    SHOULD_STOP_AUDIO.store(true, Ordering::SeqCst);
+    std::thread::spawn(move || {
+        let _ = encoder.finish();
+        ENCODING_FINISHED.store(true, Ordering::SeqCst);
+    });
+}
+
+/// Write the stored poster frame as a PNG next to the video. Returns its path.
+pub fn save_poster_png(video_path: &str) -> Option<String> {
+    let (width, height, mut pixels) = POSTER_FRAME.lock().take()?;
+    // BGRA -> RGBA
+    for chunk in pixels.chunks_exact_mut(4) {
+        chunk.swap(0, 2);
+        chunk[3] = 255;
+    }
+    let img = image::RgbaImage::from_raw(width, height, pixels)?;
+    let path = std::path::Path::new(video_path).with_extension("png");
+    img.save_with_format(&path, image::ImageFormat::Png).ok()?;
+    let path = path.to_string_lossy().to_string();
+    unsafe {
+        POSTER_PATH = Some(path.clone());
+    }
+    Some(path)
+}
+
+/// Bounding rect of all displays, in virtual-screen coordinates
+pub fn virtual_screen_rect() -> RECT {
+    unsafe {
+        let left = GetSystemMetrics(SM_XVIRTUALSCREEN);
+        let top = GetSystemMetrics(SM_YVIRTUALSCREEN);
+        RECT {
+            left,
+            top,
+            right: left + GetSystemMetrics(SM_CXVIRTUALSCREEN),
+            bottom: top + GetSystemMetrics(SM_CYVIRTUALSCREEN),
+        }
+    }
+}
+
+/// "Record all" mode: graphics capture is per-monitor, so the whole virtual
+/// desktop is grabbed with GDI instead and fed to the encoder as raw BGRA frames.
+/// Blocks until SHOULD_STOP is set.
+pub fn record_virtual_screen() -> Result<(), CaptureError> {
+    const FRAME_RATE: u32 = 30;
+
+    let rect = virtual_screen_rect();
+    // H.264 needs even dimensions
+    let width = (rect.right - rect.left) & !1;
+    let height = (rect.bottom - rect.top) & !1;
+    if width <= 0 || height <= 0 {
+        return Err("Virtual screen has no area".into());
+    }
+
+    unsafe {
+        MONITOR_X = rect.left;
+        MONITOR_Y = rect.top;
+    }
+
+    let mut encoder = start_encoder(width as u32, height as u32, FRAME_RATE)?;
+    let frame_interval = Duration::from_secs_f64(1.0 / FRAME_RATE as f64);
+    let start = RECORDING_START.lock().unwrap_or_else(Instant::now);
+    let mut last_mouse_capture = Instant::now();
+    let mut buffer = vec![0u8; (width * height * 4) as usize];
+
+    unsafe {
+        let hdc_screen = GetDC(None);
+        let hdc_mem = CreateCompatibleDC(Some(hdc_screen));
+        let bmi = BITMAPINFO {
+            bmiHeader: BITMAPINFOHEADER {
+                biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+                biWidth: width,
+                biHeight: -height, // Top-down, like graphics capture frames
+                biPlanes: 1,
+                biBitCount: 32,
+                biCompression: BI_RGB.0 as u32,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut bits: *mut core::ffi::c_void = std::ptr::null_mut();
+        let hbitmap = CreateDIBSection(Some(hdc_mem), &bmi, DIB_RGB_COLORS, &mut bits, None, 0)?;
+        let old_obj = SelectObject(hdc_mem, hbitmap.into());
+
+        while !SHOULD_STOP.load(Ordering::SeqCst) {
+            let frame_start = Instant::now();
+            if reached_max_duration(start) {
+                SHOULD_STOP.store(true, Ordering::SeqCst);
+                break;
+            }
+
+            if BitBlt(
+                hdc_mem,
+                0,
+                0,
+                width,
+                height,
+                Some(hdc_screen),
+                rect.left,
+                rect.top,
+                SRCCOPY | CAPTUREBLT,
+            )
+            .is_ok()
+            {
+                if CURSOR_BAKED.load(Ordering::SeqCst) {
+                    draw_cursor(hdc_mem, rect.left, rect.top);
+                }
+                let _ = GdiFlush();
+                std::ptr::copy_nonoverlapping(bits as *const u8, buffer.as_mut_ptr(), buffer.len());
+                // Encoder timestamps are in 100ns units
+                let timestamp = (start.elapsed().as_nanos() / 100) as i64;
+                let result = encoder.send_frame_buffer(&buffer, timestamp);
+                if let Err(e) = &result {
+                    eprintln!("Encoder error: {}", e);
+                }
+                record_frame_stats(start, result.is_ok(), frame_start.elapsed(), frame_interval);
+                let mut poster = POSTER_FRAME.lock();
+                if poster.is_none() {
+                    *poster = Some((width as u32, height as u32, buffer.clone()));
+                }
+            }
+
+            if last_mouse_capture.elapsed().as_millis() >= 16 {
+                record_mouse_position(start);
+                last_mouse_capture = Instant::now();
+            }
+
+            if let Some(rest) = frame_interval.checked_sub(frame_start.elapsed()) {
+                std::thread::sleep(rest);
+            }
+        }
+
+        SelectObject(hdc_mem, old_obj);
+        let _ = DeleteObject(hbitmap.into());
+        let _ = DeleteDC(hdc_mem);
+        ReleaseDC(None, hdc_screen);
+    }
+
+    finish_encoder(encoder);
+    Ok(())
+}
+
+/// BitBlt leaves the cursor out: draw it at its screen position, relative to `origin`
+unsafe fn draw_cursor(hdc: HDC, origin_x: i32, origin_y: i32) {
+    let mut info = CURSORINFO {
+        cbSize: std::mem::size_of::<CURSORINFO>() as u32,
+        ..Default::default()
+    };
+    if GetCursorInfo(&mut info).is_err()
+        || info.flags != CURSOR_SHOWING
+        || info.hCursor.is_invalid()
+    {
+        return;
+    }
+    let mut icon = ICONINFO::default();
+    if GetIconInfo(info.hCursor.into(), &mut icon).is_err() {
+        return;
+    }
+    let _ = DrawIconEx(
+        hdc,
+        info.ptScreenPos.x - origin_x - icon.xHotspot as i32,
+        info.ptScreenPos.y - origin_y - icon.yHotspot as i32,
+        info.hCursor.into(),
+        0,
+        0,
+        0,
+        None,
+        DI_NORMAL,
+    );
+    // GetIconInfo hands us copies of the cursor bitmaps
+    if !icon.hbmMask.is_invalid() {
+        let _ = DeleteObject(icon.hbmMask.into());
+    }
+    if !icon.hbmColor.is_invalid() {
+        let _ = DeleteObject(icon.hbmColor.into());
+    }
+}
+
 pub fn get_monitors() -> Vec<MonitorInfo> {
     let mut monitors_vec: Vec<HMONITOR> = Vec::new();
     unsafe {
@@ -233,27 +637,176 @@ pub fn get_monitors() -> Vec<MonitorInfo> {
         );
 
         let mut monitor_infos = Vec::new();
+        let mut devices = Vec::new();
         for (index, &hmonitor) in monitors_vec.iter().enumerate() {
             let mut info: MONITORINFOEXW = zeroed();
             info.monitorInfo.cbSize = std::mem::size_of::<MONITORINFOEXW>() as u32;
 
             if GetMonitorInfoW(hmonitor, &mut info.monitorInfo as *mut _).as_bool() {
                 let rect = info.monitorInfo.rcMonitor;
+                let name = Monitor::from_raw_hmonitor(hmonitor.0)
+                    .name()
+                    .ok()
+                    .filter(|name| !name.trim().is_empty())
+                    .unwrap_or_else(|| format!("Display {}", index + 1));
+                let (mut dpi_x, mut dpi_y) = (96u32, 96u32);
+                let _ = GetDpiForMonitor(hmonitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y);
+                // Keep the enumeration index even if an earlier monitor was skipped,
+                // so the id still maps onto Monitor::from_index
                 monitor_infos.push(MonitorInfo {
                     id: index.to_string(),
-                    name: format!("Display {}", index + 1),
+                    name,
                     x: rect.left,
                     y: rect.top,
                     width: (rect.right - rect.left) as u32,
                     height: (rect.bottom - rect.top) as u32,
                     is_primary: info.monitorInfo.dwFlags & 1 == 1,
+                    scale: dpi_x as f32 / 96.0,
+                    is_default: false,
                 });
+                devices.push(device_name(&info));
             }
         }
+
+        let last_device = crate::APP
+            .lock()
+            .map(|app| app.config.screen_record_monitor.clone())
+            .unwrap_or_default();
+        let default_pos = devices
+            .iter()
+            .position(|d| !last_device.is_empty() && *d == last_device)
+            .or_else(|| monitor_infos.iter().position(|m| m.is_primary));
+        if let Some(monitor) = default_pos.and_then(|i| monitor_infos.get_mut(i)) {
+            monitor.is_default = true;
+        }
         monitor_infos
     }
 }
 
+fn device_name(info: &MONITORINFOEXW) -> String {
+    let len = info
+        .szDevice
+        .iter()
+        .position(|&c| c == 0)
+        .unwrap_or(info.szDevice.len());
+    String::from_utf16_lossy(&info.szDevice[..len])
+}
+
+/// `drawBorder` from the recorder UI: force the yellow capture border on or
+/// off, or leave it to the system when not given
+pub fn border_settings(draw_border: Option<bool>) -> DrawBorderSettings {
+    match draw_border {
+        Some(true) => DrawBorderSettings::WithBorder,
+        Some(false) => DrawBorderSettings::WithoutBorder,
+        None => DrawBorderSettings::Default,
+    }
+}
+
+/// Remember the monitor behind `monitor_index` as the recorder's default
+pub fn remember_monitor(monitor_index: usize) {
+    let mut monitors: Vec<HMONITOR> = Vec::new();
+    unsafe {
+        let _ = EnumDisplayMonitors(
+            None,
+            None,
+            Some(monitor_enum_proc),
+            LPARAM(&mut monitors as *mut _ as isize),
+        );
+        let Some(&hmonitor) = monitors.get(monitor_index) else {
+            return;
+        };
+        let mut info: MONITORINFOEXW = zeroed();
+        info.monitorInfo.cbSize = std::mem::size_of::<MONITORINFOEXW>() as u32;
+        if !GetMonitorInfoW(hmonitor, &mut info.monitorInfo as *mut _).as_bool() {
+            return;
+        }
+        let device = device_name(&info);
+        if let Ok(mut app) = crate::APP.lock() {
+            if app.config.screen_record_monitor != device {
+                app.config.screen_record_monitor = device;
+                crate::config::save_config(&app.config);
+            }
+        }
+    }
+}
+
+/// Visible bounds of a window (without the drop shadow), matching what window capture records
+pub fn window_bounds(hwnd: HWND) -> Option<RECT> {
+    unsafe {
+        if !IsWindow(Some(hwnd)).as_bool() {
+            return None;
+        }
+        let mut rect = RECT::default();
+        DwmGetWindowAttribute(
+            hwnd,
+            DWMWA_EXTENDED_FRAME_BOUNDS,
+            &mut rect as *mut _ as *mut _,
+            std::mem::size_of::<RECT>() as u32,
+        )
+        .ok()?;
+        Some(rect)
+    }
+}
+
+/// Top-level windows that can be picked as a recording target
+pub fn get_windows() -> Vec<WindowInfo> {
+    let mut hwnds: Vec<HWND> = Vec::new();
+    unsafe {
+        let _ = EnumWindows(
+            Some(window_enum_proc),
+            LPARAM(&mut hwnds as *mut _ as isize),
+        );
+
+        hwnds
+            .into_iter()
+            .filter_map(|hwnd| {
+                if !IsWindowVisible(hwnd).as_bool() || IsIconic(hwnd).as_bool() {
+                    return None;
+                }
+                let ex_style = GetWindowLongW(hwnd, GWL_EXSTYLE) as u32;
+                if ex_style & WS_EX_TOOLWINDOW.0 != 0 {
+                    return None;
+                }
+                // Skip windows hidden by DWM (suspended UWP apps, other virtual desktops)
+                let mut cloaked = 0u32;
+                let _ = DwmGetWindowAttribute(
+                    hwnd,
+                    DWMWA_CLOAKED,
+                    &mut cloaked as *mut _ as *mut _,
+                    std::mem::size_of::<u32>() as u32,
+                );
+                if cloaked != 0 {
+                    return None;
+                }
+
+                let mut title = [0u16; 256];
+                let len = GetWindowTextW(hwnd, &mut title);
+                if len == 0 {
+                    return None;
+                }
+                let rect = window_bounds(hwnd)?;
+                let (width, height) = (rect.right - rect.left, rect.bottom - rect.top);
+                if width < 2 || height < 2 {
+                    return None;
+                }
+
+                Some(WindowInfo {
+                    id: (hwnd.0 as isize).to_string(),
+                    title: String::from_utf16_lossy(&title[..len as usize]),
+                    width: width as u32,
+                    height: height as u32,
+                })
+            })
+            .collect()
+    }
+}
+
+unsafe extern "system" fn window_enum_proc(hwnd: HWND, lparam: LPARAM) -> BOOL {
+    let hwnds = &mut *(lparam.0 as *mut Vec<HWND>);
+    hwnds.push(hwnd);
+    true.into()
+}
+
 pub unsafe extern "system" fn monitor_enum_proc(
     hmonitor: HMONITOR,
     _: HDC,
@@ -3,7 +3,7 @@ use raw_window_handle::{
 };
 use std::borrow::Cow;
 use std::num::NonZeroIsize;
-use std::sync::{Arc, Once};
+use std::sync::{Arc, Condvar, Mutex, Once};
 use windows::Win32::Foundation::*;
 use windows::Win32::Graphics::Dwm::{
     DwmSetWindowAttribute, DWMWA_WINDOW_CORNER_PREFERENCE, DWMWCP_ROUND,
@@ -25,13 +25,14 @@ const MOD_WIN: u32 = 0x0008;
 
 pub mod engine;
 pub mod audio_engine;
+pub mod trim;
 use engine::{
-    get_monitors, CaptureHandler, AUDIO_ENCODING_FINISHED, ENCODING_FINISHED, MOUSE_POSITIONS,
-    SHOULD_STOP, VIDEO_PATH, AUDIO_PATH
+    get_monitors, get_windows, CaptureHandler, CaptureTarget, AUDIO_ENCODING_FINISHED,
+    ENCODING_FINISHED, MOUSE_POSITIONS, SHOULD_STOP, VIDEO_PATH, AUDIO_PATH
 };
 use windows_capture::capture::GraphicsCaptureApiHandler;
 use windows_capture::settings::{
-    ColorFormat, CursorCaptureSettings, DrawBorderSettings, Settings,
+    ColorFormat, CursorCaptureSettings, Settings,
     SecondaryWindowSettings, MinimumUpdateIntervalSettings, DirtyRegionSettings
 };
 use windows_capture::monitor::Monitor;
@@ -44,8 +45,15 @@ use crate::win_types::SendHwnd;
 
 static REGISTER_SR_CLASS: Once = Once::new();
 static mut SR_HWND: SendHwnd = SendHwnd(HWND(std::ptr::null_mut()));
-static mut IS_WARMED_UP: bool = false;
-static mut IS_INITIALIZING: bool = false;
+static WARMUP: Mutex<Warmup> = Mutex::new(Warmup::Cold);
+/// When the pending show was requested and whether the WebView had to be created
+/// first; logged as the open time once the window shows
+static OPEN_REQUESTED: Mutex<Option<(std::time::Instant, bool)>> = Mutex::new(None);
+/// Notified whenever WARMUP changes
+static WARMUP_CHANGED: Condvar = Condvar::new();
+/// Say the recorder is starting if it isn't up within this long
+const LOADING_NOTICE_DELAY: std::time::Duration = std::time::Duration::from_millis(300);
+const WARMUP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(20);
 const WM_APP_SHOW: u32 = WM_USER + 110;
 const WM_APP_TOGGLE: u32 = WM_USER + 111;
 const WM_APP_RUN_SCRIPT: u32 = WM_USER + 112;
@@ -59,6 +67,42 @@ thread_local! {
 }
 
 
+/// Lifecycle of the recorder window and its WebView
+#[derive(Clone, Copy, PartialEq)]
+enum Warmup {
+    Cold,
+    Initializing,
+    Ready,
+}
+
+fn set_warmup(state: Warmup) {
+    *WARMUP.lock().unwrap() = state;
+    WARMUP_CHANGED.notify_all();
+}
+
+/// Start creating the recorder window unless it exists or is on its way
+fn start_warmup() {
+    let mut warmup = WARMUP.lock().unwrap();
+    if *warmup == Warmup::Cold {
+        *warmup = Warmup::Initializing;
+        std::thread::spawn(|| unsafe {
+            internal_create_sr_loop();
+        });
+    }
+}
+
+/// Block until the recorder is ready, it failed to start, or `timeout` passes
+fn wait_until_ready(timeout: std::time::Duration) -> bool {
+    let warmup = WARMUP.lock().unwrap();
+    let (warmup, _) = WARMUP_CHANGED
+        .wait_timeout_while(warmup, timeout, |w| *w == Warmup::Initializing)
+        .unwrap();
+    *warmup == Warmup::Ready
+}
+
+static COUNTDOWN_SESSION: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+static STATUS_SESSION: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
 lazy_static::lazy_static! {
     static ref SERVER_PORT: std::sync::atomic::AtomicU16 = std::sync::atomic::AtomicU16::new(0);
 }
@@ -90,6 +134,13 @@ unsafe extern "system" fn sr_wnd_proc(
             let _ = ShowWindow(hwnd, SW_SHOW);
             let _ = SetForegroundWindow(hwnd);
             let _ = SetFocus(Some(hwnd));
+            if let Some((requested, cold)) = OPEN_REQUESTED.lock().unwrap().take() {
+                crate::log_info!(
+                    "[ScreenRecord] {} open took {} ms",
+                    if cold { "Cold" } else { "Warm" },
+                    requested.elapsed().as_millis()
+                );
+            }
             LRESULT(0)
         }
         WM_CLOSE => {
@@ -221,30 +272,47 @@ fn wnd_http_response(
         })
 }
 
+/// Create the hidden recorder window and its WebView ahead of time so the first
+/// `show_screen_record` only has to show it. Called from the startup warmup sequence.
+pub fn warmup() {
+    start_warmup();
+}
+
 pub fn show_screen_record() {
-    unsafe {
-        if !IS_WARMED_UP {
-            if !IS_INITIALIZING {
-                IS_INITIALIZING = true;
-                std::thread::spawn(|| {
-                    internal_create_sr_loop();
-                });
-            }
+    if !crate::overlay::webview_runtime::ensure_available() {
+        return;
+    }
+    let cold = *WARMUP.lock().unwrap() != Warmup::Ready;
+    *OPEN_REQUESTED.lock().unwrap() = Some((std::time::Instant::now(), cold));
+    if cold {
+        start_warmup();
 
-            std::thread::spawn(|| {
-                for _ in 0..100 {
-                    std::thread::sleep(std::time::Duration::from_millis(100));
-                    let hwnd_wrapper = std::ptr::addr_of!(SR_HWND).read();
-                    if IS_WARMED_UP && !hwnd_wrapper.is_invalid() {
-                        let _ =
-                            PostMessageW(Some(hwnd_wrapper.0), WM_APP_SHOW, WPARAM(0), LPARAM(0));
-                        return;
-                    }
+        std::thread::spawn(move || {
+            // A quick start stays silent; a slow one says it is on its way
+            let ready = wait_until_ready(LOADING_NOTICE_DELAY) || {
+                if *WARMUP.lock().unwrap() == Warmup::Initializing {
+                    let lang = APP.lock().unwrap().config.ui_language.clone();
+                    let locale = crate::gui::locale::LocaleText::get(&lang);
+                    crate::overlay::auto_copy_badge::show_notification(
+                        locale.screen_record_loading,
+                    );
                 }
-            });
-            return;
-        }
+                wait_until_ready(WARMUP_TIMEOUT)
+            };
+            if !ready {
+                return;
+            }
+            unsafe {
+                let hwnd_wrapper = std::ptr::addr_of!(SR_HWND).read();
+                if !hwnd_wrapper.is_invalid() {
+                    let _ = PostMessageW(Some(hwnd_wrapper.0), WM_APP_SHOW, WPARAM(0), LPARAM(0));
+                }
+            }
+        });
+        return;
+    }
 
+    unsafe {
         let hwnd_wrapper = std::ptr::addr_of!(SR_HWND).read();
         if !hwnd_wrapper.is_invalid() {
             let _ = PostMessageW(Some(hwnd_wrapper.0), WM_APP_SHOW, WPARAM(0), LPARAM(0));
@@ -452,9 +520,10 @@ unsafe fn internal_create_sr_loop() {
     let webview = match webview_result {
         Ok(wv) => wv,
         Err(e) => {
-            eprintln!("Failed to create ScreenRecord WebView: {:?}", e);
+            crate::log_info!("[ScreenRecord] Failed to create WebView: {:?}", e);
             let _ = DestroyWindow(hwnd);
             SR_HWND = SendHwnd::default();
+            set_warmup(Warmup::Cold);
             return;
         }
     };
@@ -474,9 +543,7 @@ unsafe fn internal_create_sr_loop() {
         *wv.borrow_mut() = Some(webview_arc);
     });
 
-    unsafe {
-        IS_WARMED_UP = true;
-    }
+    set_warmup(Warmup::Ready);
 
     let mut msg = MSG::default();
     unsafe {
@@ -491,9 +558,8 @@ unsafe fn internal_create_sr_loop() {
     });
     unsafe {
         SR_HWND = SendHwnd::default();
-        IS_WARMED_UP = false;
-        IS_INITIALIZING = false;
     }
+    set_warmup(Warmup::Cold);
 }
 
 fn handle_ipc_command(cmd: String, args: serde_json::Value) -> Result<serde_json::Value, String> {
@@ -502,17 +568,158 @@ fn handle_ipc_command(cmd: String, args: serde_json::Value) -> Result<serde_json
             let monitors = get_monitors();
             Ok(serde_json::to_value(monitors).unwrap())
         }
+        "get_windows" => {
+            let windows = get_windows();
+            Ok(serde_json::to_value(windows).unwrap())
+        }
+        "get_recording_status" => Ok(recording_status()),
+        "list_presets" => Ok(crate::overlay::preset_launcher::list_presets()),
+        "trigger_preset" => {
+            let id = args["id"].as_str().ok_or("Missing preset id")?;
+            crate::overlay::preset_launcher::trigger_preset(id)?;
+            Ok(serde_json::Value::Null)
+        }
+        "get_capture_cursor" => {
+            let app = crate::APP.lock().map_err(|e| e.to_string())?;
+            Ok(serde_json::Value::Bool(app.config.screen_record_capture_cursor))
+        }
+        "get_auto_save" => {
+            let app = crate::APP.lock().map_err(|e| e.to_string())?;
+            Ok(serde_json::json!({
+                "enabled": app.config.auto_save_recordings,
+                "dir": recording_output_dir(&app.config.recording_output_dir),
+            }))
+        }
+        "set_auto_save" => {
+            let mut app = crate::APP.lock().map_err(|e| e.to_string())?;
+            if let Some(enabled) = args["enabled"].as_bool() {
+                app.config.auto_save_recordings = enabled;
+            }
+            if let Some(dir) = args["dir"].as_str() {
+                app.config.recording_output_dir = dir.trim().to_string();
+            }
+            crate::config::save_config(&app.config);
+            Ok(serde_json::Value::Null)
+        }
         "start_recording" => {
-            let monitor_id = args["monitorId"].as_str().unwrap_or("0");
-            let monitor_index = monitor_id.parse::<usize>().unwrap_or(0);
+            let monitors = get_monitors();
+            // No monitor given: the one last recorded from, else the primary one
+            let monitor_id = args["monitorId"].as_str().map(str::to_string).unwrap_or_else(|| {
+                monitors
+                    .iter()
+                    .find(|m| m.is_default)
+                    .map_or_else(|| "0".to_string(), |m| m.id.clone())
+            });
             
             // RESET ALL RECORDING STATES
             SHOULD_STOP.store(false, std::sync::atomic::Ordering::SeqCst);
             crate::overlay::screen_record::engine::IS_MOUSE_CLICKED.store(false, std::sync::atomic::Ordering::SeqCst);
             crate::overlay::screen_record::engine::CLICK_CAPTURED.store(false, std::sync::atomic::Ordering::SeqCst);
             crate::overlay::screen_record::engine::MOUSE_POSITIONS.lock().clear();
+            crate::overlay::screen_record::engine::TARGET_WINDOW.store(0, std::sync::atomic::Ordering::SeqCst);
+            *crate::overlay::screen_record::engine::POSTER_FRAME.lock() = None;
+
+            // Bake the OS cursor into the video? Missing = the last choice, kept in the config
+            let capture_cursor = {
+                let mut app = crate::APP.lock().map_err(|e| e.to_string())?;
+                match args["captureCursor"].as_bool() {
+                    Some(capture) => {
+                        if app.config.screen_record_capture_cursor != capture {
+                            app.config.screen_record_capture_cursor = capture;
+                            crate::config::save_config(&app.config);
+                        }
+                        capture
+                    }
+                    None => app.config.screen_record_capture_cursor,
+                }
+            };
+            crate::overlay::screen_record::engine::CURSOR_BAKED
+                .store(capture_cursor, std::sync::atomic::Ordering::SeqCst);
+            let mouse_sample_hz = crate::APP
+                .lock()
+                .map_err(|e| e.to_string())?
+                .config
+                .screen_record_mouse_sample_hz;
+            crate::overlay::screen_record::engine::MOUSE_SAMPLE_HZ
+                .store(mouse_sample_hz, std::sync::atomic::Ordering::SeqCst);
+            let cursor_capture = if capture_cursor {
+                CursorCaptureSettings::WithCursor
+            } else {
+                CursorCaptureSettings::WithoutCursor
+            };
+            let draw_border =
+                crate::overlay::screen_record::engine::border_settings(args["drawBorder"].as_bool());
+
+            // 0 / missing = record until stopped
+            let max_duration_secs = args["maxDurationSecs"].as_u64().unwrap_or(0);
+            crate::overlay::screen_record::engine::MAX_DURATION_SECS
+                .store(max_duration_secs, std::sync::atomic::Ordering::SeqCst);
+            start_countdown(max_duration_secs);
+            start_status_updates();
+            unsafe {
+                crate::overlay::screen_record::engine::POSTER_PATH = None;
+            }
+
+            // Window target: capture follows the window, mouse is relative to its bounds
+            if let Some(window_id) = args["windowId"].as_str() {
+                let hwnd = window_id.parse::<isize>().map_err(|e| e.to_string())?;
+                let window =
+                    windows_capture::window::Window::from_raw_hwnd(hwnd as *mut std::ffi::c_void);
+                if !window.is_valid() {
+                    return Err("Window is no longer available".to_string());
+                }
+                crate::overlay::screen_record::engine::TARGET_WINDOW
+                    .store(hwnd, std::sync::atomic::Ordering::SeqCst);
+
+                let settings = Settings::new(
+                    window,
+                    cursor_capture,
+                    draw_border,
+                    SecondaryWindowSettings::Include,
+                    MinimumUpdateIntervalSettings::Default,
+                    DirtyRegionSettings::Default,
+                    ColorFormat::Bgra8,
+                    CaptureTarget::Window(hwnd),
+                );
+
+                std::thread::spawn(move || {
+                    let _ = CaptureHandler::start_free_threaded(settings);
+                });
+
+                return Ok(serde_json::Value::Null);
+            }
+
+            // "Record all": one video of the whole virtual desktop, mouse in virtual-screen coords
+            if monitor_id == crate::overlay::screen_record::engine::ALL_MONITORS_ID {
+                std::thread::spawn(|| {
+                    if let Err(e) = crate::overlay::screen_record::engine::record_virtual_screen() {
+                        eprintln!("Virtual screen recording failed: {}", e);
+                    }
+                });
+                return Ok(serde_json::Value::Null);
+            }
             
-            let monitor = Monitor::from_index(monitor_index + 1).map_err(|e| e.to_string())?;
+            // The id can be stale (a display unplugged since the list was fetched):
+            // record the primary monitor rather than the wrong one, or none at all
+            let monitor_index = match monitor_id.parse::<usize>() {
+                Ok(index) if index < monitors.len() => index,
+                _ => {
+                    let primary = monitors
+                        .iter()
+                        .position(|m| m.is_primary)
+                        .ok_or_else(|| "No monitors available to record".to_string())?;
+                    crate::log_info!(
+                        "[ScreenRecord] Monitor {:?} not connected ({} found), using {}",
+                        monitor_id,
+                        monitors.len(),
+                        primary
+                    );
+                    primary
+                }
+            };
+            let monitor = Monitor::from_index(monitor_index + 1)
+                .map_err(|e| format!("Monitor {} is unavailable: {}", monitor_index, e))?;
+            crate::overlay::screen_record::engine::remember_monitor(monitor_index);
 
             // Set monitor coordinates for mouse tracking
             unsafe {
@@ -535,13 +742,13 @@ fn handle_ipc_command(cmd: String, args: serde_json::Value) -> Result<serde_json
 
             let settings = Settings::new(
                 monitor,
-                CursorCaptureSettings::WithoutCursor,
-                DrawBorderSettings::Default,
+                cursor_capture,
+                draw_border,
                 SecondaryWindowSettings::Include,
                 MinimumUpdateIntervalSettings::Default,
                 DirtyRegionSettings::Default,
                 ColorFormat::Bgra8,
-                monitor_id.to_string(),
+                CaptureTarget::Monitor(monitor_index),
             );
 
             std::thread::spawn(move || {
@@ -561,10 +768,34 @@ fn handle_ipc_command(cmd: String, args: serde_json::Value) -> Result<serde_json
                 std::thread::sleep(std::time::Duration::from_millis(100));
             }
 
-            let video_path = unsafe { VIDEO_PATH.clone() }.ok_or("No video path")?;
+            let mut video_path = unsafe { VIDEO_PATH.clone() }.ok_or("No video path")?;
             let audio_path = unsafe { AUDIO_PATH.clone() }.ok_or("No audio path")?;
-            
-            let port = start_media_server(video_path, audio_path)?;
+
+            // The poster stays next to the app data copy, out of the user's folder
+            let poster_path = crate::overlay::screen_record::engine::save_poster_png(&video_path);
+            let has_poster = poster_path.is_some();
+
+            // Auto-save: move the finished MP4 out of the app data folder first,
+            // so playback, trim and copy all use the saved file
+            let (auto_save, output_dir) = {
+                let app = crate::APP.lock().map_err(|e| e.to_string())?;
+                (app.config.auto_save_recordings, app.config.recording_output_dir.clone())
+            };
+            let mut saved_path = None;
+            if auto_save && ENCODING_FINISHED.load(std::sync::atomic::Ordering::SeqCst) {
+                match save_recording(&video_path, &recording_output_dir(&output_dir)) {
+                    Ok(path) => {
+                        unsafe {
+                            VIDEO_PATH = Some(path.clone());
+                        }
+                        video_path = path.clone();
+                        saved_path = Some(path);
+                    }
+                    Err(e) => crate::log_info!("[ScreenRecord] Auto-save failed: {}", e),
+                }
+            }
+
+            let port = start_media_server(video_path, audio_path, poster_path)?;
             
             let mouse_positions = MOUSE_POSITIONS.lock().drain(..).collect::<Vec<_>>();
             
@@ -579,7 +810,70 @@ fn handle_ipc_command(cmd: String, args: serde_json::Value) -> Result<serde_json
             let video_url = format!("http://localhost:{}/video", port);
             let audio_url = format!("http://localhost:{}/audio", port);
             
-            Ok(serde_json::json!([video_url, audio_url, mouse_positions]))
+            let poster_url = if has_poster {
+                format!("http://localhost:{}/poster.png", port)
+            } else {
+                String::new()
+            };
+
+            let cursor_baked = crate::overlay::screen_record::engine::CURSOR_BAKED
+                .load(std::sync::atomic::Ordering::SeqCst);
+
+            Ok(serde_json::json!([
+                video_url,
+                audio_url,
+                mouse_positions,
+                poster_url,
+                cursor_baked,
+                saved_path.map(|p| file_url(&p))
+            ]))
+        }
+        "copy_poster" => {
+            let poster_path = unsafe { crate::overlay::screen_record::engine::POSTER_PATH.clone() }
+                .ok_or("No poster for this recording")?;
+            let bytes = std::fs::read(&poster_path).map_err(|e| e.to_string())?;
+            crate::overlay::utils::copy_image_to_clipboard(&bytes);
+            Ok(serde_json::Value::Null)
+        }
+        "copy_recording_to_clipboard" => {
+            // The media server only opens the file for reading, so it can be
+            // copied while playback is running; a file still being encoded can't
+            if !ENCODING_FINISHED.load(std::sync::atomic::Ordering::SeqCst) {
+                return Err("Recording is still being saved".to_string());
+            }
+            let video_path = unsafe { VIDEO_PATH.clone() }.ok_or("No video path")?;
+            if !std::path::Path::new(&video_path).is_file() {
+                return Err("Recording file not found".to_string());
+            }
+            if !crate::overlay::utils::copy_file_to_clipboard(&video_path) {
+                return Err("Clipboard is busy".to_string());
+            }
+            Ok(serde_json::Value::Null)
+        }
+        "trim_recording" => {
+            let start_ms = args["startMs"].as_u64().ok_or("Missing startMs")?;
+            let end_ms = args["endMs"].as_u64().ok_or("Missing endMs")?;
+            // Re-encode for a frame-exact in point; otherwise copy from the keyframe before it
+            let precise = args["precise"].as_bool().unwrap_or(false);
+            let video_path = unsafe { VIDEO_PATH.clone() }.ok_or("No video path")?;
+            let audio_path = unsafe { AUDIO_PATH.clone() }.ok_or("No audio path")?;
+
+            let trimmed =
+                trim::trim_recording(&video_path, &audio_path, start_ms, end_ms, precise)?;
+            unsafe {
+                VIDEO_PATH = Some(trimmed.video_path.clone());
+                AUDIO_PATH = Some(trimmed.audio_path.clone());
+            }
+
+            // The old server keeps serving the untrimmed files; the player switches to a new one
+            let poster_path = unsafe { crate::overlay::screen_record::engine::POSTER_PATH.clone() };
+            let port = start_media_server(trimmed.video_path, trimmed.audio_path, poster_path)?;
+            Ok(serde_json::json!({
+                "videoUrl": format!("http://localhost:{}/video", port),
+                "audioUrl": format!("http://localhost:{}/audio", port),
+                "startMs": trimmed.start_ms,
+                "durationMs": trimmed.duration_ms,
+            }))
         }
         "get_hotkeys" => {
             let app = APP.lock().unwrap();
@@ -788,7 +1082,142 @@ fn js_code_to_vk(code: &str) -> Option<u32> {
     }
 }
 
-fn start_media_server(video_path: String, audio_path: String) -> Result<u16, String> {
+/// Run a script in the recorder webview from any thread
+fn post_script(script: String) {
+    let script_ptr = Box::into_raw(Box::new(script));
+    unsafe {
+        if PostMessageW(
+            Some(SR_HWND.0),
+            WM_APP_RUN_SCRIPT,
+            WPARAM(0),
+            LPARAM(script_ptr as isize),
+        )
+        .is_err()
+        {
+            drop(Box::from_raw(script_ptr));
+        }
+    }
+}
+
+/// Tell the webview how many seconds are left each second until the limit is hit
+/// (detail 0 = auto-stopped, the UI then calls stop_recording as usual).
+/// Always called on start so a previous recording's countdown is cancelled.
+fn start_countdown(max_duration_secs: u64) {
+    let session = COUNTDOWN_SESSION.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+    if max_duration_secs == 0 {
+        return;
+    }
+    thread::spawn(move || {
+        let started = std::time::Instant::now();
+        loop {
+            let remaining = max_duration_secs.saturating_sub(started.elapsed().as_secs());
+            post_script(format!(
+                "window.dispatchEvent(new CustomEvent('recording-time-left', {{ detail: {} }}))",
+                remaining
+            ));
+            if remaining == 0 {
+                break;
+            }
+            thread::sleep(std::time::Duration::from_secs(1));
+            // Stopped by the user, or superseded by a newer recording
+            let superseded = COUNTDOWN_SESSION.load(std::sync::atomic::Ordering::SeqCst) != session;
+            if superseded
+                || (SHOULD_STOP.load(std::sync::atomic::Ordering::SeqCst)
+                    && started.elapsed().as_secs() < max_duration_secs)
+            {
+                break;
+            }
+        }
+    });
+}
+
+/// The configured auto-save folder, else the user's Videos folder
+fn recording_output_dir(configured: &str) -> String {
+    if !configured.is_empty() {
+        return configured.to_string();
+    }
+    dirs::video_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(std::env::temp_dir)
+        .join("Screen Goated Toolbox")
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Move a finished recording into `dir` as "SGT_Recording_<date>_<time>.mp4"
+fn save_recording(video_path: &str, dir: &str) -> Result<String, String> {
+    std::fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir, e))?;
+    let stamp = chrono::Local::now().format("%Y-%m-%d_%H-%M-%S");
+    let mut target = std::path::Path::new(dir).join(format!("SGT_Recording_{}.mp4", stamp));
+    let mut n = 2;
+    while target.exists() {
+        target = std::path::Path::new(dir).join(format!("SGT_Recording_{}_{}.mp4", stamp, n));
+        n += 1;
+    }
+    // A rename can't cross volumes: copy, then drop the original
+    if std::fs::rename(video_path, &target).is_err() {
+        std::fs::copy(video_path, &target).map_err(|e| e.to_string())?;
+        let _ = std::fs::remove_file(video_path);
+    }
+    Ok(target.to_string_lossy().into_owned())
+}
+
+/// "C:\Videos\a b.mp4" -> "file:///C:/Videos/a%20b.mp4"
+fn file_url(path: &str) -> String {
+    format!(
+        "file:///{}",
+        path.replace('\\', "/")
+            .replace('%', "%25")
+            .replace(' ', "%20")
+            .replace('#', "%23")
+    )
+}
+
+fn recording_status() -> serde_json::Value {
+    use engine::{DROPPED_FRAMES, ELAPSED_MS, ENCODER_ACTIVE, FRAMES_CAPTURED};
+    use std::sync::atomic::Ordering;
+    serde_json::json!({
+        "isRecording": ENCODER_ACTIVE.load(Ordering::SeqCst),
+        "elapsedMs": ELAPSED_MS.load(Ordering::SeqCst),
+        "framesCaptured": FRAMES_CAPTURED.load(Ordering::SeqCst),
+        "droppedFrames": DROPPED_FRAMES.load(Ordering::SeqCst),
+    })
+}
+
+/// Push `recording-status` events (same payload as get_recording_status) twice a
+/// second while the encoder runs, so the UI can show a live timer and frame drops.
+fn start_status_updates() {
+    use std::sync::atomic::Ordering;
+    let session = STATUS_SESSION.fetch_add(1, Ordering::SeqCst) + 1;
+    thread::spawn(move || {
+        let started = std::time::Instant::now();
+        let mut was_active = false;
+        loop {
+            thread::sleep(std::time::Duration::from_millis(500));
+            if STATUS_SESSION.load(Ordering::SeqCst) != session {
+                break;
+            }
+            if engine::ENCODER_ACTIVE.load(Ordering::SeqCst) {
+                was_active = true;
+            } else if was_active || started.elapsed().as_secs() >= 10 {
+                // Finished, or capture never got going
+                break;
+            } else {
+                continue;
+            }
+            post_script(format!(
+                "window.dispatchEvent(new CustomEvent('recording-status', {{ detail: {} }}))",
+                recording_status()
+            ));
+        }
+    });
+}
+
+fn start_media_server(
+    video_path: String,
+    audio_path: String,
+    poster_path: Option<String>,
+) -> Result<u16, String> {
     let mut port = 8000;
     let server = loop {
         match Server::http(format!("127.0.0.1:{}", port)) {
@@ -815,6 +1244,19 @@ fn start_media_server(video_path: String, audio_path: String) -> Result<u16, Str
             }
 
             let url = request.url();
+            if url.contains("poster") {
+                let res = match poster_path.as_ref().and_then(|p| std::fs::read(p).ok()) {
+                    Some(bytes) => Response::from_data(bytes)
+                        .with_header(tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"image/png"[..]).unwrap())
+                        .with_header(tiny_http::Header::from_bytes(&b"Access-Control-Allow-Origin"[..], &b"*"[..]).unwrap()),
+                    None => Response::from_string("File not found").with_status_code(404).with_header(
+                        tiny_http::Header::from_bytes(&b"Access-Control-Allow-Origin"[..], &b"*"[..]).unwrap(),
+                    ),
+                };
+                let _ = request.respond(res);
+                continue;
+            }
+
             let is_audio = url.contains("audio");
             let media_path = if is_audio { &audio_path } else { &video_path };
             let content_type = if is_audio { "audio/wav" } else { "video/mp4" };
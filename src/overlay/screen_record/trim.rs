@@ -0,0 +1,245 @@
+//! Cut a finished recording down to an in/out range, keeping the WAV track in sync
+
+use hound::{SampleFormat, WavReader, WavWriter};
+use windows::core::{Error, GUID, HSTRING};
+use windows::Win32::Foundation::E_POINTER;
+use windows::Win32::Media::MediaFoundation::*;
+use windows::Win32::System::Com::StructuredStorage::PROPVARIANT;
+use windows::Win32::System::Com::{CoInitializeEx, CoUninitialize, COINIT_MULTITHREADED};
+use windows::Win32::System::Variant::VT_I8;
+
+/// Media Foundation timestamps are in 100-ns units
+const HNS_PER_MS: i64 = 10_000;
+
+/// Same bitrate the recorder encodes with
+const REENCODE_BITRATE: u32 = 15_000_000;
+
+const VIDEO_STREAM: u32 = MF_SOURCE_READER_FIRST_VIDEO_STREAM.0 as u32;
+
+pub struct TrimmedRecording {
+    pub video_path: String,
+    pub audio_path: String,
+    /// Where the cut really starts: a stream copy snaps back to the previous keyframe
+    pub start_ms: u64,
+    pub duration_ms: u64,
+}
+
+/// Write `start_ms..end_ms` of the recording to new files next to it. With
+/// `precise` the video is re-encoded so it starts exactly at `start_ms`;
+/// otherwise compressed frames are copied from the keyframe at or before it,
+/// which is much faster.
+pub fn trim_recording(
+    video_path: &str,
+    audio_path: &str,
+    start_ms: u64,
+    end_ms: u64,
+    precise: bool,
+) -> Result<TrimmedRecording, String> {
+    if end_ms <= start_ms {
+        return Err("Trim range is empty".to_string());
+    }
+
+    let suffix = format!(
+        "_trim_{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+    );
+    let out_video = sibling_path(video_path, &suffix, "mp4");
+    let out_audio = sibling_path(audio_path, &suffix, "wav");
+
+    // Media Foundation needs COM: run it on its own MTA thread
+    let (input, output) = (video_path.to_string(), out_video.clone());
+    let (start_hns, duration_hns) = std::thread::spawn(move || unsafe {
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+        let result = MFStartup(MF_VERSION, MFSTARTUP_FULL).and_then(|_| {
+            let result = trim_video(
+                &input,
+                &output,
+                start_ms as i64 * HNS_PER_MS,
+                end_ms as i64 * HNS_PER_MS,
+                precise,
+            );
+            let _ = MFShutdown();
+            result
+        });
+        CoUninitialize();
+        result
+    })
+    .join()
+    .map_err(|_| "Trim thread panicked".to_string())?
+    .map_err(|e| format!("Trim failed: {}", e))?;
+
+    // Cut the audio from where the video really starts so they stay in sync
+    let actual_start_ms = (start_hns / HNS_PER_MS) as u64;
+    if let Err(e) = trim_wav(audio_path, &out_audio, actual_start_ms, end_ms) {
+        crate::log_info!("[ScreenRecord] Audio trim failed: {}", e);
+    }
+
+    Ok(TrimmedRecording {
+        video_path: out_video,
+        audio_path: out_audio,
+        start_ms: actual_start_ms,
+        duration_ms: (duration_hns / HNS_PER_MS) as u64,
+    })
+}
+
+/// "dir/recording_1.mp4" + "_trim_2" -> "dir/recording_1_trim_2.mp4"
+fn sibling_path(path: &str, suffix: &str, extension: &str) -> String {
+    let path = std::path::Path::new(path);
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "recording".to_string());
+    path.with_file_name(format!("{}{}.{}", stem, suffix, extension))
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Copy (or re-encode) the video samples in `start..end`, rebased to 0.
+/// Returns the timestamp the output starts at and its duration.
+unsafe fn trim_video(
+    input: &str,
+    output: &str,
+    start: i64,
+    end: i64,
+    precise: bool,
+) -> windows::core::Result<(i64, i64)> {
+    let mut attributes: Option<IMFAttributes> = None;
+    MFCreateAttributes(&mut attributes, 2)?;
+    let attributes = attributes.ok_or_else(|| Error::from(E_POINTER))?;
+    attributes.SetUINT32(&MF_READWRITE_ENABLE_HARDWARE_TRANSFORMS, 1)?;
+    if precise {
+        attributes.SetUINT32(&MF_SOURCE_READER_ENABLE_VIDEO_PROCESSING, 1)?;
+    }
+
+    let reader = MFCreateSourceReaderFromURL(&HSTRING::from(input), &attributes)?;
+    reader.SetStreamSelection(MF_SOURCE_READER_ALL_STREAMS.0 as u32, false)?;
+    reader.SetStreamSelection(VIDEO_STREAM, true)?;
+    let native = reader.GetNativeMediaType(VIDEO_STREAM, 0)?;
+
+    let writer =
+        MFCreateSinkWriterFromURL(&HSTRING::from(output), None::<&IMFByteStream>, &attributes)?;
+    let stream = if precise {
+        // Decode to NV12 and let the sink writer encode H.264 again
+        let decoded = MFCreateMediaType()?;
+        decoded.SetGUID(&MF_MT_MAJOR_TYPE, &MFMediaType_Video)?;
+        decoded.SetGUID(&MF_MT_SUBTYPE, &MFVideoFormat_NV12)?;
+        reader.SetCurrentMediaType(VIDEO_STREAM, None, &decoded)?;
+
+        let encoded = MFCreateMediaType()?;
+        encoded.SetGUID(&MF_MT_MAJOR_TYPE, &MFMediaType_Video)?;
+        encoded.SetGUID(&MF_MT_SUBTYPE, &MFVideoFormat_H264)?;
+        encoded.SetUINT32(
+            &MF_MT_AVG_BITRATE,
+            native
+                .GetUINT32(&MF_MT_AVG_BITRATE)
+                .unwrap_or(REENCODE_BITRATE),
+        )?;
+        encoded.SetUINT32(
+            &MF_MT_INTERLACE_MODE,
+            MFVideoInterlace_Progressive.0 as u32,
+        )?;
+        for key in [
+            &MF_MT_FRAME_SIZE,
+            &MF_MT_FRAME_RATE,
+            &MF_MT_PIXEL_ASPECT_RATIO,
+        ] {
+            if let Ok(value) = native.GetUINT64(key) {
+                encoded.SetUINT64(key, value)?;
+            }
+        }
+
+        let stream = writer.AddStreamType(&encoded)?;
+        writer.SetInputMediaType(
+            stream,
+            &reader.GetCurrentMediaType(VIDEO_STREAM)?,
+            None::<&IMFAttributes>,
+        )?;
+        stream
+    } else {
+        // Same type in and out: the sink writer just remuxes
+        let stream = writer.AddStreamType(&native)?;
+        writer.SetInputMediaType(stream, &native, None::<&IMFAttributes>)?;
+        stream
+    };
+
+    // Seeking lands on the keyframe at or before `start`
+    let mut position = PROPVARIANT::default();
+    (*position.Anonymous.Anonymous).vt = VT_I8;
+    (*position.Anonymous.Anonymous).Anonymous.hVal = start;
+    reader.SetCurrentPosition(&GUID::zeroed(), &position)?;
+
+    writer.BeginWriting()?;
+    let mut base: Option<i64> = None;
+    let mut duration = 0;
+    loop {
+        let mut flags = 0u32;
+        let mut sample: Option<IMFSample> = None;
+        reader.ReadSample(
+            VIDEO_STREAM,
+            0,
+            None,
+            Some(&mut flags as *mut _),
+            None,
+            Some(&mut sample as *mut _),
+        )?;
+        if flags & MF_SOURCE_READERF_ENDOFSTREAM.0 as u32 != 0 {
+            break;
+        }
+        // Stream ticks carry no sample
+        let Some(sample) = sample else {
+            continue;
+        };
+
+        let time = sample.GetSampleTime()?;
+        if time >= end {
+            break;
+        }
+        if base.is_none() {
+            // Decoded frames can start exactly at the in point; a copy must
+            // start on a keyframe
+            let usable = if precise {
+                time >= start
+            } else {
+                sample.GetUINT32(&MFSampleExtension_CleanPoint).unwrap_or(0) != 0
+            };
+            if !usable {
+                continue;
+            }
+        }
+
+        let base = *base.get_or_insert(time);
+        sample.SetSampleTime(time - base)?;
+        duration = time - base + sample.GetSampleDuration().unwrap_or(0);
+        writer.WriteSample(stream, &sample)?;
+    }
+    writer.Finalize()?;
+
+    Ok((base.unwrap_or(start), duration))
+}
+
+fn trim_wav(input: &str, output: &str, start_ms: u64, end_ms: u64) -> Result<(), hound::Error> {
+    let mut reader = WavReader::open(input)?;
+    let spec = reader.spec();
+    let to_frame = |ms: u64| ((ms * spec.sample_rate as u64 / 1000) as u32).min(reader.duration());
+    let start = to_frame(start_ms);
+    let samples = to_frame(end_ms).saturating_sub(start) as usize * spec.channels as usize;
+    reader.seek(start)?;
+
+    let mut writer = WavWriter::create(output, spec)?;
+    match spec.sample_format {
+        SampleFormat::Float => {
+            for sample in reader.samples::<f32>().take(samples) {
+                writer.write_sample(sample?)?;
+            }
+        }
+        SampleFormat::Int => {
+            for sample in reader.samples::<i32>().take(samples) {
+                writer.write_sample(sample?)?;
+            }
+        }
+    }
+    writer.finalize()
+}
@@ -3,7 +3,7 @@ use windows::Win32::Foundation::*;
 use windows::Win32::Graphics::Gdi::*;
 use windows::Win32::System::LibraryLoader::*;
 use windows::Win32::UI::Input::KeyboardAndMouse::{
-    GetAsyncKeyState, ReleaseCapture, SetCapture, VK_ESCAPE,
+    GetAsyncKeyState, ReleaseCapture, SetCapture, VK_ESCAPE, VK_SHIFT,
 };
 use windows::Win32::UI::WindowsAndMessaging::*;
 
@@ -41,6 +41,10 @@ static mut CURRENT_ALPHA: u8 = 0;
 static SELECTION_OVERLAY_ACTIVE: AtomicBool = AtomicBool::new(false);
 static mut SELECTION_OVERLAY_HWND: SendHwnd = SendHwnd(HWND(std::ptr::null_mut()));
 static mut CURRENT_PRESET_IDX: usize = 0;
+/// Streaming override for the runs this overlay session starts (Shift held on the hotkey)
+static mut STREAMING_OVERRIDE: Option<bool> = None;
+/// Width / height ratio the dragged region is held to (the preset's `lock_aspect`)
+static mut LOCK_ASPECT: Option<f32> = None;
 static mut SELECTION_HOOK: HHOOK = HHOOK(std::ptr::null_mut());
 
 // CONTINUOUS MODE HOTKEY TRACKING
@@ -116,7 +120,7 @@ unsafe fn load_magnification_api() -> bool {
 }
 
 // Helper to extract bytes from the HBITMAP only for the selected area
-unsafe fn extract_crop_from_hbitmap(
+pub(super) unsafe fn extract_crop_from_hbitmap(
     capture: &GdiCapture,
     crop_rect: RECT,
 ) -> image::ImageBuffer<image::Rgba<u8>, Vec<u8>> {
@@ -194,9 +198,20 @@ pub fn is_selection_overlay_active() -> bool {
 }
 
 #[allow(static_mut_refs)]
-pub fn show_selection_overlay(preset_idx: usize) {
+pub fn show_selection_overlay(preset_idx: usize, streaming_override: Option<bool>) {
     unsafe {
         CURRENT_PRESET_IDX = preset_idx;
+        STREAMING_OVERRIDE = streaming_override;
+        LOCK_ASPECT = APP
+            .lock()
+            .ok()
+            .and_then(|app| {
+                app.config
+                    .presets
+                    .get(preset_idx)
+                    .and_then(|p| p.lock_aspect)
+            })
+            .filter(|a| *a > 0.0);
         SELECTION_OVERLAY_ACTIVE.store(true, Ordering::SeqCst);
         CURRENT_ALPHA = 0;
         IS_FADING_OUT = false;
@@ -373,6 +388,27 @@ unsafe extern "system" fn selection_hook_proc(
     CallNextHookEx(None, code, wparam, lparam)
 }
 
+/// Move `curr` so the box from `start` has the given width / height ratio,
+/// following whichever side the cursor has pulled further
+fn constrain_to_aspect(start: POINT, curr: POINT, aspect: f32) -> POINT {
+    let dx = curr.x - start.x;
+    let dy = curr.y - start.y;
+    let sign = |d: i32| if d < 0 { -1 } else { 1 };
+    if dx.abs() as f32 >= dy.abs() as f32 * aspect {
+        let h = (dx.abs() as f32 / aspect).round() as i32;
+        POINT {
+            x: curr.x,
+            y: start.y + h * sign(dy),
+        }
+    } else {
+        let w = (dy.abs() as f32 * aspect).round() as i32;
+        POINT {
+            x: start.x + w * sign(dx),
+            y: curr.y,
+        }
+    }
+}
+
 #[allow(static_mut_refs)]
 unsafe extern "system" fn selection_wnd_proc(
     hwnd: HWND,
@@ -413,6 +449,11 @@ unsafe extern "system" fn selection_wnd_proc(
         WM_MOUSEMOVE => {
             if IS_DRAGGING {
                 let _ = GetCursorPos(std::ptr::addr_of_mut!(CURR_POS));
+                // Holding Shift drags freely despite the preset's aspect lock
+                let shift_down = (GetAsyncKeyState(VK_SHIFT.0 as i32) as u16 & 0x8000) != 0;
+                if let Some(aspect) = LOCK_ASPECT.filter(|_| !shift_down) {
+                    CURR_POS = constrain_to_aspect(START_POS, CURR_POS, aspect);
+                }
                 // Force immediate repaint for smoothness
                 sync_layered_window_contents(hwnd);
             } else if IS_RIGHT_DRAGGING {
@@ -556,18 +597,18 @@ unsafe extern "system" fn selection_wnd_proc(
 
                 if width > 10 && height > 10 {
                     // Check if this is a MASTER preset
-                    let is_master = {
+                    let master = {
                         let guard = APP.lock().unwrap();
                         guard
                             .config
                             .presets
                             .get(CURRENT_PRESET_IDX)
-                            .map(|p| p.is_master)
-                            .unwrap_or(false)
+                            .filter(|p| p.is_master)
+                            .cloned()
                     };
 
                     // For MASTER presets, show the preset wheel first
-                    let final_preset_idx = if is_master {
+                    let final_preset_idx = if let Some(master) = master {
                         // Get cursor position for wheel center
                         let mut cursor_pos = POINT::default();
                         let _ = GetCursorPos(&mut cursor_pos);
@@ -579,7 +620,7 @@ unsafe extern "system" fn selection_wnd_proc(
 
                         // Show preset wheel - this blocks until user makes selection
                         let selected =
-                            super::preset_wheel::show_preset_wheel("image", None, cursor_pos);
+                            super::preset_wheel::choose_preset(&master, "image", None, cursor_pos);
 
                         if let Some(idx) = selected {
                             Some(idx)
@@ -663,9 +704,16 @@ unsafe extern "system" fn selection_wnd_proc(
                         };
 
                         // 2. TRIGGER PROCESSING
+                        let streaming_override = STREAMING_OVERRIDE;
                         std::thread::spawn(move || {
                             // Pass the rect for result window positioning
-                            start_processing_pipeline(cropped_img, rect, config, preset);
+                            start_processing_pipeline(
+                                cropped_img,
+                                rect,
+                                config,
+                                preset,
+                                streaming_override,
+                            );
                         });
 
                         // 3. Continuous mode is handled by the loop in main.rs
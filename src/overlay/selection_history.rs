@@ -0,0 +1,151 @@
+//! Recent selected-and-translated snippets from text-selection mode.
+//! Kept in memory on `APP` so a hotkey can cycle back through past results
+//! without re-selecting the text.
+
+use std::sync::atomic::{AtomicIsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use windows::Win32::Foundation::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+
+use crate::overlay::result::{
+    create_result_window, get_chain_color, update_window_text, RefineContext, WindowType,
+};
+use crate::APP;
+
+/// Oldest snippets are dropped once the buffer holds this many
+pub const MAX_SELECTION_HISTORY: usize = 20;
+
+/// Presses further apart than this restart cycling from the newest snippet
+const CYCLE_RESET: Duration = Duration::from_secs(5);
+
+#[derive(Clone)]
+pub struct SelectionSnippet {
+    pub source: String,
+    /// Empty until the first text block of the chain finishes
+    pub translation: String,
+    pub timestamp: chrono::DateTime<chrono::Local>,
+}
+
+/// (index of the snippet shown last, time of the last cycle press)
+static CYCLE_STATE: Mutex<(usize, Option<Instant>)> = Mutex::new((0, None));
+
+/// The open snippet window, reused by later presses (0 = none)
+static SNIPPET_HWND: AtomicIsize = AtomicIsize::new(0);
+
+/// Remember a selection that is about to be processed
+pub fn record_selection(source: &str) {
+    if source.trim().is_empty() {
+        return;
+    }
+    if let Ok(mut app) = APP.lock() {
+        let history = &mut app.selection_history;
+        history.push_front(SelectionSnippet {
+            source: source.to_string(),
+            translation: String::new(),
+            timestamp: chrono::Local::now(),
+        });
+        history.truncate(MAX_SELECTION_HISTORY);
+    }
+    if let Ok(mut cycle) = CYCLE_STATE.lock() {
+        *cycle = (0, None);
+    }
+}
+
+/// Attach a finished result to the pending selection it was produced from.
+/// Results for any other input (typed text, later chain blocks) are ignored.
+pub fn record_result(source: &str, result: &str) {
+    if let Ok(mut app) = APP.lock() {
+        if let Some(snippet) = app
+            .selection_history
+            .iter_mut()
+            .find(|s| s.translation.is_empty() && s.source == source)
+        {
+            snippet.translation = result.to_string();
+        }
+    }
+}
+
+/// Show the next older snippet (starting from the newest) in a result window
+pub fn show_next_snippet() {
+    let snippets: Vec<SelectionSnippet> = match APP.lock() {
+        Ok(app) => app
+            .selection_history
+            .iter()
+            .filter(|s| !s.translation.is_empty())
+            .cloned()
+            .collect(),
+        Err(_) => return,
+    };
+    if snippets.is_empty() {
+        return;
+    }
+
+    let idx = {
+        let Ok(mut cycle) = CYCLE_STATE.lock() else {
+            return;
+        };
+        let next = match cycle.1 {
+            Some(last) if last.elapsed() < CYCLE_RESET => (cycle.0 + 1) % snippets.len(),
+            _ => 0,
+        };
+        *cycle = (next, Some(Instant::now()));
+        next
+    };
+
+    let snippet = &snippets[idx];
+    let text = format!(
+        "{}\n\n———\n{} · {}",
+        snippet.translation.trim(),
+        snippet.timestamp.format("%H:%M"),
+        snippet.source.trim()
+    );
+
+    let open = HWND(SNIPPET_HWND.load(Ordering::SeqCst) as *mut std::ffi::c_void);
+    if !open.is_invalid() && unsafe { IsWindow(Some(open)).as_bool() } {
+        update_window_text(open, &text);
+        return;
+    }
+    std::thread::spawn(move || show_snippet_window(text));
+}
+
+fn show_snippet_window(text: String) {
+    unsafe {
+        let screen_w = GetSystemMetrics(SM_CXSCREEN);
+        let screen_h = GetSystemMetrics(SM_CYSCREEN);
+        let rect = RECT {
+            left: (screen_w - 700) / 2,
+            top: (screen_h - 300) / 2,
+            right: (screen_w + 700) / 2,
+            bottom: (screen_h + 300) / 2,
+        };
+
+        // No model: follow-up refinements fall back to the default text model
+        let hwnd = create_result_window(
+            rect,
+            WindowType::Primary,
+            RefineContext::None,
+            String::new(),
+            String::new(),
+            false,
+            false,
+            String::new(),
+            get_chain_color(0),
+            "plain",
+            text,
+        );
+        let _ = ShowWindow(hwnd, SW_SHOWNA);
+        SNIPPET_HWND.store(hwnd.0 as isize, Ordering::SeqCst);
+
+        let mut msg = MSG::default();
+        while GetMessageW(&mut msg, None, 0, 0).into() {
+            let _ = TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+            if !IsWindow(Some(hwnd)).as_bool() {
+                break;
+            }
+        }
+        let _ =
+            SNIPPET_HWND.compare_exchange(hwnd.0 as isize, 0, Ordering::SeqCst, Ordering::SeqCst);
+    }
+}
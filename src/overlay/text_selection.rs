@@ -7,7 +7,6 @@ use windows::core::*;
 use windows::Win32::Foundation::*;
 use windows::Win32::System::DataExchange::*;
 use windows::Win32::System::LibraryLoader::*;
-use windows::Win32::System::Memory::*;
 use windows::Win32::UI::Input::KeyboardAndMouse::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
 
@@ -17,21 +16,85 @@ use crate::overlay::realtime_webview::state::HwndWrapper;
 // --- SHARED STATE ---
 struct TextSelectionState {
     preset_idx: usize,
+    /// Streaming override for the run this tag starts (Shift held on the hotkey)
+    streaming_override: Option<bool>,
     is_selecting: bool,
     is_processing: bool,
-    hook_handle: HHOOK,
+    hook: Option<EscHook>,
     webview: Option<wry::WebView>,
 }
 unsafe impl Send for TextSelectionState {}
 
 static SELECTION_STATE: Mutex<TextSelectionState> = Mutex::new(TextSelectionState {
     preset_idx: 0,
+    streaming_override: None,
     is_selecting: false,
     is_processing: false,
-    hook_handle: HHOOK(std::ptr::null_mut()),
+    hook: None,
     webview: None,
 });
 
+/// The low-level keyboard hook that swallows ESC while the tag is up.
+/// Unhooks on drop, so no exit path can leave Escape eaten system-wide.
+struct EscHook {
+    handle: HHOOK,
+    /// Thread that installed it (the tag thread)
+    thread_id: u32,
+}
+
+impl EscHook {
+    unsafe fn install() -> Option<Self> {
+        let handle = SetWindowsHookExW(
+            WH_KEYBOARD_LL,
+            Some(keyboard_hook_proc),
+            Some(GetModuleHandleW(None).ok()?.into()),
+            0,
+        )
+        .ok()?;
+        Some(Self {
+            handle,
+            thread_id: windows::Win32::System::Threading::GetCurrentThreadId(),
+        })
+    }
+
+    /// Left behind by a tag thread that is gone, or by a hide that never
+    /// got to remove it
+    fn is_stale(&self) -> bool {
+        let hwnd_val = TAG_HWND.load(Ordering::SeqCst);
+        if hwnd_val == 0 {
+            return true;
+        }
+        unsafe {
+            let hwnd = HWND(hwnd_val as *mut std::ffi::c_void);
+            if !IsWindow(Some(hwnd)).as_bool()
+                || GetWindowThreadProcessId(hwnd, None) != self.thread_id
+            {
+                return true;
+            }
+            !IsWindowVisible(hwnd).as_bool() && !crate::overlay::continuous_mode::is_active()
+        }
+    }
+}
+
+impl Drop for EscHook {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = UnhookWindowsHookEx(self.handle);
+        }
+    }
+}
+
+/// Drops the hook when the tag thread ends, including by panic
+struct TagThreadGuard;
+
+impl Drop for TagThreadGuard {
+    fn drop(&mut self) {
+        let mut state = SELECTION_STATE.lock().unwrap_or_else(|e| e.into_inner());
+        state.hook = None;
+        state.webview = None;
+    }
+}
+
 static REGISTER_TAG_CLASS: Once = Once::new();
 
 lazy_static::lazy_static! {
@@ -63,6 +126,8 @@ static LAST_INSTANT_PROCESS_TIME: std::sync::atomic::AtomicU64 =
 // DRAG DETECTION: Mouse start position when selection begins
 static MOUSE_START_X: std::sync::atomic::AtomicI32 = std::sync::atomic::AtomicI32::new(0);
 static MOUSE_START_Y: std::sync::atomic::AtomicI32 = std::sync::atomic::AtomicI32::new(0);
+// STALE SELECTION GUARD: Top-level window the drag started in
+static SELECTION_TARGET_HWND: AtomicIsize = AtomicIsize::new(0);
 static PENDING_SHOW_ON_WARMUP: AtomicBool = AtomicBool::new(false);
 
 // Messages
@@ -94,7 +159,7 @@ impl Drop for ProcessingGuard {
 }
 
 /// Try to process already-selected text instantly.
-pub fn try_instant_process(preset_idx: usize) -> bool {
+pub fn try_instant_process(preset_idx: usize, streaming_override: Option<bool>) -> bool {
     // TIME-BASED DEBOUNCE: If we processed via instant process recently, skip
     // This prevents multiple processes when holding hotkey on preselected text
     let now = std::time::SystemTime::now()
@@ -122,7 +187,7 @@ pub fn try_instant_process(preset_idx: usize) -> bool {
 
     unsafe {
         // Step 1: Save clipboard
-        let original_clipboard = get_clipboard_text();
+        let original_clipboard = crate::overlay::utils::get_clipboard_text(HWND::default());
 
         // Step 2: Clear & Copy
         if OpenClipboard(Some(HWND::default())).is_ok() {
@@ -159,7 +224,7 @@ pub fn try_instant_process(preset_idx: usize) -> bool {
         let mut clipboard_text = String::new();
         for _ in 0..6 {
             std::thread::sleep(std::time::Duration::from_millis(20));
-            clipboard_text = get_clipboard_text();
+            clipboard_text = crate::overlay::utils::get_clipboard_text(HWND::default());
             if !clipboard_text.is_empty() {
                 break;
             }
@@ -228,13 +293,13 @@ pub fn try_instant_process(preset_idx: usize) -> bool {
                 std::thread::spawn(move || {
                     std::thread::sleep(std::time::Duration::from_millis(150));
                     if crate::overlay::continuous_mode::is_active() {
-                        let _ = show_text_selection_tag(current_idx);
+                        let _ = show_text_selection_tag(current_idx, streaming_override);
                     }
                 });
             }
         }
 
-        process_selected_text(final_preset_idx, clipboard_text);
+        process_selected_text(final_preset_idx, clipboard_text, streaming_override);
         true
     }
 }
@@ -279,7 +344,7 @@ pub fn is_warming_up() -> bool {
 const OFFSET_X: i32 = -20;
 const OFFSET_Y: i32 = -90;
 
-pub fn show_text_selection_tag(preset_idx: usize) {
+pub fn show_text_selection_tag(preset_idx: usize, streaming_override: Option<bool>) {
     // 1. Ensure Warmed Up / Trigger Warmup
     if !IS_WARMED_UP.load(Ordering::SeqCst) {
         PENDING_SHOW_ON_WARMUP.store(true, Ordering::SeqCst);
@@ -291,10 +356,18 @@ pub fn show_text_selection_tag(preset_idx: usize) {
     // 2. Prepare State
     {
         let mut state = SELECTION_STATE.lock().unwrap();
+        // Watchdog: a hook still around from a previous activation that did
+        // not clean up would keep eating ESC
+        if state.hook.as_ref().is_some_and(|h| h.is_stale()) {
+            crate::log_info!("[TextSelection] Removing stale ESC hook");
+            state.hook = None;
+        }
         state.preset_idx = preset_idx;
+        state.streaming_override = streaming_override;
         state.is_selecting = false;
         state.is_processing = false;
         TAG_ABORT_SIGNAL.store(false, Ordering::SeqCst);
+        SELECTION_TARGET_HWND.store(0, Ordering::SeqCst);
 
         // Initialize Hotkey Tracking
         // Only reset session flags if NOT already in continuous mode
@@ -416,6 +489,8 @@ fn internal_create_tag_thread() {
     unsafe {
         use windows::Win32::System::Com::*;
         let _coinit = CoInitialize(None);
+        // Unhooks ESC however this thread ends
+        let _cleanup = TagThreadGuard;
 
         let instance = GetModuleHandleW(None).unwrap();
         let class_name = w!("SGT_TextTag_Web_Persistent");
@@ -467,6 +542,7 @@ fn internal_create_tag_thread() {
         let initial_text = match lang.as_str() {
             "vi" => "Bôi đen văn bản...",
             "ko" => "텍스트 선택...",
+            "ja" => "テキストを選択...",
             _ => "Select text...",
         };
         *INITIAL_TEXT_GLOBAL.lock().unwrap() = initial_text.to_string();
@@ -615,16 +691,8 @@ fn internal_create_tag_thread() {
                 let mut state = SELECTION_STATE.lock().unwrap();
                 if visible {
                     // Install Hook
-                    if state.hook_handle.is_invalid() {
-                        let hook = SetWindowsHookExW(
-                            WH_KEYBOARD_LL,
-                            Some(keyboard_hook_proc),
-                            Some(GetModuleHandleW(None).unwrap().into()),
-                            0,
-                        );
-                        if let Ok(h) = hook {
-                            state.hook_handle = h;
-                        }
+                    if state.hook.is_none() {
+                        state.hook = EscHook::install();
                     }
 
                     // NOTE: Physical key sync moved to main polling loop below
@@ -650,11 +718,8 @@ fn internal_create_tag_thread() {
                     // Uninstall Hook ONLY if continuous mode is NOT active.
                     // If continuous mode is active, we keep the hook to catch the exit command (ESC or Hotkey)
                     // even while the tag is temporarily hidden/processing.
-                    if !crate::overlay::continuous_mode::is_active()
-                        && !state.hook_handle.is_invalid()
-                    {
-                        let _ = UnhookWindowsHookEx(state.hook_handle);
-                        state.hook_handle = HHOOK::default();
+                    if !crate::overlay::continuous_mode::is_active() {
+                        state.hook = None;
                     }
                 }
             }
@@ -734,6 +799,7 @@ fn internal_create_tag_thread() {
 
                 let mut should_spawn_thread = false;
                 let mut preset_idx_for_thread = 0;
+                let mut streaming_override_for_thread = None;
 
                 // Scope for State Lock
                 let update_js = {
@@ -753,6 +819,8 @@ fn internal_create_tag_thread() {
                             // Record mouse start position for drag detection
                             MOUSE_START_X.store(pt.x, Ordering::SeqCst);
                             MOUSE_START_Y.store(pt.y, Ordering::SeqCst);
+                            let target = GetAncestor(hwnd_under_mouse, GA_ROOT);
+                            SELECTION_TARGET_HWND.store(target.0 as isize, Ordering::SeqCst);
                         }
                     } else if state.is_selecting && !lbutton_down && !state.is_processing {
                         // DRAG DETECTION: Only process if mouse moved significantly
@@ -769,6 +837,7 @@ fn internal_create_tag_thread() {
                             state.is_processing = true;
                             should_spawn_thread = true;
                             preset_idx_for_thread = state.preset_idx;
+                            streaming_override_for_thread = state.streaming_override;
                         } else {
                             // Just a click, not a selection - reset state
                             state.is_selecting = false;
@@ -781,6 +850,7 @@ fn internal_create_tag_thread() {
                             match lang.as_str() {
                                 "vi" => "Thả chuột để xử lý",
                                 "ko" => "처리를 위해 마우스를 놓으세요",
+                                "ja" => "離すと処理します",
                                 _ => "Release to process",
                             }
                         } else {
@@ -813,7 +883,23 @@ fn internal_create_tag_thread() {
                         }
                         std::thread::sleep(std::time::Duration::from_millis(50));
 
-                        // Clear Clipboard
+                        // Alt-tab etc. since the drag started: Ctrl+C would hit the wrong window
+                        if !is_selection_target_foreground() {
+                            cancel_selection();
+                            return;
+                        }
+
+                        // Clear Clipboard (remember it so an abort can put it back)
+                        let original_clipboard =
+                            crate::overlay::utils::get_clipboard_text(HWND::default());
+                        let restore_clipboard = || {
+                            if !original_clipboard.is_empty() {
+                                crate::overlay::utils::copy_to_clipboard(
+                                    &original_clipboard,
+                                    HWND::default(),
+                                );
+                            }
+                        };
                         if OpenClipboard(Some(HWND::default())).is_ok() {
                             let _ = EmptyClipboard();
                             let _ = CloseClipboard();
@@ -847,15 +933,24 @@ fn internal_create_tag_thread() {
                         let mut clipboard_text = String::new();
                         for _ in 0..10 {
                             if TAG_ABORT_SIGNAL.load(Ordering::Relaxed) {
+                                restore_clipboard();
                                 return;
                             }
                             std::thread::sleep(std::time::Duration::from_millis(25));
-                            clipboard_text = get_clipboard_text();
+                            clipboard_text =
+                                crate::overlay::utils::get_clipboard_text(HWND::default());
                             if !clipboard_text.is_empty() {
                                 break;
                             }
                         }
 
+                        // Focus moved while the copy was in flight: the text may be from elsewhere
+                        if !is_selection_target_foreground() {
+                            restore_clipboard();
+                            cancel_selection();
+                            return;
+                        }
+
                         if !clipboard_text.trim().is_empty()
                             && !TAG_ABORT_SIGNAL.load(Ordering::Relaxed)
                         {
@@ -937,13 +1032,25 @@ fn internal_create_tag_thread() {
                                     // Small delay to let the hide animation complete
                                     std::thread::sleep(std::time::Duration::from_millis(150));
                                     if crate::overlay::continuous_mode::is_active() {
-                                        let _ = super::show_text_selection_tag(retrigger_idx);
+                                        let _ = super::show_text_selection_tag(
+                                            retrigger_idx,
+                                            streaming_override_for_thread,
+                                        );
                                     }
                                 });
                             }
 
-                            process_selected_text(p_idx, clipboard_text);
+                            process_selected_text(
+                                p_idx,
+                                clipboard_text,
+                                streaming_override_for_thread,
+                            );
                         } else {
+                            if clipboard_text.trim().is_empty()
+                                || TAG_ABORT_SIGNAL.load(Ordering::Relaxed)
+                            {
+                                restore_clipboard();
+                            }
                             // Reset state if failed or empty
                             let mut state = SELECTION_STATE.lock().unwrap();
                             state.is_selecting = false;
@@ -956,53 +1063,53 @@ fn internal_create_tag_thread() {
                 std::thread::sleep(std::time::Duration::from_millis(16));
             }
         }
+    }
+}
 
-        // Cleanup
-        {
-            let mut state = SELECTION_STATE.lock().unwrap();
-            state.webview = None;
-            if !state.hook_handle.is_invalid() {
-                let _ = UnhookWindowsHookEx(state.hook_handle);
-                state.hook_handle = HHOOK::default();
-            }
-        }
+/// Whether the window the drag started in is still the foreground window
+unsafe fn is_selection_target_foreground() -> bool {
+    let target = SELECTION_TARGET_HWND.load(Ordering::SeqCst);
+    if target == 0 {
+        return true;
     }
+    GetAncestor(GetForegroundWindow(), GA_ROOT).0 as isize == target
 }
 
-// Reuse helper functions like get_clipboard_text, process_selected_text
-unsafe fn get_clipboard_text() -> String {
-    let mut result = String::new();
-    if OpenClipboard(Some(HWND::default())).is_ok() {
-        if let Ok(h_data) = GetClipboardData(13u32) {
-            let h_global: HGLOBAL = std::mem::transmute(h_data);
-            let ptr = GlobalLock(h_global);
-            if !ptr.is_null() {
-                let size = GlobalSize(h_global);
-                let wide_slice = std::slice::from_raw_parts(ptr as *const u16, size / 2);
-                if let Some(end) = wide_slice.iter().position(|&c| c == 0) {
-                    result = String::from_utf16_lossy(&wide_slice[..end]);
-                }
-            }
-            let _ = GlobalUnlock(h_global);
-        }
-        let _ = CloseClipboard();
+/// Clipboard input mode: run the preset on the clipboard's current text, no Ctrl+C
+pub fn process_clipboard(preset_idx: usize, streaming_override: Option<bool>) -> bool {
+    let clipboard_text = crate::overlay::utils::get_clipboard_text(HWND::default());
+    if clipboard_text.trim().is_empty() {
+        let ui_lang = APP.lock().unwrap().config.ui_language.clone();
+        let locale = crate::gui::locale::LocaleText::get(&ui_lang);
+        crate::overlay::auto_copy_badge::show_error_notification(locale.clipboard_empty_error);
+        return false;
     }
-    result
+    process_selected_text(preset_idx, clipboard_text, streaming_override);
+    true
 }
 
-fn process_selected_text(preset_idx: usize, clipboard_text: String) {
+fn process_selected_text(
+    preset_idx: usize,
+    clipboard_text: String,
+    streaming_override: Option<bool>,
+) {
     unsafe {
-        let (is_master, _original_mode) = {
-            let app = APP.lock().unwrap();
-            let p = &app.config.presets[preset_idx];
-            (p.is_master, p.text_input_mode.clone())
-        };
+        let master = APP.lock().unwrap().config.presets[preset_idx].clone();
 
-        let final_preset_idx = if is_master {
+        let from_clipboard = master.text_input_mode == "clipboard";
+        let final_preset_idx = if master.is_master {
             let mut cursor_pos = POINT { x: 0, y: 0 };
             let _ = GetCursorPos(&mut cursor_pos);
-            let selected =
-                crate::overlay::preset_wheel::show_preset_wheel("text", Some("select"), cursor_pos);
+            let selected = crate::overlay::preset_wheel::choose_preset(
+                &master,
+                "text",
+                Some(if from_clipboard {
+                    "clipboard"
+                } else {
+                    "select"
+                }),
+                cursor_pos,
+            );
             if let Some(idx) = selected {
                 idx
             } else {
@@ -1023,7 +1130,12 @@ fn process_selected_text(preset_idx: usize, clipboard_text: String) {
             )
         };
 
-        preset.text_input_mode = "select".to_string();
+        if from_clipboard {
+            preset.text_input_mode = "clipboard".to_string();
+        } else {
+            preset.text_input_mode = "select".to_string();
+            crate::overlay::selection_history::record_selection(&clipboard_text);
+        }
 
         let center_rect = RECT {
             left: (screen_w - 700) / 2,
@@ -1046,6 +1158,7 @@ fn process_selected_text(preset_idx: usize, clipboard_text: String) {
             preset,
             localized_name,
             cancel_hotkey,
+            streaming_override,
         );
         // NOTE: Continuous retrigger is now handled at mouse release, not here
     }
@@ -191,6 +191,7 @@ fn process_selected_text(preset_idx: usize, clipboard_text: String) {
             preset,
             localized_name,
             cancel_hotkey,
+            None,
         );
     }
 }
@@ -294,6 +295,7 @@ pub fn show_text_selection_tag(preset_idx: usize) {
         let initial_text = match lang.as_str() {
             "vi" => "Bôi đen văn bản...",
             "ko" => "텍스트 선택...",
+            "ja" => "テキストを選択...",
             _ => "Select text...",
         };
 
@@ -389,6 +391,7 @@ pub fn show_text_selection_tag(preset_idx: usize) {
                         match lang.as_str() {
                             "vi" => "Thả chuột để xử lý",
                             "ko" => "처리를 위해 마우스를 놓으세요",
+                            "ja" => "離すと処理します",
                             _ => "Release to process",
                         }
                     } else {
@@ -70,8 +70,10 @@ impl raw_window_handle::HasWindowHandle for HwndWrapper {
 /// Show the tray popup at cursor position
 pub fn show_tray_popup() {
     unsafe {
-        // Fallback to native menu if WebView failed completely
-        if WEBVIEW_INIT_FAILED.load(Ordering::SeqCst) {
+        // Fallback to native menu if WebView failed completely or can't exist
+        if WEBVIEW_INIT_FAILED.load(Ordering::SeqCst)
+            || !crate::overlay::webview_runtime::is_available()
+        {
             show_native_context_menu();
             return;
         }
@@ -196,21 +198,25 @@ fn generate_popup_html() -> String {
         let settings = match lang.as_str() {
             "vi" => "Cài đặt",
             "ko" => "설정",
+            "ja" => "設定",
             _ => "Settings",
         };
         let bubble = match lang.as_str() {
             "vi" => "Hiện bong bóng",
             "ko" => "즐겨찾기 버블",
+            "ja" => "お気に入りバブル",
             _ => "Favorite Bubble",
         };
         let stop_tts = match lang.as_str() {
             "vi" => "Dừng đọc",
             "ko" => "재생 중인 모든 음성 중지",
+            "ja" => "再生中の読み上げをすべて停止",
             _ => "Stop All Playing TTS",
         };
         let quit = match lang.as_str() {
             "vi" => "Thoát",
             "ko" => "종료",
+            "ja" => "終了",
             _ => "Quit",
         };
         let checked = app.config.show_favorite_bubble;
@@ -851,21 +857,25 @@ unsafe fn show_native_context_menu() {
         let settings = match lang.as_str() {
             "vi" => "Cài đặt",
             "ko" => "설정",
+            "ja" => "設定",
             _ => "Settings",
         };
         let bubble = match lang.as_str() {
             "vi" => "Hiện bong bóng",
             "ko" => "즐겨찾기 버블",
+            "ja" => "お気に入りバブル",
             _ => "Favorite Bubble",
         };
         let stop_tts = match lang.as_str() {
             "vi" => "Dừng đọc",
             "ko" => "재생 중인 모든 음성 중지",
+            "ja" => "再生中の読み上げをすべて停止",
             _ => "Stop All Playing TTS",
         };
         let quit = match lang.as_str() {
             "vi" => "Thoát",
             "ko" => "종료",
+            "ja" => "終了",
             _ => "Quit",
         };
         let checked = app.config.show_favorite_bubble;
@@ -1,4 +1,5 @@
 use windows::Win32::Foundation::*;
+use windows::Win32::Globalization::{LCIDToLocaleName, LOCALE_NAME_MAX_LENGTH};
 use windows::Win32::Graphics::Gdi::{
     CreateDIBitmap, GetDC, ReleaseDC, BITMAPINFO, BITMAPINFOHEADER, CBM_INIT, DIB_RGB_COLORS,
 };
@@ -18,6 +19,21 @@ use std::time::{SystemTime, UNIX_EPOCH};
 static LAST_NO_CARET_ERROR_MS: AtomicU64 = AtomicU64::new(0);
 const NO_CARET_ERROR_COOLDOWN_MS: u64 = 5000; // Show error at most once per 5 seconds
 
+/// Whether Windows' "Show animations in Windows" setting is on. Off means the
+/// user asked for reduced motion: overlays skip physics and CSS animations.
+pub fn animations_enabled() -> bool {
+    let mut enabled = windows::core::BOOL(1);
+    unsafe {
+        let _ = SystemParametersInfoW(
+            SPI_GETCLIENTAREAANIMATION,
+            0,
+            Some(&mut enabled as *mut _ as *mut std::ffi::c_void),
+            SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+        );
+    }
+    enabled.as_bool()
+}
+
 /// Checks if there's a text input element focused using UI Automation.
 /// This works for modern apps (Chrome, VS Code, Electron) unlike the legacy caret API.
 /// Returns true if a text input is focused, false otherwise.
@@ -121,6 +137,60 @@ pub fn copy_to_clipboard(text: &str, hwnd: HWND) {
     }
 }
 
+/// Separator between results collected by append-mode auto-copy
+const APPEND_SEPARATOR: &str = "\n\n";
+/// Append-mode clipboard text is trimmed from the oldest end beyond this many chars
+const MAX_APPENDED_CLIPBOARD_CHARS: usize = 100_000;
+
+/// Add `text` after the current clipboard text instead of replacing it
+pub fn append_to_clipboard(text: &str, hwnd: HWND) {
+    let existing = get_clipboard_text(hwnd);
+    let mut combined = if existing.trim().is_empty() {
+        text.to_string()
+    } else {
+        format!("{}{}{}", existing, APPEND_SEPARATOR, text)
+    };
+
+    let excess = combined
+        .chars()
+        .count()
+        .saturating_sub(MAX_APPENDED_CLIPBOARD_CHARS);
+    if excess > 0 {
+        let cut = combined
+            .char_indices()
+            .nth(excess)
+            .map_or(combined.len(), |(i, _)| i);
+        combined.drain(..cut);
+    }
+    copy_to_clipboard(&combined, hwnd);
+}
+
+/// Current clipboard text (empty if there is none)
+pub fn get_clipboard_text(hwnd: HWND) -> String {
+    let mut result = String::new();
+    unsafe {
+        if OpenClipboard(Some(hwnd)).is_ok() {
+            // CF_UNICODETEXT = 13
+            if let Ok(h_data) = GetClipboardData(13u32) {
+                let h_global = HGLOBAL(h_data.0);
+                let ptr = GlobalLock(h_global);
+                if !ptr.is_null() {
+                    let size = GlobalSize(h_global);
+                    let wide_slice = std::slice::from_raw_parts(ptr as *const u16, size / 2);
+                    let end = wide_slice
+                        .iter()
+                        .position(|&c| c == 0)
+                        .unwrap_or(wide_slice.len());
+                    result = String::from_utf16_lossy(&wide_slice[..end]);
+                }
+                let _ = GlobalUnlock(h_global);
+            }
+            let _ = CloseClipboard();
+        }
+    }
+    result
+}
+
 pub fn copy_image_to_clipboard(image_bytes: &[u8]) {
     // Convert PNG/etc bytes to BMP format using image crate
     // Clipboard expects CF_DIB which is BMP without the File Header (first 14 bytes)
@@ -204,6 +274,53 @@ pub fn copy_image_to_clipboard(image_bytes: &[u8]) {
     }
 }
 
+/// Put `path` on the clipboard as a file (CF_HDROP), so pasting into Explorer
+/// or a chat app attaches the file itself
+pub fn copy_file_to_clipboard(path: &str) -> bool {
+    // DROPFILES header followed by the double-NUL-terminated UTF-16 path list
+    let header = windows::Win32::UI::Shell::DROPFILES {
+        pFiles: std::mem::size_of::<windows::Win32::UI::Shell::DROPFILES>() as u32,
+        pt: POINT::default(),
+        fNC: false.into(),
+        fWide: true.into(),
+    };
+    let wide_path: Vec<u16> = path.encode_utf16().chain([0, 0]).collect();
+    let header_size = header.pFiles as usize;
+    let mem_size = header_size + wide_path.len() * 2;
+
+    unsafe {
+        for attempt in 0..5 {
+            if OpenClipboard(None).is_ok() {
+                let _ = EmptyClipboard();
+                let mut ok = false;
+                if let Ok(h_mem) = GlobalAlloc(GMEM_MOVEABLE | GMEM_ZEROINIT, mem_size) {
+                    let ptr = GlobalLock(h_mem) as *mut u8;
+                    std::ptr::copy_nonoverlapping(
+                        &header as *const _ as *const u8,
+                        ptr,
+                        header_size,
+                    );
+                    std::ptr::copy_nonoverlapping(
+                        wide_path.as_ptr() as *const u8,
+                        ptr.add(header_size),
+                        wide_path.len() * 2,
+                    );
+                    let _ = GlobalUnlock(h_mem);
+
+                    // CF_HDROP = 15
+                    ok = SetClipboardData(15u32, Some(HANDLE(h_mem.0))).is_ok();
+                }
+                let _ = CloseClipboard();
+                return ok;
+            }
+            if attempt < 4 {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+        }
+    }
+    false
+}
+
 /// Read image bytes from clipboard (returns PNG-encoded bytes)
 /// Returns None if no image is available in clipboard
 pub fn get_clipboard_image_bytes() -> Option<Vec<u8>> {
@@ -345,7 +462,79 @@ pub fn get_target_window_for_paste() -> Option<HWND> {
     }
 }
 
-pub fn force_focus_and_paste(hwnd_target: HWND) {
+/// Language of the keyboard layout active in `hwnd`'s thread ("Vietnamese"), if
+/// its locale maps to a known language
+pub fn keyboard_layout_language(hwnd: HWND) -> Option<String> {
+    unsafe {
+        let thread_id = GetWindowThreadProcessId(hwnd, None);
+        // The low word of the HKL is the input language's LANGID
+        let lang_id = (GetKeyboardLayout(thread_id).0 as usize & 0xFFFF) as u32;
+        let mut buffer = [0u16; LOCALE_NAME_MAX_LENGTH as usize];
+        let len = LCIDToLocaleName(lang_id, Some(&mut buffer), 0);
+        if len <= 1 {
+            return None;
+        }
+        // "vi-VN" -> "vi" -> "Vietnamese"
+        let locale = String::from_utf16_lossy(&buffer[..len as usize - 1]);
+        let code = locale.split('-').next()?;
+        isolang::Language::from_639_1(code).map(|lang| lang.to_name().to_string())
+    }
+}
+
+/// The window the preset was triggered from and the configured fallback language,
+/// for `resolve_language_var`. Locks `APP`, so call it before taking that lock.
+pub fn keyboard_language_source() -> (Option<HWND>, String) {
+    match crate::APP.lock() {
+        Ok(app) => (
+            app.last_active_window.map(|h| h.0),
+            app.config.keyboard_language_fallback.clone(),
+        ),
+        Err(_) => (None, "English".to_string()),
+    }
+}
+
+/// A block's language value as sent to the model: `SYSTEM_LANGUAGE` becomes the
+/// keyboard language of `target` (the foreground window if none), else `fallback`
+pub fn resolve_language_var(value: &str, target: Option<HWND>, fallback: &str) -> String {
+    if value != crate::config::SYSTEM_LANGUAGE {
+        return value.to_string();
+    }
+    target
+        .or_else(|| Some(unsafe { GetForegroundWindow() }))
+        .and_then(keyboard_layout_language)
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+/// Executable name of the process owning `hwnd`, without extension ("chrome")
+pub fn get_window_process_name(hwnd: HWND) -> Option<String> {
+    unsafe {
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+        if pid == 0 {
+            return None;
+        }
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+        let mut buffer = [0u16; 1024];
+        let mut size = buffer.len() as u32;
+        let result = QueryFullProcessImageNameW(
+            handle,
+            PROCESS_NAME_WIN32,
+            windows::core::PWSTR(buffer.as_mut_ptr()),
+            &mut size,
+        );
+        let _ = CloseHandle(handle);
+        result.ok()?;
+
+        let path = String::from_utf16_lossy(&buffer[..size as usize]);
+        std::path::Path::new(&path)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+    }
+}
+
+/// Focus `hwnd_target` and paste with the configured method. `text` is what the
+/// clipboard holds, used by the "type" method (empty for image pastes).
+pub fn force_focus_and_paste(hwnd_target: HWND, text: &str) {
     unsafe {
         // 1. Force focus back to the target window
         if IsWindow(Some(hwnd_target)).as_bool() {
@@ -417,7 +606,20 @@ pub fn force_focus_and_paste(hwnd_target: HWND) {
 
         std::thread::sleep(std::time::Duration::from_millis(50));
 
-        // 4. Send Ctrl+V Sequence
+        // 4. Send the configured paste
+        let (paste_method, key_delay) = {
+            let app = crate::APP.lock().unwrap();
+            (
+                app.config.paste_method.clone(),
+                std::time::Duration::from_millis(app.config.paste_key_delay_ms),
+            )
+        };
+        // Image pastes have no text to type: fall back to Ctrl+V
+        if paste_method == "type" && !text.is_empty() {
+            send_text_chars(text);
+            return;
+        }
+
         let send_input_event = |vk: u16, flags: KEYBD_EVENT_FLAGS| {
             let input = INPUT {
                 r#type: INPUT_KEYBOARD,
@@ -434,20 +636,27 @@ pub fn force_focus_and_paste(hwnd_target: HWND) {
             SendInput(&[input], std::mem::size_of::<INPUT>() as i32);
         };
 
-        // Ctrl Down
-        send_input_event(VK_CONTROL.0, KEYBD_EVENT_FLAGS(0));
-        std::thread::sleep(std::time::Duration::from_millis(50));
+        // Insert must be flagged extended, otherwise it arrives as numpad 0 with NumLock on
+        let (modifier, key, key_flags) = if paste_method == "shift_insert" {
+            (VK_SHIFT.0, VK_INSERT.0, KEYEVENTF_EXTENDEDKEY)
+        } else {
+            (VK_CONTROL.0, VK_V.0, KEYBD_EVENT_FLAGS(0))
+        };
 
-        // V Down
-        send_input_event(VK_V.0, KEYBD_EVENT_FLAGS(0));
-        std::thread::sleep(std::time::Duration::from_millis(50));
+        // Modifier Down
+        send_input_event(modifier, KEYBD_EVENT_FLAGS(0));
+        std::thread::sleep(key_delay);
 
-        // V Up
-        send_input_event(VK_V.0, KEYEVENTF_KEYUP);
-        std::thread::sleep(std::time::Duration::from_millis(50));
+        // Key Down
+        send_input_event(key, key_flags);
+        std::thread::sleep(key_delay);
 
-        // Ctrl Up
-        send_input_event(VK_CONTROL.0, KEYEVENTF_KEYUP);
+        // Key Up
+        send_input_event(key, key_flags | KEYEVENTF_KEYUP);
+        std::thread::sleep(key_delay);
+
+        // Modifier Up
+        send_input_event(modifier, KEYEVENTF_KEYUP);
     }
 }
 
@@ -506,82 +715,86 @@ pub fn type_text_to_window(hwnd_target_opt: Option<HWND>, text: &str) {
             }
         }
 
-        // Send Chars
-        // NOTE: Notepad and some other legacy apps process synthetic input slowly.
-        // We use extended delays and special space handling to avoid dropped characters.
-        let mut prev_was_space = false;
-        for c in text.chars() {
-            // If previous char was a space, add extra delay before next char
-            // This fixes Notepad dropping the first consonant after a space
-            if prev_was_space {
-                std::thread::sleep(std::time::Duration::from_millis(25));
-            }
-            prev_was_space = c == ' ';
+        send_text_chars(text);
+    }
+}
 
-            // Use VK_SPACE for space characters (more reliable in Notepad)
-            if c == ' ' {
-                let input_down = INPUT {
-                    r#type: INPUT_KEYBOARD,
-                    Anonymous: INPUT_0 {
-                        ki: KEYBDINPUT {
-                            wVk: VK_SPACE,
-                            wScan: 0,
-                            dwFlags: KEYBD_EVENT_FLAGS(0),
-                            ..Default::default()
-                        },
+/// Type `text` into the focused window as synthesized key presses
+unsafe fn send_text_chars(text: &str) {
+    // NOTE: Notepad and some other legacy apps process synthetic input slowly.
+    // We use extended delays and special space handling to avoid dropped characters.
+    let mut prev_was_space = false;
+    for c in text.chars() {
+        // If previous char was a space, add extra delay before next char
+        // This fixes Notepad dropping the first consonant after a space
+        if prev_was_space {
+            std::thread::sleep(std::time::Duration::from_millis(25));
+        }
+        prev_was_space = c == ' ';
+
+        // Use VK_SPACE for space characters (more reliable in Notepad)
+        if c == ' ' {
+            let input_down = INPUT {
+                r#type: INPUT_KEYBOARD,
+                Anonymous: INPUT_0 {
+                    ki: KEYBDINPUT {
+                        wVk: VK_SPACE,
+                        wScan: 0,
+                        dwFlags: KEYBD_EVENT_FLAGS(0),
+                        ..Default::default()
                     },
-                };
-                let input_up = INPUT {
-                    r#type: INPUT_KEYBOARD,
-                    Anonymous: INPUT_0 {
-                        ki: KEYBDINPUT {
-                            wVk: VK_SPACE,
-                            wScan: 0,
-                            dwFlags: KEYEVENTF_KEYUP,
-                            ..Default::default()
-                        },
+                },
+            };
+            let input_up = INPUT {
+                r#type: INPUT_KEYBOARD,
+                Anonymous: INPUT_0 {
+                    ki: KEYBDINPUT {
+                        wVk: VK_SPACE,
+                        wScan: 0,
+                        dwFlags: KEYEVENTF_KEYUP,
+                        ..Default::default()
                     },
-                };
-                SendInput(&[input_down], std::mem::size_of::<INPUT>() as i32);
-                std::thread::sleep(std::time::Duration::from_millis(8));
-                SendInput(&[input_up], std::mem::size_of::<INPUT>() as i32);
-                std::thread::sleep(std::time::Duration::from_millis(15));
-                continue;
-            }
+                },
+            };
+            SendInput(&[input_down], std::mem::size_of::<INPUT>() as i32);
+            std::thread::sleep(std::time::Duration::from_millis(8));
+            SendInput(&[input_up], std::mem::size_of::<INPUT>() as i32);
+            std::thread::sleep(std::time::Duration::from_millis(15));
+            continue;
+        }
 
-            let mut buffer = [0u16; 2];
-            let encoded = c.encode_utf16(&mut buffer);
+        let mut buffer = [0u16; 2];
+        let encoded = c.encode_utf16(&mut buffer);
 
-            for utf16_val in encoded.iter() {
-                let val = *utf16_val;
-                let input_down = INPUT {
-                    r#type: INPUT_KEYBOARD,
-                    Anonymous: INPUT_0 {
-                        ki: KEYBDINPUT {
-                            wVk: VIRTUAL_KEY(0),
-                            wScan: val,
-                            dwFlags: KEYEVENTF_UNICODE,
-                            ..Default::default()
-                        },
+        for utf16_val in encoded.iter() {
+            let val = *utf16_val;
+            let input_down = INPUT {
+                r#type: INPUT_KEYBOARD,
+                Anonymous: INPUT_0 {
+                    ki: KEYBDINPUT {
+                        wVk: VIRTUAL_KEY(0),
+                        wScan: val,
+                        dwFlags: KEYEVENTF_UNICODE,
+                        ..Default::default()
                     },
-                };
-                let input_up = INPUT {
-                    r#type: INPUT_KEYBOARD,
-                    Anonymous: INPUT_0 {
-                        ki: KEYBDINPUT {
-                            wVk: VIRTUAL_KEY(0),
-                            wScan: val,
-                            dwFlags: KEYEVENTF_UNICODE | KEYEVENTF_KEYUP,
-                            ..Default::default()
-                        },
+                },
+            };
+            let input_up = INPUT {
+                r#type: INPUT_KEYBOARD,
+                Anonymous: INPUT_0 {
+                    ki: KEYBDINPUT {
+                        wVk: VIRTUAL_KEY(0),
+                        wScan: val,
+                        dwFlags: KEYEVENTF_UNICODE | KEYEVENTF_KEYUP,
+                        ..Default::default()
                     },
-                };
-                // Send keydown and keyup separately with delay between for better app compatibility
-                SendInput(&[input_down], std::mem::size_of::<INPUT>() as i32);
-                std::thread::sleep(std::time::Duration::from_millis(5));
-                SendInput(&[input_up], std::mem::size_of::<INPUT>() as i32);
-                std::thread::sleep(std::time::Duration::from_millis(12));
-            }
+                },
+            };
+            // Send keydown and keyup separately with delay between for better app compatibility
+            SendInput(&[input_down], std::mem::size_of::<INPUT>() as i32);
+            std::thread::sleep(std::time::Duration::from_millis(5));
+            SendInput(&[input_up], std::mem::size_of::<INPUT>() as i32);
+            std::thread::sleep(std::time::Duration::from_millis(12));
         }
     }
 }
@@ -596,6 +809,8 @@ pub fn get_error_message(error: &str, lang: &str, model_name: Option<&str>) -> S
                     "groq" => "Groq",
                     "google" => "Google Gemini",
                     "openai" => "OpenAI",
+                    "anthropic" => "Anthropic Claude",
+                    "custom-openai" => "OpenAI-compatible",
                     other => other,
                 }
             } else {
@@ -623,6 +838,8 @@ pub fn get_error_message(error: &str, lang: &str, model_name: Option<&str>) -> S
                     "groq" => "Groq",
                     "google" => "Google Gemini",
                     "openai" => "OpenAI",
+                    "anthropic" => "Anthropic Claude",
+                    "custom-openai" => "OpenAI-compatible",
                     other => other,
                 }
             } else {
@@ -648,6 +865,29 @@ pub fn get_error_message(error: &str, lang: &str, model_name: Option<&str>) -> S
         return format_http_error(status_code, &provider, model_name, lang);
     }
 
+    // No network at all (and no reachable local fallback)
+    if crate::api::client::is_offline_error(error) {
+        return match lang {
+            "vi" => "Không có kết nối mạng. Hãy kiểm tra Internet, hoặc bật Ollama và chọn mô hình văn bản để dịch ngoại tuyến.".to_string(),
+            "ko" => "네트워크에 연결되어 있지 않습니다. 인터넷 연결을 확인하거나, 오프라인 번역을 위해 Ollama를 켜고 텍스트 모델을 선택하세요.".to_string(),
+            "ja" => "ネットワークに接続されていません。インターネット接続を確認するか、オフライン翻訳用に Ollama を有効にしてテキストモデルを選択してください。".to_string(),
+            "zh" => "没有网络连接。请检查网络,或启用 Ollama 并选择文本模型以离线翻译。".to_string(),
+            _ => "You're offline. Check your internet connection, or enable Ollama and pick a text model to translate offline.".to_string(),
+        };
+    }
+
+    // Connect / stall timeouts from the API client
+    if crate::api::client::is_timeout_error(error) {
+        let target = model_name.unwrap_or("API");
+        return match lang {
+            "vi" => format!("Hết thời gian chờ phản hồi từ {}. Mô hình có thể đang quá tải hoặc đang tải, vui lòng thử lại.", target),
+            "ko" => format!("{} 응답 시간이 초과되었습니다. 모델이 과부하 상태이거나 로딩 중일 수 있습니다. 다시 시도해 주세요.", target),
+            "ja" => format!("{} からの応答がタイムアウトしました。モデルが過負荷または読み込み中の可能性があります。再試行してください。", target),
+            "zh" => format!("{} 响应超时。模型可能过载或正在加载,请重试。", target),
+            _ => format!("Request to {} timed out. The model may be overloaded or still loading - please try again.", target),
+        };
+    }
+
     // Fallback for other errors
     match lang {
         "vi" => format!("Lỗi: {}", error),
@@ -0,0 +1,91 @@
+//! Detect the Edge WebView2 runtime that every WebView window depends on.
+//! Without it `WebViewBuilder::build*` fails, so features check here first and
+//! tell the user how to install it instead of silently doing nothing.
+
+use crate::gui::locale::LocaleText;
+use crate::APP;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use windows::core::HSTRING;
+use windows::Win32::UI::WindowsAndMessaging::{
+    MessageBoxW, IDYES, MB_ICONWARNING, MB_SETFOREGROUND, MB_TOPMOST, MB_YESNO,
+};
+
+const DOWNLOAD_URL: &str = "https://developer.microsoft.com/microsoft-edge/webview2/";
+
+/// Oldest runtime with the transparent-background support the overlays rely on
+const MIN_MAJOR_VERSION: u32 = 87;
+
+static RUNTIME_VERSION: OnceLock<Option<String>> = OnceLock::new();
+static DIALOG_OPEN: AtomicBool = AtomicBool::new(false);
+
+/// Installed runtime version (e.g. "131.0.2903.70"), None when missing.
+/// Asks WebView2 itself, so per-user, system and fixed-version installs all count.
+pub fn runtime_version() -> Option<String> {
+    RUNTIME_VERSION
+        .get_or_init(|| wry::webview_version().ok().filter(|v| !v.is_empty()))
+        .clone()
+}
+
+/// Whether a new enough runtime is installed
+pub fn is_available() -> bool {
+    runtime_version()
+        .and_then(|v| {
+            v.split('.')
+                .next()
+                .and_then(|major| major.parse::<u32>().ok())
+        })
+        .is_some_and(|major| major >= MIN_MAJOR_VERSION)
+}
+
+/// Log the runtime at startup and explain what to do when it is missing
+pub fn check_at_startup() {
+    match runtime_version() {
+        Some(version) => crate::log_info!("[WebView2] Runtime {}", version),
+        None => crate::log_info!("[WebView2] Runtime not installed"),
+    }
+    if !is_available() {
+        show_missing_dialog();
+    }
+}
+
+/// For WebView features: true if they can run, otherwise show the dialog
+pub fn ensure_available() -> bool {
+    if is_available() {
+        return true;
+    }
+    show_missing_dialog();
+    false
+}
+
+/// Non-blocking "install WebView2" prompt; Yes opens the download page
+pub fn show_missing_dialog() {
+    if DIALOG_OPEN.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let ui_language = APP
+        .lock()
+        .map(|app| app.config.ui_language.clone())
+        .unwrap_or_default();
+    let locale = LocaleText::get(&ui_language);
+    let message = if runtime_version().is_some() {
+        locale.webview2_too_old
+    } else {
+        locale.webview2_missing
+    };
+
+    std::thread::spawn(move || {
+        let choice = unsafe {
+            MessageBoxW(
+                None,
+                &HSTRING::from(message),
+                &HSTRING::from("Screen Goated Toolbox - WebView2"),
+                MB_YESNO | MB_ICONWARNING | MB_TOPMOST | MB_SETFOREGROUND,
+            )
+        };
+        if choice == IDYES {
+            let _ = open::that(DOWNLOAD_URL);
+        }
+        DIALOG_OPEN.store(false, Ordering::SeqCst);
+    });
+}
@@ -0,0 +1,100 @@
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use crate::api::client::TokenUsage;
+
+/// Token totals for one preset in the current month
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PresetUsage {
+    /// "YYYY-MM" the counters belong to; counters restart when the month changes
+    pub month: String,
+    pub requests: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    /// True if any request in this month had to be estimated from character counts
+    #[serde(default)]
+    pub estimated: bool,
+}
+
+impl PresetUsage {
+    pub fn total_tokens(&self) -> u64 {
+        self.prompt_tokens + self.completion_tokens
+    }
+}
+
+/// Per-preset token accounting, persisted next to the history database
+pub struct TokenUsageManager {
+    pub totals: Arc<Mutex<HashMap<String, PresetUsage>>>,
+}
+
+impl TokenUsageManager {
+    pub fn new() -> Self {
+        let path = get_usage_path();
+        let totals = fs::File::open(&path)
+            .ok()
+            .and_then(|f| serde_json::from_reader(f).ok())
+            .unwrap_or_default();
+
+        Self {
+            totals: Arc::new(Mutex::new(totals)),
+        }
+    }
+
+    /// Add one request's usage to the preset's monthly totals and persist
+    pub fn record(&self, preset_id: &str, usage: TokenUsage) {
+        let month = current_month();
+        let mut totals = self.totals.lock().unwrap();
+
+        let entry = totals.entry(preset_id.to_string()).or_default();
+        if entry.month != month {
+            *entry = PresetUsage {
+                month,
+                ..Default::default()
+            };
+        }
+        entry.requests += 1;
+        entry.prompt_tokens += usage.prompt_tokens;
+        entry.completion_tokens += usage.completion_tokens;
+        entry.estimated |= usage.estimated;
+
+        if let Ok(file) = fs::File::create(get_usage_path()) {
+            let _ = serde_json::to_writer_pretty(file, &*totals);
+        }
+    }
+
+    /// Totals for the current month only (stale months are left out)
+    pub fn current_month_totals(&self) -> HashMap<String, PresetUsage> {
+        let month = current_month();
+        self.totals
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, u)| u.month == month)
+            .map(|(id, u)| (id.clone(), u.clone()))
+            .collect()
+    }
+}
+
+/// Record usage for a preset run (no-op if the app state is unavailable)
+pub fn record_preset_usage(preset_id: &str, usage: TokenUsage) {
+    let manager = crate::APP.lock().ok().map(|app| app.token_usage.clone());
+    if let Some(manager) = manager {
+        manager.record(preset_id, usage);
+    }
+}
+
+fn current_month() -> String {
+    Local::now().format("%Y-%m").to_string()
+}
+
+fn get_usage_path() -> PathBuf {
+    let config_dir = dirs::config_dir()
+        .unwrap_or_default()
+        .join("screen-goated-toolbox");
+    let _ = fs::create_dir_all(&config_dir);
+    config_dir.join("token_usage.json")
+}